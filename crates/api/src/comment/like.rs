@@ -16,7 +16,10 @@ use lemmy_db_schema::{
   traits::Likeable,
 };
 use lemmy_db_views::structs::{CommentView, LocalUserView};
-use lemmy_utils::{error::LemmyError, ConnectionId};
+use lemmy_utils::{
+  error::{LemmyError, LemmyErrorExt, LemmyErrorType},
+  ConnectionId,
+};
 
 #[async_trait::async_trait(?Send)]
 impl Perform for CreateCommentLike {
@@ -75,7 +78,7 @@ impl Perform for CreateCommentLike {
       let like_form2 = like_form.clone();
       CommentLike::like(context.pool(), &like_form2)
         .await
-        .map_err(|e| LemmyError::from_error_message(e, "couldnt_like_comment"))?;
+        .with_lemmy_type(LemmyErrorType::CouldntLikeComment)?;
     }
 
     send_comment_ws_message(
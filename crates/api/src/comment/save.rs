@@ -10,7 +10,10 @@ use lemmy_db_schema::{
   traits::Saveable,
 };
 use lemmy_db_views::structs::CommentView;
-use lemmy_utils::{error::LemmyError, ConnectionId};
+use lemmy_utils::{
+  error::{LemmyError, LemmyErrorExt, LemmyErrorType},
+  ConnectionId,
+};
 
 #[async_trait::async_trait(?Send)]
 impl Perform for SaveComment {
@@ -34,11 +37,11 @@ impl Perform for SaveComment {
     if data.save {
       CommentSaved::save(context.pool(), &comment_saved_form)
         .await
-        .map_err(|e| LemmyError::from_error_message(e, "couldnt_save_comment"))?;
+        .with_lemmy_type(LemmyErrorType::CouldntSaveComment)?;
     } else {
       CommentSaved::unsave(context.pool(), &comment_saved_form)
         .await
-        .map_err(|e| LemmyError::from_error_message(e, "couldnt_save_comment"))?;
+        .with_lemmy_type(LemmyErrorType::CouldntSaveComment)?;
     }
 
     let comment_id = data.comment_id;
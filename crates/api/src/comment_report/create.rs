@@ -3,7 +3,12 @@ use actix_web::web::Data;
 use lemmy_api_common::{
   comment::{CommentReportResponse, CreateCommentReport},
   context::LemmyContext,
-  utils::{check_community_ban, get_local_user_view_from_jwt, send_new_report_email_to_admins},
+  utils::{
+    check_community_ban,
+    check_community_rule,
+    get_local_user_view_from_jwt,
+    send_new_report_email_to_admins,
+  },
   websocket::UserOperation,
 };
 use lemmy_db_schema::{
@@ -14,7 +19,10 @@ use lemmy_db_schema::{
   traits::Reportable,
 };
 use lemmy_db_views::structs::{CommentReportView, CommentView};
-use lemmy_utils::{error::LemmyError, ConnectionId};
+use lemmy_utils::{
+  error::{LemmyError, LemmyErrorExt, LemmyErrorType},
+  ConnectionId,
+};
 
 /// Creates a comment report and notifies the moderators of the community
 #[async_trait::async_trait(?Send)]
@@ -40,17 +48,19 @@ impl Perform for CreateCommentReport {
     let comment_view = CommentView::read(context.pool(), comment_id, None).await?;
 
     check_community_ban(person_id, comment_view.community.id, context.pool()).await?;
+    check_community_rule(data.rule_id, comment_view.community.id, context.pool()).await?;
 
     let report_form = CommentReportForm {
       creator_id: person_id,
       comment_id,
       original_comment_text: comment_view.comment.content,
       reason: reason.to_owned(),
+      rule_id: data.rule_id,
     };
 
     let report = CommentReport::report(context.pool(), &report_form)
       .await
-      .map_err(|e| LemmyError::from_error_message(e, "couldnt_create_report"))?;
+      .with_lemmy_type(LemmyErrorType::CouldntCreateReport)?;
 
     let comment_report_view = CommentReportView::read(context.pool(), report.id, person_id).await?;
 
@@ -0,0 +1,95 @@
+use crate::Perform;
+use actix_web::web::Data;
+use lemmy_api_common::{
+  comment::{CommentReportResponse, EscalateCommentReport},
+  context::LemmyContext,
+  utils::{
+    get_local_user_view_from_jwt,
+    is_admin,
+    is_mod_or_admin,
+    send_new_report_email_to_admins,
+  },
+  websocket::UserOperation,
+};
+use lemmy_db_schema::{
+  newtypes::CommunityId,
+  source::comment_report::CommentReport,
+  traits::Reportable,
+};
+use lemmy_db_views::structs::CommentReportView;
+use lemmy_utils::{
+  error::{LemmyError, LemmyErrorExt, LemmyErrorType},
+  ConnectionId,
+};
+
+/// Escalates a comment report to the site admins, or hands it back to the community moderators
+#[async_trait::async_trait(?Send)]
+impl Perform for EscalateCommentReport {
+  type Response = CommentReportResponse;
+
+  #[tracing::instrument(skip(context, websocket_id))]
+  async fn perform(
+    &self,
+    context: &Data<LemmyContext>,
+    websocket_id: Option<ConnectionId>,
+  ) -> Result<CommentReportResponse, LemmyError> {
+    let data: &EscalateCommentReport = self;
+    let local_user_view =
+      get_local_user_view_from_jwt(&data.auth, context.pool(), context.secret()).await?;
+
+    let report_id = data.report_id;
+    let person_id = local_user_view.person.id;
+    let report = CommentReportView::read(context.pool(), report_id, person_id).await?;
+
+    is_mod_or_admin(context.pool(), person_id, report.community.id).await?;
+
+    // Once escalated, only the admins can handle the report
+    if report.comment_report.escalated {
+      is_admin(&local_user_view)?;
+    }
+
+    CommentReport::escalate(context.pool(), report_id, data.escalated)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntEscalateReport)?;
+
+    let comment_report_view = CommentReportView::read(context.pool(), report_id, person_id).await?;
+
+    // Escalated reports always reach the admins, regardless of reports_email_admins
+    if data.escalated {
+      send_new_report_email_to_admins(
+        &comment_report_view.creator.name,
+        &comment_report_view.comment_creator.name,
+        context.pool(),
+        context.settings(),
+      )
+      .await?;
+    }
+
+    let res = CommentReportResponse {
+      comment_report_view,
+    };
+
+    context
+      .chat_server()
+      .send_mod_room_message(
+        UserOperation::EscalateCommentReport,
+        &res,
+        report.community.id,
+        websocket_id,
+      )
+      .await?;
+
+    // The admins room
+    context
+      .chat_server()
+      .send_mod_room_message(
+        UserOperation::EscalateCommentReport,
+        &res,
+        CommunityId(0),
+        websocket_id,
+      )
+      .await?;
+
+    Ok(res)
+  }
+}
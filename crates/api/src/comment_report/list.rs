@@ -37,6 +37,9 @@ impl Perform for ListCommentReports {
       .admin(admin)
       .community_id(community_id)
       .unresolved_only(unresolved_only)
+      .comment_id(data.comment_id)
+      .assignee_id(data.assignee_id)
+      .escalated_only(data.escalated_only)
       .page(page)
       .limit(limit)
       .build()
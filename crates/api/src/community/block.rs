@@ -13,7 +13,10 @@ use lemmy_db_schema::{
   traits::{Blockable, Followable},
 };
 use lemmy_db_views_actor::structs::CommunityView;
-use lemmy_utils::{error::LemmyError, ConnectionId};
+use lemmy_utils::{
+  error::{LemmyError, LemmyErrorExt, LemmyErrorType},
+  ConnectionId,
+};
 
 #[async_trait::async_trait(?Send)]
 impl Perform for BlockCommunity {
@@ -39,7 +42,7 @@ impl Perform for BlockCommunity {
     if data.block {
       CommunityBlock::block(context.pool(), &community_block_form)
         .await
-        .map_err(|e| LemmyError::from_error_message(e, "community_block_already_exists"))?;
+        .with_lemmy_type(LemmyErrorType::CommunityBlockAlreadyExists)?;
 
       // Also, unfollow the community, and send a federated unfollow
       let community_follower_form = CommunityFollowerForm {
@@ -54,7 +57,7 @@ impl Perform for BlockCommunity {
     } else {
       CommunityBlock::unblock(context.pool(), &community_block_form)
         .await
-        .map_err(|e| LemmyError::from_error_message(e, "community_block_already_exists"))?;
+        .with_lemmy_type(LemmyErrorType::CommunityBlockAlreadyExists)?;
     }
 
     let community_view = CommunityView::read(context.pool(), community_id, Some(person_id)).await?;
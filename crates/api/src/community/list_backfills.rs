@@ -0,0 +1,31 @@
+use crate::Perform;
+use actix_web::web::Data;
+use lemmy_api_common::{
+  community::{ListCommunityBackfills, ListCommunityBackfillsResponse},
+  context::LemmyContext,
+  utils::{get_local_user_view_from_jwt, is_admin},
+};
+use lemmy_db_views_actor::structs::CommunityBackfillView;
+use lemmy_utils::{error::LemmyError, ConnectionId};
+
+/// Lists the history fetches of remote communities, so that admins can follow their progress.
+#[async_trait::async_trait(?Send)]
+impl Perform for ListCommunityBackfills {
+  type Response = ListCommunityBackfillsResponse;
+
+  #[tracing::instrument(skip(context, _websocket_id))]
+  async fn perform(
+    &self,
+    context: &Data<LemmyContext>,
+    _websocket_id: Option<ConnectionId>,
+  ) -> Result<ListCommunityBackfillsResponse, LemmyError> {
+    let data: &ListCommunityBackfills = self;
+    let local_user_view =
+      get_local_user_view_from_jwt(&data.auth, context.pool(), context.secret()).await?;
+    is_admin(&local_user_view)?;
+
+    let backfills = CommunityBackfillView::list(context.pool(), data.page, data.limit).await?;
+
+    Ok(ListCommunityBackfillsResponse { backfills })
+  }
+}
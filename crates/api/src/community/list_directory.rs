@@ -0,0 +1,52 @@
+use crate::Perform;
+use actix_web::web::Data;
+use lemmy_api_common::{
+  community::{ListDirectoryCommunities, ListDirectoryCommunitiesResponse},
+  context::LemmyContext,
+  utils::{check_private_instance, get_local_user_view_from_jwt_opt},
+};
+use lemmy_db_schema::{
+  impls::directory_community::DirectoryCommunityQuery,
+  source::local_site::LocalSite,
+};
+use lemmy_utils::{
+  error::{LemmyError, LemmyErrorType},
+  ConnectionId,
+};
+
+#[async_trait::async_trait(?Send)]
+impl Perform for ListDirectoryCommunities {
+  type Response = ListDirectoryCommunitiesResponse;
+
+  #[tracing::instrument(skip(context, _websocket_id))]
+  async fn perform(
+    &self,
+    context: &Data<LemmyContext>,
+    _websocket_id: Option<ConnectionId>,
+  ) -> Result<ListDirectoryCommunitiesResponse, LemmyError> {
+    let data: &ListDirectoryCommunities = self;
+    let local_user_view =
+      get_local_user_view_from_jwt_opt(data.auth.as_ref(), context.pool(), context.secret())
+        .await?;
+    let local_site = LocalSite::read(context.pool()).await?;
+
+    check_private_instance(&local_user_view, &local_site)?;
+    if !local_site.community_directory {
+      return Err(LemmyErrorType::CommunityDirectoryDisabled.into());
+    }
+
+    let show_nsfw = local_user_view.map(|l| l.local_user.show_nsfw);
+    let communities = DirectoryCommunityQuery::builder()
+      .pool(context.pool())
+      .search_term(data.q.clone())
+      .language_id(data.language_id)
+      .show_nsfw(show_nsfw)
+      .page(data.page)
+      .limit(data.limit)
+      .build()
+      .list()
+      .await?;
+
+    Ok(ListDirectoryCommunitiesResponse { communities })
+  }
+}
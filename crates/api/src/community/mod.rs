@@ -3,4 +3,6 @@ mod ban;
 mod block;
 mod follow;
 mod hide;
+mod list_backfills;
+mod list_directory;
 mod transfer;
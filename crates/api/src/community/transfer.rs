@@ -14,7 +14,11 @@ use lemmy_db_schema::{
   traits::{Crud, Joinable},
 };
 use lemmy_db_views_actor::structs::{CommunityModeratorView, CommunityView, PersonViewSafe};
-use lemmy_utils::{error::LemmyError, location_info, ConnectionId};
+use lemmy_utils::{
+  error::{LemmyError, LemmyErrorExt, LemmyErrorType},
+  location_info,
+  ConnectionId,
+};
 
 // TODO: we dont do anything for federation here, it should be updated the next time the community
 //       gets fetched. i hope we can get rid of the community creator role soon.
@@ -46,7 +50,7 @@ impl Perform for TransferCommunity {
         .map(|a| a.person.id)
         .any(|x| x == local_user_view.person.id)
     {
-      return Err(LemmyError::from_message("not_an_admin"));
+      return Err(LemmyErrorType::NotAnAdmin.into());
     }
 
     // You have to re-do the community_moderator table, reordering it.
@@ -73,7 +77,7 @@ impl Perform for TransferCommunity {
 
       CommunityModerator::join(context.pool(), &community_moderator_form)
         .await
-        .map_err(|e| LemmyError::from_error_message(e, "community_moderator_already_exists"))?;
+        .with_lemmy_type(LemmyErrorType::CommunityModeratorAlreadyExists)?;
     }
 
     // Mod tables
@@ -90,12 +94,12 @@ impl Perform for TransferCommunity {
     let person_id = local_user_view.person.id;
     let community_view = CommunityView::read(context.pool(), community_id, Some(person_id))
       .await
-      .map_err(|e| LemmyError::from_error_message(e, "couldnt_find_community"))?;
+      .with_lemmy_type(LemmyErrorType::CouldntFindCommunity)?;
 
     let community_id = data.community_id;
     let moderators = CommunityModeratorView::for_community(context.pool(), community_id)
       .await
-      .map_err(|e| LemmyError::from_error_message(e, "couldnt_find_community"))?;
+      .with_lemmy_type(LemmyErrorType::CouldntFindCommunity)?;
 
     // Return the jwt
     Ok(GetCommunityResponse {
@@ -105,6 +109,7 @@ impl Perform for TransferCommunity {
       online: 0,
       discussion_languages: vec![],
       default_post_language: None,
+      rules: vec![],
     })
   }
 }
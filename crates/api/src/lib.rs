@@ -2,7 +2,11 @@ use actix_web::web::Data;
 use captcha::Captcha;
 use lemmy_api_common::{context::LemmyContext, utils::local_site_to_slur_regex};
 use lemmy_db_schema::source::local_site::LocalSite;
-use lemmy_utils::{error::LemmyError, utils::slurs::check_slurs, ConnectionId};
+use lemmy_utils::{
+  error::{LemmyError, LemmyErrorType},
+  utils::slurs::check_slurs,
+  ConnectionId,
+};
 
 mod comment;
 mod comment_report;
@@ -12,6 +16,7 @@ mod post;
 mod post_report;
 mod private_message;
 mod private_message_report;
+mod report_comment;
 mod site;
 mod websocket;
 
@@ -47,10 +52,10 @@ pub(crate) fn check_report_reason(reason: &str, local_site: &LocalSite) -> Resul
 
   check_slurs(reason, slur_regex)?;
   if reason.is_empty() {
-    return Err(LemmyError::from_message("report_reason_required"));
+    return Err(LemmyErrorType::ReportReasonRequired.into());
   }
   if reason.chars().count() > 1000 {
-    return Err(LemmyError::from_message("report_too_long"));
+    return Err(LemmyErrorType::ReportTooLong.into());
   }
   Ok(())
 }
@@ -10,7 +10,10 @@ use lemmy_db_schema::{
   traits::Blockable,
 };
 use lemmy_db_views_actor::structs::PersonViewSafe;
-use lemmy_utils::{error::LemmyError, ConnectionId};
+use lemmy_utils::{
+  error::{LemmyError, LemmyErrorExt, LemmyErrorType},
+  ConnectionId,
+};
 
 #[async_trait::async_trait(?Send)]
 impl Perform for BlockPerson {
@@ -31,7 +34,7 @@ impl Perform for BlockPerson {
 
     // Don't let a person block themselves
     if target_id == person_id {
-      return Err(LemmyError::from_message("cant_block_yourself"));
+      return Err(LemmyErrorType::CantBlockYourself.into());
     }
 
     let person_block_form = PersonBlockForm {
@@ -42,17 +45,17 @@ impl Perform for BlockPerson {
     let target_person_view = PersonViewSafe::read(context.pool(), target_id).await?;
 
     if target_person_view.person.admin {
-      return Err(LemmyError::from_message("cant_block_admin"));
+      return Err(LemmyErrorType::CantBlockAdmin.into());
     }
 
     if data.block {
       PersonBlock::block(context.pool(), &person_block_form)
         .await
-        .map_err(|e| LemmyError::from_error_message(e, "person_block_already_exists"))?;
+        .with_lemmy_type(LemmyErrorType::PersonBlockAlreadyExists)?;
     } else {
       PersonBlock::unblock(context.pool(), &person_block_form)
         .await
-        .map_err(|e| LemmyError::from_error_message(e, "person_block_already_exists"))?;
+        .with_lemmy_type(LemmyErrorType::PersonBlockAlreadyExists)?;
     }
 
     let res = BlockPersonResponse {
@@ -47,7 +47,7 @@ impl Perform for GetCaptcha {
     };
 
     // Stores the captcha item on the queue
-    context.chat_server().add_captcha(captcha_item)?;
+    context.chat_server().add_captcha(captcha_item).await?;
 
     Ok(GetCaptchaResponse {
       ok: Some(CaptchaResponse { png, wav, uuid }),
@@ -0,0 +1,30 @@
+use crate::Perform;
+use actix_web::web::Data;
+use lemmy_api_common::{
+  context::LemmyContext,
+  person::{ListMyCommunityBans, ListMyCommunityBansResponse},
+  utils::get_local_user_view_from_jwt,
+};
+use lemmy_db_views_actor::structs::CommunityPersonBanView;
+use lemmy_utils::{error::LemmyError, ConnectionId};
+
+#[async_trait::async_trait(?Send)]
+impl Perform for ListMyCommunityBans {
+  type Response = ListMyCommunityBansResponse;
+
+  #[tracing::instrument(skip(context, _websocket_id))]
+  async fn perform(
+    &self,
+    context: &Data<LemmyContext>,
+    _websocket_id: Option<ConnectionId>,
+  ) -> Result<Self::Response, LemmyError> {
+    let data: &ListMyCommunityBans = self;
+    let local_user_view =
+      get_local_user_view_from_jwt(&data.auth, context.pool(), context.secret()).await?;
+
+    let bans =
+      CommunityPersonBanView::for_person(context.pool(), local_user_view.person.id).await?;
+
+    Ok(Self::Response { bans })
+  }
+}
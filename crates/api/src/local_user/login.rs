@@ -8,7 +8,11 @@ use lemmy_api_common::{
 };
 use lemmy_db_schema::source::local_site::LocalSite;
 use lemmy_db_views::structs::LocalUserView;
-use lemmy_utils::{claims::Claims, error::LemmyError, ConnectionId};
+use lemmy_utils::{
+  claims::Claims,
+  error::{LemmyError, LemmyErrorExt, LemmyErrorType},
+  ConnectionId,
+};
 
 #[async_trait::async_trait(?Send)]
 impl Perform for Login {
@@ -28,7 +32,7 @@ impl Perform for Login {
     let username_or_email = data.username_or_email.clone();
     let local_user_view = LocalUserView::find_by_email_or_name(context.pool(), &username_or_email)
       .await
-      .map_err(|e| LemmyError::from_error_message(e, "couldnt_find_that_username_or_email"))?;
+      .with_lemmy_type(LemmyErrorType::CouldntFindThatUsernameOrEmail)?;
 
     // Verify the password
     let valid: bool = verify(
@@ -37,7 +41,7 @@ impl Perform for Login {
     )
     .unwrap_or(false);
     if !valid {
-      return Err(LemmyError::from_message("password_incorrect"));
+      return Err(LemmyErrorType::PasswordIncorrect.into());
     }
     check_user_valid(
       local_user_view.person.banned,
@@ -46,7 +50,7 @@ impl Perform for Login {
     )?;
 
     if local_site.require_email_verification && !local_user_view.local_user.email_verified {
-      return Err(LemmyError::from_message("email_not_verified"));
+      return Err(LemmyErrorType::EmailNotVerified.into());
     }
 
     check_registration_application(&local_user_view, &local_site, context.pool()).await?;
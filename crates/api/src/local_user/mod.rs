@@ -5,6 +5,7 @@ mod change_password;
 mod change_password_after_reset;
 mod get_captcha;
 mod list_banned;
+mod list_community_bans;
 mod login;
 mod notifications;
 mod report_count;
@@ -17,7 +17,7 @@ use lemmy_db_schema::{
 };
 use lemmy_utils::{
   claims::Claims,
-  error::LemmyError,
+  error::{LemmyError, LemmyErrorExt, LemmyErrorType},
   utils::validation::{is_valid_display_name, is_valid_matrix_id},
   ConnectionId,
 };
@@ -58,13 +58,13 @@ impl Perform for SaveUserSettings {
     // When the site requires email, make sure email is not Some(None). IE, an overwrite to a None value
     if let Some(email) = &email {
       if email.is_none() && local_site.require_email_verification {
-        return Err(LemmyError::from_message("email_required"));
+        return Err(LemmyErrorType::EmailRequired.into());
       }
     }
 
     if let Some(Some(bio)) = &bio {
       if bio.chars().count() > 300 {
-        return Err(LemmyError::from_message("bio_length_overflow"));
+        return Err(LemmyErrorType::BioLengthOverflow.into());
       }
     }
 
@@ -73,13 +73,13 @@ impl Perform for SaveUserSettings {
         display_name.trim(),
         local_site.actor_name_max_length as usize,
       ) {
-        return Err(LemmyError::from_message("invalid_username"));
+        return Err(LemmyErrorType::InvalidUsername.into());
       }
     }
 
     if let Some(Some(matrix_user_id)) = &matrix_user_id {
       if !is_valid_matrix_id(matrix_user_id) {
-        return Err(LemmyError::from_message("invalid_matrix_id"));
+        return Err(LemmyErrorType::InvalidMatrixId.into());
       }
     }
 
@@ -99,7 +99,7 @@ impl Perform for SaveUserSettings {
 
     Person::update(context.pool(), person_id, &person_form)
       .await
-      .map_err(|e| LemmyError::from_error_message(e, "user_already_exists"))?;
+      .with_lemmy_type(LemmyErrorType::UserAlreadyExists)?;
 
     if let Some(discussion_languages) = data.discussion_languages.clone() {
       LocalUserLanguage::update(context.pool(), discussion_languages, local_user_id).await?;
@@ -127,12 +127,12 @@ impl Perform for SaveUserSettings {
         let err_type = if e.to_string()
           == "duplicate key value violates unique constraint \"local_user_email_key\""
         {
-          "email_already_exists"
+          LemmyErrorType::EmailAlreadyExists
         } else {
-          "user_already_exists"
+          LemmyErrorType::UserAlreadyExists
         };
 
-        return Err(LemmyError::from_error_message(e, err_type));
+        return Err(e).with_lemmy_type(err_type);
       }
     };
 
@@ -19,7 +19,10 @@ use lemmy_db_schema::{
   },
   traits::{Crud, Likeable},
 };
-use lemmy_utils::{error::LemmyError, ConnectionId};
+use lemmy_utils::{
+  error::{LemmyError, LemmyErrorExt, LemmyErrorType},
+  ConnectionId,
+};
 
 #[async_trait::async_trait(?Send)]
 impl Perform for CreatePostLike {
@@ -63,7 +66,7 @@ impl Perform for CreatePostLike {
       let like_form2 = like_form.clone();
       PostLike::like(context.pool(), &like_form2)
         .await
-        .map_err(|e| LemmyError::from_error_message(e, "couldnt_like_post"))?;
+        .with_lemmy_type(LemmyErrorType::CouldntLikePost)?;
     }
 
     // Mark the post as read
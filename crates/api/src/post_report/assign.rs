@@ -0,0 +1,69 @@
+use crate::Perform;
+use actix_web::web::Data;
+use lemmy_api_common::{
+  context::LemmyContext,
+  post::{AssignPostReport, PostReportResponse},
+  utils::{get_local_user_view_from_jwt, is_admin, is_mod_or_admin},
+  websocket::UserOperation,
+};
+use lemmy_db_schema::{source::post_report::PostReport, traits::Reportable};
+use lemmy_db_views::structs::PostReportView;
+use lemmy_utils::{
+  error::{LemmyError, LemmyErrorExt, LemmyErrorExt2, LemmyErrorType},
+  ConnectionId,
+};
+
+/// Assigns a post report to a moderator of the community, or unassigns it
+#[async_trait::async_trait(?Send)]
+impl Perform for AssignPostReport {
+  type Response = PostReportResponse;
+
+  #[tracing::instrument(skip(context, websocket_id))]
+  async fn perform(
+    &self,
+    context: &Data<LemmyContext>,
+    websocket_id: Option<ConnectionId>,
+  ) -> Result<PostReportResponse, LemmyError> {
+    let data: &AssignPostReport = self;
+    let local_user_view =
+      get_local_user_view_from_jwt(&data.auth, context.pool(), context.secret()).await?;
+
+    let report_id = data.report_id;
+    let person_id = local_user_view.person.id;
+    let report = PostReportView::read(context.pool(), report_id, person_id).await?;
+
+    is_mod_or_admin(context.pool(), person_id, report.community.id).await?;
+
+    // Once escalated, only the admins can handle the report
+    if report.post_report.escalated {
+      is_admin(&local_user_view)?;
+    }
+
+    // The assignee needs to be able to resolve the report
+    if let Some(assignee_id) = data.assignee_id {
+      is_mod_or_admin(context.pool(), assignee_id, report.community.id)
+        .await
+        .with_lemmy_type(LemmyErrorType::ReportAssigneeNotAModOrAdmin)?;
+    }
+
+    PostReport::assign(context.pool(), report_id, data.assignee_id)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntAssignReport)?;
+
+    let post_report_view = PostReportView::read(context.pool(), report_id, person_id).await?;
+
+    let res = PostReportResponse { post_report_view };
+
+    context
+      .chat_server()
+      .send_mod_room_message(
+        UserOperation::AssignPostReport,
+        &res,
+        report.community.id,
+        websocket_id,
+      )
+      .await?;
+
+    Ok(res)
+  }
+}
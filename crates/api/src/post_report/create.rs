@@ -3,7 +3,12 @@ use actix_web::web::Data;
 use lemmy_api_common::{
   context::LemmyContext,
   post::{CreatePostReport, PostReportResponse},
-  utils::{check_community_ban, get_local_user_view_from_jwt, send_new_report_email_to_admins},
+  utils::{
+    check_community_ban,
+    check_community_rule,
+    get_local_user_view_from_jwt,
+    send_new_report_email_to_admins,
+  },
   websocket::UserOperation,
 };
 use lemmy_db_schema::{
@@ -14,7 +19,10 @@ use lemmy_db_schema::{
   traits::Reportable,
 };
 use lemmy_db_views::structs::{PostReportView, PostView};
-use lemmy_utils::{error::LemmyError, ConnectionId};
+use lemmy_utils::{
+  error::{LemmyError, LemmyErrorExt, LemmyErrorType},
+  ConnectionId,
+};
 
 /// Creates a post report and notifies the moderators of the community
 #[async_trait::async_trait(?Send)]
@@ -40,6 +48,7 @@ impl Perform for CreatePostReport {
     let post_view = PostView::read(context.pool(), post_id, None).await?;
 
     check_community_ban(person_id, post_view.community.id, context.pool()).await?;
+    check_community_rule(data.rule_id, post_view.community.id, context.pool()).await?;
 
     let report_form = PostReportForm {
       creator_id: person_id,
@@ -48,11 +57,12 @@ impl Perform for CreatePostReport {
       original_post_url: post_view.post.url,
       original_post_body: post_view.post.body,
       reason: reason.to_owned(),
+      rule_id: data.rule_id,
     };
 
     let report = PostReport::report(context.pool(), &report_form)
       .await
-      .map_err(|e| LemmyError::from_error_message(e, "couldnt_create_report"))?;
+      .with_lemmy_type(LemmyErrorType::CouldntCreateReport)?;
 
     let post_report_view = PostReportView::read(context.pool(), report.id, person_id).await?;
 
@@ -0,0 +1,89 @@
+use crate::Perform;
+use actix_web::web::Data;
+use lemmy_api_common::{
+  context::LemmyContext,
+  post::{EscalatePostReport, PostReportResponse},
+  utils::{
+    get_local_user_view_from_jwt,
+    is_admin,
+    is_mod_or_admin,
+    send_new_report_email_to_admins,
+  },
+  websocket::UserOperation,
+};
+use lemmy_db_schema::{newtypes::CommunityId, source::post_report::PostReport, traits::Reportable};
+use lemmy_db_views::structs::PostReportView;
+use lemmy_utils::{
+  error::{LemmyError, LemmyErrorExt, LemmyErrorType},
+  ConnectionId,
+};
+
+/// Escalates a post report to the site admins, or hands it back to the community moderators
+#[async_trait::async_trait(?Send)]
+impl Perform for EscalatePostReport {
+  type Response = PostReportResponse;
+
+  #[tracing::instrument(skip(context, websocket_id))]
+  async fn perform(
+    &self,
+    context: &Data<LemmyContext>,
+    websocket_id: Option<ConnectionId>,
+  ) -> Result<PostReportResponse, LemmyError> {
+    let data: &EscalatePostReport = self;
+    let local_user_view =
+      get_local_user_view_from_jwt(&data.auth, context.pool(), context.secret()).await?;
+
+    let report_id = data.report_id;
+    let person_id = local_user_view.person.id;
+    let report = PostReportView::read(context.pool(), report_id, person_id).await?;
+
+    is_mod_or_admin(context.pool(), person_id, report.community.id).await?;
+
+    // Once escalated, only the admins can handle the report
+    if report.post_report.escalated {
+      is_admin(&local_user_view)?;
+    }
+
+    PostReport::escalate(context.pool(), report_id, data.escalated)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntEscalateReport)?;
+
+    let post_report_view = PostReportView::read(context.pool(), report_id, person_id).await?;
+
+    // Escalated reports always reach the admins, regardless of reports_email_admins
+    if data.escalated {
+      send_new_report_email_to_admins(
+        &post_report_view.creator.name,
+        &post_report_view.post_creator.name,
+        context.pool(),
+        context.settings(),
+      )
+      .await?;
+    }
+
+    let res = PostReportResponse { post_report_view };
+
+    context
+      .chat_server()
+      .send_mod_room_message(
+        UserOperation::EscalatePostReport,
+        &res,
+        report.community.id,
+        websocket_id,
+      )
+      .await?;
+
+    // The admins room
+    context
+      .chat_server()
+      .send_mod_room_message(
+        UserOperation::EscalatePostReport,
+        &res,
+        CommunityId(0),
+        websocket_id,
+      )
+      .await?;
+
+    Ok(res)
+  }
+}
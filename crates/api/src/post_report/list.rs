@@ -37,6 +37,9 @@ impl Perform for ListPostReports {
       .admin(admin)
       .community_id(community_id)
       .unresolved_only(unresolved_only)
+      .post_id(data.post_id)
+      .assignee_id(data.assignee_id)
+      .escalated_only(data.escalated_only)
       .page(page)
       .limit(limit)
       .build()
@@ -1,26 +1,32 @@
-use crate::Perform;
+use crate::{check_report_reason, Perform};
 use actix_web::web::Data;
 use lemmy_api_common::{
   context::LemmyContext,
-  post::{PostReportResponse, ResolvePostReport},
-  utils::{get_local_user_view_from_jwt, is_mod_or_admin},
+  post::{ResolvePostReport, ResolvePostReportResponse},
+  utils::{get_local_user_view_from_jwt, is_admin, is_mod_or_admin},
   websocket::UserOperation,
 };
-use lemmy_db_schema::{source::post_report::PostReport, traits::Reportable};
+use lemmy_db_schema::{
+  source::{local_site::LocalSite, post_report::PostReport},
+  traits::Reportable,
+};
 use lemmy_db_views::structs::PostReportView;
-use lemmy_utils::{error::LemmyError, ConnectionId};
+use lemmy_utils::{
+  error::{LemmyError, LemmyErrorExt, LemmyErrorType},
+  ConnectionId,
+};
 
 /// Resolves or unresolves a post report and notifies the moderators of the community
 #[async_trait::async_trait(?Send)]
 impl Perform for ResolvePostReport {
-  type Response = PostReportResponse;
+  type Response = ResolvePostReportResponse;
 
   #[tracing::instrument(skip(context, websocket_id))]
   async fn perform(
     &self,
     context: &Data<LemmyContext>,
     websocket_id: Option<ConnectionId>,
-  ) -> Result<PostReportResponse, LemmyError> {
+  ) -> Result<ResolvePostReportResponse, LemmyError> {
     let data: &ResolvePostReport = self;
     let local_user_view =
       get_local_user_view_from_jwt(&data.auth, context.pool(), context.secret()).await?;
@@ -32,19 +38,50 @@ impl Perform for ResolvePostReport {
     let person_id = local_user_view.person.id;
     is_mod_or_admin(context.pool(), person_id, report.community.id).await?;
 
-    if data.resolved {
-      PostReport::resolve(context.pool(), report_id, person_id)
+    // Once escalated, only the admins can handle the report
+    if report.post_report.escalated {
+      is_admin(&local_user_view)?;
+    }
+
+    let resolution_reason = data.resolution_reason.as_deref().map(str::trim);
+    if let Some(resolution_reason) = resolution_reason {
+      let local_site = LocalSite::read(context.pool()).await?;
+      check_report_reason(resolution_reason, &local_site)?;
+    }
+    let resolution_reason = resolution_reason.map(ToOwned::to_owned);
+
+    let resolved_report_ids = if data.resolved {
+      if data.resolve_all.unwrap_or(false) {
+        PostReport::resolve_all_for_object(
+          context.pool(),
+          report.post.id,
+          person_id,
+          resolution_reason,
+        )
         .await
-        .map_err(|e| LemmyError::from_error_message(e, "couldnt_resolve_report"))?;
+        .with_lemmy_type(LemmyErrorType::CouldntResolveReport)?
+        .into_iter()
+        .map(|r| r.id)
+        .collect()
+      } else {
+        PostReport::resolve(context.pool(), report_id, person_id, resolution_reason)
+          .await
+          .with_lemmy_type(LemmyErrorType::CouldntResolveReport)?;
+        vec![report_id]
+      }
     } else {
       PostReport::unresolve(context.pool(), report_id, person_id)
         .await
-        .map_err(|e| LemmyError::from_error_message(e, "couldnt_resolve_report"))?;
-    }
+        .with_lemmy_type(LemmyErrorType::CouldntResolveReport)?;
+      vec![]
+    };
 
     let post_report_view = PostReportView::read(context.pool(), report_id, person_id).await?;
 
-    let res = PostReportResponse { post_report_view };
+    let res = ResolvePostReportResponse {
+      post_report_view,
+      resolved_report_ids,
+    };
 
     context
       .chat_server()
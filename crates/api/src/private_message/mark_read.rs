@@ -10,7 +10,10 @@ use lemmy_db_schema::{
   source::private_message::{PrivateMessage, PrivateMessageUpdateForm},
   traits::Crud,
 };
-use lemmy_utils::{error::LemmyError, ConnectionId};
+use lemmy_utils::{
+  error::{LemmyError, LemmyErrorExt, LemmyErrorType},
+  ConnectionId,
+};
 
 #[async_trait::async_trait(?Send)]
 impl Perform for MarkPrivateMessageAsRead {
@@ -30,7 +33,7 @@ impl Perform for MarkPrivateMessageAsRead {
     let private_message_id = data.private_message_id;
     let orig_private_message = PrivateMessage::read(context.pool(), private_message_id).await?;
     if local_user_view.person.id != orig_private_message.recipient_id {
-      return Err(LemmyError::from_message("couldnt_update_private_message"));
+      return Err(LemmyErrorType::CouldntUpdatePrivateMessage.into());
     }
 
     // Doing the update
@@ -42,7 +45,7 @@ impl Perform for MarkPrivateMessageAsRead {
       &PrivateMessageUpdateForm::builder().read(Some(read)).build(),
     )
     .await
-    .map_err(|e| LemmyError::from_error_message(e, "couldnt_update_private_message"))?;
+    .with_lemmy_type(LemmyErrorType::CouldntUpdatePrivateMessage)?;
 
     // No need to send an apub update
     let op = UserOperation::MarkPrivateMessageAsRead;
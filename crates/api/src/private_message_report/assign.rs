@@ -0,0 +1,67 @@
+use crate::Perform;
+use actix_web::web::Data;
+use lemmy_api_common::{
+  context::LemmyContext,
+  private_message::{AssignPrivateMessageReport, PrivateMessageReportResponse},
+  utils::{get_local_user_view_from_jwt, is_admin},
+  websocket::UserOperation,
+};
+use lemmy_db_schema::{
+  newtypes::CommunityId,
+  source::{person::Person, private_message_report::PrivateMessageReport},
+  traits::{Crud, Reportable},
+};
+use lemmy_db_views::structs::PrivateMessageReportView;
+use lemmy_utils::{
+  error::{LemmyError, LemmyErrorExt, LemmyErrorType},
+  ConnectionId,
+};
+
+#[async_trait::async_trait(?Send)]
+impl Perform for AssignPrivateMessageReport {
+  type Response = PrivateMessageReportResponse;
+
+  #[tracing::instrument(skip(context, websocket_id))]
+  async fn perform(
+    &self,
+    context: &Data<LemmyContext>,
+    websocket_id: Option<ConnectionId>,
+  ) -> Result<Self::Response, LemmyError> {
+    let local_user_view =
+      get_local_user_view_from_jwt(&self.auth, context.pool(), context.secret()).await?;
+
+    is_admin(&local_user_view)?;
+
+    // Private message reports are only handled by the admins
+    if let Some(assignee_id) = self.assignee_id {
+      let assignee = Person::read(context.pool(), assignee_id).await?;
+      if !assignee.admin {
+        return Err(LemmyErrorType::ReportAssigneeNotAnAdmin.into());
+      }
+    }
+
+    let report_id = self.report_id;
+    PrivateMessageReport::assign(context.pool(), report_id, self.assignee_id)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntAssignReport)?;
+
+    let private_message_report_view =
+      PrivateMessageReportView::read(context.pool(), report_id).await?;
+
+    let res = PrivateMessageReportResponse {
+      private_message_report_view,
+    };
+
+    context
+      .chat_server()
+      .send_mod_room_message(
+        UserOperation::AssignPrivateMessageReport,
+        &res,
+        CommunityId(0),
+        websocket_id,
+      )
+      .await?;
+
+    Ok(res)
+  }
+}
@@ -28,7 +28,9 @@ impl Perform for ListPrivateMessageReports {
     let limit = self.limit;
     let private_message_reports = PrivateMessageReportQuery::builder()
       .pool(context.pool())
+      .my_person_id(Some(local_user_view.person.id))
       .unresolved_only(unresolved_only)
+      .assignee_id(self.assignee_id)
       .page(page)
       .limit(limit)
       .build()
@@ -1,4 +1,4 @@
-use crate::Perform;
+use crate::{check_report_reason, Perform};
 use actix_web::web::Data;
 use lemmy_api_common::{
   context::LemmyContext,
@@ -8,11 +8,14 @@ use lemmy_api_common::{
 };
 use lemmy_db_schema::{
   newtypes::CommunityId,
-  source::private_message_report::PrivateMessageReport,
+  source::{local_site::LocalSite, private_message_report::PrivateMessageReport},
   traits::Reportable,
 };
 use lemmy_db_views::structs::PrivateMessageReportView;
-use lemmy_utils::{error::LemmyError, ConnectionId};
+use lemmy_utils::{
+  error::{LemmyError, LemmyErrorExt, LemmyErrorType},
+  ConnectionId,
+};
 
 #[async_trait::async_trait(?Send)]
 impl Perform for ResolvePrivateMessageReport {
@@ -31,14 +34,21 @@ impl Perform for ResolvePrivateMessageReport {
 
     let report_id = self.report_id;
     let person_id = local_user_view.person.id;
+    let resolution_reason = self.resolution_reason.as_deref().map(str::trim);
+    if let Some(resolution_reason) = resolution_reason {
+      let local_site = LocalSite::read(context.pool()).await?;
+      check_report_reason(resolution_reason, &local_site)?;
+    }
+    let resolution_reason = resolution_reason.map(ToOwned::to_owned);
+
     if self.resolved {
-      PrivateMessageReport::resolve(context.pool(), report_id, person_id)
+      PrivateMessageReport::resolve(context.pool(), report_id, person_id, resolution_reason)
         .await
-        .map_err(|e| LemmyError::from_error_message(e, "couldnt_resolve_report"))?;
+        .with_lemmy_type(LemmyErrorType::CouldntResolveReport)?;
     } else {
       PrivateMessageReport::unresolve(context.pool(), report_id, person_id)
         .await
-        .map_err(|e| LemmyError::from_error_message(e, "couldnt_resolve_report"))?;
+        .with_lemmy_type(LemmyErrorType::CouldntResolveReport)?;
     }
 
     let private_message_report_view =
@@ -0,0 +1,79 @@
+use crate::{check_report_reason, report_comment::check_report_comment_permission, Perform};
+use actix_web::web::Data;
+use lemmy_api_common::{
+  context::LemmyContext,
+  person::{CreateReportComment, ReportCommentResponse},
+  utils::get_local_user_view_from_jwt,
+  websocket::UserOperation,
+};
+use lemmy_db_schema::{
+  source::{
+    local_site::LocalSite,
+    report_comment::{ReportComment, ReportCommentForm},
+  },
+  traits::Crud,
+};
+use lemmy_db_views::structs::ReportCommentView;
+use lemmy_utils::{
+  error::{LemmyError, LemmyErrorExt, LemmyErrorType},
+  ConnectionId,
+};
+
+/// Adds an internal note to a report and notifies the other moderators handling it
+#[async_trait::async_trait(?Send)]
+impl Perform for CreateReportComment {
+  type Response = ReportCommentResponse;
+
+  #[tracing::instrument(skip(context, websocket_id))]
+  async fn perform(
+    &self,
+    context: &Data<LemmyContext>,
+    websocket_id: Option<ConnectionId>,
+  ) -> Result<ReportCommentResponse, LemmyError> {
+    let data: &CreateReportComment = self;
+    let local_user_view =
+      get_local_user_view_from_jwt(&data.auth, context.pool(), context.secret()).await?;
+    let local_site = LocalSite::read(context.pool()).await?;
+
+    let room_id = check_report_comment_permission(
+      &local_user_view,
+      data.post_report_id,
+      data.comment_report_id,
+      data.private_message_report_id,
+      context,
+    )
+    .await?;
+
+    let content = data.content.trim();
+    check_report_reason(content, &local_site)?;
+
+    let form = ReportCommentForm {
+      creator_id: local_user_view.person.id,
+      post_report_id: data.post_report_id,
+      comment_report_id: data.comment_report_id,
+      private_message_report_id: data.private_message_report_id,
+      content: content.to_owned(),
+    };
+    let report_comment = ReportComment::create(context.pool(), &form)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntCreateReportComment)?;
+
+    let report_comment_view = ReportCommentView::read(context.pool(), report_comment.id).await?;
+
+    let res = ReportCommentResponse {
+      report_comment_view,
+    };
+
+    context
+      .chat_server()
+      .send_mod_room_message(
+        UserOperation::CreateReportComment,
+        &res,
+        room_id,
+        websocket_id,
+      )
+      .await?;
+
+    Ok(res)
+  }
+}
@@ -0,0 +1,49 @@
+use crate::{report_comment::check_report_comment_permission, Perform};
+use actix_web::web::Data;
+use lemmy_api_common::{
+  context::LemmyContext,
+  person::{ListReportComments, ListReportCommentsResponse},
+  utils::get_local_user_view_from_jwt,
+};
+use lemmy_db_views::report_comment_view::ReportCommentQuery;
+use lemmy_utils::{error::LemmyError, ConnectionId};
+
+/// Lists the internal discussion of a report
+#[async_trait::async_trait(?Send)]
+impl Perform for ListReportComments {
+  type Response = ListReportCommentsResponse;
+
+  #[tracing::instrument(skip(context, _websocket_id))]
+  async fn perform(
+    &self,
+    context: &Data<LemmyContext>,
+    _websocket_id: Option<ConnectionId>,
+  ) -> Result<ListReportCommentsResponse, LemmyError> {
+    let data: &ListReportComments = self;
+    let local_user_view =
+      get_local_user_view_from_jwt(&data.auth, context.pool(), context.secret()).await?;
+
+    check_report_comment_permission(
+      &local_user_view,
+      data.post_report_id,
+      data.comment_report_id,
+      data.private_message_report_id,
+      context,
+    )
+    .await?;
+
+    let report_comments = ReportCommentQuery::builder()
+      .pool(context.pool())
+      .my_person_id(Some(local_user_view.person.id))
+      .post_report_id(data.post_report_id)
+      .comment_report_id(data.comment_report_id)
+      .private_message_report_id(data.private_message_report_id)
+      .page(data.page)
+      .limit(data.limit)
+      .build()
+      .list()
+      .await?;
+
+    Ok(ListReportCommentsResponse { report_comments })
+  }
+}
@@ -0,0 +1,52 @@
+use actix_web::web::Data;
+use lemmy_api_common::{
+  context::LemmyContext,
+  utils::{is_admin, is_mod_or_admin},
+};
+use lemmy_db_schema::newtypes::{
+  CommentReportId,
+  CommunityId,
+  PostReportId,
+  PrivateMessageReportId,
+};
+use lemmy_db_views::structs::{
+  CommentReportView,
+  LocalUserView,
+  PostReportView,
+  PrivateMessageReportView,
+};
+use lemmy_utils::error::{LemmyError, LemmyErrorType};
+
+mod create;
+mod list;
+
+/// Makes sure that exactly one report is referenced, and that the user is allowed to handle it.
+///
+/// Returns the id of the mod room which should be notified, `CommunityId(0)` being the admins.
+async fn check_report_comment_permission(
+  local_user_view: &LocalUserView,
+  post_report_id: Option<PostReportId>,
+  comment_report_id: Option<CommentReportId>,
+  private_message_report_id: Option<PrivateMessageReportId>,
+  context: &Data<LemmyContext>,
+) -> Result<CommunityId, LemmyError> {
+  let person_id = local_user_view.person.id;
+  match (post_report_id, comment_report_id, private_message_report_id) {
+    (Some(report_id), None, None) => {
+      let report = PostReportView::read(context.pool(), report_id, person_id).await?;
+      is_mod_or_admin(context.pool(), person_id, report.community.id).await?;
+      Ok(report.community.id)
+    }
+    (None, Some(report_id), None) => {
+      let report = CommentReportView::read(context.pool(), report_id, person_id).await?;
+      is_mod_or_admin(context.pool(), person_id, report.community.id).await?;
+      Ok(report.community.id)
+    }
+    (None, None, Some(report_id)) => {
+      is_admin(local_user_view)?;
+      PrivateMessageReportView::read(context.pool(), report_id).await?;
+      Ok(CommunityId(0))
+    }
+    _ => Err(LemmyErrorType::ReportCommentNeedsOneReport.into()),
+  }
+}
@@ -0,0 +1,79 @@
+use crate::Perform;
+use actix_web::web::Data;
+use lemmy_api_common::{
+  context::LemmyContext,
+  site::{BlockInstance, BlockInstanceResponse},
+  utils::{
+    get_local_user_view_from_jwt,
+    is_admin,
+    is_top_admin,
+    spawn_blocked_instance_cleanup,
+    spawn_unblocked_instance_restore,
+  },
+};
+use lemmy_db_schema::{
+  source::{
+    federation_blocklist::FederationBlockList,
+    instance::Instance,
+    moderator::{AdminBlockInstance, AdminBlockInstanceForm},
+  },
+  traits::Crud,
+};
+use lemmy_utils::{
+  error::{LemmyError, LemmyErrorType},
+  ConnectionId,
+};
+
+#[async_trait::async_trait(?Send)]
+impl Perform for BlockInstance {
+  type Response = BlockInstanceResponse;
+
+  #[tracing::instrument(skip(context, _websocket_id))]
+  async fn perform(
+    &self,
+    context: &Data<LemmyContext>,
+    _websocket_id: Option<ConnectionId>,
+  ) -> Result<BlockInstanceResponse, LemmyError> {
+    let data: &BlockInstance = self;
+    let local_user_view =
+      get_local_user_view_from_jwt(&data.auth, context.pool(), context.secret()).await?;
+    is_admin(&local_user_view)?;
+
+    let purge_content = data.block && data.purge_content.unwrap_or(false);
+    let hide_content = data.block && !purge_content && data.hide_content.unwrap_or(false);
+    // Only let the top admin purge content
+    if purge_content {
+      is_top_admin(context.pool(), local_user_view.person.id).await?;
+    }
+
+    let domain = data.instance.trim().to_lowercase();
+    if domain == context.settings().get_hostname_without_port()? {
+      return Err(LemmyErrorType::CantBlockLocalInstance.into());
+    }
+    let instance = Instance::create(context.pool(), &domain).await?;
+
+    if data.block {
+      FederationBlockList::block(context.pool(), instance.id).await?;
+      spawn_blocked_instance_cleanup(context, instance.id, hide_content, purge_content);
+    } else {
+      FederationBlockList::unblock(context.pool(), instance.id).await?;
+      spawn_unblocked_instance_restore(context.pool(), instance.id);
+    }
+
+    // Mod tables
+    let form = AdminBlockInstanceForm {
+      admin_person_id: local_user_view.person.id,
+      instance_id: instance.id,
+      blocked: data.block,
+      hide_content,
+      purge_content,
+      reason: data.reason.clone(),
+    };
+    AdminBlockInstance::create(context.pool(), &form).await?;
+
+    Ok(BlockInstanceResponse {
+      instance,
+      blocked: data.block,
+    })
+  }
+}
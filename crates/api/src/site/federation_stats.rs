@@ -0,0 +1,32 @@
+use crate::Perform;
+use actix_web::web::Data;
+use lemmy_api_common::{
+  context::LemmyContext,
+  site::{GetFederationStats, GetFederationStatsResponse},
+  utils::{get_local_user_view_from_jwt, is_admin},
+};
+use lemmy_db_views::structs::InstanceFederationStatsView;
+use lemmy_utils::{error::LemmyError, ConnectionId};
+
+/// Shows how well federation works with each remote instance, so that admins can find broken peers.
+#[async_trait::async_trait(?Send)]
+impl Perform for GetFederationStats {
+  type Response = GetFederationStatsResponse;
+
+  #[tracing::instrument(skip(context, _websocket_id))]
+  async fn perform(
+    &self,
+    context: &Data<LemmyContext>,
+    _websocket_id: Option<ConnectionId>,
+  ) -> Result<GetFederationStatsResponse, LemmyError> {
+    let data: &GetFederationStats = self;
+    let local_user_view =
+      get_local_user_view_from_jwt(&data.auth, context.pool(), context.secret()).await?;
+    is_admin(&local_user_view)?;
+
+    let instances =
+      InstanceFederationStatsView::list(context.pool(), data.page, data.limit).await?;
+
+    Ok(GetFederationStatsResponse { instances })
+  }
+}
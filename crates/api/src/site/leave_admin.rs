@@ -17,7 +17,11 @@ use lemmy_db_schema::{
 };
 use lemmy_db_views::structs::SiteView;
 use lemmy_db_views_actor::structs::PersonViewSafe;
-use lemmy_utils::{error::LemmyError, version, ConnectionId};
+use lemmy_utils::{
+  error::{LemmyError, LemmyErrorType},
+  version,
+  ConnectionId,
+};
 
 #[async_trait::async_trait(?Send)]
 impl Perform for LeaveAdmin {
@@ -38,7 +42,7 @@ impl Perform for LeaveAdmin {
     // Make sure there isn't just one admin (so if one leaves, there will still be one left)
     let admins = PersonViewSafe::admins(context.pool()).await?;
     if admins.len() == 1 {
-      return Err(LemmyError::from_message("cannot_leave_admin"));
+      return Err(LemmyErrorType::CannotLeaveAdmin.into());
     }
 
     let person_id = local_user_view.person.id;
@@ -0,0 +1,58 @@
+use crate::Perform;
+use actix_web::web::Data;
+use chrono::{DateTime, NaiveDateTime};
+use lemmy_api_common::{
+  context::LemmyContext,
+  site::{ListActivities, ListActivitiesResponse},
+  utils::{get_local_user_view_from_jwt, is_admin},
+};
+use lemmy_db_schema::impls::activity::ActivityQuery;
+use lemmy_utils::{
+  error::{LemmyError, LemmyErrorExt, LemmyErrorType},
+  ConnectionId,
+};
+
+/// Searches the sent and received activities, to debug federation with other instances.
+#[async_trait::async_trait(?Send)]
+impl Perform for ListActivities {
+  type Response = ListActivitiesResponse;
+
+  #[tracing::instrument(skip(context, _websocket_id))]
+  async fn perform(
+    &self,
+    context: &Data<LemmyContext>,
+    _websocket_id: Option<ConnectionId>,
+  ) -> Result<ListActivitiesResponse, LemmyError> {
+    let data: &ListActivities = self;
+    let local_user_view =
+      get_local_user_view_from_jwt(&data.auth, context.pool(), context.secret()).await?;
+    is_admin(&local_user_view)?;
+
+    let activities = ActivityQuery::builder()
+      .pool(context.pool())
+      .actor(data.actor.clone())
+      .object(data.object.clone())
+      .kind(data.type_.clone())
+      .local_only(data.local)
+      .published_after(parse_time(&data.published_after)?)
+      .published_before(parse_time(&data.published_before)?)
+      .page(data.page)
+      .limit(data.limit)
+      .build()
+      .list()
+      .await?;
+
+    Ok(ListActivitiesResponse { activities })
+  }
+}
+
+fn parse_time(time: &Option<String>) -> Result<Option<NaiveDateTime>, LemmyError> {
+  time
+    .as_ref()
+    .map(|t| {
+      DateTime::parse_from_rfc3339(t)
+        .map(|t| t.naive_utc())
+        .with_lemmy_type(LemmyErrorType::InvalidTime)
+    })
+    .transpose()
+}
@@ -1,4 +1,9 @@
+mod block_instance;
+mod federation_stats;
 mod leave_admin;
+mod list_activities;
+mod list_relays;
 mod mod_log;
 mod purge;
 mod registration_applications;
+mod verify_aggregates;
@@ -11,6 +11,7 @@ use lemmy_db_schema::{
   ModlogActionType,
 };
 use lemmy_db_views_moderator::structs::{
+  AdminBlockInstanceView,
   AdminPurgeCommentView,
   AdminPurgeCommunityView,
   AdminPurgePersonView,
@@ -53,6 +54,7 @@ impl Perform for GetModlog {
     let type_ = data.type_.unwrap_or(All);
     let community_id = data.community_id;
 
+    let my_person_id = local_user_view.as_ref().map(|l| l.person.id);
     let (local_person_id, is_admin) = match local_user_view {
       Some(s) => (s.person.id, is_admin(&s).is_ok()),
       None => (PersonId(-1), false),
@@ -74,6 +76,7 @@ impl Perform for GetModlog {
     };
     let other_person_id = data.other_person_id;
     let params = ModlogListParams {
+      my_person_id,
       community_id,
       mod_person_id,
       other_person_id,
@@ -132,6 +135,7 @@ impl Perform for GetModlog {
       admin_purged_communities,
       admin_purged_posts,
       admin_purged_comments,
+      blocked_instances,
     ) = if data.community_id.is_none() {
       (
         match type_ {
@@ -172,6 +176,12 @@ impl Perform for GetModlog {
           }
           _ => Default::default(),
         },
+        match type_ {
+          All | AdminBlockInstance if other_person_id.is_none() => {
+            AdminBlockInstanceView::list(context.pool(), params).await?
+          }
+          _ => Default::default(),
+        },
       )
     } else {
       Default::default()
@@ -194,6 +204,7 @@ impl Perform for GetModlog {
       admin_purged_posts,
       admin_purged_comments,
       hidden_communities,
+      blocked_instances,
     })
   }
 }
@@ -34,6 +34,7 @@ impl Perform for ListRegistrationApplications {
     let limit = data.limit;
     let registration_applications = RegistrationApplicationQuery::builder()
       .pool(context.pool())
+      .my_person_id(Some(local_user_view.person.id))
       .unread_only(unread_only)
       .verified_email_only(Some(verified_email_only))
       .page(page)
@@ -0,0 +1,33 @@
+use crate::Perform;
+use actix_web::web::Data;
+use lemmy_api_common::{
+  context::LemmyContext,
+  site::{VerifyAggregates, VerifyAggregatesResponse},
+  utils::{get_local_user_view_from_jwt, is_admin},
+};
+use lemmy_db_schema::aggregates::structs::AggregateDifferences;
+use lemmy_utils::{error::LemmyError, ConnectionId};
+
+/// Finds aggregates which drifted from the source tables, eg after failed migrations or purges,
+/// and optionally repairs them.
+#[async_trait::async_trait(?Send)]
+impl Perform for VerifyAggregates {
+  type Response = VerifyAggregatesResponse;
+
+  #[tracing::instrument(skip(context, _websocket_id))]
+  async fn perform(
+    &self,
+    context: &Data<LemmyContext>,
+    _websocket_id: Option<ConnectionId>,
+  ) -> Result<VerifyAggregatesResponse, LemmyError> {
+    let data: &VerifyAggregates = self;
+    let local_user_view =
+      get_local_user_view_from_jwt(&data.auth, context.pool(), context.secret()).await?;
+    is_admin(&local_user_view)?;
+
+    let repair = data.repair.unwrap_or(false);
+    let differences = AggregateDifferences::check(context.pool(), repair).await?;
+
+    Ok(VerifyAggregatesResponse { differences })
+  }
+}
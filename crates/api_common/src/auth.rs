@@ -0,0 +1,200 @@
+use crate::{
+  comment::{
+    AssignCommentReport,
+    CreateComment,
+    CreateCommentLike,
+    CreateCommentReport,
+    DeleteComment,
+    DistinguishComment,
+    EditComment,
+    EscalateCommentReport,
+    RemoveComment,
+    ResolveCommentReport,
+    SaveComment,
+  },
+  community::{
+    AddModToCommunity,
+    BanFromCommunity,
+    BlockCommunity,
+    CreateCommunity,
+    DeleteCommunity,
+    EditCommunity,
+    FollowCommunity,
+    HideCommunity,
+    RemoveCommunity,
+    TransferCommunity,
+  },
+  person::{
+    AddAdmin,
+    BanPerson,
+    BlockPerson,
+    ChangePassword,
+    CreateReportComment,
+    DeleteAccount,
+    Login,
+    MarkAllAsRead,
+    MarkCommentReplyAsRead,
+    MarkPersonMentionAsRead,
+    PasswordChangeAfterReset,
+    PasswordReset,
+    Register,
+    SaveUserSettings,
+    VerifyEmail,
+  },
+  post::{
+    AssignPostReport,
+    CreatePost,
+    CreatePostLike,
+    CreatePostReport,
+    DeletePost,
+    EditPost,
+    EscalatePostReport,
+    FeaturePost,
+    LockPost,
+    MarkPostAsRead,
+    RemovePost,
+    ResolvePostReport,
+    SavePost,
+  },
+  private_message::{
+    AssignPrivateMessageReport,
+    CreatePrivateMessage,
+    CreatePrivateMessageReport,
+    DeletePrivateMessage,
+    EditPrivateMessage,
+    MarkPrivateMessageAsRead,
+    ResolvePrivateMessageReport,
+  },
+  site::{
+    AddRelay,
+    ApproveRegistrationApplication,
+    BlockInstance,
+    CreateSite,
+    EditSite,
+    LeaveAdmin,
+    PurgeComment,
+    PurgeCommunity,
+    PurgePerson,
+    PurgePost,
+    RemoveRelay,
+    ReplayActivity,
+    ResendActivity,
+    VerifyAggregates,
+  },
+};
+
+/// Gives access to the login token of a request which changes something, without knowing the
+/// type of the request.
+pub trait RequestAuth {
+  /// None for requests which can be made without logging in
+  fn auth(&self) -> Option<&str>;
+}
+
+macro_rules! request_auth {
+  ($($request:ty),* $(,)?) => {
+    $(
+      impl RequestAuth for $request {
+        fn auth(&self) -> Option<&str> {
+          Some(self.auth.as_ref())
+        }
+      }
+    )*
+  };
+}
+
+macro_rules! no_request_auth {
+  ($($request:ty),* $(,)?) => {
+    $(
+      impl RequestAuth for $request {
+        fn auth(&self) -> Option<&str> {
+          None
+        }
+      }
+    )*
+  };
+}
+
+request_auth!(
+  CreateComment,
+  EditComment,
+  DeleteComment,
+  RemoveComment,
+  DistinguishComment,
+  CreateCommentLike,
+  SaveComment,
+  CreateCommentReport,
+  ResolveCommentReport,
+  AssignCommentReport,
+  EscalateCommentReport,
+  CreateCommunity,
+  EditCommunity,
+  HideCommunity,
+  FollowCommunity,
+  BlockCommunity,
+  DeleteCommunity,
+  RemoveCommunity,
+  TransferCommunity,
+  BanFromCommunity,
+  AddModToCommunity,
+  MarkCommentReplyAsRead,
+  MarkPersonMentionAsRead,
+  BanPerson,
+  BlockPerson,
+  DeleteAccount,
+  MarkAllAsRead,
+  SaveUserSettings,
+  ChangePassword,
+  CreateReportComment,
+  AddAdmin,
+  CreatePost,
+  EditPost,
+  DeletePost,
+  RemovePost,
+  MarkPostAsRead,
+  LockPost,
+  FeaturePost,
+  CreatePostLike,
+  SavePost,
+  CreatePostReport,
+  ResolvePostReport,
+  AssignPostReport,
+  EscalatePostReport,
+  CreatePrivateMessage,
+  EditPrivateMessage,
+  DeletePrivateMessage,
+  MarkPrivateMessageAsRead,
+  CreatePrivateMessageReport,
+  ResolvePrivateMessageReport,
+  AssignPrivateMessageReport,
+  CreateSite,
+  EditSite,
+  LeaveAdmin,
+  BlockInstance,
+  AddRelay,
+  RemoveRelay,
+  ReplayActivity,
+  ResendActivity,
+  VerifyAggregates,
+  ApproveRegistrationApplication,
+  PurgePerson,
+  PurgeCommunity,
+  PurgePost,
+  PurgeComment,
+);
+
+no_request_auth!(
+  Register,
+  Login,
+  VerifyEmail,
+  PasswordReset,
+  PasswordChangeAfterReset,
+);
+
+#[cfg(feature = "full")]
+mod websocket {
+  use super::RequestAuth;
+  use crate::websocket::structs::{CommunityJoin, ModJoin, PostJoin, UserJoin};
+
+  request_auth!(UserJoin);
+  no_request_auth!(CommunityJoin, ModJoin, PostJoin);
+}
@@ -1,13 +1,23 @@
 use crate::sensitive::Sensitive;
+use doku::Document;
 use lemmy_db_schema::{
-  newtypes::{CommentId, CommentReportId, CommunityId, LanguageId, LocalUserId, PostId},
+  newtypes::{
+    CommentId,
+    CommentReportId,
+    CommunityId,
+    CommunityRuleId,
+    LanguageId,
+    LocalUserId,
+    PersonId,
+    PostId,
+  },
   CommentSortType,
   ListingType,
 };
 use lemmy_db_views::structs::{CommentReportView, CommentView};
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, Document)]
 pub struct CreateComment {
   pub content: String,
   pub post_id: PostId,
@@ -17,13 +27,13 @@ pub struct CreateComment {
   pub auth: Sensitive<String>,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, Document)]
 pub struct GetComment {
   pub id: CommentId,
   pub auth: Option<Sensitive<String>>,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, Document)]
 pub struct EditComment {
   pub comment_id: CommentId,
   pub content: Option<String>,
@@ -32,50 +42,52 @@ pub struct EditComment {
   pub auth: Sensitive<String>,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, Document)]
 pub struct DistinguishComment {
   pub comment_id: CommentId,
   pub distinguished: bool,
   pub auth: Sensitive<String>,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, Document)]
 pub struct DeleteComment {
   pub comment_id: CommentId,
   pub deleted: bool,
   pub auth: Sensitive<String>,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, Document)]
 pub struct RemoveComment {
   pub comment_id: CommentId,
   pub removed: bool,
   pub reason: Option<String>,
+  /// The community rule which the comment breaks
+  pub rule_id: Option<CommunityRuleId>,
   pub auth: Sensitive<String>,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, Document)]
 pub struct SaveComment {
   pub comment_id: CommentId,
   pub save: bool,
   pub auth: Sensitive<String>,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, Document)]
 pub struct CommentResponse {
   pub comment_view: CommentView,
   pub recipient_ids: Vec<LocalUserId>,
   pub form_id: Option<String>, // An optional front end ID, to tell which is coming back
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, Document)]
 pub struct CreateCommentLike {
   pub comment_id: CommentId,
   pub score: i16,
   pub auth: Sensitive<String>,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, Document)]
 pub struct GetComments {
   pub type_: Option<ListingType>,
   pub sort: Option<CommentSortType>,
@@ -90,31 +102,60 @@ pub struct GetComments {
   pub auth: Option<Sensitive<String>>,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, Document)]
 pub struct GetCommentsResponse {
   pub comments: Vec<CommentView>,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, Document)]
 pub struct CreateCommentReport {
   pub comment_id: CommentId,
   pub reason: String,
+  /// The community rule which the comment breaks
+  pub rule_id: Option<CommunityRuleId>,
   pub auth: Sensitive<String>,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, Document)]
 pub struct CommentReportResponse {
   pub comment_report_view: CommentReportView,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[derive(Debug, Serialize, Deserialize, Clone, Document)]
+pub struct ResolveCommentReportResponse {
+  pub comment_report_view: CommentReportView,
+  /// The reports which were resolved by this request, more than one with `resolve_all`
+  pub resolved_report_ids: Vec<CommentReportId>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default, Document)]
 pub struct ResolveCommentReport {
   pub report_id: CommentReportId,
   pub resolved: bool,
+  /// An optional note explaining how the report was handled
+  pub resolution_reason: Option<String>,
+  /// Also resolve all the other open reports for the same comment
+  pub resolve_all: Option<bool>,
+  pub auth: Sensitive<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default, Document)]
+pub struct AssignCommentReport {
+  pub report_id: CommentReportId,
+  /// The moderator to assign the report to, or None to unassign it
+  pub assignee_id: Option<PersonId>,
+  pub auth: Sensitive<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default, Document)]
+pub struct EscalateCommentReport {
+  pub report_id: CommentReportId,
+  /// Hand the report over to the site admins
+  pub escalated: bool,
   pub auth: Sensitive<String>,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, Document)]
 pub struct ListCommentReports {
   pub page: Option<i64>,
   pub limit: Option<i64>,
@@ -122,10 +163,16 @@ pub struct ListCommentReports {
   pub unresolved_only: Option<bool>,
   /// if no community is given, it returns reports for all communities moderated by the auth user
   pub community_id: Option<CommunityId>,
+  /// Only shows the reports for a single comment
+  pub comment_id: Option<CommentId>,
+  /// Only shows the reports assigned to this moderator
+  pub assignee_id: Option<PersonId>,
+  /// Only shows the reports escalated to the admins
+  pub escalated_only: Option<bool>,
   pub auth: Sensitive<String>,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, Document)]
 pub struct ListCommentReportsResponse {
   pub comment_reports: Vec<CommentReportView>,
 }
@@ -1,14 +1,20 @@
 use crate::sensitive::Sensitive;
+use doku::Document;
 use lemmy_db_schema::{
-  newtypes::{CommunityId, LanguageId, PersonId},
-  source::site::Site,
+  newtypes::{CommunityId, CommunityRuleId, LanguageId, PersonId},
+  source::{community_rule::CommunityRule, directory_community::DirectoryCommunity, site::Site},
   ListingType,
   SortType,
 };
-use lemmy_db_views_actor::structs::{CommunityModeratorView, CommunityView, PersonViewSafe};
+use lemmy_db_views_actor::structs::{
+  CommunityBackfillView,
+  CommunityModeratorView,
+  CommunityView,
+  PersonViewSafe,
+};
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, Document)]
 pub struct GetCommunity {
   pub id: Option<CommunityId>,
   /// Example: star_trek , or star_trek@xyz.tld
@@ -16,7 +22,7 @@ pub struct GetCommunity {
   pub auth: Option<Sensitive<String>>,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, Document)]
 pub struct GetCommunityResponse {
   pub community_view: CommunityView,
   pub site: Option<Site>,
@@ -26,9 +32,11 @@ pub struct GetCommunityResponse {
   /// Default language used for new posts if none is specified, generated based on community and
   /// user languages.
   pub default_post_language: Option<LanguageId>,
+  /// The rules of the community, ordered by position
+  pub rules: Vec<CommunityRule>,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, Document)]
 pub struct CreateCommunity {
   pub name: String,
   pub title: String,
@@ -41,13 +49,13 @@ pub struct CreateCommunity {
   pub auth: Sensitive<String>,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, Document)]
 pub struct CommunityResponse {
   pub community_view: CommunityView,
   pub discussion_languages: Vec<LanguageId>,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, Document)]
 pub struct ListCommunities {
   pub type_: Option<ListingType>,
   pub sort: Option<SortType>,
@@ -56,12 +64,29 @@ pub struct ListCommunities {
   pub auth: Option<Sensitive<String>>,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, Document)]
 pub struct ListCommunitiesResponse {
   pub communities: Vec<CommunityView>,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, Document)]
+/// Browses the communities of other instances which were found by the community directory
+/// crawler. Use `ResolveObject` on the `actor_id` of a result to fetch it.
+pub struct ListDirectoryCommunities {
+  /// Searches the name and title
+  pub q: Option<String>,
+  pub language_id: Option<LanguageId>,
+  pub page: Option<i64>,
+  pub limit: Option<i64>,
+  pub auth: Option<Sensitive<String>>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Document)]
+pub struct ListDirectoryCommunitiesResponse {
+  pub communities: Vec<DirectoryCommunity>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default, Document)]
 pub struct BanFromCommunity {
   pub community_id: CommunityId,
   pub person_id: PersonId,
@@ -72,13 +97,13 @@ pub struct BanFromCommunity {
   pub auth: Sensitive<String>,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, Document)]
 pub struct BanFromCommunityResponse {
   pub person_view: PersonViewSafe,
   pub banned: bool,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, Document)]
 pub struct AddModToCommunity {
   pub community_id: CommunityId,
   pub person_id: PersonId,
@@ -86,12 +111,12 @@ pub struct AddModToCommunity {
   pub auth: Sensitive<String>,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, Document)]
 pub struct AddModToCommunityResponse {
   pub moderators: Vec<CommunityModeratorView>,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, Document)]
 pub struct EditCommunity {
   pub community_id: CommunityId,
   pub title: Option<String>,
@@ -101,10 +126,23 @@ pub struct EditCommunity {
   pub nsfw: Option<bool>,
   pub posting_restricted_to_mods: Option<bool>,
   pub discussion_languages: Option<Vec<LanguageId>>,
+  /// Regex of words which are filtered from posts and comments in the community. An empty string
+  /// removes the filter.
+  pub slur_filter_regex: Option<String>,
+  /// Replaces the rules of the community, in the given order. Existing rules are kept by their
+  /// id, rules without an id are added and rules left out are deleted.
+  pub rules: Option<Vec<CommunityRuleInput>>,
   pub auth: Sensitive<String>,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, Document)]
+pub struct CommunityRuleInput {
+  pub id: Option<CommunityRuleId>,
+  pub title: String,
+  pub body: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default, Document)]
 pub struct HideCommunity {
   pub community_id: CommunityId,
   pub hidden: bool,
@@ -112,14 +150,14 @@ pub struct HideCommunity {
   pub auth: Sensitive<String>,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, Document)]
 pub struct DeleteCommunity {
   pub community_id: CommunityId,
   pub deleted: bool,
   pub auth: Sensitive<String>,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, Document)]
 pub struct RemoveCommunity {
   pub community_id: CommunityId,
   pub removed: bool,
@@ -128,29 +166,42 @@ pub struct RemoveCommunity {
   pub auth: Sensitive<String>,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, Document)]
 pub struct FollowCommunity {
   pub community_id: CommunityId,
   pub follow: bool,
   pub auth: Sensitive<String>,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, Document)]
 pub struct BlockCommunity {
   pub community_id: CommunityId,
   pub block: bool,
   pub auth: Sensitive<String>,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, Document)]
 pub struct BlockCommunityResponse {
   pub community_view: CommunityView,
   pub blocked: bool,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, Document)]
 pub struct TransferCommunity {
   pub community_id: CommunityId,
   pub person_id: PersonId,
   pub auth: Sensitive<String>,
 }
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default, Document)]
+pub struct ListCommunityBackfills {
+  pub page: Option<i64>,
+  pub limit: Option<i64>,
+  pub auth: Sensitive<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Document)]
+pub struct ListCommunityBackfillsResponse {
+  /// Progress of fetching the history of remote communities, newest first
+  pub backfills: Vec<CommunityBackfillView>,
+}
@@ -1,6 +1,16 @@
 use crate::sensitive::Sensitive;
+use doku::Document;
 use lemmy_db_schema::{
-  newtypes::{CommentId, CommunityId, DbUrl, LanguageId, PostId, PostReportId},
+  newtypes::{
+    CommentId,
+    CommunityId,
+    CommunityRuleId,
+    DbUrl,
+    LanguageId,
+    PersonId,
+    PostId,
+    PostReportId,
+  },
   ListingType,
   PostFeatureType,
   SortType,
@@ -10,7 +20,7 @@ use lemmy_db_views_actor::structs::{CommunityModeratorView, CommunityView};
 use serde::{Deserialize, Serialize};
 use url::Url;
 
-#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, Document)]
 pub struct CreatePost {
   pub name: String,
   pub community_id: CommunityId,
@@ -22,19 +32,19 @@ pub struct CreatePost {
   pub auth: Sensitive<String>,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, Document)]
 pub struct PostResponse {
   pub post_view: PostView,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, Document)]
 pub struct GetPost {
   pub id: Option<PostId>,
   pub comment_id: Option<CommentId>,
   pub auth: Option<Sensitive<String>>,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, Document)]
 pub struct GetPostResponse {
   pub post_view: PostView,
   pub community_view: CommunityView,
@@ -42,7 +52,7 @@ pub struct GetPostResponse {
   pub online: usize,
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, Default, Document)]
 pub struct GetPosts {
   pub type_: Option<ListingType>,
   pub sort: Option<SortType>,
@@ -54,19 +64,19 @@ pub struct GetPosts {
   pub auth: Option<Sensitive<String>>,
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone, Document)]
 pub struct GetPostsResponse {
   pub posts: Vec<PostView>,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, Document)]
 pub struct CreatePostLike {
   pub post_id: PostId,
   pub score: i16,
   pub auth: Sensitive<String>,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, Document)]
 pub struct EditPost {
   pub post_id: PostId,
   pub name: Option<String>,
@@ -77,36 +87,38 @@ pub struct EditPost {
   pub auth: Sensitive<String>,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, Document)]
 pub struct DeletePost {
   pub post_id: PostId,
   pub deleted: bool,
   pub auth: Sensitive<String>,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, Document)]
 pub struct RemovePost {
   pub post_id: PostId,
   pub removed: bool,
   pub reason: Option<String>,
+  /// The community rule which the post breaks
+  pub rule_id: Option<CommunityRuleId>,
   pub auth: Sensitive<String>,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, Document)]
 pub struct MarkPostAsRead {
   pub post_id: PostId,
   pub read: bool,
   pub auth: Sensitive<String>,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, Document)]
 pub struct LockPost {
   pub post_id: PostId,
   pub locked: bool,
   pub auth: Sensitive<String>,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, Document)]
 pub struct FeaturePost {
   pub post_id: PostId,
   pub featured: bool,
@@ -114,33 +126,62 @@ pub struct FeaturePost {
   pub auth: Sensitive<String>,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, Document)]
 pub struct SavePost {
   pub post_id: PostId,
   pub save: bool,
   pub auth: Sensitive<String>,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, Document)]
 pub struct CreatePostReport {
   pub post_id: PostId,
   pub reason: String,
+  /// The community rule which the post breaks
+  pub rule_id: Option<CommunityRuleId>,
   pub auth: Sensitive<String>,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, Document)]
 pub struct PostReportResponse {
   pub post_report_view: PostReportView,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[derive(Debug, Serialize, Deserialize, Clone, Document)]
+pub struct ResolvePostReportResponse {
+  pub post_report_view: PostReportView,
+  /// The reports which were resolved by this request, more than one with `resolve_all`
+  pub resolved_report_ids: Vec<PostReportId>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default, Document)]
 pub struct ResolvePostReport {
   pub report_id: PostReportId,
   pub resolved: bool,
+  /// An optional note explaining how the report was handled
+  pub resolution_reason: Option<String>,
+  /// Also resolve all the other open reports for the same post
+  pub resolve_all: Option<bool>,
+  pub auth: Sensitive<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default, Document)]
+pub struct AssignPostReport {
+  pub report_id: PostReportId,
+  /// The moderator to assign the report to, or None to unassign it
+  pub assignee_id: Option<PersonId>,
+  pub auth: Sensitive<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default, Document)]
+pub struct EscalatePostReport {
+  pub report_id: PostReportId,
+  /// Hand the report over to the site admins
+  pub escalated: bool,
   pub auth: Sensitive<String>,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, Document)]
 pub struct ListPostReports {
   pub page: Option<i64>,
   pub limit: Option<i64>,
@@ -148,25 +189,31 @@ pub struct ListPostReports {
   pub unresolved_only: Option<bool>,
   /// if no community is given, it returns reports for all communities moderated by the auth user
   pub community_id: Option<CommunityId>,
+  /// Only shows the reports for a single post
+  pub post_id: Option<PostId>,
+  /// Only shows the reports assigned to this moderator
+  pub assignee_id: Option<PersonId>,
+  /// Only shows the reports escalated to the admins
+  pub escalated_only: Option<bool>,
   pub auth: Sensitive<String>,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, Document)]
 pub struct ListPostReportsResponse {
   pub post_reports: Vec<PostReportView>,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, Document)]
 pub struct GetSiteMetadata {
   pub url: Url,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, Document)]
 pub struct GetSiteMetadataResponse {
   pub metadata: SiteMetadata,
 }
 
-#[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Clone)]
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Clone, Document)]
 pub struct SiteMetadata {
   pub title: Option<String>,
   pub description: Option<String>,
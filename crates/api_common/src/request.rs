@@ -2,7 +2,7 @@ use crate::post::SiteMetadata;
 use encoding::{all::encodings, DecoderTrap};
 use lemmy_db_schema::newtypes::DbUrl;
 use lemmy_utils::{
-  error::LemmyError,
+  error::{LemmyError, LemmyErrorType},
   settings::structs::Settings,
   version::VERSION,
   REQWEST_TIMEOUT,
@@ -41,13 +41,11 @@ fn html_to_site_metadata(html_bytes: &[u8]) -> Result<SiteMetadata, LemmyError>
     .lines()
     .into_iter()
     .next()
-    .ok_or_else(|| LemmyError::from_message("No lines in html"))?
+    .ok_or(LemmyErrorType::NoLinesInHtml)?
     .to_lowercase();
 
   if !first_line.starts_with("<!doctype html>") {
-    return Err(LemmyError::from_message(
-      "Site metadata page fetch is not DOCTYPE html",
-    ));
+    return Err(LemmyErrorType::SiteMetadataPageIsNotDoctypeHtml.into());
   }
 
   let mut page = HTML::from_string(html.to_string(), None)?;
@@ -141,7 +139,7 @@ pub(crate) async fn fetch_pictrs(
   if response.msg == "ok" {
     Ok(response)
   } else {
-    Err(LemmyError::from_message(&response.msg))
+    Err(LemmyErrorType::PictrsResponseError(response.msg).into())
   }
 }
 
@@ -160,15 +158,15 @@ pub async fn purge_image_from_pictrs(
 
   let alias = image_url
     .path_segments()
-    .ok_or_else(|| LemmyError::from_message("Image URL missing path segments"))?
+    .ok_or(LemmyErrorType::ImageUrlMissingPathSegments)?
     .next_back()
-    .ok_or_else(|| LemmyError::from_message("Image URL missing last path segment"))?;
+    .ok_or(LemmyErrorType::ImageUrlMissingLastPathSegment)?;
 
   let purge_url = format!("{}/internal/purge?alias={}", pictrs_config.url, alias);
 
   let pictrs_api_key = pictrs_config
     .api_key
-    .ok_or_else(|| LemmyError::from_message("pictrs_api_key_not_provided"))?;
+    .ok_or(LemmyErrorType::PictrsApiKeyNotProvided)?;
   let response = client
     .post(&purge_url)
     .timeout(REQWEST_TIMEOUT)
@@ -181,7 +179,7 @@ pub async fn purge_image_from_pictrs(
   if response.msg == "ok" {
     Ok(())
   } else {
-    Err(LemmyError::from_message(&response.msg))
+    Err(LemmyErrorType::PictrsResponseError(response.msg).into())
   }
 }
 
@@ -244,13 +242,13 @@ async fn is_image_content_type(client: &ClientWithMiddleware, url: &Url) -> Resu
   if response
     .headers()
     .get("Content-Type")
-    .ok_or_else(|| LemmyError::from_message("No Content-Type header"))?
+    .ok_or(LemmyErrorType::NoContentTypeHeader)?
     .to_str()?
     .starts_with("image/")
   {
     Ok(())
   } else {
-    Err(LemmyError::from_message("Not an image type."))
+    Err(LemmyErrorType::NotAnImageType.into())
   }
 }
 
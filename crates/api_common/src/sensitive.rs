@@ -1,3 +1,4 @@
+use doku::{Document, Fields, Type, TypeKind};
 use serde::{Deserialize, Serialize};
 use std::{
   borrow::Borrow,
@@ -18,6 +19,21 @@ impl<T> Sensitive<T> {
   }
 }
 
+/// Marks the value in the API schema, so that clients can handle it as a secret.
+impl<T: Document> Document for Sensitive<T> {
+  fn ty() -> Type {
+    let mut inner = T::ty();
+    inner.metas.add("sensitive", "true");
+    TypeKind::Struct {
+      fields: Fields::Unnamed {
+        fields: vec![inner.into()],
+      },
+      transparent: true,
+    }
+    .into()
+  }
+}
+
 impl<T> std::fmt::Debug for Sensitive<T> {
   fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
     f.debug_struct("Sensitive").finish()
@@ -1,10 +1,14 @@
 use crate::sensitive::Sensitive;
+use doku::Document;
 use lemmy_db_schema::{
-  newtypes::{CommentId, CommunityId, LanguageId, PersonId, PostId},
+  aggregates::structs::AggregateDifferences,
+  newtypes::{CommentId, CommunityId, LanguageId, PersonId, PostId, RelayId},
   source::{
+    activity::Activity,
     instance::Instance,
     language::Language,
     local_site::RegistrationMode,
+    relay::Relay,
     tagline::Tagline,
   },
   ListingType,
@@ -14,6 +18,7 @@ use lemmy_db_schema::{
 };
 use lemmy_db_views::structs::{
   CommentView,
+  InstanceFederationStatsView,
   LocalUserSettingsView,
   PostView,
   RegistrationApplicationView,
@@ -28,6 +33,7 @@ use lemmy_db_views_actor::structs::{
   PersonViewSafe,
 };
 use lemmy_db_views_moderator::structs::{
+  AdminBlockInstanceView,
   AdminPurgeCommentView,
   AdminPurgeCommunityView,
   AdminPurgePersonView,
@@ -46,7 +52,7 @@ use lemmy_db_views_moderator::structs::{
 };
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, Document)]
 pub struct Search {
   pub q: String,
   pub community_id: Option<CommunityId>,
@@ -60,7 +66,7 @@ pub struct Search {
   pub auth: Option<Sensitive<String>>,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, Document)]
 pub struct SearchResponse {
   pub type_: String,
   pub comments: Vec<CommentView>,
@@ -69,13 +75,13 @@ pub struct SearchResponse {
   pub users: Vec<PersonViewSafe>,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, Document)]
 pub struct ResolveObject {
   pub q: String,
   pub auth: Option<Sensitive<String>>,
 }
 
-#[derive(Debug, Serialize, Deserialize, Default)]
+#[derive(Debug, Serialize, Deserialize, Default, Document)]
 pub struct ResolveObjectResponse {
   pub comment: Option<CommentView>,
   pub post: Option<PostView>,
@@ -83,7 +89,7 @@ pub struct ResolveObjectResponse {
   pub person: Option<PersonViewSafe>,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, Document)]
 pub struct GetModlog {
   pub mod_person_id: Option<PersonId>,
   pub community_id: Option<CommunityId>,
@@ -94,7 +100,7 @@ pub struct GetModlog {
   pub other_person_id: Option<PersonId>,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, Document)]
 pub struct GetModlogResponse {
   pub removed_posts: Vec<ModRemovePostView>,
   pub locked_posts: Vec<ModLockPostView>,
@@ -111,9 +117,10 @@ pub struct GetModlogResponse {
   pub admin_purged_posts: Vec<AdminPurgePostView>,
   pub admin_purged_comments: Vec<AdminPurgeCommentView>,
   pub hidden_communities: Vec<ModHideCommunityView>,
+  pub blocked_instances: Vec<AdminBlockInstanceView>,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, Document)]
 pub struct CreateSite {
   pub name: String,
   pub sidebar: Option<String>,
@@ -155,10 +162,17 @@ pub struct CreateSite {
   pub blocked_instances: Option<Vec<String>>,
   pub taglines: Option<Vec<String>>,
   pub registration_mode: Option<RegistrationMode>,
+  /// Turn top level notes from microblogging software, which are addressed to a community, into
+  /// posts
+  pub convert_notes_to_posts: Option<bool>,
+  /// Only answer ActivityPub fetches which are signed by an instance that is allowed to federate
+  pub authorized_fetch: Option<bool>,
+  /// Periodically crawl the community listings of linked instances, so that users can browse them
+  pub community_directory: Option<bool>,
   pub auth: Sensitive<String>,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, Document)]
 pub struct EditSite {
   pub name: Option<String>,
   pub sidebar: Option<String>,
@@ -201,20 +215,27 @@ pub struct EditSite {
   pub taglines: Option<Vec<String>>,
   pub registration_mode: Option<RegistrationMode>,
   pub reports_email_admins: Option<bool>,
+  /// Turn top level notes from microblogging software, which are addressed to a community, into
+  /// posts
+  pub convert_notes_to_posts: Option<bool>,
+  /// Only answer ActivityPub fetches which are signed by an instance that is allowed to federate
+  pub authorized_fetch: Option<bool>,
+  /// Periodically crawl the community listings of linked instances, so that users can browse them
+  pub community_directory: Option<bool>,
   pub auth: Sensitive<String>,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, Document)]
 pub struct GetSite {
   pub auth: Option<Sensitive<String>>,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, Document)]
 pub struct SiteResponse {
   pub site_view: SiteView,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, Document)]
 pub struct GetSiteResponse {
   pub site_view: SiteView,
   pub admins: Vec<PersonViewSafe>,
@@ -227,7 +248,7 @@ pub struct GetSiteResponse {
   pub taglines: Option<Vec<Tagline>>,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, Document)]
 pub struct MyUserInfo {
   pub local_user_view: LocalUserSettingsView,
   pub follows: Vec<CommunityFollowerView>,
@@ -237,52 +258,52 @@ pub struct MyUserInfo {
   pub discussion_languages: Vec<LanguageId>,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, Document)]
 pub struct LeaveAdmin {
   pub auth: Sensitive<String>,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, Document)]
 pub struct FederatedInstances {
   pub linked: Vec<Instance>,
   pub allowed: Option<Vec<Instance>>,
   pub blocked: Option<Vec<Instance>>,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, Document)]
 pub struct PurgePerson {
   pub person_id: PersonId,
   pub reason: Option<String>,
   pub auth: String,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, Document)]
 pub struct PurgeCommunity {
   pub community_id: CommunityId,
   pub reason: Option<String>,
   pub auth: String,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, Document)]
 pub struct PurgePost {
   pub post_id: PostId,
   pub reason: Option<String>,
   pub auth: String,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, Document)]
 pub struct PurgeComment {
   pub comment_id: CommentId,
   pub reason: Option<String>,
   pub auth: String,
 }
 
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, Document)]
 pub struct PurgeItemResponse {
   pub success: bool,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, Document)]
 pub struct ListRegistrationApplications {
   /// Only shows the unread applications (IE those without an admin actor)
   pub unread_only: Option<bool>,
@@ -291,12 +312,134 @@ pub struct ListRegistrationApplications {
   pub auth: String,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, Document)]
 pub struct ListRegistrationApplicationsResponse {
   pub registration_applications: Vec<RegistrationApplicationView>,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, Document)]
+pub struct BlockInstance {
+  /// Domain of the instance
+  pub instance: String,
+  pub block: bool,
+  /// Removes the cached communities, posts and comments of the instance, until it is unblocked
+  pub hide_content: Option<bool>,
+  /// Permanently deletes the cached communities and users of the instance, with all their content.
+  /// Takes precedence over `hide_content`.
+  pub purge_content: Option<bool>,
+  pub reason: Option<String>,
+  pub auth: Sensitive<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Document)]
+pub struct BlockInstanceResponse {
+  pub instance: Instance,
+  pub blocked: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default, Document)]
+pub struct GetFederationStats {
+  pub page: Option<i64>,
+  pub limit: Option<i64>,
+  pub auth: Sensitive<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Document)]
+pub struct GetFederationStatsResponse {
+  /// Health of federation with each remote instance, ordered by domain
+  pub instances: Vec<InstanceFederationStatsView>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default, Document)]
+pub struct AddRelay {
+  /// Actor id of the relay, eg `https://relay.example.com/actor`
+  pub actor_id: String,
+  /// Also send the activities of local communities to the relay
+  pub publish: Option<bool>,
+  pub auth: Sensitive<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default, Document)]
+pub struct RemoveRelay {
+  pub relay_id: RelayId,
+  pub auth: Sensitive<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Document)]
+pub struct RelayResponse {
+  pub relay: Relay,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default, Document)]
+pub struct ListRelays {
+  pub auth: Sensitive<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Document)]
+pub struct ListRelaysResponse {
+  pub relays: Vec<Relay>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default, Document)]
+pub struct ListActivities {
+  /// Actor id which sent the activity
+  pub actor: Option<String>,
+  /// Id of the activity object, eg a post or comment
+  pub object: Option<String>,
+  /// Activity type, eg `Create` or `Like`
+  pub type_: Option<String>,
+  /// Only shows activities which were sent (true) or received (false)
+  pub local: Option<bool>,
+  /// RFC 3339 timestamp
+  pub published_after: Option<String>,
+  /// RFC 3339 timestamp
+  pub published_before: Option<String>,
+  pub page: Option<i64>,
+  pub limit: Option<i64>,
+  pub auth: Sensitive<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Document)]
+pub struct ListActivitiesResponse {
+  pub activities: Vec<Activity>,
+}
+
+/// Processes a received activity again, as if it had just arrived in the shared inbox.
+#[derive(Debug, Serialize, Deserialize, Clone, Default, Document)]
+pub struct ReplayActivity {
+  pub activity_id: i32,
+  pub auth: Sensitive<String>,
+}
+
+/// Sends a local activity again to a single inbox, signed by its actor.
+#[derive(Debug, Serialize, Deserialize, Clone, Default, Document)]
+pub struct ResendActivity {
+  pub activity_id: i32,
+  pub inbox: String,
+  pub auth: Sensitive<String>,
+}
+
+/// Compares the aggregates tables (scores, comment counts, subscribers, newest comment times and
+/// user counts) with the values calculated from the source tables.
+#[derive(Debug, Serialize, Deserialize, Clone, Default, Document)]
+pub struct VerifyAggregates {
+  /// Also overwrites the wrong values
+  pub repair: Option<bool>,
+  pub auth: Sensitive<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Document)]
+pub struct VerifyAggregatesResponse {
+  /// Columns which didn't match, empty if everything is correct
+  pub differences: Vec<AggregateDifferences>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Document)]
+pub struct ActivityResponse {
+  pub activity: Activity,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default, Document)]
 pub struct ApproveRegistrationApplication {
   pub id: i32,
   pub approve: bool,
@@ -304,17 +447,17 @@ pub struct ApproveRegistrationApplication {
   pub auth: String,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, Document)]
 pub struct RegistrationApplicationResponse {
   pub registration_application: RegistrationApplicationView,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, Document)]
 pub struct GetUnreadRegistrationApplicationCount {
   pub auth: String,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, Document)]
 pub struct GetUnreadRegistrationApplicationCountResponse {
   pub registration_applications: i64,
 }
@@ -1,16 +1,24 @@
-use crate::{request::purge_image_from_pictrs, sensitive::Sensitive, site::FederatedInstances};
+use crate::{
+  context::LemmyContext,
+  request::purge_image_from_pictrs,
+  sensitive::Sensitive,
+  site::FederatedInstances,
+};
 use anyhow::Context;
 use chrono::NaiveDateTime;
 use lemmy_db_schema::{
   impls::person::is_banned,
-  newtypes::{CommunityId, DbUrl, LocalUserId, PersonId, PostId},
+  newtypes::{CommunityId, CommunityRuleId, DbUrl, InstanceId, LocalUserId, PersonId, PostId},
   source::{
     comment::{Comment, CommentUpdateForm},
     community::{Community, CommunityUpdateForm},
+    community_rule::CommunityRule,
     email_verification::{EmailVerification, EmailVerificationForm},
     instance::Instance,
+    instance_block_hidden::InstanceBlockHidden,
     local_site::{LocalSite, RegistrationMode},
     local_site_rate_limit::LocalSiteRateLimit,
+    local_user::LocalUser,
     password_reset_request::PasswordResetRequest,
     person::{Person, PersonUpdateForm},
     person_block::PersonBlock,
@@ -35,7 +43,7 @@ use lemmy_db_views_actor::structs::{
 use lemmy_utils::{
   claims::Claims,
   email::{send_email, translations::Lang},
-  error::LemmyError,
+  error::{LemmyError, LemmyErrorExt, LemmyErrorExt2, LemmyErrorType},
   location_info,
   rate_limit::RateLimitConfig,
   settings::structs::Settings,
@@ -45,7 +53,7 @@ use regex::Regex;
 use reqwest_middleware::ClientWithMiddleware;
 use rosetta_i18n::{Language, LanguageId};
 use std::str::FromStr;
-use tracing::warn;
+use tracing::{info, warn};
 use url::{ParseError, Url};
 
 #[tracing::instrument(skip_all)]
@@ -56,26 +64,24 @@ pub async fn is_mod_or_admin(
 ) -> Result<(), LemmyError> {
   let is_mod_or_admin = CommunityView::is_mod_or_admin(pool, person_id, community_id).await?;
   if !is_mod_or_admin {
-    return Err(LemmyError::from_message("not_a_mod_or_admin"));
+    return Err(LemmyErrorType::NotAModOrAdmin.into());
   }
   Ok(())
 }
 
 pub async fn is_top_admin(pool: &DbPool, person_id: PersonId) -> Result<(), LemmyError> {
   let admins = PersonViewSafe::admins(pool).await?;
-  let top_admin = admins
-    .get(0)
-    .ok_or_else(|| LemmyError::from_message("no admins"))?;
+  let top_admin = admins.get(0).ok_or(LemmyErrorType::NoAdmins)?;
 
   if top_admin.person.id != person_id {
-    return Err(LemmyError::from_message("not_top_admin"));
+    return Err(LemmyErrorType::NotTopAdmin.into());
   }
   Ok(())
 }
 
 pub fn is_admin(local_user_view: &LocalUserView) -> Result<(), LemmyError> {
   if !local_user_view.person.admin {
-    return Err(LemmyError::from_message("not_an_admin"));
+    return Err(LemmyErrorType::NotAnAdmin.into());
   }
   Ok(())
 }
@@ -84,7 +90,7 @@ pub fn is_admin(local_user_view: &LocalUserView) -> Result<(), LemmyError> {
 pub async fn get_post(post_id: PostId, pool: &DbPool) -> Result<Post, LemmyError> {
   Post::read(pool, post_id)
     .await
-    .map_err(|e| LemmyError::from_error_message(e, "couldnt_find_post"))
+    .with_lemmy_type(LemmyErrorType::CouldntFindPost)
 }
 
 #[tracing::instrument(skip_all)]
@@ -97,7 +103,7 @@ pub async fn mark_post_as_read(
 
   PostRead::mark_as_read(pool, &post_read_form)
     .await
-    .map_err(|e| LemmyError::from_error_message(e, "couldnt_mark_post_as_read"))
+    .with_lemmy_type(LemmyErrorType::CouldntMarkPostAsRead)
 }
 
 #[tracing::instrument(skip_all)]
@@ -110,7 +116,7 @@ pub async fn mark_post_as_unread(
 
   PostRead::mark_as_unread(pool, &post_read_form)
     .await
-    .map_err(|e| LemmyError::from_error_message(e, "couldnt_mark_post_as_read"))
+    .with_lemmy_type(LemmyErrorType::CouldntMarkPostAsRead)
 }
 
 #[tracing::instrument(skip_all)]
@@ -120,7 +126,7 @@ pub async fn get_local_user_view_from_jwt(
   secret: &Secret,
 ) -> Result<LocalUserView, LemmyError> {
   let claims = Claims::decode(jwt, &secret.jwt_secret)
-    .map_err(|e| e.with_message("not_logged_in"))?
+    .with_lemmy_type(LemmyErrorType::NotLoggedIn)?
     .claims;
   let local_user_id = LocalUserId(claims.sub);
   let local_user_view = LocalUserView::read(pool, local_user_id).await?;
@@ -142,7 +148,7 @@ pub fn check_validator_time(
 ) -> Result<(), LemmyError> {
   let user_validation_time = validator_time.timestamp();
   if user_validation_time > claims.iat {
-    Err(LemmyError::from_message("not_logged_in"))
+    Err(LemmyErrorType::NotLoggedIn.into())
   } else {
     Ok(())
   }
@@ -160,6 +166,21 @@ pub async fn get_local_user_view_from_jwt_opt(
   }
 }
 
+/// Sends the listings of this user to the primary database for a while, so that they see their
+/// own changes even if the read replicas lag behind. Does nothing without read replicas.
+#[tracing::instrument(skip_all)]
+pub async fn record_write(jwt: Option<&str>, pool: &DbPool, secret: &Secret) {
+  if !pool.has_read_replicas() {
+    return;
+  }
+  // Invalid tokens are rejected by the request itself
+  if let Some(claims) = jwt.and_then(|jwt| Claims::decode(jwt, &secret.jwt_secret).ok()) {
+    if let Ok(local_user) = LocalUser::read(pool, LocalUserId(claims.claims.sub)).await {
+      pool.record_write(local_user.person_id).await;
+    }
+  }
+}
+
 #[tracing::instrument(skip_all)]
 pub async fn get_local_user_settings_view_from_jwt_opt(
   jwt: Option<&Sensitive<String>>,
@@ -169,7 +190,7 @@ pub async fn get_local_user_settings_view_from_jwt_opt(
   match jwt {
     Some(jwt) => {
       let claims = Claims::decode(jwt.as_ref(), &secret.jwt_secret)
-        .map_err(|e| e.with_message("not_logged_in"))?
+        .with_lemmy_type(LemmyErrorType::NotLoggedIn)?
         .claims;
       let local_user_id = LocalUserId(claims.sub);
       let local_user_view = LocalUserSettingsView::read(pool, local_user_id).await?;
@@ -193,12 +214,12 @@ pub fn check_user_valid(
 ) -> Result<(), LemmyError> {
   // Check for a site ban
   if is_banned(banned, ban_expires) {
-    return Err(LemmyError::from_message("site_ban"));
+    return Err(LemmyErrorType::SiteBan.into());
   }
 
   // check for account deletion
   if deleted {
-    return Err(LemmyError::from_message("deleted"));
+    return Err(LemmyErrorType::Deleted.into());
   }
 
   Ok(())
@@ -214,7 +235,7 @@ pub async fn check_community_ban(
     .await
     .is_ok();
   if is_banned {
-    Err(LemmyError::from_message("community_ban"))
+    Err(LemmyErrorType::CommunityBan.into())
   } else {
     Ok(())
   }
@@ -227,17 +248,34 @@ pub async fn check_community_deleted_or_removed(
 ) -> Result<(), LemmyError> {
   let community = Community::read(pool, community_id)
     .await
-    .map_err(|e| LemmyError::from_error_message(e, "couldnt_find_community"))?;
+    .with_lemmy_type(LemmyErrorType::CouldntFindCommunity)?;
   if community.deleted || community.removed {
-    Err(LemmyError::from_message("deleted"))
+    Err(LemmyErrorType::Deleted.into())
   } else {
     Ok(())
   }
 }
 
+/// Makes sure that a rule cited in a report or removal is one of the rules of the community.
+pub async fn check_community_rule(
+  rule_id: Option<CommunityRuleId>,
+  community_id: CommunityId,
+  pool: &DbPool,
+) -> Result<(), LemmyError> {
+  if let Some(rule_id) = rule_id {
+    let rule = CommunityRule::read(pool, rule_id)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntFindCommunityRule)?;
+    if rule.community_id != community_id {
+      return Err(LemmyErrorType::RuleNotInCommunity.into());
+    }
+  }
+  Ok(())
+}
+
 pub fn check_post_deleted_or_removed(post: &Post) -> Result<(), LemmyError> {
   if post.deleted || post.removed {
-    Err(LemmyError::from_message("deleted"))
+    Err(LemmyErrorType::Deleted.into())
   } else {
     Ok(())
   }
@@ -253,7 +291,7 @@ pub async fn check_person_block(
     .await
     .is_ok();
   if is_blocked {
-    Err(LemmyError::from_message("person_block"))
+    Err(LemmyErrorType::PersonBlock.into())
   } else {
     Ok(())
   }
@@ -262,7 +300,7 @@ pub async fn check_person_block(
 #[tracing::instrument(skip_all)]
 pub fn check_downvotes_enabled(score: i16, local_site: &LocalSite) -> Result<(), LemmyError> {
   if score == -1 && !local_site.enable_downvotes {
-    return Err(LemmyError::from_message("downvotes_disabled"));
+    return Err(LemmyErrorType::DownvotesDisabled.into());
   }
   Ok(())
 }
@@ -273,7 +311,7 @@ pub fn check_private_instance(
   local_site: &LocalSite,
 ) -> Result<(), LemmyError> {
   if local_user_view.is_none() && local_site.private_instance {
-    return Err(LemmyError::from_message("instance_is_private"));
+    return Err(LemmyErrorType::InstanceIsPrivate.into());
   }
   Ok(())
 }
@@ -306,7 +344,7 @@ pub async fn build_federated_instances(
 /// Checks the password length
 pub fn password_length_check(pass: &str) -> Result<(), LemmyError> {
   if !(10..=60).contains(&pass.chars().count()) {
-    Err(LemmyError::from_message("invalid_password"))
+    Err(LemmyErrorType::InvalidPassword.into())
   } else {
     Ok(())
   }
@@ -315,7 +353,7 @@ pub fn password_length_check(pass: &str) -> Result<(), LemmyError> {
 /// Checks the site description length
 pub fn site_description_length_check(description: &str) -> Result<(), LemmyError> {
   if description.len() > 150 {
-    Err(LemmyError::from_message("site_description_length_overflow"))
+    Err(LemmyErrorType::SiteDescriptionLengthOverflow.into())
   } else {
     Ok(())
   }
@@ -324,7 +362,7 @@ pub fn site_description_length_check(description: &str) -> Result<(), LemmyError
 /// Checks for a honeypot. If this field is filled, fail the rest of the function
 pub fn honeypot_check(honeypot: &Option<String>) -> Result<(), LemmyError> {
   if honeypot.is_some() && honeypot != &Some(String::new()) {
-    Err(LemmyError::from_message("honeypot_fail"))
+    Err(LemmyErrorType::HoneypotFail.into())
   } else {
     Ok(())
   }
@@ -531,11 +569,9 @@ pub async fn check_registration_application(
     let local_user_id = local_user_view.local_user.id;
     let registration = RegistrationApplication::find_by_local_user_id(pool, local_user_id).await?;
     if let Some(deny_reason) = registration.deny_reason {
-      let lang = get_interface_language(local_user_view);
-      let registration_denied_message = format!("{}: {}", lang.registration_denied(), &deny_reason);
-      return Err(LemmyError::from_message(&registration_denied_message));
+      return Err(LemmyErrorType::RegistrationDenied(deny_reason).into());
     } else {
-      return Err(LemmyError::from_message("registration_application_pending"));
+      return Err(LemmyErrorType::RegistrationApplicationPending.into());
     }
   }
   Ok(())
@@ -545,9 +581,7 @@ pub fn check_private_instance_and_federation_enabled(
   local_site: &LocalSite,
 ) -> Result<(), LemmyError> {
   if local_site.private_instance && local_site.federation_enabled {
-    return Err(LemmyError::from_message(
-      "Cannot have both private instance and federation enabled.",
-    ));
+    return Err(LemmyErrorType::CantEnablePrivateInstanceAndFederationTogether.into());
   }
   Ok(())
 }
@@ -598,6 +632,104 @@ pub async fn purge_image_posts_for_community(
   Ok(())
 }
 
+/// Number of items which are hidden, restored or purged at once when an instance is (un)blocked
+const INSTANCE_CLEANUP_BATCH_SIZE: i64 = 1000;
+
+/// Purges the pictrs images of the persons of an instance, their image posts, and the images of
+/// the communities which are purged together with the instance. Has to run before the content is
+/// deleted with [`Instance::purge_content_batch`].
+pub async fn purge_instance_images(
+  instance_id: InstanceId,
+  pool: &DbPool,
+  settings: &Settings,
+  client: &ClientWithMiddleware,
+) -> Result<(), LemmyError> {
+  let mut after = None;
+  loop {
+    let persons =
+      Instance::persons_batch(pool, instance_id, after, INSTANCE_CLEANUP_BATCH_SIZE).await?;
+    after = match persons.last() {
+      Some(person) => Some(person.id),
+      None => break,
+    };
+    for person in persons {
+      for image in [person.avatar, person.banner].into_iter().flatten() {
+        purge_image_from_pictrs(client, settings, &image).await.ok();
+      }
+      purge_image_posts_for_person(person.id, pool, settings, client).await?;
+    }
+  }
+
+  for community in Instance::communities_to_purge(pool, instance_id).await? {
+    for image in [community.icon, community.banner].into_iter().flatten() {
+      purge_image_from_pictrs(client, settings, &image).await.ok();
+    }
+  }
+  Ok(())
+}
+
+/// Cleans up after blocking an instance in the background. Local follows of its communities are
+/// always removed. Its communities, posts and comments are either purged, hidden until the instance
+/// is unblocked, or left as they are.
+pub fn spawn_blocked_instance_cleanup(
+  context: &LemmyContext,
+  instance_id: InstanceId,
+  hide_content: bool,
+  purge_content: bool,
+) {
+  let context = context.clone();
+  actix_rt::spawn(async move {
+    let pool = context.pool();
+    let result: Result<usize, LemmyError> = async {
+      let follows = Instance::remove_community_follows(pool, instance_id).await?;
+      info!("Removed {} follows of blocked instance", follows);
+      if purge_content {
+        purge_instance_images(instance_id, pool, context.settings(), context.client()).await?;
+      }
+      let mut total = 0;
+      loop {
+        let batch = if purge_content {
+          Instance::purge_content_batch(pool, instance_id, INSTANCE_CLEANUP_BATCH_SIZE).await?
+        } else if hide_content {
+          InstanceBlockHidden::hide_batch(pool, instance_id, INSTANCE_CLEANUP_BATCH_SIZE).await?
+        } else {
+          0
+        };
+        if batch == 0 {
+          return Ok(total);
+        }
+        total += batch;
+      }
+    }
+    .await;
+    match result {
+      Ok(total) => info!("Cleaned up {} items of blocked instance", total),
+      Err(e) => warn!("Failed to clean up content of blocked instance: {}", e),
+    }
+  });
+}
+
+/// Restores the content which was hidden when the instance was blocked, in the background.
+pub fn spawn_unblocked_instance_restore(pool: &DbPool, instance_id: InstanceId) {
+  let pool = pool.clone();
+  actix_rt::spawn(async move {
+    let mut total = 0;
+    loop {
+      match InstanceBlockHidden::restore_batch(&pool, instance_id, INSTANCE_CLEANUP_BATCH_SIZE)
+        .await
+      {
+        Ok(0) => break,
+        Ok(batch) => total += batch,
+        Err(e) => {
+          warn!("Failed to restore content of unblocked instance: {}", e);
+          break;
+        }
+      }
+    }
+    info!("Restored {} items of unblocked instance", total);
+  });
+}
+
 pub async fn remove_user_data(
   banned_person_id: PersonId,
   pool: &DbPool,
@@ -736,12 +868,12 @@ pub async fn delete_user_account(
   // Comments
   Comment::permadelete_for_creator(pool, person_id)
     .await
-    .map_err(|e| LemmyError::from_error_message(e, "couldnt_update_comment"))?;
+    .with_lemmy_type(LemmyErrorType::CouldntUpdateComment)?;
 
   // Posts
   Post::permadelete_for_creator(pool, person_id)
     .await
-    .map_err(|e| LemmyError::from_error_message(e, "couldnt_update_post"))?;
+    .with_lemmy_type(LemmyErrorType::CouldntUpdatePost)?;
 
   // Purge image posts
   purge_image_posts_for_person(person_id, pool, settings, client).await?;
@@ -840,6 +972,10 @@ pub fn generate_outbox_url(actor_id: &DbUrl) -> Result<DbUrl, ParseError> {
   Ok(Url::parse(&format!("{actor_id}/outbox"))?.into())
 }
 
+pub fn generate_replies_url(ap_id: &DbUrl) -> Result<DbUrl, ParseError> {
+  Ok(Url::parse(&format!("{ap_id}/replies"))?.into())
+}
+
 pub fn generate_featured_url(actor_id: &DbUrl) -> Result<DbUrl, ParseError> {
   Ok(Url::parse(&format!("{actor_id}/featured"))?.into())
 }
@@ -6,10 +6,18 @@ use crate::{
 use actix_ws::Session;
 use anyhow::Context as acontext;
 use futures::future::join_all;
-use lemmy_db_schema::newtypes::{CommunityId, LocalUserId, PostId};
-use lemmy_utils::{error::LemmyError, location_info, ConnectionId};
+use lemmy_db_schema::{
+  newtypes::{CommunityId, LocalUserId, PostId},
+  source::websocket_message::{WebsocketMessage, WebsocketMessageForm},
+  utils::DbPool,
+};
+use lemmy_utils::{
+  error::{LemmyError, LemmyErrorType},
+  location_info,
+  ConnectionId,
+};
 use rand::{rngs::StdRng, SeedableRng};
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use std::{
   collections::{HashMap, HashSet},
   sync::{Mutex, MutexGuard},
@@ -20,6 +28,24 @@ use tracing::log::warn;
 /// session.
 pub struct ChatServer {
   inner: Mutex<ChatServerInner>,
+  /// Set if other lemmy_server processes share the runtime state through Postgres
+  pub(super) shared_state: Option<SharedState>,
+}
+
+pub(super) struct SharedState {
+  pub(super) pool: DbPool,
+  /// Random id of this process, to ignore the websocket messages which it relayed itself
+  node_id: String,
+}
+
+/// A group of connections which receive the same websocket messages.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy)]
+pub enum WebsocketRoom {
+  All,
+  Post(PostId),
+  Community(CommunityId),
+  Mod(CommunityId),
+  User(LocalUserId),
 }
 
 pub struct ChatServerInner {
@@ -49,6 +75,19 @@ pub struct ChatServerInner {
 /// room through `ChatServer`.
 impl ChatServer {
   pub fn startup() -> ChatServer {
+    Self::create(None)
+  }
+
+  /// Keeps captchas in the database, and relays websocket messages to the other processes which
+  /// use the same database.
+  pub fn startup_shared(pool: DbPool) -> ChatServer {
+    Self::create(Some(SharedState {
+      pool,
+      node_id: uuid::Uuid::new_v4().to_string(),
+    }))
+  }
+
+  fn create(shared_state: Option<SharedState>) -> ChatServer {
     ChatServer {
       inner: Mutex::new(ChatServerInner {
         sessions: Default::default(),
@@ -59,6 +98,7 @@ impl ChatServer {
         rng: StdRng::from_entropy(),
         captchas: vec![],
       }),
+      shared_state,
     }
   }
 
@@ -178,9 +218,9 @@ impl ChatServer {
     Response: Serialize,
   {
     let msg = serialize_websocket_message(op, response)?;
-    let room = self.inner()?.post_rooms.get(&post_id).cloned();
-    self.send_message_in_room(&msg, room, websocket_id).await?;
-    Ok(())
+    self
+      .send_room_message(WebsocketRoom::Post(post_id), &msg, websocket_id)
+      .await
   }
 
   /// Send message to all users viewing the given community.
@@ -196,9 +236,9 @@ impl ChatServer {
     Response: Serialize,
   {
     let msg = serialize_websocket_message(op, response)?;
-    let room = self.inner()?.community_rooms.get(&community_id).cloned();
-    self.send_message_in_room(&msg, room, websocket_id).await?;
-    Ok(())
+    self
+      .send_room_message(WebsocketRoom::Community(community_id), &msg, websocket_id)
+      .await
   }
 
   /// Send message to mods of a given community. Set community_id = 0 to send to site admins.
@@ -214,9 +254,9 @@ impl ChatServer {
     Response: Serialize,
   {
     let msg = serialize_websocket_message(&op, response)?;
-    let room = self.inner()?.mod_rooms.get(&community_id).cloned();
-    self.send_message_in_room(&msg, room, websocket_id).await?;
-    Ok(())
+    self
+      .send_room_message(WebsocketRoom::Mod(community_id), &msg, websocket_id)
+      .await
   }
 
   pub async fn send_all_message<OP, Response>(
@@ -229,17 +269,10 @@ impl ChatServer {
     OP: OperationType + ToString,
     Response: Serialize,
   {
-    let msg = &serialize_websocket_message(&op, response)?;
-    let sessions = self.inner()?.sessions.clone();
-    // Note, this will ignore any errors, such as closed connections
-    join_all(
-      sessions
-        .into_iter()
-        .filter(|(id, _)| Some(id) != exclude_connection.as_ref())
-        .map(|(_, mut s): (_, Session)| async move { s.text(msg).await }),
-    )
-    .await;
-    Ok(())
+    let msg = serialize_websocket_message(&op, response)?;
+    self
+      .send_room_message(WebsocketRoom::All, &msg, exclude_connection)
+      .await
   }
 
   pub async fn send_user_room_message<OP, Response>(
@@ -254,9 +287,9 @@ impl ChatServer {
     Response: Serialize,
   {
     let msg = serialize_websocket_message(op, response)?;
-    let room = self.inner()?.user_rooms.get(&recipient_id).cloned();
-    self.send_message_in_room(&msg, room, websocket_id).await?;
-    Ok(())
+    self
+      .send_room_message(WebsocketRoom::User(recipient_id), &msg, websocket_id)
+      .await
   }
 
   pub async fn send_comment<OP>(
@@ -355,19 +388,66 @@ impl ChatServer {
     Ok(())
   }
 
-  /// Send websocket message in all sessions which joined a specific room.
+  /// Send websocket message in all sessions which joined a specific room, and relay it to the
+  /// other processes.
   ///
   /// `message` - The json message body to send
-  /// `room` - Connection IDs which should receive the message
   /// `exclude_connection` - Dont send to user who initiated the api call, as that
   ///                        would result in duplicate notification
+  async fn send_room_message(
+    &self,
+    room: WebsocketRoom,
+    message: &str,
+    exclude_connection: Option<ConnectionId>,
+  ) -> Result<(), LemmyError> {
+    self
+      .send_message_in_room(message, room, exclude_connection)
+      .await?;
+    if let Some(shared_state) = &self.shared_state {
+      let form = WebsocketMessageForm {
+        node_id: shared_state.node_id.clone(),
+        room: serde_json::to_value(room)?,
+        message: message.to_string(),
+      };
+      WebsocketMessage::create(&shared_state.pool, &form).await?;
+    }
+    Ok(())
+  }
+
+  /// Delivers a message which another process relayed, to the sessions of this process.
+  pub async fn handle_relayed_message(&self, message_id: i32) -> Result<(), LemmyError> {
+    let shared_state = match &self.shared_state {
+      Some(s) => s,
+      None => return Ok(()),
+    };
+    let message = WebsocketMessage::read(&shared_state.pool, message_id).await?;
+    if message.node_id == shared_state.node_id {
+      return Ok(());
+    }
+    let room: WebsocketRoom = serde_json::from_value(message.room)?;
+    self
+      .send_message_in_room(&message.message, room, None)
+      .await
+  }
+
+  /// Send websocket message in all sessions of this process which joined a specific room.
   async fn send_message_in_room(
     &self,
     message: &str,
-    room: Option<HashSet<ConnectionId>>,
+    room: WebsocketRoom,
     exclude_connection: Option<ConnectionId>,
   ) -> Result<(), LemmyError> {
-    let mut session = self.inner()?.sessions.clone();
+    let (room, mut session) = {
+      let inner = self.inner()?;
+      let room = match room {
+        WebsocketRoom::All => Some(inner.sessions.keys().copied().collect()),
+        WebsocketRoom::Post(id) => inner.post_rooms.get(&id).cloned(),
+        WebsocketRoom::Community(id) => inner.community_rooms.get(&id).cloned(),
+        WebsocketRoom::Mod(id) => inner.mod_rooms.get(&id).cloned(),
+        WebsocketRoom::User(id) => inner.user_rooms.get(&id).cloned(),
+      };
+      (room, inner.sessions.clone())
+    };
     if let Some(room) = room {
       // Note, this will ignore any errors, such as closed connections
       join_all(
@@ -387,7 +467,7 @@ impl ChatServer {
       Ok(g) => Ok(g),
       Err(e) => {
         warn!("Failed to lock chatserver mutex: {}", e);
-        Err(LemmyError::from_message("Failed to lock chatserver mutex"))
+        Err(LemmyErrorType::FailedToLockMutex.into())
       }
     }
   }
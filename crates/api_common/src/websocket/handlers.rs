@@ -2,6 +2,7 @@ use crate::websocket::{chat_server::ChatServer, structs::CaptchaItem};
 use actix_ws::Session;
 use lemmy_db_schema::{
   newtypes::{CommunityId, PostId},
+  source::captcha_answer::{CaptchaAnswer, CaptchaAnswerForm},
   utils::naive_now,
 };
 use lemmy_utils::{error::LemmyError, ConnectionId};
@@ -60,12 +61,25 @@ impl ChatServer {
     }
   }
 
-  pub fn add_captcha(&self, captcha: CaptchaItem) -> Result<(), LemmyError> {
-    self.inner()?.captchas.push(captcha);
+  pub async fn add_captcha(&self, captcha: CaptchaItem) -> Result<(), LemmyError> {
+    if let Some(shared_state) = &self.shared_state {
+      let form = CaptchaAnswerForm {
+        uuid: captcha.uuid,
+        answer: captcha.answer,
+        expires: captcha.expires,
+      };
+      CaptchaAnswer::insert(&shared_state.pool, &form).await?;
+    } else {
+      self.inner()?.captchas.push(captcha);
+    }
     Ok(())
   }
 
-  pub fn check_captcha(&self, uuid: String, answer: String) -> Result<bool, LemmyError> {
+  pub async fn check_captcha(&self, uuid: String, answer: String) -> Result<bool, LemmyError> {
+    if let Some(shared_state) = &self.shared_state {
+      return Ok(CaptchaAnswer::check_captcha(&shared_state.pool, &uuid, &answer).await?);
+    }
+
     let mut inner = self.inner()?;
     // Remove all the ones that are past the expire time
     inner.captchas.retain(|x| x.expires.gt(&naive_now()));
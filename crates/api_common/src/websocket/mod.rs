@@ -37,6 +37,8 @@ pub enum UserOperation {
   CreateCommentReport,
   ResolveCommentReport,
   ListCommentReports,
+  AssignCommentReport,
+  EscalateCommentReport,
   CreatePostLike,
   LockPost,
   FeaturePost,
@@ -45,7 +47,11 @@ pub enum UserOperation {
   CreatePostReport,
   ResolvePostReport,
   ListPostReports,
+  AssignPostReport,
+  EscalatePostReport,
   GetReportCount,
+  CreateReportComment,
+  ListReportComments,
   GetUnreadCount,
   VerifyEmail,
   FollowCommunity,
@@ -62,6 +68,14 @@ pub enum UserOperation {
   ApproveRegistrationApplication,
   BanPerson,
   GetBannedPersons,
+  ListMyCommunityBans,
+  ListCommunityBackfills,
+  ListDirectoryCommunities,
+  GetFederationStats,
+  BlockInstance,
+  ListRelays,
+  ListActivities,
+  VerifyAggregates,
   MarkAllAsRead,
   SaveUserSettings,
   TransferCommunity,
@@ -72,6 +86,7 @@ pub enum UserOperation {
   CreatePrivateMessageReport,
   ResolvePrivateMessageReport,
   ListPrivateMessageReports,
+  AssignPrivateMessageReport,
   UserJoin,
   PostJoin,
   CommunityJoin,
@@ -128,6 +143,10 @@ pub enum UserOperationApub {
   GetPersonDetails,
   Search,
   ResolveObject,
+  AddRelay,
+  RemoveRelay,
+  ReplayActivity,
+  ResendActivity,
 }
 
 pub trait OperationType {}
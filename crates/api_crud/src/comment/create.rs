@@ -23,14 +23,18 @@ use lemmy_db_schema::{
     actor_language::CommunityLanguage,
     comment::{Comment, CommentInsertForm, CommentLike, CommentLikeForm, CommentUpdateForm},
     comment_reply::{CommentReply, CommentReplyUpdateForm},
+    community::Community,
     local_site::LocalSite,
     person_mention::{PersonMention, PersonMentionUpdateForm},
   },
   traits::{Crud, Likeable},
 };
 use lemmy_utils::{
-  error::LemmyError,
-  utils::{mention::scrape_text_for_mentions, slurs::remove_slurs},
+  error::{LemmyError, LemmyErrorExt, LemmyErrorType},
+  utils::{
+    mention::scrape_text_for_mentions,
+    slurs::{build_slur_regex, remove_slurs},
+  },
   ConnectionId,
 };
 
@@ -63,9 +67,15 @@ impl PerformCrud for CreateComment {
     check_community_deleted_or_removed(community_id, context.pool()).await?;
     check_post_deleted_or_removed(&post)?;
 
+    let community = Community::read(context.pool(), community_id).await?;
+    let content_slurs_removed = remove_slurs(
+      &content_slurs_removed,
+      &build_slur_regex(community.slur_filter_regex.as_deref()),
+    );
+
     // Check if post is locked, no new comments
     if post.locked {
-      return Err(LemmyError::from_message("locked"));
+      return Err(LemmyErrorType::Locked.into());
     }
 
     // Fetch the parent, if it exists
@@ -79,7 +89,7 @@ impl PerformCrud for CreateComment {
     // Strange issue where sometimes the post ID of the parent comment is incorrect
     if let Some(parent) = parent_opt.as_ref() {
       if parent.post_id != post_id {
-        return Err(LemmyError::from_message("couldnt_create_comment"));
+        return Err(LemmyErrorType::CouldntCreateComment.into());
       }
     }
 
@@ -109,7 +119,7 @@ impl PerformCrud for CreateComment {
     let parent_path = parent_opt.clone().map(|t| t.path);
     let inserted_comment = Comment::create(context.pool(), &comment_form2, parent_path.as_ref())
       .await
-      .map_err(|e| LemmyError::from_error_message(e, "couldnt_create_comment"))?;
+      .with_lemmy_type(LemmyErrorType::CouldntCreateComment)?;
 
     // Necessary to update the ap_id
     let inserted_comment_id = inserted_comment.id;
@@ -126,7 +136,7 @@ impl PerformCrud for CreateComment {
       &CommentUpdateForm::builder().ap_id(Some(apub_id)).build(),
     )
     .await
-    .map_err(|e| LemmyError::from_error_message(e, "couldnt_create_comment"))?;
+    .with_lemmy_type(LemmyErrorType::CouldntCreateComment)?;
 
     // Scan the comment for user mentions, add those rows
     let post_id = post.id;
@@ -151,7 +161,7 @@ impl PerformCrud for CreateComment {
 
     CommentLike::like(context.pool(), &like_form)
       .await
-      .map_err(|e| LemmyError::from_error_message(e, "couldnt_like_comment"))?;
+      .with_lemmy_type(LemmyErrorType::CouldntLikeComment)?;
 
     // If its a reply, mark the parent as read
     if let Some(parent) = parent_opt {
@@ -164,7 +174,7 @@ impl PerformCrud for CreateComment {
           &CommentReplyUpdateForm { read: Some(true) },
         )
         .await
-        .map_err(|e| LemmyError::from_error_message(e, "couldnt_update_replies"))?;
+        .with_lemmy_type(LemmyErrorType::CouldntUpdateReplies)?;
       }
 
       // If the parent has PersonMentions mark them as read too
@@ -178,7 +188,7 @@ impl PerformCrud for CreateComment {
           &PersonMentionUpdateForm { read: Some(true) },
         )
         .await
-        .map_err(|e| LemmyError::from_error_message(e, "couldnt_update_person_mentions"))?;
+        .with_lemmy_type(LemmyErrorType::CouldntUpdatePersonMentions)?;
       }
     }
 
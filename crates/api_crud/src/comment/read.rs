@@ -7,7 +7,10 @@ use lemmy_api_common::{
 };
 use lemmy_db_schema::source::local_site::LocalSite;
 use lemmy_db_views::structs::CommentView;
-use lemmy_utils::{error::LemmyError, ConnectionId};
+use lemmy_utils::{
+  error::{LemmyError, LemmyErrorExt, LemmyErrorType},
+  ConnectionId,
+};
 
 #[async_trait::async_trait(?Send)]
 impl PerformCrud for GetComment {
@@ -31,7 +34,7 @@ impl PerformCrud for GetComment {
     let id = data.id;
     let comment_view = CommentView::read(context.pool(), id, person_id)
       .await
-      .map_err(|e| LemmyError::from_error_message(e, "couldnt_find_comment"))?;
+      .with_lemmy_type(LemmyErrorType::CouldntFindComment)?;
 
     Ok(Self::Response {
       comment_view,
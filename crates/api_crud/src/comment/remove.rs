@@ -3,7 +3,12 @@ use actix_web::web::Data;
 use lemmy_api_common::{
   comment::{CommentResponse, RemoveComment},
   context::LemmyContext,
-  utils::{check_community_ban, get_local_user_view_from_jwt, is_mod_or_admin},
+  utils::{
+    check_community_ban,
+    check_community_rule,
+    get_local_user_view_from_jwt,
+    is_mod_or_admin,
+  },
   websocket::{
     send::{send_comment_ws_message, send_local_notifs},
     UserOperationCrud,
@@ -18,7 +23,10 @@ use lemmy_db_schema::{
   traits::Crud,
 };
 use lemmy_db_views::structs::CommentView;
-use lemmy_utils::{error::LemmyError, ConnectionId};
+use lemmy_utils::{
+  error::{LemmyError, LemmyErrorExt, LemmyErrorType},
+  ConnectionId,
+};
 
 #[async_trait::async_trait(?Send)]
 impl PerformCrud for RemoveComment {
@@ -51,6 +59,7 @@ impl PerformCrud for RemoveComment {
       orig_comment.community.id,
     )
     .await?;
+    check_community_rule(data.rule_id, orig_comment.community.id, context.pool()).await?;
 
     // Do the remove
     let removed = data.removed;
@@ -60,7 +69,7 @@ impl PerformCrud for RemoveComment {
       &CommentUpdateForm::builder().removed(Some(removed)).build(),
     )
     .await
-    .map_err(|e| LemmyError::from_error_message(e, "couldnt_update_comment"))?;
+    .with_lemmy_type(LemmyErrorType::CouldntUpdateComment)?;
 
     // Mod tables
     let form = ModRemoveCommentForm {
@@ -68,6 +77,7 @@ impl PerformCrud for RemoveComment {
       comment_id: data.comment_id,
       removed: Some(removed),
       reason: data.reason.clone(),
+      rule_id: data.rule_id,
     };
     ModRemoveComment::create(context.pool(), &form).await?;
 
@@ -20,8 +20,11 @@ use lemmy_db_schema::{
 };
 use lemmy_db_views::structs::CommentView;
 use lemmy_utils::{
-  error::LemmyError,
-  utils::{mention::scrape_text_for_mentions, slurs::remove_slurs},
+  error::{LemmyError, LemmyErrorExt, LemmyErrorType},
+  utils::{
+    mention::scrape_text_for_mentions,
+    slurs::{build_slur_regex, remove_slurs},
+  },
   ConnectionId,
 };
 
@@ -52,7 +55,7 @@ impl PerformCrud for EditComment {
 
     // Verify that only the creator can edit
     if local_user_view.person.id != orig_comment.creator.id {
-      return Err(LemmyError::from_message("no_comment_edit_allowed"));
+      return Err(LemmyErrorType::NoCommentEditAllowed.into());
     }
 
     let language_id = self.language_id;
@@ -67,7 +70,13 @@ impl PerformCrud for EditComment {
     let content_slurs_removed = data
       .content
       .as_ref()
-      .map(|c| remove_slurs(c, &local_site_to_slur_regex(&local_site)));
+      .map(|c| remove_slurs(c, &local_site_to_slur_regex(&local_site)))
+      .map(|c| {
+        remove_slurs(
+          &c,
+          &build_slur_regex(orig_comment.community.slur_filter_regex.as_deref()),
+        )
+      });
     let comment_id = data.comment_id;
     let form = CommentUpdateForm::builder()
       .content(content_slurs_removed)
@@ -76,7 +85,7 @@ impl PerformCrud for EditComment {
       .build();
     let updated_comment = Comment::update(context.pool(), comment_id, &form)
       .await
-      .map_err(|e| LemmyError::from_error_message(e, "couldnt_update_comment"))?;
+      .with_lemmy_type(LemmyErrorType::CouldntUpdateComment)?;
 
     // Do the mentions / recipients
     let updated_comment_content = updated_comment.content.clone();
@@ -33,7 +33,7 @@ use lemmy_db_schema::{
 use lemmy_db_views::structs::SiteView;
 use lemmy_db_views_actor::structs::CommunityView;
 use lemmy_utils::{
-  error::LemmyError,
+  error::{LemmyError, LemmyErrorExt, LemmyErrorType},
   utils::{
     slurs::{check_slurs, check_slurs_opt},
     validation::is_valid_actor_name,
@@ -58,9 +58,7 @@ impl PerformCrud for CreateCommunity {
     let local_site = site_view.local_site;
 
     if local_site.community_creation_admin_only && is_admin(&local_user_view).is_err() {
-      return Err(LemmyError::from_message(
-        "only_admins_can_create_communities",
-      ));
+      return Err(LemmyErrorType::OnlyAdminsCanCreateCommunities.into());
     }
 
     // Check to make sure the icon and banners are urls
@@ -73,7 +71,7 @@ impl PerformCrud for CreateCommunity {
     check_slurs_opt(&data.description, &slur_regex)?;
 
     if !is_valid_actor_name(&data.name, local_site.actor_name_max_length as usize) {
-      return Err(LemmyError::from_message("invalid_community_name"));
+      return Err(LemmyErrorType::InvalidCommunityName.into());
     }
 
     // Double check for duplicate community actor_ids
@@ -84,7 +82,7 @@ impl PerformCrud for CreateCommunity {
     )?;
     let community_dupe = Community::read_from_apub_id(context.pool(), &community_actor_id).await?;
     if community_dupe.is_some() {
-      return Err(LemmyError::from_message("community_already_exists"));
+      return Err(LemmyErrorType::CommunityAlreadyExists.into());
     }
 
     // When you create a community, make sure the user becomes a moderator and a follower
@@ -109,7 +107,7 @@ impl PerformCrud for CreateCommunity {
 
     let inserted_community = Community::create(context.pool(), &community_form)
       .await
-      .map_err(|e| LemmyError::from_error_message(e, "community_already_exists"))?;
+      .with_lemmy_type(LemmyErrorType::CommunityAlreadyExists)?;
 
     // The community creator becomes a moderator
     let community_moderator_form = CommunityModeratorForm {
@@ -119,7 +117,7 @@ impl PerformCrud for CreateCommunity {
 
     CommunityModerator::join(context.pool(), &community_moderator_form)
       .await
-      .map_err(|e| LemmyError::from_error_message(e, "community_moderator_already_exists"))?;
+      .with_lemmy_type(LemmyErrorType::CommunityModeratorAlreadyExists)?;
 
     // Follow your own community
     let community_follower_form = CommunityFollowerForm {
@@ -130,7 +128,7 @@ impl PerformCrud for CreateCommunity {
 
     CommunityFollower::follow(context.pool(), &community_follower_form)
       .await
-      .map_err(|e| LemmyError::from_error_message(e, "community_follower_already_exists"))?;
+      .with_lemmy_type(LemmyErrorType::CommunityFollowerAlreadyExists)?;
 
     // Update the discussion_languages if that's provided
     let community_id = inserted_community.id;
@@ -140,7 +138,7 @@ impl PerformCrud for CreateCommunity {
       // https://stackoverflow.com/a/64227550
       let is_subset = languages.iter().all(|item| site_languages.contains(item));
       if !is_subset {
-        return Err(LemmyError::from_message("language_not_allowed"));
+        return Err(LemmyErrorType::LanguageNotAllowed.into());
       }
       CommunityLanguage::update(context.pool(), languages, community_id).await?;
     }
@@ -11,13 +11,18 @@ use lemmy_db_schema::{
   source::{
     actor_language::{CommunityLanguage, SiteLanguage},
     community::{Community, CommunityUpdateForm},
+    community_rule::CommunityRule,
     local_site::LocalSite,
   },
   traits::Crud,
   utils::{diesel_option_overwrite, diesel_option_overwrite_to_url, naive_now},
 };
 use lemmy_db_views_actor::structs::CommunityModeratorView;
-use lemmy_utils::{error::LemmyError, utils::slurs::check_slurs_opt, ConnectionId};
+use lemmy_utils::{
+  error::{LemmyError, LemmyErrorExt, LemmyErrorType},
+  utils::slurs::{check_slur_regex, check_slurs, check_slurs_opt},
+  ConnectionId,
+};
 
 #[async_trait::async_trait(?Send)]
 impl PerformCrud for EditCommunity {
@@ -37,10 +42,23 @@ impl PerformCrud for EditCommunity {
     let icon = diesel_option_overwrite_to_url(&data.icon)?;
     let banner = diesel_option_overwrite_to_url(&data.banner)?;
     let description = diesel_option_overwrite(&data.description);
+    let slur_filter_regex = diesel_option_overwrite(&data.slur_filter_regex);
+    if let Some(Some(regex)) = &slur_filter_regex {
+      check_slur_regex(regex)?;
+    }
 
     let slur_regex = local_site_to_slur_regex(&local_site);
     check_slurs_opt(&data.title, &slur_regex)?;
     check_slurs_opt(&data.description, &slur_regex)?;
+    if let Some(rules) = &data.rules {
+      for rule in rules {
+        if rule.title.trim().is_empty() {
+          return Err(LemmyErrorType::CommunityRuleTitleRequired.into());
+        }
+        check_slurs(&rule.title, &slur_regex)?;
+        check_slurs_opt(&rule.body, &slur_regex)?;
+      }
+    }
 
     // Verify its a mod (only mods can edit it)
     let community_id = data.community_id;
@@ -48,7 +66,7 @@ impl PerformCrud for EditCommunity {
       .await
       .map(|v| v.into_iter().map(|m| m.moderator.id).collect())?;
     if !mods.contains(&local_user_view.person.id) {
-      return Err(LemmyError::from_message("not_a_moderator"));
+      return Err(LemmyErrorType::NotAModerator.into());
     }
 
     let community_id = data.community_id;
@@ -58,10 +76,16 @@ impl PerformCrud for EditCommunity {
       // https://stackoverflow.com/a/64227550
       let is_subset = languages.iter().all(|item| site_languages.contains(item));
       if !is_subset {
-        return Err(LemmyError::from_message("language_not_allowed"));
+        return Err(LemmyErrorType::LanguageNotAllowed.into());
       }
       CommunityLanguage::update(context.pool(), languages, community_id).await?;
     }
+    if let Some(rules) = data.rules.clone() {
+      let rules = rules.into_iter().map(|r| (r.id, r.title, r.body)).collect();
+      CommunityRule::update(context.pool(), rules, community_id)
+        .await
+        .with_lemmy_type(LemmyErrorType::CouldntUpdateCommunity)?;
+    }
 
     let community_form = CommunityUpdateForm::builder()
       .title(data.title.clone())
@@ -70,13 +94,14 @@ impl PerformCrud for EditCommunity {
       .banner(banner)
       .nsfw(data.nsfw)
       .posting_restricted_to_mods(data.posting_restricted_to_mods)
+      .slur_filter_regex(slur_filter_regex)
       .updated(Some(Some(naive_now())))
       .build();
 
     let community_id = data.community_id;
     Community::update(context.pool(), community_id, &community_form)
       .await
-      .map_err(|e| LemmyError::from_error_message(e, "couldnt_update_community"))?;
+      .with_lemmy_type(LemmyErrorType::CouldntUpdateCommunity)?;
 
     let op = UserOperationCrud::EditCommunity;
     send_community_ws_message(data.community_id, op, websocket_id, None, context).await
@@ -28,9 +28,9 @@ use lemmy_db_schema::{
 };
 use lemmy_db_views_actor::structs::CommunityView;
 use lemmy_utils::{
-  error::LemmyError,
+  error::{LemmyError, LemmyErrorExt, LemmyErrorType},
   utils::{
-    slurs::{check_slurs, check_slurs_opt},
+    slurs::{build_slur_regex, check_slurs, check_slurs_opt},
     validation::{clean_url_params, is_valid_post_title},
   },
   ConnectionId,
@@ -63,7 +63,7 @@ impl PerformCrud for CreatePost {
     let url = data_url.map(clean_url_params).map(Into::into); // TODO no good way to handle a "clear"
 
     if !is_valid_post_title(&data.name) {
-      return Err(LemmyError::from_message("invalid_post_title"));
+      return Err(LemmyErrorType::InvalidPostTitle.into());
     }
 
     check_community_ban(local_user_view.person.id, data.community_id, context.pool()).await?;
@@ -71,6 +71,9 @@ impl PerformCrud for CreatePost {
 
     let community_id = data.community_id;
     let community = Community::read(context.pool(), community_id).await?;
+    let community_slur_regex = build_slur_regex(community.slur_filter_regex.as_deref());
+    check_slurs(&data.name, &community_slur_regex)?;
+    check_slurs_opt(&data.body, &community_slur_regex)?;
     if community.posting_restricted_to_mods {
       let community_id = data.community_id;
       let is_mod = CommunityView::is_mod_or_admin(
@@ -80,7 +83,7 @@ impl PerformCrud for CreatePost {
       )
       .await?;
       if !is_mod {
-        return Err(LemmyError::from_message("only_mods_can_post_in_community"));
+        return Err(LemmyErrorType::OnlyModsCanPostInCommunity.into());
       }
     }
 
@@ -118,12 +121,12 @@ impl PerformCrud for CreatePost {
       Ok(post) => post,
       Err(e) => {
         let err_type = if e.to_string() == "value too long for type character varying(200)" {
-          "post_title_too_long"
+          LemmyErrorType::PostTitleTooLong
         } else {
-          "couldnt_create_post"
+          LemmyErrorType::CouldntCreatePost
         };
 
-        return Err(LemmyError::from_error_message(e, err_type));
+        return Err(e).with_lemmy_type(err_type);
       }
     };
 
@@ -140,7 +143,7 @@ impl PerformCrud for CreatePost {
       &PostUpdateForm::builder().ap_id(Some(apub_id)).build(),
     )
     .await
-    .map_err(|e| LemmyError::from_error_message(e, "couldnt_create_post"))?;
+    .with_lemmy_type(LemmyErrorType::CouldntCreatePost)?;
 
     // They like their own post by default
     let person_id = local_user_view.person.id;
@@ -153,7 +156,7 @@ impl PerformCrud for CreatePost {
 
     PostLike::like(context.pool(), &like_form)
       .await
-      .map_err(|e| LemmyError::from_error_message(e, "couldnt_like_post"))?;
+      .with_lemmy_type(LemmyErrorType::CouldntLikePost)?;
 
     // Mark the post as read
     mark_post_as_read(person_id, post_id, context.pool()).await?;
@@ -12,7 +12,10 @@ use lemmy_db_schema::{
 };
 use lemmy_db_views::structs::PostView;
 use lemmy_db_views_actor::structs::{CommunityModeratorView, CommunityView};
-use lemmy_utils::{error::LemmyError, ConnectionId};
+use lemmy_utils::{
+  error::{LemmyError, LemmyErrorExt, LemmyErrorType},
+  ConnectionId,
+};
 
 #[async_trait::async_trait(?Send)]
 impl PerformCrud for GetPost {
@@ -40,15 +43,15 @@ impl PerformCrud for GetPost {
     } else if let Some(comment_id) = data.comment_id {
       Comment::read(context.pool(), comment_id)
         .await
-        .map_err(|e| LemmyError::from_error_message(e, "couldnt_find_post"))?
+        .with_lemmy_type(LemmyErrorType::CouldntFindPost)?
         .post_id
     } else {
-      Err(LemmyError::from_message("couldnt_find_post"))?
+      Err(LemmyErrorType::CouldntFindPost)?
     };
 
     let mut post_view = PostView::read(context.pool(), post_id, person_id)
       .await
-      .map_err(|e| LemmyError::from_error_message(e, "couldnt_find_post"))?;
+      .with_lemmy_type(LemmyErrorType::CouldntFindPost)?;
 
     // Mark the post as read
     let post_id = post_view.post.id;
@@ -60,7 +63,7 @@ impl PerformCrud for GetPost {
     let community_id = post_view.community.id;
     let mut community_view = CommunityView::read(context.pool(), community_id, person_id)
       .await
-      .map_err(|e| LemmyError::from_error_message(e, "couldnt_find_community"))?;
+      .with_lemmy_type(LemmyErrorType::CouldntFindCommunity)?;
 
     // Insert into PersonPostAggregates
     // to update the read_comments count
@@ -74,7 +77,7 @@ impl PerformCrud for GetPost {
       };
       PersonPostAggregates::upsert(context.pool(), &person_post_agg_form)
         .await
-        .map_err(|e| LemmyError::from_error_message(e, "couldnt_find_post"))?;
+        .with_lemmy_type(LemmyErrorType::CouldntFindPost)?;
     }
 
     // Blank out deleted or removed info for non-logged in users
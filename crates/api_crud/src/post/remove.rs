@@ -3,7 +3,12 @@ use actix_web::web::Data;
 use lemmy_api_common::{
   context::LemmyContext,
   post::{PostResponse, RemovePost},
-  utils::{check_community_ban, get_local_user_view_from_jwt, is_mod_or_admin},
+  utils::{
+    check_community_ban,
+    check_community_rule,
+    get_local_user_view_from_jwt,
+    is_mod_or_admin,
+  },
   websocket::{send::send_post_ws_message, UserOperationCrud},
 };
 use lemmy_db_schema::{
@@ -46,6 +51,7 @@ impl PerformCrud for RemovePost {
       orig_post.community_id,
     )
     .await?;
+    check_community_rule(data.rule_id, orig_post.community_id, context.pool()).await?;
 
     // Update the post
     let post_id = data.post_id;
@@ -63,6 +69,7 @@ impl PerformCrud for RemovePost {
       post_id: data.post_id,
       removed: Some(removed),
       reason: data.reason.clone(),
+      rule_id: data.rule_id,
     };
     ModRemovePost::create(context.pool(), &form).await?;
 
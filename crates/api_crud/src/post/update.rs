@@ -10,6 +10,7 @@ use lemmy_api_common::{
 use lemmy_db_schema::{
   source::{
     actor_language::CommunityLanguage,
+    community::Community,
     local_site::LocalSite,
     post::{Post, PostUpdateForm},
   },
@@ -17,9 +18,9 @@ use lemmy_db_schema::{
   utils::{diesel_option_overwrite, naive_now},
 };
 use lemmy_utils::{
-  error::LemmyError,
+  error::{LemmyError, LemmyErrorExt, LemmyErrorType},
   utils::{
-    slurs::check_slurs_opt,
+    slurs::{build_slur_regex, check_slurs_opt},
     validation::{clean_url_params, is_valid_post_title},
   },
   ConnectionId,
@@ -53,13 +54,18 @@ impl PerformCrud for EditPost {
 
     if let Some(name) = &data.name {
       if !is_valid_post_title(name) {
-        return Err(LemmyError::from_message("invalid_post_title"));
+        return Err(LemmyErrorType::InvalidPostTitle.into());
       }
     }
 
     let post_id = data.post_id;
     let orig_post = Post::read(context.pool(), post_id).await?;
 
+    let community = Community::read(context.pool(), orig_post.community_id).await?;
+    let community_slur_regex = build_slur_regex(community.slur_filter_regex.as_deref());
+    check_slurs_opt(&data.name, &community_slur_regex)?;
+    check_slurs_opt(&data.body, &community_slur_regex)?;
+
     check_community_ban(
       local_user_view.person.id,
       orig_post.community_id,
@@ -69,7 +75,7 @@ impl PerformCrud for EditPost {
 
     // Verify that only the creator can edit
     if !Post::is_post_creator(local_user_view.person.id, orig_post.creator_id) {
-      return Err(LemmyError::from_message("no_post_edit_allowed"));
+      return Err(LemmyErrorType::NoPostEditAllowed.into());
     }
 
     // Fetch post links and Pictrs cached image
@@ -105,12 +111,12 @@ impl PerformCrud for EditPost {
     let res = Post::update(context.pool(), post_id, &post_form).await;
     if let Err(e) = res {
       let err_type = if e.to_string() == "value too long for type character varying(200)" {
-        "post_title_too_long"
+        LemmyErrorType::PostTitleTooLong
       } else {
-        "couldnt_update_post"
+        LemmyErrorType::CouldntUpdatePost
       };
 
-      return Err(LemmyError::from_error_message(e, err_type));
+      return Err(e).with_lemmy_type(err_type);
     }
 
     send_post_ws_message(
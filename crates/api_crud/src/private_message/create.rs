@@ -22,7 +22,11 @@ use lemmy_db_schema::{
   traits::Crud,
 };
 use lemmy_db_views::structs::LocalUserView;
-use lemmy_utils::{error::LemmyError, utils::slurs::remove_slurs, ConnectionId};
+use lemmy_utils::{
+  error::{LemmyError, LemmyErrorExt, LemmyErrorType},
+  utils::slurs::remove_slurs,
+  ConnectionId,
+};
 
 #[async_trait::async_trait(?Send)]
 impl PerformCrud for CreatePrivateMessage {
@@ -56,10 +60,7 @@ impl PerformCrud for CreatePrivateMessage {
       match PrivateMessage::create(context.pool(), &private_message_form).await {
         Ok(private_message) => private_message,
         Err(e) => {
-          return Err(LemmyError::from_error_message(
-            e,
-            "couldnt_create_private_message",
-          ));
+          return Err(e).with_lemmy_type(LemmyErrorType::CouldntCreatePrivateMessage);
         }
       };
 
@@ -78,7 +79,7 @@ impl PerformCrud for CreatePrivateMessage {
         .build(),
     )
     .await
-    .map_err(|e| LemmyError::from_error_message(e, "couldnt_create_private_message"))?;
+    .with_lemmy_type(LemmyErrorType::CouldntCreatePrivateMessage)?;
 
     let res = send_pm_ws_message(
       inserted_private_message.id,
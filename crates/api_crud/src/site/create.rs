@@ -25,7 +25,7 @@ use lemmy_db_schema::{
 };
 use lemmy_db_views::structs::SiteView;
 use lemmy_utils::{
-  error::LemmyError,
+  error::{LemmyError, LemmyErrorType},
   utils::slurs::{check_slurs, check_slurs_opt},
   ConnectionId,
 };
@@ -46,7 +46,7 @@ impl PerformCrud for CreateSite {
     let local_site = LocalSite::read(context.pool()).await?;
 
     if local_site.site_setup {
-      return Err(LemmyError::from_message("site_already_exists"));
+      return Err(LemmyErrorType::SiteAlreadyExists.into());
     };
 
     let local_user_view =
@@ -119,6 +119,9 @@ impl PerformCrud for CreateSite {
       .federation_worker_count(data.federation_worker_count)
       .captcha_enabled(data.captcha_enabled)
       .captcha_difficulty(data.captcha_difficulty.clone())
+      .convert_notes_to_posts(data.convert_notes_to_posts)
+      .authorized_fetch(data.authorized_fetch)
+      .community_directory(data.community_directory)
       .build();
 
     LocalSite::update(context.pool(), &local_site_form).await?;
@@ -1,5 +1,5 @@
 use lemmy_db_schema::source::local_site::RegistrationMode;
-use lemmy_utils::error::LemmyError;
+use lemmy_utils::error::{LemmyError, LemmyErrorType};
 
 mod create;
 mod read;
@@ -12,7 +12,7 @@ pub fn check_application_question(
   if registration_mode == RegistrationMode::RequireApplication
     && application_question.as_ref().unwrap_or(&None).is_none()
   {
-    Err(LemmyError::from_message("application_question_required"))
+    Err(LemmyErrorType::ApplicationQuestionRequired.into())
   } else {
     Ok(())
   }
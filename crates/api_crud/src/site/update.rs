@@ -9,14 +9,18 @@ use lemmy_api_common::{
     local_site_rate_limit_to_rate_limit_config,
     local_site_to_slur_regex,
     site_description_length_check,
+    spawn_blocked_instance_cleanup,
+    spawn_unblocked_instance_restore,
   },
   websocket::UserOperationCrud,
 };
 use lemmy_db_schema::{
+  newtypes::InstanceId,
   source::{
     actor_language::SiteLanguage,
     federation_allowlist::FederationAllowList,
     federation_blocklist::FederationBlockList,
+    instance::Instance,
     local_site::{LocalSite, LocalSiteUpdateForm, RegistrationMode},
     local_site_rate_limit::{LocalSiteRateLimit, LocalSiteRateLimitUpdateForm},
     local_user::LocalUser,
@@ -28,7 +32,11 @@ use lemmy_db_schema::{
   ListingType,
 };
 use lemmy_db_views::structs::SiteView;
-use lemmy_utils::{error::LemmyError, utils::slurs::check_slurs_opt, ConnectionId};
+use lemmy_utils::{
+  error::{LemmyError, LemmyErrorExt, LemmyErrorType},
+  utils::slurs::check_slurs_opt,
+  ConnectionId,
+};
 use std::str::FromStr;
 
 #[async_trait::async_trait(?Send)]
@@ -70,9 +78,7 @@ impl PerformCrud for EditSite {
       // only allow all or local as default listing types
       let val = ListingType::from_str(default_post_listing_type);
       if val != Ok(ListingType::All) && val != Ok(ListingType::Local) {
-        return Err(LemmyError::from_message(
-          "invalid_default_post_listing_type",
-        ));
+        return Err(LemmyErrorType::InvalidDefaultPostListingType.into());
       }
     }
 
@@ -120,6 +126,9 @@ impl PerformCrud for EditSite {
       .captcha_enabled(data.captcha_enabled)
       .captcha_difficulty(data.captcha_difficulty.clone())
       .reports_email_admins(data.reports_email_admins)
+      .convert_notes_to_posts(data.convert_notes_to_posts)
+      .authorized_fetch(data.authorized_fetch)
+      .community_directory(data.community_directory)
       .build();
 
     let update_local_site = LocalSite::update(context.pool(), &local_site_form)
@@ -149,7 +158,27 @@ impl PerformCrud for EditSite {
     let allowed = data.allowed_instances.clone();
     FederationAllowList::replace(context.pool(), allowed).await?;
     let blocked = data.blocked_instances.clone();
+    let previously_blocked: Vec<InstanceId> = Instance::blocklist(context.pool())
+      .await?
+      .into_iter()
+      .map(|i| i.id)
+      .collect();
     FederationBlockList::replace(context.pool(), blocked).await?;
+    let now_blocked: Vec<InstanceId> = Instance::blocklist(context.pool())
+      .await?
+      .into_iter()
+      .map(|i| i.id)
+      .collect();
+    for instance_id in &now_blocked {
+      if !previously_blocked.contains(instance_id) {
+        spawn_blocked_instance_cleanup(context, *instance_id, false, false);
+      }
+    }
+    for instance_id in &previously_blocked {
+      if !now_blocked.contains(instance_id) {
+        spawn_unblocked_instance_restore(context.pool(), *instance_id);
+      }
+    }
 
     // TODO can't think of a better way to do this.
     // If the server suddenly requires email verification, or required applications, no old users
@@ -165,7 +194,7 @@ impl PerformCrud for EditSite {
     if !old_require_application && new_require_application {
       LocalUser::set_all_users_registration_applications_accepted(context.pool())
         .await
-        .map_err(|e| LemmyError::from_error_message(e, "couldnt_set_all_registrations_accepted"))?;
+        .with_lemmy_type(LemmyErrorType::CouldntSetAllRegistrationsAccepted)?;
     }
 
     let new_require_email_verification = update_local_site
@@ -175,7 +204,7 @@ impl PerformCrud for EditSite {
     if !local_site.require_email_verification && new_require_email_verification {
       LocalUser::set_all_users_email_verified(context.pool())
         .await
-        .map_err(|e| LemmyError::from_error_message(e, "couldnt_set_all_email_verified"))?;
+        .with_lemmy_type(LemmyErrorType::CouldntSetAllEmailVerified)?;
     }
 
     let taglines = data.taglines.clone();
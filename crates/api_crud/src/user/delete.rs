@@ -6,7 +6,10 @@ use lemmy_api_common::{
   person::{DeleteAccount, DeleteAccountResponse},
   utils::get_local_user_view_from_jwt,
 };
-use lemmy_utils::{error::LemmyError, ConnectionId};
+use lemmy_utils::{
+  error::{LemmyError, LemmyErrorType},
+  ConnectionId,
+};
 
 #[async_trait::async_trait(?Send)]
 impl PerformCrud for DeleteAccount {
@@ -29,7 +32,7 @@ impl PerformCrud for DeleteAccount {
     )
     .unwrap_or(false);
     if !valid {
-      return Err(LemmyError::from_message("password_incorrect"));
+      return Err(LemmyErrorType::PasswordIncorrect.into());
     }
 
     Ok(DeleteAccountResponse {})
@@ -19,7 +19,8 @@ use crate::{
 use activitypub_federation::{core::object_id::ObjectId, data::Data, traits::ActivityHandler};
 use activitystreams_kinds::{activity::AnnounceType, public};
 use lemmy_api_common::context::LemmyContext;
-use lemmy_utils::error::LemmyError;
+use lemmy_db_schema::source::relay::Relay;
+use lemmy_utils::error::{LemmyError, LemmyErrorType};
 use serde_json::Value;
 use tracing::debug;
 use url::Url;
@@ -55,7 +56,7 @@ impl ActivityHandler for RawAnnouncableActivities {
     let activity: AnnouncableActivities = self.clone().try_into()?;
     // This is only for sending, not receiving so we reject it.
     if let AnnouncableActivities::Page(_) = activity {
-      return Err(LemmyError::from_message("Cant receive page"));
+      return Err(LemmyErrorType::CannotReceivePage.into());
     }
     let community = activity.community(data, &mut 0).await?;
     let actor_id = ObjectId::new(activity.actor().clone());
@@ -117,6 +118,12 @@ impl AnnounceActivity {
           .clone(),
       };
       let announce_compat = AnnounceActivity::new(announcable_page, community, context)?;
+      // Relays understand this format as well, so local posts are published to them this way
+      let relay_inboxes = Relay::list_publish_inboxes(context.pool()).await?;
+      let inboxes = inboxes
+        .into_iter()
+        .chain(relay_inboxes.into_iter().map(Into::into))
+        .collect();
       send_lemmy_activity(context, announce_compat, community, inboxes, false).await?;
     }
     Ok(())
@@ -159,14 +166,16 @@ impl ActivityHandler for AnnounceActivity {
       .try_into()?;
     // This is only for sending, not receiving so we reject it.
     if let AnnouncableActivities::Page(_) = object {
-      return Err(LemmyError::from_message("Cant receive page"));
+      return Err(LemmyErrorType::CannotReceivePage.into());
     }
 
     // we have to verify this here in order to avoid fetching the object twice over http
     object.verify(context, request_counter).await?;
 
+    // The announce which contains it was signed by the community
     let object_value = serde_json::to_value(&object)?;
-    let insert = insert_activity(object.id(), object_value, false, true, context.pool()).await?;
+    let insert =
+      insert_activity(object.id(), object_value, false, true, true, context.pool()).await?;
     if !insert {
       debug!(
         "Received duplicate activity in announce {}",
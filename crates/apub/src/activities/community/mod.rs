@@ -15,6 +15,7 @@ pub mod collection_add;
 pub mod collection_remove;
 pub mod lock_page;
 pub mod report;
+pub mod resolve_report;
 pub mod update;
 
 /// This function sends all activities which are happening in a community to the right inboxes.
@@ -152,6 +152,7 @@ impl ActivityHandler for Report {
           original_post_url: post.url.clone(),
           reason: self.summary,
           original_post_body: post.body.clone(),
+          rule_id: None,
         };
 
         let report = PostReport::report(context.pool(), &report_form).await?;
@@ -174,6 +175,7 @@ impl ActivityHandler for Report {
           comment_id: comment.id,
           original_comment_text: comment.content.clone(),
           reason: self.summary,
+          rule_id: None,
         };
 
         let report = CommentReport::report(context.pool(), &report_form).await?;
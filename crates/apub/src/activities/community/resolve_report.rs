@@ -0,0 +1,281 @@
+use crate::{
+  activities::{generate_activity_id, send_lemmy_activity, verify_mod_action},
+  local_instance,
+  objects::person::ApubPerson,
+  protocol::{
+    activities::community::resolve_report::{ResolveReport, ResolveType},
+    InCommunity,
+  },
+  ActorType,
+  PostOrComment,
+  SendActivity,
+};
+use activitypub_federation::{core::object_id::ObjectId, data::Data, traits::ActivityHandler};
+use lemmy_api_common::{
+  comment::{CommentReportResponse, ResolveCommentReport, ResolveCommentReportResponse},
+  context::LemmyContext,
+  post::{PostReportResponse, ResolvePostReport, ResolvePostReportResponse},
+  utils::get_local_user_view_from_jwt,
+  websocket::UserOperation,
+};
+use lemmy_db_schema::{
+  newtypes::DbUrl,
+  source::{comment_report::CommentReport, person::PersonSafe, post_report::PostReport},
+};
+use lemmy_db_views::structs::{CommentReportView, LocalUserView, PostReportView};
+use lemmy_utils::error::{LemmyError, LemmyErrorType};
+use std::collections::HashMap;
+use url::Url;
+
+/// Upper limit of reports on the same item which are federated at once with `resolve_all`
+const RESOLVE_ALL_LIMIT: usize = 50;
+
+#[async_trait::async_trait(?Send)]
+impl SendActivity for ResolvePostReport {
+  type Response = ResolvePostReportResponse;
+
+  async fn send_activity(
+    request: &Self,
+    response: &Self::Response,
+    context: &LemmyContext,
+  ) -> Result<(), LemmyError> {
+    if !request.resolved {
+      return Ok(());
+    }
+    let local_user_view =
+      get_local_user_view_from_jwt(&request.auth, context.pool(), context.secret()).await?;
+    let report_view = &response.post_report_view;
+    let reporters = if request.resolve_all.unwrap_or(false) {
+      let mut reporters = Vec::new();
+      for report_id in response.resolved_report_ids.iter().take(RESOLVE_ALL_LIMIT) {
+        let report =
+          PostReportView::read(context.pool(), *report_id, local_user_view.person.id).await?;
+        reporters.push(report.creator);
+      }
+      reporters
+    } else {
+      vec![report_view.creator.clone()]
+    };
+    ResolveReport::send(
+      ObjectId::new(report_view.post.ap_id.clone()),
+      &local_user_view.person.into(),
+      report_view.community.actor_id.clone(),
+      remote_reporter_inboxes(reporters),
+      request.resolution_reason.clone(),
+      context,
+    )
+    .await
+  }
+}
+
+#[async_trait::async_trait(?Send)]
+impl SendActivity for ResolveCommentReport {
+  type Response = ResolveCommentReportResponse;
+
+  async fn send_activity(
+    request: &Self,
+    response: &Self::Response,
+    context: &LemmyContext,
+  ) -> Result<(), LemmyError> {
+    if !request.resolved {
+      return Ok(());
+    }
+    let local_user_view =
+      get_local_user_view_from_jwt(&request.auth, context.pool(), context.secret()).await?;
+    let report_view = &response.comment_report_view;
+    let reporters = if request.resolve_all.unwrap_or(false) {
+      let mut reporters = Vec::new();
+      for report_id in response.resolved_report_ids.iter().take(RESOLVE_ALL_LIMIT) {
+        let report =
+          CommentReportView::read(context.pool(), *report_id, local_user_view.person.id).await?;
+        reporters.push(report.creator);
+      }
+      reporters
+    } else {
+      vec![report_view.creator.clone()]
+    };
+    ResolveReport::send(
+      ObjectId::new(report_view.comment.ap_id.clone()),
+      &local_user_view.person.into(),
+      report_view.community.actor_id.clone(),
+      remote_reporter_inboxes(reporters),
+      request.resolution_reason.clone(),
+      context,
+    )
+    .await
+  }
+}
+
+/// Returns the inbox of each remote reporter, keyed by actor id so that every reporter is only
+/// notified once.
+fn remote_reporter_inboxes(reporters: Vec<PersonSafe>) -> HashMap<Url, Url> {
+  reporters
+    .into_iter()
+    .filter(|p| !p.local)
+    .map(|p| {
+      let inbox = p.shared_inbox_url.unwrap_or(p.inbox_url);
+      (p.actor_id.into(), inbox.into())
+    })
+    .collect()
+}
+
+impl ResolveReport {
+  /// Sends one activity to each remote reporter, so that their instances can mark the reports as
+  /// resolved.
+  #[tracing::instrument(skip_all)]
+  async fn send(
+    object_id: ObjectId<PostOrComment>,
+    actor: &ApubPerson,
+    community_id: DbUrl,
+    reporters: HashMap<Url, Url>,
+    reason: Option<String>,
+    context: &LemmyContext,
+  ) -> Result<(), LemmyError> {
+    for (reporter_id, inbox) in reporters {
+      let id = generate_activity_id(
+        ResolveType::Resolve,
+        &context.settings().get_protocol_and_hostname(),
+      )?;
+      let resolve = ResolveReport {
+        actor: ObjectId::new(actor.actor_id()),
+        to: [ObjectId::new(reporter_id)],
+        object: object_id.clone(),
+        summary: reason.clone(),
+        kind: ResolveType::Resolve,
+        id,
+        audience: Some(ObjectId::new(community_id.clone())),
+      };
+      send_lemmy_activity(context, resolve, actor, vec![inbox], false).await?;
+    }
+    Ok(())
+  }
+}
+
+#[async_trait::async_trait(?Send)]
+impl ActivityHandler for ResolveReport {
+  type DataType = LemmyContext;
+  type Error = LemmyError;
+
+  fn id(&self) -> &Url {
+    &self.id
+  }
+
+  fn actor(&self) -> &Url {
+    self.actor.inner()
+  }
+
+  #[tracing::instrument(skip_all)]
+  async fn verify(
+    &self,
+    context: &Data<LemmyContext>,
+    request_counter: &mut i32,
+  ) -> Result<(), LemmyError> {
+    let community = self.community(context, request_counter).await?;
+    verify_mod_action(
+      &self.actor,
+      self.object.inner(),
+      community.id,
+      context,
+      request_counter,
+    )
+    .await?;
+    // Only reports which were made on this instance can be resolved by a remote mod
+    let reporter = self.to[0].dereference_local(context).await?;
+    if !reporter.local {
+      return Err(LemmyErrorType::ReporterNotLocal.into());
+    }
+    Ok(())
+  }
+
+  #[tracing::instrument(skip_all)]
+  async fn receive(
+    self,
+    context: &Data<LemmyContext>,
+    request_counter: &mut i32,
+  ) -> Result<(), LemmyError> {
+    let actor = self
+      .actor
+      .dereference(context, local_instance(context).await, request_counter)
+      .await?;
+    let reporter = self.to[0].dereference_local(context).await?;
+    let reporter_user = LocalUserView::read_person(context.pool(), reporter.id).await?;
+    match self
+      .object
+      .dereference(context, local_instance(context).await, request_counter)
+      .await?
+    {
+      PostOrComment::Post(post) => {
+        let reports = PostReport::resolve_for_creator(
+          context.pool(),
+          post.id,
+          reporter.id,
+          actor.id,
+          self.summary,
+        )
+        .await?;
+
+        for report in reports {
+          let post_report_view =
+            PostReportView::read(context.pool(), report.id, reporter.id).await?;
+          let res = PostReportResponse { post_report_view };
+          context
+            .chat_server()
+            .send_mod_room_message(
+              UserOperation::ResolvePostReport,
+              &res,
+              post.community_id,
+              None,
+            )
+            .await?;
+          context
+            .chat_server()
+            .send_user_room_message(
+              &UserOperation::ResolvePostReport,
+              &res,
+              reporter_user.local_user.id,
+              None,
+            )
+            .await?;
+        }
+      }
+      PostOrComment::Comment(comment) => {
+        let reports = CommentReport::resolve_for_creator(
+          context.pool(),
+          comment.id,
+          reporter.id,
+          actor.id,
+          self.summary,
+        )
+        .await?;
+
+        for report in reports {
+          let comment_report_view =
+            CommentReportView::read(context.pool(), report.id, reporter.id).await?;
+          let community_id = comment_report_view.community.id;
+          let res = CommentReportResponse {
+            comment_report_view,
+          };
+          context
+            .chat_server()
+            .send_mod_room_message(
+              UserOperation::ResolveCommentReport,
+              &res,
+              community_id,
+              None,
+            )
+            .await?;
+          context
+            .chat_server()
+            .send_user_room_message(
+              &UserOperation::ResolveCommentReport,
+              &res,
+              reporter_user.local_user.id,
+              None,
+            )
+            .await?;
+        }
+      }
+    };
+    Ok(())
+  }
+}
@@ -24,7 +24,10 @@ use lemmy_api_common::{
   utils::get_local_user_view_from_jwt,
   websocket::{send::send_community_ws_message, UserOperationCrud},
 };
-use lemmy_db_schema::{source::community::Community, traits::Crud};
+use lemmy_db_schema::{
+  source::{community::Community, community_rule::CommunityRule},
+  traits::Crud,
+};
 use lemmy_utils::error::LemmyError;
 use url::Url;
 
@@ -118,10 +121,14 @@ impl ActivityHandler for UpdateCommunity {
   ) -> Result<(), LemmyError> {
     let community = self.community(context, request_counter).await?;
 
+    let rules = self.object.rules();
     let community_update_form = self.object.into_update_form();
 
     let updated_community =
       Community::update(context.pool(), community.id, &community_update_form).await?;
+    if let Some(rules) = rules {
+      CommunityRule::update_from_remote(context.pool(), rules, community.id).await?;
+    }
 
     send_community_ws_message(
       updated_community.id,
@@ -83,6 +83,31 @@ impl SendActivity for EditComment {
 }
 
 impl CreateOrUpdateNote {
+  pub(crate) async fn new(
+    comment: ApubComment,
+    actor: &ApubPerson,
+    community: &ApubCommunity,
+    kind: CreateOrUpdateType,
+    context: &LemmyContext,
+  ) -> Result<CreateOrUpdateNote, LemmyError> {
+    let id = generate_activity_id(
+      kind.clone(),
+      &context.settings().get_protocol_and_hostname(),
+    )?;
+    let note = comment.into_apub(context).await?;
+
+    Ok(CreateOrUpdateNote {
+      actor: ObjectId::new(actor.actor_id()),
+      to: vec![public()],
+      cc: note.cc.clone(),
+      tag: note.tag.clone(),
+      object: note,
+      kind,
+      id,
+      audience: Some(ObjectId::new(community.actor_id())),
+    })
+  }
+
   #[tracing::instrument(skip(comment, person_id, kind, context))]
   async fn send(
     comment: &Comment,
@@ -97,22 +122,14 @@ impl CreateOrUpdateNote {
     let person: ApubPerson = Person::read(context.pool(), person_id).await?.into();
     let community: ApubCommunity = Community::read(context.pool(), community_id).await?.into();
 
-    let id = generate_activity_id(
-      kind.clone(),
-      &context.settings().get_protocol_and_hostname(),
-    )?;
-    let note = ApubComment(comment.clone()).into_apub(context).await?;
-
-    let create_or_update = CreateOrUpdateNote {
-      actor: ObjectId::new(person.actor_id()),
-      to: vec![public()],
-      cc: note.cc.clone(),
-      tag: note.tag.clone(),
-      object: note,
+    let create_or_update = CreateOrUpdateNote::new(
+      ApubComment(comment.clone()),
+      &person,
+      &community,
       kind,
-      id: id.clone(),
-      audience: Some(ObjectId::new(community.actor_id())),
-    };
+      context,
+    )
+    .await?;
 
     let tagged_users: Vec<ObjectId<ApubPerson>> = create_or_update
       .tag
@@ -37,7 +37,7 @@ use lemmy_db_schema::{
   },
   traits::{Crud, Likeable},
 };
-use lemmy_utils::error::LemmyError;
+use lemmy_utils::error::{LemmyError, LemmyErrorType};
 use url::Url;
 
 #[async_trait::async_trait(?Send)]
@@ -165,9 +165,7 @@ impl ActivityHandler for CreateOrUpdatePage {
         let is_featured_or_locked =
           self.object.stickied == Some(true) || self.object.comments_enabled == Some(false);
         if community.local && is_featured_or_locked {
-          return Err(LemmyError::from_message(
-            "New post cannot be stickied or locked",
-          ));
+          return Err(LemmyErrorType::NewPostCannotBeStickiedOrLocked.into());
         }
       }
       CreateOrUpdateType::Update => {
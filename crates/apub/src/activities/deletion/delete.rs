@@ -32,7 +32,7 @@ use lemmy_db_schema::{
   },
   traits::Crud,
 };
-use lemmy_utils::error::LemmyError;
+use lemmy_utils::error::{LemmyError, LemmyErrorType};
 use url::Url;
 
 #[async_trait::async_trait(?Send)]
@@ -133,9 +133,7 @@ pub(in crate::activities) async fn receive_remove_action(
   match DeletableObjects::read_from_db(object, context).await? {
     DeletableObjects::Community(community) => {
       if community.local {
-        return Err(LemmyError::from_message(
-          "Only local admin can remove community",
-        ));
+        return Err(LemmyErrorType::OnlyLocalAdminCanRemoveCommunity.into());
       }
       let form = ModRemoveCommunityForm {
         mod_person_id: actor.id,
@@ -160,6 +158,7 @@ pub(in crate::activities) async fn receive_remove_action(
         post_id: post.id,
         removed: Some(true),
         reason,
+        rule_id: None,
       };
       ModRemovePost::create(context.pool(), &form).await?;
       let removed_post = Post::update(
@@ -177,6 +176,7 @@ pub(in crate::activities) async fn receive_remove_action(
         comment_id: comment.id,
         removed: Some(true),
         reason,
+        rule_id: None,
       };
       ModRemoveComment::create(context.pool(), &form).await?;
       let removed_comment = Comment::update(
@@ -32,7 +32,7 @@ use lemmy_db_schema::{
   },
   traits::Crud,
 };
-use lemmy_utils::error::LemmyError;
+use lemmy_utils::error::{LemmyError, LemmyErrorType};
 use url::Url;
 
 #[async_trait::async_trait(?Send)]
@@ -132,9 +132,7 @@ impl UndoDelete {
     match DeletableObjects::read_from_db(object, context).await? {
       DeletableObjects::Community(community) => {
         if community.local {
-          return Err(LemmyError::from_message(
-            "Only local admin can restore community",
-          ));
+          return Err(LemmyErrorType::OnlyLocalAdminCanRestoreCommunity.into());
         }
         let form = ModRemoveCommunityForm {
           mod_person_id: actor.id,
@@ -158,6 +156,7 @@ impl UndoDelete {
           post_id: post.id,
           removed: Some(false),
           reason: None,
+          rule_id: None,
         };
         ModRemovePost::create(context.pool(), &form).await?;
         let removed_post = Post::update(
@@ -174,6 +173,7 @@ impl UndoDelete {
           comment_id: comment.id,
           removed: Some(false),
           reason: None,
+          rule_id: None,
         };
         ModRemoveComment::create(context.pool(), &form).await?;
         let removed_comment = Comment::update(
@@ -1,4 +1,5 @@
 use crate::{
+  fetcher::backfill::start_backfill,
   objects::community::ApubCommunity,
   protocol::activities::following::{follow::Follow, undo_follow::UndoFollow},
   SendActivity,
@@ -33,7 +34,8 @@ impl SendActivity for FollowCommunity {
     if community.local {
       Ok(())
     } else if request.follow {
-      Follow::send(&person, &community, context).await
+      Follow::send(&person, &community, context).await?;
+      start_backfill(&community, context).await
     } else {
       UndoFollow::send(&person, &community, context).await
     }
@@ -18,7 +18,7 @@ use lemmy_db_schema::{
   source::{community::Community, local_site::LocalSite},
 };
 use lemmy_db_views_actor::structs::{CommunityPersonBanView, CommunityView};
-use lemmy_utils::error::LemmyError;
+use lemmy_utils::error::{LemmyError, LemmyErrorExt, LemmyErrorType};
 use serde::Serialize;
 use std::ops::Deref;
 use tracing::info;
@@ -30,6 +30,7 @@ pub mod community;
 pub mod create_or_update;
 pub mod deletion;
 pub mod following;
+pub mod relay;
 pub mod unfederated;
 pub mod voting;
 
@@ -46,7 +47,7 @@ async fn verify_person(
     .await?;
   if person.banned {
     let err = anyhow!("Person {} is banned", person_id);
-    return Err(LemmyError::from_error_message(err, "banned"));
+    return Err(err).with_lemmy_type(LemmyErrorType::Banned);
   }
   Ok(())
 }
@@ -64,7 +65,7 @@ pub(crate) async fn verify_person_in_community(
     .dereference(context, local_instance(context).await, request_counter)
     .await?;
   if person.banned {
-    return Err(LemmyError::from_message("Person is banned from site"));
+    return Err(LemmyErrorType::SiteBan.into());
   }
   let person_id = person.id;
   let community_id = community.id;
@@ -72,7 +73,7 @@ pub(crate) async fn verify_person_in_community(
     .await
     .is_ok();
   if is_banned {
-    return Err(LemmyError::from_message("Person is banned from community"));
+    return Err(LemmyErrorType::CommunityBan.into());
   }
 
   Ok(())
@@ -108,12 +109,12 @@ pub(crate) async fn verify_mod_action(
     return Ok(());
   }
 
-  Err(LemmyError::from_message("Not a mod"))
+  Err(LemmyErrorType::NotAModerator.into())
 }
 
 pub(crate) fn verify_is_public(to: &[Url], cc: &[Url]) -> Result<(), LemmyError> {
   if ![to, cc].iter().any(|set| set.contains(&public())) {
-    return Err(LemmyError::from_message("Object is not public"));
+    return Err(LemmyErrorType::ObjectIsNotPublic.into());
   }
   Ok(())
 }
@@ -127,16 +128,14 @@ where
 {
   let b: ObjectId<ApubCommunity> = b.into();
   if a != &b {
-    return Err(LemmyError::from_message("Invalid community"));
+    return Err(LemmyErrorType::InvalidCommunity.into());
   }
   Ok(())
 }
 
 pub(crate) fn check_community_deleted_or_removed(community: &Community) -> Result<(), LemmyError> {
   if community.deleted || community.removed {
-    Err(LemmyError::from_message(
-      "New post or comment cannot be created in deleted or removed community",
-    ))
+    Err(LemmyErrorType::CannotCreatePostOrCommentInDeletedOrRemovedCommunity.into())
   } else {
     Ok(())
   }
@@ -182,7 +181,15 @@ where
   let activity = WithContext::new(activity, CONTEXT.deref().clone());
 
   let object_value = serde_json::to_value(&activity)?;
-  insert_activity(activity.id(), object_value, true, sensitive, context.pool()).await?;
+  insert_activity(
+    activity.id(),
+    object_value,
+    true,
+    sensitive,
+    false,
+    context.pool(),
+  )
+  .await?;
 
   send_activity(
     activity,
@@ -0,0 +1,76 @@
+use crate::{
+  local_instance,
+  protocol::activities::relay::{accept::AcceptFollowRelay, follow::FollowRelay},
+};
+use activitypub_federation::{data::Data, traits::ActivityHandler, utils::verify_domains_match};
+use lemmy_api_common::context::LemmyContext;
+use lemmy_db_schema::{
+  source::{
+    activity::Activity,
+    relay::{Relay, RelayUpdateForm},
+  },
+  traits::Crud,
+};
+use lemmy_db_views::structs::SiteView;
+use lemmy_utils::error::{LemmyError, LemmyErrorExt, LemmyErrorType};
+use url::Url;
+
+#[async_trait::async_trait(?Send)]
+impl ActivityHandler for AcceptFollowRelay {
+  type DataType = LemmyContext;
+  type Error = LemmyError;
+
+  fn id(&self) -> &Url {
+    &self.id
+  }
+
+  fn actor(&self) -> &Url {
+    self.actor.inner()
+  }
+
+  /// Relays often only include the id of the follow, so the follow is read from the activities
+  /// which we sent. It has to be addressed to this relay, and still wait for an answer.
+  #[tracing::instrument(skip_all)]
+  async fn verify(
+    &self,
+    context: &Data<LemmyContext>,
+    _request_counter: &mut i32,
+  ) -> Result<(), LemmyError> {
+    let site = SiteView::read_local(context.pool()).await?.site;
+    verify_domains_match(self.object.id(), &site.actor_id.into())?;
+
+    let relay = Relay::read_from_apub_id(context.pool(), self.actor.inner().clone())
+      .await?
+      .ok_or(LemmyErrorType::CouldntFindRelay)?;
+    if !relay.pending {
+      return Err(LemmyErrorType::RelayFollowNotPending.into());
+    }
+    let sent = Activity::read_from_apub_id(context.pool(), &self.object.id().clone().into())
+      .await
+      .with_lemmy_type(LemmyErrorType::RelayFollowMismatch)?;
+    let follow: FollowRelay =
+      serde_json::from_value(sent.data).with_lemmy_type(LemmyErrorType::RelayFollowMismatch)?;
+    if !sent.local || follow.object.inner() != self.actor.inner() {
+      return Err(LemmyErrorType::RelayFollowMismatch.into());
+    }
+    Ok(())
+  }
+
+  #[tracing::instrument(skip_all)]
+  async fn receive(
+    self,
+    context: &Data<LemmyContext>,
+    request_counter: &mut i32,
+  ) -> Result<(), LemmyError> {
+    let relay = self
+      .actor
+      .dereference(context, local_instance(context).await, request_counter)
+      .await?;
+    let form = RelayUpdateForm {
+      pending: Some(false),
+      ..Default::default()
+    };
+    Relay::update(context.pool(), relay.id, &form).await?;
+    Ok(())
+  }
+}
@@ -0,0 +1,68 @@
+use crate::{
+  check_apub_id_valid_with_strictness,
+  fetch_local_site_data,
+  local_instance,
+  protocol::activities::relay::announce::RelayAnnounce,
+};
+use activitypub_federation::{data::Data, traits::ActivityHandler};
+use lemmy_api_common::context::LemmyContext;
+use lemmy_db_schema::source::relay::Relay;
+use lemmy_utils::error::{LemmyError, LemmyErrorType};
+use tracing::debug;
+use url::Url;
+
+#[async_trait::async_trait(?Send)]
+impl ActivityHandler for RelayAnnounce {
+  type DataType = LemmyContext;
+  type Error = LemmyError;
+
+  fn id(&self) -> &Url {
+    &self.id
+  }
+
+  fn actor(&self) -> &Url {
+    self.actor.inner()
+  }
+
+  /// Only relays which accepted our follow can deliver posts
+  #[tracing::instrument(skip_all)]
+  async fn verify(
+    &self,
+    context: &Data<LemmyContext>,
+    _request_counter: &mut i32,
+  ) -> Result<(), LemmyError> {
+    let relay = Relay::read_from_apub_id(context.pool(), self.actor.inner().clone())
+      .await?
+      .ok_or(LemmyErrorType::CouldntFindRelay)?;
+    if relay.pending {
+      return Err(LemmyErrorType::RelayNotAccepted.into());
+    }
+    let local_site_data = fetch_local_site_data(context.pool()).await?;
+    check_apub_id_valid_with_strictness(
+      self.object.inner(),
+      false,
+      &local_site_data,
+      context.settings(),
+    )?;
+    Ok(())
+  }
+
+  /// Stores the post so that it can be discovered. Nobody follows its community because of this,
+  /// so it stays out of subscribed listings.
+  #[tracing::instrument(skip_all)]
+  async fn receive(
+    self,
+    context: &Data<LemmyContext>,
+    request_counter: &mut i32,
+  ) -> Result<(), LemmyError> {
+    // Relays forward any public object, but only posts can be stored
+    if let Err(e) = self
+      .object
+      .dereference(context, local_instance(context).await, request_counter)
+      .await
+    {
+      debug!("Ignoring {} from relay: {}", self.object.inner(), e);
+    }
+    Ok(())
+  }
+}
@@ -0,0 +1,80 @@
+use crate::{
+  activities::{generate_activity_id, send_lemmy_activity},
+  objects::{instance::ApubSite, relay::ApubRelay},
+  protocol::{activities::relay::follow::FollowRelay, Id},
+  ActorType,
+};
+use activitypub_federation::{
+  core::object_id::ObjectId,
+  data::Data,
+  traits::{ActivityHandler, Actor},
+};
+use activitystreams_kinds::activity::FollowType;
+use lemmy_api_common::context::LemmyContext;
+use lemmy_db_views::structs::SiteView;
+use lemmy_utils::error::{LemmyError, LemmyErrorType};
+use url::Url;
+
+impl FollowRelay {
+  pub(in crate::activities::relay) fn new(
+    site: &ApubSite,
+    relay: &ApubRelay,
+    context: &LemmyContext,
+  ) -> Result<FollowRelay, LemmyError> {
+    Ok(FollowRelay {
+      actor: ObjectId::new(site.actor_id()),
+      object: ObjectId::new(relay.actor_id()),
+      kind: FollowType::Follow,
+      id: generate_activity_id(
+        FollowType::Follow,
+        &context.settings().get_protocol_and_hostname(),
+      )?,
+    })
+  }
+
+  /// Subscribes the site actor to the relay. It stays pending until the relay accepts.
+  #[tracing::instrument(skip_all)]
+  pub async fn send(relay: &ApubRelay, context: &LemmyContext) -> Result<(), LemmyError> {
+    let site: ApubSite = SiteView::read_local(context.pool()).await?.site.into();
+    let follow = FollowRelay::new(&site, relay, context)?;
+    let inbox = vec![relay.inbox()];
+    send_lemmy_activity(context, follow, &site, inbox, true).await
+  }
+}
+
+impl Id for FollowRelay {
+  fn object_id(&self) -> &Url {
+    &self.id
+  }
+}
+
+#[async_trait::async_trait(?Send)]
+impl ActivityHandler for FollowRelay {
+  type DataType = LemmyContext;
+  type Error = LemmyError;
+
+  fn id(&self) -> &Url {
+    &self.id
+  }
+
+  fn actor(&self) -> &Url {
+    self.actor.inner()
+  }
+
+  async fn verify(
+    &self,
+    _context: &Data<LemmyContext>,
+    _request_counter: &mut i32,
+  ) -> Result<(), LemmyError> {
+    Ok(())
+  }
+
+  // Lemmy doesn't act as a relay, so this is only sent
+  async fn receive(
+    self,
+    _context: &Data<LemmyContext>,
+    _request_counter: &mut i32,
+  ) -> Result<(), LemmyError> {
+    Err(LemmyErrorType::CannotReceiveRelayFollow.into())
+  }
+}
@@ -0,0 +1,66 @@
+use crate::{
+  activities::{generate_activity_id, send_lemmy_activity},
+  objects::{instance::ApubSite, relay::ApubRelay},
+  protocol::activities::relay::{follow::FollowRelay, undo_follow::UndoFollowRelay},
+  ActorType,
+};
+use activitypub_federation::{
+  core::object_id::ObjectId,
+  data::Data,
+  traits::{ActivityHandler, Actor},
+};
+use activitystreams_kinds::activity::UndoType;
+use lemmy_api_common::context::LemmyContext;
+use lemmy_db_views::structs::SiteView;
+use lemmy_utils::error::{LemmyError, LemmyErrorType};
+use url::Url;
+
+impl UndoFollowRelay {
+  #[tracing::instrument(skip_all)]
+  pub async fn send(relay: &ApubRelay, context: &LemmyContext) -> Result<(), LemmyError> {
+    let site: ApubSite = SiteView::read_local(context.pool()).await?.site.into();
+    let object = FollowRelay::new(&site, relay, context)?;
+    let undo = UndoFollowRelay {
+      actor: ObjectId::new(site.actor_id()),
+      object,
+      kind: UndoType::Undo,
+      id: generate_activity_id(
+        UndoType::Undo,
+        &context.settings().get_protocol_and_hostname(),
+      )?,
+    };
+    let inbox = vec![relay.inbox()];
+    send_lemmy_activity(context, undo, &site, inbox, true).await
+  }
+}
+
+#[async_trait::async_trait(?Send)]
+impl ActivityHandler for UndoFollowRelay {
+  type DataType = LemmyContext;
+  type Error = LemmyError;
+
+  fn id(&self) -> &Url {
+    &self.id
+  }
+
+  fn actor(&self) -> &Url {
+    self.actor.inner()
+  }
+
+  async fn verify(
+    &self,
+    _context: &Data<LemmyContext>,
+    _request_counter: &mut i32,
+  ) -> Result<(), LemmyError> {
+    Ok(())
+  }
+
+  // Lemmy doesn't act as a relay, so this is only sent
+  async fn receive(
+    self,
+    _context: &Data<LemmyContext>,
+    _request_counter: &mut i32,
+  ) -> Result<(), LemmyError> {
+    Err(LemmyErrorType::CannotReceiveRelayFollow.into())
+  }
+}
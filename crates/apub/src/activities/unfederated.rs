@@ -1,15 +1,16 @@
 use crate::SendActivity;
 use lemmy_api_common::{
   comment::{
+    AssignCommentReport,
     CommentReportResponse,
     CommentResponse,
     DistinguishComment,
+    EscalateCommentReport,
     GetComment,
     GetComments,
     GetCommentsResponse,
     ListCommentReports,
     ListCommentReportsResponse,
-    ResolveCommentReport,
     SaveComment,
   },
   community::{
@@ -19,6 +20,10 @@ use lemmy_api_common::{
     GetCommunityResponse,
     ListCommunities,
     ListCommunitiesResponse,
+    ListCommunityBackfills,
+    ListCommunityBackfillsResponse,
+    ListDirectoryCommunities,
+    ListDirectoryCommunitiesResponse,
     TransferCommunity,
   },
   person::{
@@ -29,6 +34,7 @@ use lemmy_api_common::{
     BlockPersonResponse,
     ChangePassword,
     CommentReplyResponse,
+    CreateReportComment,
     GetBannedPersons,
     GetCaptcha,
     GetCaptchaResponse,
@@ -42,6 +48,10 @@ use lemmy_api_common::{
     GetReportCountResponse,
     GetUnreadCount,
     GetUnreadCountResponse,
+    ListMyCommunityBans,
+    ListMyCommunityBansResponse,
+    ListReportComments,
+    ListReportCommentsResponse,
     Login,
     LoginResponse,
     MarkAllAsRead,
@@ -52,11 +62,14 @@ use lemmy_api_common::{
     PasswordResetResponse,
     PersonMentionResponse,
     Register,
+    ReportCommentResponse,
     SaveUserSettings,
     VerifyEmail,
     VerifyEmailResponse,
   },
   post::{
+    AssignPostReport,
+    EscalatePostReport,
     GetPost,
     GetPostResponse,
     GetPosts,
@@ -68,10 +81,10 @@ use lemmy_api_common::{
     MarkPostAsRead,
     PostReportResponse,
     PostResponse,
-    ResolvePostReport,
     SavePost,
   },
   private_message::{
+    AssignPrivateMessageReport,
     CreatePrivateMessageReport,
     GetPrivateMessages,
     ListPrivateMessageReports,
@@ -83,9 +96,15 @@ use lemmy_api_common::{
     ResolvePrivateMessageReport,
   },
   site::{
+    ActivityResponse,
+    AddRelay,
     ApproveRegistrationApplication,
+    BlockInstance,
+    BlockInstanceResponse,
     CreateSite,
     EditSite,
+    GetFederationStats,
+    GetFederationStatsResponse,
     GetModlog,
     GetModlogResponse,
     GetSite,
@@ -93,19 +112,29 @@ use lemmy_api_common::{
     GetUnreadRegistrationApplicationCount,
     GetUnreadRegistrationApplicationCountResponse,
     LeaveAdmin,
+    ListActivities,
+    ListActivitiesResponse,
     ListRegistrationApplications,
     ListRegistrationApplicationsResponse,
+    ListRelays,
+    ListRelaysResponse,
     PurgeComment,
     PurgeCommunity,
     PurgeItemResponse,
     PurgePerson,
     PurgePost,
     RegistrationApplicationResponse,
+    RelayResponse,
+    RemoveRelay,
+    ReplayActivity,
+    ResendActivity,
     ResolveObject,
     ResolveObjectResponse,
     Search,
     SearchResponse,
     SiteResponse,
+    VerifyAggregates,
+    VerifyAggregatesResponse,
   },
   websocket::structs::{
     CommunityJoin,
@@ -203,6 +232,54 @@ impl SendActivity for GetBannedPersons {
   type Response = BannedPersonsResponse;
 }
 
+impl SendActivity for ListMyCommunityBans {
+  type Response = ListMyCommunityBansResponse;
+}
+
+impl SendActivity for ListCommunityBackfills {
+  type Response = ListCommunityBackfillsResponse;
+}
+
+impl SendActivity for ListDirectoryCommunities {
+  type Response = ListDirectoryCommunitiesResponse;
+}
+
+impl SendActivity for BlockInstance {
+  type Response = BlockInstanceResponse;
+}
+
+impl SendActivity for GetFederationStats {
+  type Response = GetFederationStatsResponse;
+}
+
+impl SendActivity for AddRelay {
+  type Response = RelayResponse;
+}
+
+impl SendActivity for RemoveRelay {
+  type Response = RelayResponse;
+}
+
+impl SendActivity for ListRelays {
+  type Response = ListRelaysResponse;
+}
+
+impl SendActivity for ListActivities {
+  type Response = ListActivitiesResponse;
+}
+
+impl SendActivity for VerifyAggregates {
+  type Response = VerifyAggregatesResponse;
+}
+
+impl SendActivity for ReplayActivity {
+  type Response = ActivityResponse;
+}
+
+impl SendActivity for ResendActivity {
+  type Response = ActivityResponse;
+}
+
 impl SendActivity for BlockPerson {
   type Response = BlockPersonResponse;
 }
@@ -331,10 +408,6 @@ impl SendActivity for ListPostReports {
   type Response = ListPostReportsResponse;
 }
 
-impl SendActivity for ResolvePostReport {
-  type Response = PostReportResponse;
-}
-
 impl SendActivity for GetSiteMetadata {
   type Response = GetSiteMetadataResponse;
 }
@@ -351,6 +424,30 @@ impl SendActivity for ListCommentReports {
   type Response = ListCommentReportsResponse;
 }
 
-impl SendActivity for ResolveCommentReport {
+impl SendActivity for AssignPostReport {
+  type Response = PostReportResponse;
+}
+
+impl SendActivity for EscalatePostReport {
+  type Response = PostReportResponse;
+}
+
+impl SendActivity for AssignCommentReport {
   type Response = CommentReportResponse;
 }
+
+impl SendActivity for EscalateCommentReport {
+  type Response = CommentReportResponse;
+}
+
+impl SendActivity for AssignPrivateMessageReport {
+  type Response = PrivateMessageReportResponse;
+}
+
+impl SendActivity for CreateReportComment {
+  type Response = ReportCommentResponse;
+}
+
+impl SendActivity for ListReportComments {
+  type Response = ListReportCommentsResponse;
+}
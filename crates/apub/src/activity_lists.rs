@@ -9,6 +9,7 @@ use crate::{
         collection_remove::CollectionRemove,
         lock_page::{LockPage, UndoLockPage},
         report::Report,
+        resolve_report::ResolveReport,
         update::UpdateCommunity,
       },
       create_or_update::{
@@ -18,6 +19,7 @@ use crate::{
       },
       deletion::{delete::Delete, delete_user::DeleteUser, undo_delete::UndoDelete},
       following::{accept::AcceptFollow, follow::Follow, undo_follow::UndoFollow},
+      relay::{accept::AcceptFollowRelay, announce::RelayAnnounce},
       voting::{undo_vote::UndoVote, vote::Vote},
     },
     objects::page::Page,
@@ -25,9 +27,11 @@ use crate::{
   },
 };
 use activitypub_federation::{data::Data, deser::context::WithContext, traits::ActivityHandler};
+use activitystreams_kinds::public;
 use lemmy_api_common::context::LemmyContext;
 use lemmy_utils::error::LemmyError;
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 use url::Url;
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -60,6 +64,7 @@ pub enum PersonInboxActivities {
   Delete(Delete),
   UndoDelete(UndoDelete),
   AnnounceActivity(AnnounceActivity),
+  ResolveReport(ResolveReport),
 }
 
 /// This is necessary for user inbox, which can also receive some "announcable" activities,
@@ -104,6 +109,26 @@ pub enum SiteInboxActivities {
   DeleteUser(DeleteUser),
 }
 
+/// Activities which subscribed relays send to the site inbox
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(untagged)]
+#[enum_delegate::implement(ActivityHandler)]
+pub enum RelayInboxActivities {
+  AcceptFollowRelay(AcceptFollowRelay),
+  RelayAnnounce(RelayAnnounce),
+}
+
+/// Whether the activity is a private message or the deletion of one, which admins must not be able
+/// to read. Deletions of private messages are only addressed to the recipient.
+pub(crate) fn is_private_message_activity(activity: &Value) -> bool {
+  match serde_json::from_value::<PersonInboxActivities>(activity.clone()) {
+    Ok(PersonInboxActivities::CreateOrUpdatePrivateMessage(_)) => true,
+    Ok(PersonInboxActivities::Delete(delete)) => !delete.to.contains(&public()),
+    Ok(PersonInboxActivities::UndoDelete(undo)) => !undo.object.to.contains(&public()),
+    _ => false,
+  }
+}
+
 #[async_trait::async_trait(?Send)]
 impl InCommunity for AnnouncableActivities {
   #[tracing::instrument(skip(self, context))]
@@ -141,8 +166,9 @@ mod tests {
       PersonInboxActivitiesWithAnnouncable,
       SiteInboxActivities,
     },
-    protocol::tests::{test_json, test_parse_lemmy_item},
+    protocol::tests::{file_to_json_object, test_json, test_parse_lemmy_item},
   };
+  use serde_json::Value;
 
   #[test]
   fn test_group_inbox() {
@@ -177,4 +203,28 @@ mod tests {
     )
     .unwrap();
   }
+
+  #[test]
+  fn test_private_message_activities() {
+    let is_private =
+      |path: &str| super::is_private_message_activity(&file_to_json_object::<Value>(path).unwrap());
+    assert!(is_private(
+      "assets/lemmy/activities/create_or_update/create_private_message.json"
+    ));
+    assert!(is_private(
+      "assets/lemmy/activities/deletion/delete_private_message.json"
+    ));
+    assert!(is_private(
+      "assets/lemmy/activities/deletion/undo_delete_private_message.json"
+    ));
+    assert!(!is_private(
+      "assets/lemmy/activities/create_or_update/create_page.json"
+    ));
+    assert!(!is_private(
+      "assets/lemmy/activities/deletion/delete_page.json"
+    ));
+    assert!(!is_private(
+      "assets/lemmy/activities/deletion/undo_delete_page.json"
+    ));
+  }
 }
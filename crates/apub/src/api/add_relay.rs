@@ -0,0 +1,54 @@
+use crate::{
+  api::PerformApub,
+  local_instance,
+  objects::relay::ApubRelay,
+  protocol::activities::relay::follow::FollowRelay,
+};
+use activitypub_federation::core::object_id::ObjectId;
+use actix_web::web::Data;
+use lemmy_api_common::{
+  context::LemmyContext,
+  site::{AddRelay, RelayResponse},
+  utils::{get_local_user_view_from_jwt, is_admin},
+};
+use lemmy_db_schema::{
+  source::relay::{Relay, RelayUpdateForm},
+  traits::Crud,
+};
+use lemmy_utils::{
+  error::{LemmyError, LemmyErrorExt, LemmyErrorExt2, LemmyErrorType},
+  ConnectionId,
+};
+use url::Url;
+
+/// Subscribes the site to a relay, or changes whether local activity is published to it.
+#[async_trait::async_trait(?Send)]
+impl PerformApub for AddRelay {
+  type Response = RelayResponse;
+
+  #[tracing::instrument(skip(context, _websocket_id))]
+  async fn perform(
+    &self,
+    context: &Data<LemmyContext>,
+    _websocket_id: Option<ConnectionId>,
+  ) -> Result<RelayResponse, LemmyError> {
+    let local_user_view =
+      get_local_user_view_from_jwt(&self.auth, context.pool(), context.secret()).await?;
+    is_admin(&local_user_view)?;
+
+    let actor_id = Url::parse(&self.actor_id).with_lemmy_type(LemmyErrorType::InvalidRelay)?;
+    let relay = ObjectId::<ApubRelay>::new(actor_id)
+      .dereference(context, local_instance(context).await, &mut 0)
+      .await
+      .with_lemmy_type(LemmyErrorType::CouldntFindRelay)?;
+
+    let form = RelayUpdateForm {
+      publish: Some(self.publish.unwrap_or(false)),
+      ..Default::default()
+    };
+    let relay = Relay::update(context.pool(), relay.id, &form).await?;
+    FollowRelay::send(&relay.clone().into(), context).await?;
+
+    Ok(RelayResponse { relay })
+  }
+}
@@ -18,7 +18,10 @@ use lemmy_db_schema::{
   traits::{Crud, DeleteableOrRemoveable},
 };
 use lemmy_db_views::comment_view::CommentQuery;
-use lemmy_utils::{error::LemmyError, ConnectionId};
+use lemmy_utils::{
+  error::{LemmyError, LemmyErrorExt, LemmyErrorType},
+  ConnectionId,
+};
 
 #[async_trait::async_trait(?Send)]
 impl PerformApub for GetComments {
@@ -81,7 +84,7 @@ impl PerformApub for GetComments {
       .build()
       .list()
       .await
-      .map_err(|e| LemmyError::from_error_message(e, "couldnt_get_comments"))?;
+      .with_lemmy_type(LemmyErrorType::CouldntGetComments)?;
 
     // Blank out deleted or removed info
     for cv in comments
@@ -18,7 +18,10 @@ use lemmy_db_schema::{
   traits::DeleteableOrRemoveable,
 };
 use lemmy_db_views::post_view::PostQuery;
-use lemmy_utils::{error::LemmyError, ConnectionId};
+use lemmy_utils::{
+  error::{LemmyError, LemmyErrorExt, LemmyErrorType},
+  ConnectionId,
+};
 
 #[async_trait::async_trait(?Send)]
 impl PerformApub for GetPosts {
@@ -69,7 +72,7 @@ impl PerformApub for GetPosts {
       .build()
       .list()
       .await
-      .map_err(|e| LemmyError::from_error_message(e, "couldnt_get_posts"))?;
+      .with_lemmy_type(LemmyErrorType::CouldntGetPosts)?;
 
     // Blank out deleted or removed info for non-logged in users
     if !is_logged_in {
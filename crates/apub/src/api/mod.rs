@@ -2,10 +2,14 @@ use actix_web::web::Data;
 use lemmy_api_common::context::LemmyContext;
 use lemmy_utils::{error::LemmyError, ConnectionId};
 
+mod add_relay;
 mod list_comments;
 mod list_posts;
 mod read_community;
 mod read_person;
+mod remove_relay;
+mod replay_activity;
+mod resend_activity;
 mod resolve_object;
 mod search;
 
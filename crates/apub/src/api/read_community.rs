@@ -14,13 +14,17 @@ use lemmy_db_schema::{
   source::{
     actor_language::CommunityLanguage,
     community::Community,
+    community_rule::CommunityRule,
     local_site::LocalSite,
     site::Site,
   },
   traits::DeleteableOrRemoveable,
 };
 use lemmy_db_views_actor::structs::{CommunityModeratorView, CommunityView};
-use lemmy_utils::{error::LemmyError, ConnectionId};
+use lemmy_utils::{
+  error::{LemmyError, LemmyErrorExt, LemmyErrorExt2, LemmyErrorType},
+  ConnectionId,
+};
 
 #[async_trait::async_trait(?Send)]
 impl PerformApub for GetCommunity {
@@ -39,7 +43,7 @@ impl PerformApub for GetCommunity {
     let local_site = LocalSite::read(context.pool()).await?;
 
     if data.name.is_none() && data.id.is_none() {
-      return Err(LemmyError::from_message("no_id_given"));
+      return Err(LemmyErrorType::NoIdGiven.into());
     }
 
     check_private_instance(&local_user_view, &local_site)?;
@@ -52,14 +56,14 @@ impl PerformApub for GetCommunity {
         let name = data.name.clone().unwrap_or_else(|| "main".to_string());
         resolve_actor_identifier::<ApubCommunity, Community>(&name, context, true)
           .await
-          .map_err(|e| e.with_message("couldnt_find_community"))?
+          .with_lemmy_type(LemmyErrorType::CouldntFindCommunity)?
           .id
       }
     };
 
     let mut community_view = CommunityView::read(context.pool(), community_id, person_id)
       .await
-      .map_err(|e| LemmyError::from_error_message(e, "couldnt_find_community"))?;
+      .with_lemmy_type(LemmyErrorType::CouldntFindCommunity)?;
 
     // Blank out deleted or removed info for non-logged in users
     if person_id.is_none() && (community_view.community.deleted || community_view.community.removed)
@@ -69,7 +73,7 @@ impl PerformApub for GetCommunity {
 
     let moderators = CommunityModeratorView::for_community(context.pool(), community_id)
       .await
-      .map_err(|e| LemmyError::from_error_message(e, "couldnt_find_community"))?;
+      .with_lemmy_type(LemmyErrorType::CouldntFindCommunity)?;
 
     let online = context
       .chat_server()
@@ -93,6 +97,7 @@ impl PerformApub for GetCommunity {
     } else {
       None
     };
+    let rules = CommunityRule::for_community(context.pool(), community_id).await?;
 
     let res = GetCommunityResponse {
       community_view,
@@ -101,6 +106,7 @@ impl PerformApub for GetCommunity {
       online,
       discussion_languages,
       default_post_language,
+      rules,
     };
 
     // Return the jwt
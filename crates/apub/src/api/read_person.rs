@@ -11,7 +11,10 @@ use lemmy_db_schema::{
 };
 use lemmy_db_views::{comment_view::CommentQuery, post_view::PostQuery};
 use lemmy_db_views_actor::structs::{CommunityModeratorView, PersonViewSafe};
-use lemmy_utils::{error::LemmyError, ConnectionId};
+use lemmy_utils::{
+  error::{LemmyError, LemmyErrorExt2, LemmyErrorType},
+  ConnectionId,
+};
 
 #[async_trait::async_trait(?Send)]
 impl PerformApub for GetPersonDetails {
@@ -27,7 +30,7 @@ impl PerformApub for GetPersonDetails {
 
     // Check to make sure a person name or an id is given
     if data.username.is_none() && data.person_id.is_none() {
-      return Err(LemmyError::from_message("no_id_given"));
+      return Err(LemmyErrorType::NoIdGiven.into());
     }
 
     let local_user_view =
@@ -43,12 +46,10 @@ impl PerformApub for GetPersonDetails {
         if let Some(username) = &data.username {
           resolve_actor_identifier::<ApubPerson, Person>(username, context, true)
             .await
-            .map_err(|e| e.with_message("couldnt_find_that_username_or_email"))?
+            .with_lemmy_type(LemmyErrorType::CouldntFindThatUsernameOrEmail)?
             .id
         } else {
-          return Err(LemmyError::from_message(
-            "couldnt_find_that_username_or_email",
-          ));
+          return Err(LemmyErrorType::CouldntFindThatUsernameOrEmail.into());
         }
       }
     };
@@ -0,0 +1,36 @@
+use crate::{
+  api::PerformApub,
+  objects::relay::ApubRelay,
+  protocol::activities::relay::undo_follow::UndoFollowRelay,
+};
+use actix_web::web::Data;
+use lemmy_api_common::{
+  context::LemmyContext,
+  site::{RelayResponse, RemoveRelay},
+  utils::{get_local_user_view_from_jwt, is_admin},
+};
+use lemmy_db_schema::{source::relay::Relay, traits::Crud};
+use lemmy_utils::{error::LemmyError, ConnectionId};
+
+/// Unsubscribes the site from a relay.
+#[async_trait::async_trait(?Send)]
+impl PerformApub for RemoveRelay {
+  type Response = RelayResponse;
+
+  #[tracing::instrument(skip(context, _websocket_id))]
+  async fn perform(
+    &self,
+    context: &Data<LemmyContext>,
+    _websocket_id: Option<ConnectionId>,
+  ) -> Result<RelayResponse, LemmyError> {
+    let local_user_view =
+      get_local_user_view_from_jwt(&self.auth, context.pool(), context.secret()).await?;
+    is_admin(&local_user_view)?;
+
+    let relay = Relay::read(context.pool(), self.relay_id).await?;
+    UndoFollowRelay::send(&ApubRelay::from(relay.clone()), context).await?;
+    Relay::delete(context.pool(), relay.id).await?;
+
+    Ok(RelayResponse { relay })
+  }
+}
@@ -0,0 +1,139 @@
+use crate::{activity_lists::SharedInboxActivities, api::PerformApub};
+use activitypub_federation::{data::Data as ApubData, traits::ActivityHandler};
+use actix_web::web::Data;
+use lemmy_api_common::{
+  context::LemmyContext,
+  site::{ActivityResponse, ReplayActivity},
+  utils::{get_local_user_view_from_jwt, is_admin},
+};
+use lemmy_db_schema::{source::activity::Activity, traits::Crud};
+use lemmy_utils::{
+  error::{LemmyError, LemmyErrorExt, LemmyErrorType},
+  ConnectionId,
+};
+
+/// Processes a stored incoming activity again. Only activities whose HTTP signature was valid
+/// when they arrived can be replayed, and the activity itself is verified again.
+#[async_trait::async_trait(?Send)]
+impl PerformApub for ReplayActivity {
+  type Response = ActivityResponse;
+
+  #[tracing::instrument(skip(context, _websocket_id))]
+  async fn perform(
+    &self,
+    context: &Data<LemmyContext>,
+    _websocket_id: Option<ConnectionId>,
+  ) -> Result<ActivityResponse, LemmyError> {
+    let local_user_view =
+      get_local_user_view_from_jwt(&self.auth, context.pool(), context.secret()).await?;
+    is_admin(&local_user_view)?;
+
+    let activity = Activity::read(context.pool(), self.activity_id).await?;
+    replay_activity(&activity, context).await?;
+
+    Ok(ActivityResponse { activity })
+  }
+}
+
+async fn replay_activity(activity: &Activity, context: &LemmyContext) -> Result<(), LemmyError> {
+  if activity.local {
+    return Err(LemmyErrorType::CantReplayLocalActivity.into());
+  }
+  // Private messages are not exposed to admins
+  if activity.private_message {
+    return Err(LemmyErrorType::CantReplayPrivateMessage.into());
+  }
+  if !activity.signature_verified {
+    return Err(LemmyErrorType::CantReplayUnverifiedActivity.into());
+  }
+  let parsed: SharedInboxActivities = serde_json::from_value(activity.data.clone())
+    .with_lemmy_type(LemmyErrorType::CouldntParseActivity)?;
+  let data = ApubData::new(context.clone());
+  parsed.verify(&data, &mut 0).await?;
+  parsed.receive(&data, &mut 0).await
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::{
+    objects::{
+      community::tests::parse_lemmy_community,
+      person::tests::parse_lemmy_person,
+      tests::init_context,
+    },
+    protocol::tests::file_to_json_object,
+  };
+  use lemmy_db_schema::{
+    newtypes::DbUrl,
+    source::{community::Community, person::Person, post::Post, site::Site},
+  };
+  use serde_json::{json, Value};
+  use serial_test::serial;
+  use url::Url;
+
+  #[actix_rt::test]
+  #[serial]
+  async fn test_replay_remote_activity() {
+    let context = init_context().await;
+    let (person, site) = parse_lemmy_person(&context).await;
+    let community = parse_lemmy_community(&context).await;
+
+    let page: Value = file_to_json_object("assets/lemmy/objects/page.json").unwrap();
+    let activity_id =
+      "https://enterprise.lemmy.ml/activities/create/3f0b7a4e-8f5d-4e7c-9a1e-2b6d0c5e4a11";
+    let create = json!({
+      "@context": "https://www.w3.org/ns/activitystreams",
+      "id": activity_id,
+      "type": "Create",
+      "actor": "https://enterprise.lemmy.ml/u/picard",
+      "to": ["https://www.w3.org/ns/activitystreams#Public"],
+      "cc": ["https://enterprise.lemmy.ml/c/tenforward"],
+      "audience": "https://enterprise.lemmy.ml/c/tenforward",
+      "object": page,
+    });
+    let ap_id: DbUrl = Url::parse(activity_id).unwrap().into();
+    Activity::insert(
+      context.pool(),
+      ap_id.clone(),
+      create,
+      false,
+      Some(true),
+      false,
+      false,
+    )
+    .await
+    .unwrap();
+    let stored = Activity::read_from_apub_id(context.pool(), &ap_id)
+      .await
+      .unwrap();
+
+    // Activities whose signature was never checked are refused
+    assert!(replay_activity(&stored, &context).await.is_err());
+    let post_url = Url::parse("https://enterprise.lemmy.ml/post/55143").unwrap();
+    assert!(Post::read_from_apub_id(context.pool(), post_url.clone())
+      .await
+      .unwrap()
+      .is_none());
+
+    Activity::mark_signature_verified(context.pool(), &ap_id)
+      .await
+      .unwrap();
+    let stored = Activity::read(context.pool(), stored.id).await.unwrap();
+    replay_activity(&stored, &context).await.unwrap();
+    let post = Post::read_from_apub_id(context.pool(), post_url)
+      .await
+      .unwrap()
+      .unwrap();
+    assert_eq!(post.community_id, community.id);
+    assert_eq!(post.creator_id, person.id);
+
+    Activity::delete(context.pool(), stored.id).await.unwrap();
+    Post::delete(context.pool(), post.id).await.unwrap();
+    Person::delete(context.pool(), person.id).await.unwrap();
+    Community::delete(context.pool(), community.id)
+      .await
+      .unwrap();
+    Site::delete(context.pool(), site.id).await.unwrap();
+  }
+}
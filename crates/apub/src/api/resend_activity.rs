@@ -0,0 +1,110 @@
+use crate::{
+  api::PerformApub,
+  fetcher::site_or_community_or_user::SiteOrCommunityOrUser,
+  local_instance,
+  ActorType,
+};
+use activitypub_federation::{
+  core::{activity_queue::send_activity, object_id::ObjectId},
+  data::Data as ApubData,
+  traits::ActivityHandler,
+};
+use actix_web::web::Data;
+use lemmy_api_common::{
+  context::LemmyContext,
+  site::{ActivityResponse, ResendActivity},
+  utils::{get_local_user_view_from_jwt, is_admin},
+};
+use lemmy_db_schema::{source::activity::Activity, traits::Crud};
+use lemmy_utils::{
+  error::{LemmyError, LemmyErrorExt, LemmyErrorType},
+  ConnectionId,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use url::Url;
+
+/// Sends a stored local activity to a single inbox, signed by the actor who originally sent it.
+#[async_trait::async_trait(?Send)]
+impl PerformApub for ResendActivity {
+  type Response = ActivityResponse;
+
+  #[tracing::instrument(skip(context, _websocket_id))]
+  async fn perform(
+    &self,
+    context: &Data<LemmyContext>,
+    _websocket_id: Option<ConnectionId>,
+  ) -> Result<ActivityResponse, LemmyError> {
+    let local_user_view =
+      get_local_user_view_from_jwt(&self.auth, context.pool(), context.secret()).await?;
+    is_admin(&local_user_view)?;
+
+    let activity = Activity::read(context.pool(), self.activity_id).await?;
+    if !activity.local {
+      return Err(LemmyErrorType::CantResendRemoteActivity.into());
+    }
+    // Private messages are not exposed to admins
+    if activity.private_message {
+      return Err(LemmyErrorType::CantResendPrivateMessage.into());
+    }
+    let inbox = Url::parse(&self.inbox).with_lemmy_type(LemmyErrorType::InvalidInbox)?;
+    let stored: StoredActivity = serde_json::from_value(activity.data.clone())
+      .with_lemmy_type(LemmyErrorType::CouldntParseActivity)?;
+    let actor = ObjectId::<SiteOrCommunityOrUser>::new(stored.actor.clone())
+      .dereference_local(context)
+      .await?;
+    let private_key = actor
+      .private_key()
+      .ok_or(LemmyErrorType::ActorMissingPrivateKey)?;
+
+    send_activity(
+      stored,
+      actor.get_public_key(),
+      private_key,
+      vec![inbox],
+      local_instance(context).await,
+    )
+    .await?;
+
+    Ok(ActivityResponse { activity })
+  }
+}
+
+/// An activity as it was stored, which is sent without modification.
+#[derive(Debug, Deserialize, Serialize)]
+struct StoredActivity {
+  id: Url,
+  actor: Url,
+  #[serde(flatten)]
+  other: Map<String, Value>,
+}
+
+#[async_trait::async_trait(?Send)]
+impl ActivityHandler for StoredActivity {
+  type DataType = LemmyContext;
+  type Error = LemmyError;
+
+  fn id(&self) -> &Url {
+    &self.id
+  }
+
+  fn actor(&self) -> &Url {
+    &self.actor
+  }
+
+  async fn verify(
+    &self,
+    _data: &ApubData<Self::DataType>,
+    _request_counter: &mut i32,
+  ) -> Result<(), LemmyError> {
+    Ok(())
+  }
+
+  async fn receive(
+    self,
+    _data: &ApubData<Self::DataType>,
+    _request_counter: &mut i32,
+  ) -> Result<(), LemmyError> {
+    Err(LemmyErrorType::CannotReceiveStoredActivity.into())
+  }
+}
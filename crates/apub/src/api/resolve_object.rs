@@ -1,7 +1,11 @@
 use crate::{
   api::PerformApub,
-  fetcher::search::{search_query_to_object_id, SearchableObjects},
+  fetcher::{
+    replies::spawn_fetch_missing_replies,
+    search::{search_query_to_object_id, SearchableObjects},
+  },
 };
+use activitypub_federation::core::object_id::ObjectId;
 use actix_web::web::Data;
 use diesel::NotFound;
 use lemmy_api_common::{
@@ -12,7 +16,11 @@ use lemmy_api_common::{
 use lemmy_db_schema::{newtypes::PersonId, source::local_site::LocalSite, utils::DbPool};
 use lemmy_db_views::structs::{CommentView, PostView};
 use lemmy_db_views_actor::structs::{CommunityView, PersonViewSafe};
-use lemmy_utils::{error::LemmyError, ConnectionId};
+use lemmy_utils::{
+  error::{LemmyError, LemmyErrorExt2, LemmyErrorType},
+  ConnectionId,
+};
+use url::Url;
 
 #[async_trait::async_trait(?Send)]
 impl PerformApub for ResolveObject {
@@ -32,12 +40,33 @@ impl PerformApub for ResolveObject {
 
     // In release builds only allow for authenticated users to fetch remote objects
     let local_only = local_user_view.is_none() && cfg!(not(debug_assertions));
+    // Only urls can point to posts or comments
+    let known_before = match Url::parse(&self.q) {
+      Ok(url) => ObjectId::<SearchableObjects>::new(url)
+        .dereference_local(context)
+        .await
+        .is_ok(),
+      Err(_) => true,
+    };
     let res = search_query_to_object_id(&self.q, local_only, context)
       .await
-      .map_err(|e| e.with_message("couldnt_find_object"))?;
+      .with_lemmy_type(LemmyErrorType::CouldntFindObject)?;
+    // Comments which were written before this instance knew about the thread are missing. Once the
+    // thread is known, new comments arrive through federation.
+    if !known_before {
+      match &res {
+        SearchableObjects::Post(p) if !p.local => {
+          spawn_fetch_missing_replies(p.ap_id.clone().into(), context)
+        }
+        SearchableObjects::Comment(c) if !c.local => {
+          spawn_fetch_missing_replies(c.ap_id.clone().into(), context)
+        }
+        _ => {}
+      }
+    }
     convert_response(res, local_user_view.map(|l| l.person.id), context.pool())
       .await
-      .map_err(|e| e.with_message("couldnt_find_object"))
+      .with_lemmy_type(LemmyErrorType::CouldntFindObject)
   }
 }
 
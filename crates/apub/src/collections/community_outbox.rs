@@ -1,14 +1,14 @@
 use crate::{
   activity_lists::AnnouncableActivities,
   collections::CommunityContext,
-  objects::post::ApubPost,
+  objects::{community::ApubCommunity, post::ApubPost},
   protocol::{
     activities::{
       community::announce::AnnounceActivity,
       create_or_update::page::CreateOrUpdatePage,
       CreateOrUpdateType,
     },
-    collections::group_outbox::GroupOutbox,
+    collections::{group_outbox::GroupOutbox, page_url},
   },
 };
 use activitypub_federation::{
@@ -19,7 +19,7 @@ use activitypub_federation::{
 use activitystreams_kinds::collection::OrderedCollectionType;
 use chrono::NaiveDateTime;
 use futures::future::join_all;
-use lemmy_api_common::utils::generate_outbox_url;
+use lemmy_api_common::{context::LemmyContext, utils::generate_outbox_url};
 use lemmy_db_schema::{
   source::{person::Person, post::Post},
   traits::Crud,
@@ -28,6 +28,25 @@ use lemmy_db_schema::{
 use lemmy_utils::error::LemmyError;
 use url::Url;
 
+/// Wraps the creation of a post into an announce by the community, as it would have been sent out.
+pub(crate) async fn announce_post(
+  post: ApubPost,
+  community: &ApubCommunity,
+  context: &LemmyContext,
+) -> Result<AnnounceActivity, LemmyError> {
+  let person = Person::read(context.pool(), post.creator_id).await?.into();
+  let create = CreateOrUpdatePage::new(
+    post,
+    &person,
+    community,
+    CreateOrUpdateType::Create,
+    context,
+  )
+  .await?;
+  let announcable = AnnouncableActivities::CreateOrUpdatePost(create);
+  AnnounceActivity::new(announcable.try_into()?, community, context)
+}
+
 #[derive(Clone, Debug)]
 pub(crate) struct ApubCommunityOutbox(Vec<ApubPost>);
 
@@ -65,18 +84,14 @@ impl ApubObject for ApubCommunityOutbox {
   async fn into_apub(self, data: &Self::DataType) -> Result<Self::ApubType, LemmyError> {
     let mut ordered_items = vec![];
     for post in self.0 {
-      let person = Person::read(data.1.pool(), post.creator_id).await?.into();
-      let create =
-        CreateOrUpdatePage::new(post, &person, &data.0, CreateOrUpdateType::Create, &data.1)
-          .await?;
-      let announcable = AnnouncableActivities::CreateOrUpdatePost(create);
-      let announce = AnnounceActivity::new(announcable.try_into()?, &data.0, &data.1)?;
-      ordered_items.push(announce);
+      ordered_items.push(announce_post(post, &data.0, &data.1).await?);
     }
 
+    let id: Url = generate_outbox_url(&data.0.actor_id)?.into();
     Ok(GroupOutbox {
       r#type: OrderedCollectionType::OrderedCollection,
-      id: generate_outbox_url(&data.0.actor_id)?.into(),
+      first: Some(page_url(&id, None)?),
+      id,
       total_items: ordered_items.len() as i32,
       ordered_items,
     })
@@ -0,0 +1,258 @@
+use crate::{
+  objects::{comment::ApubComment, person::ApubPerson},
+  protocol::{
+    activities::{
+      create_or_update::{note::CreateOrUpdateNote, page::CreateOrUpdatePage},
+      CreateOrUpdateType,
+    },
+    collections::{from_timestamp_micros, page_url, COLLECTION_PAGE_SIZE},
+  },
+};
+use activitystreams_kinds::collection::{OrderedCollectionPageType, OrderedCollectionType};
+use chrono::NaiveDateTime;
+use lemmy_api_common::{context::LemmyContext, utils::generate_outbox_url};
+use lemmy_db_schema::{
+  newtypes::{CommentId, PostId},
+  source::{comment::Comment, community::Community, person::Person, post::Post},
+};
+use lemmy_utils::error::{LemmyError, LemmyErrorType};
+use serde::{Deserialize, Serialize};
+use std::cmp::Reverse;
+use url::Url;
+
+/// Outbox of a local person. The activities themselves are served in pages, starting at `first`.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct PersonOutbox {
+  pub(crate) r#type: OrderedCollectionType,
+  pub(crate) id: Url,
+  pub(crate) total_items: i32,
+  pub(crate) first: Url,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct PersonOutboxPage {
+  pub(crate) r#type: OrderedCollectionPageType,
+  pub(crate) id: Url,
+  pub(crate) part_of: Url,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub(crate) next: Option<Url>,
+  pub(crate) ordered_items: Vec<PersonOutboxActivity>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(untagged)]
+pub(crate) enum PersonOutboxActivity {
+  CreateOrUpdatePost(CreateOrUpdatePage),
+  CreateOrUpdateComment(CreateOrUpdateNote),
+}
+
+impl PersonOutbox {
+  pub(crate) async fn new(
+    person: &Person,
+    context: &LemmyContext,
+  ) -> Result<PersonOutbox, LemmyError> {
+    let post_count = Post::count_for_creator(context.pool(), person.id).await?;
+    let comment_count = Comment::count_for_creator(context.pool(), person.id).await?;
+    let id: Url = generate_outbox_url(&person.actor_id)?.into();
+    Ok(PersonOutbox {
+      r#type: OrderedCollectionType::OrderedCollection,
+      first: person_outbox_page_url(&id, None)?,
+      id,
+      total_items: (post_count + comment_count) as i32,
+    })
+  }
+}
+
+/// Position of the last item of an outbox page. Items are ordered newest first by publish time,
+/// then posts before comments, then by descending id, so that items sharing a publish time are
+/// neither skipped nor repeated.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum PersonOutboxCursor {
+  Post(NaiveDateTime, PostId),
+  Comment(NaiveDateTime, CommentId),
+}
+
+impl PersonOutboxCursor {
+  /// Reads the cursor from the query of a page url. A page url with only `before` returns the
+  /// items published strictly before that time.
+  pub(crate) fn from_query(
+    before: Option<i64>,
+    before_post: Option<i32>,
+    before_comment: Option<i32>,
+  ) -> Result<Option<Self>, LemmyError> {
+    let published = match before {
+      Some(before) => from_timestamp_micros(before)?,
+      None => return Ok(None),
+    };
+    Ok(Some(match (before_post, before_comment) {
+      (Some(post_id), None) => PersonOutboxCursor::Post(published, PostId(post_id)),
+      (None, Some(comment_id)) => PersonOutboxCursor::Comment(published, CommentId(comment_id)),
+      (None, None) => PersonOutboxCursor::Post(published, PostId(i32::MIN)),
+      (Some(_), Some(_)) => return Err(LemmyErrorType::InvalidOutboxPage.into()),
+    }))
+  }
+
+  /// Bound for the posts which come after the cursor
+  fn posts_before(self) -> (NaiveDateTime, PostId) {
+    match self {
+      PersonOutboxCursor::Post(published, post_id) => (published, post_id),
+      // All posts published at the same time as the comment were already returned
+      PersonOutboxCursor::Comment(published, _) => (published, PostId(i32::MIN)),
+    }
+  }
+
+  /// Bound for the comments which come after the cursor
+  fn comments_before(self) -> (NaiveDateTime, CommentId) {
+    match self {
+      PersonOutboxCursor::Post(published, PostId(i32::MIN)) => (published, CommentId(i32::MIN)),
+      // No comment published at the same time as the post was returned yet
+      PersonOutboxCursor::Post(published, _) => (published, CommentId(i32::MAX)),
+      PersonOutboxCursor::Comment(published, comment_id) => (published, comment_id),
+    }
+  }
+}
+
+/// Url of a person outbox page which starts after the given cursor, or with the newest items if
+/// it is `None`.
+fn person_outbox_page_url(
+  outbox_id: &Url,
+  cursor: Option<PersonOutboxCursor>,
+) -> Result<Url, LemmyError> {
+  let url = match cursor {
+    None => page_url(outbox_id, None)?.to_string(),
+    Some(PersonOutboxCursor::Post(published, PostId(i32::MIN))) => {
+      page_url(outbox_id, Some(published.timestamp_micros()))?.to_string()
+    }
+    Some(PersonOutboxCursor::Post(published, post_id)) => format!(
+      "{}&before_post={}",
+      page_url(outbox_id, Some(published.timestamp_micros()))?,
+      post_id.0
+    ),
+    Some(PersonOutboxCursor::Comment(published, comment_id)) => format!(
+      "{}&before_comment={}",
+      page_url(outbox_id, Some(published.timestamp_micros()))?,
+      comment_id.0
+    ),
+  };
+  Ok(Url::parse(&url)?)
+}
+
+impl PersonOutboxPage {
+  /// Returns the newest posts and comments of the person which come after the given cursor, or the
+  /// newest overall if it is `None`.
+  pub(crate) async fn new(
+    person: Person,
+    cursor: Option<PersonOutboxCursor>,
+    context: &LemmyContext,
+  ) -> Result<PersonOutboxPage, LemmyError> {
+    let outbox_id: Url = generate_outbox_url(&person.actor_id)?.into();
+
+    // Read a full page of both posts and comments, then keep only the newest items of them.
+    let posts = Post::list_for_creator(
+      context.pool(),
+      person.id,
+      cursor.map(PersonOutboxCursor::posts_before),
+      COLLECTION_PAGE_SIZE,
+    )
+    .await?;
+    let comments = Comment::list_for_creator(
+      context.pool(),
+      person.id,
+      cursor.map(PersonOutboxCursor::comments_before),
+      COLLECTION_PAGE_SIZE,
+    )
+    .await?;
+    let mut items: Vec<OutboxItem> = posts
+      .into_iter()
+      .map(|(post, community)| OutboxItem::Post(post, community))
+      .chain(
+        comments
+          .into_iter()
+          .map(|(comment, _, community)| OutboxItem::Comment(comment, community)),
+      )
+      .collect();
+    items.sort_by_key(|item| Reverse(item.sort_key()));
+    items.truncate(COLLECTION_PAGE_SIZE as usize);
+
+    let next = match items.last() {
+      Some(last) if items.len() as i64 == COLLECTION_PAGE_SIZE => {
+        Some(person_outbox_page_url(&outbox_id, Some(last.cursor()))?)
+      }
+      _ => None,
+    };
+
+    let actor: ApubPerson = person.into();
+    let mut ordered_items = vec![];
+    for item in items {
+      let activity = match item {
+        OutboxItem::Post(post, community) => {
+          let id = outbox_activity_id(&post.ap_id.clone().into());
+          let mut create = CreateOrUpdatePage::new(
+            post.into(),
+            &actor,
+            &community.into(),
+            CreateOrUpdateType::Create,
+            context,
+          )
+          .await?;
+          create.id = id;
+          PersonOutboxActivity::CreateOrUpdatePost(create)
+        }
+        OutboxItem::Comment(comment, community) => {
+          let id = outbox_activity_id(&comment.ap_id.clone().into());
+          let mut create = CreateOrUpdateNote::new(
+            ApubComment(comment),
+            &actor,
+            &community.into(),
+            CreateOrUpdateType::Create,
+            context,
+          )
+          .await?;
+          create.id = id;
+          PersonOutboxActivity::CreateOrUpdateComment(create)
+        }
+      };
+      ordered_items.push(activity);
+    }
+
+    Ok(PersonOutboxPage {
+      r#type: OrderedCollectionPageType::OrderedCollectionPage,
+      id: person_outbox_page_url(&outbox_id, cursor)?,
+      part_of: outbox_id,
+      next,
+      ordered_items,
+    })
+  }
+}
+
+/// The activities in the outbox are generated on every fetch, so their ids are derived from the
+/// object. This keeps them stable for receivers which deduplicate by id, and dereferencing one
+/// returns the object which it creates.
+fn outbox_activity_id(object_id: &Url) -> Url {
+  let mut id = object_id.clone();
+  id.set_fragment(Some("create"));
+  id
+}
+
+enum OutboxItem {
+  Post(Post, Community),
+  Comment(Comment, Community),
+}
+
+impl OutboxItem {
+  fn cursor(&self) -> PersonOutboxCursor {
+    match self {
+      OutboxItem::Post(post, _) => PersonOutboxCursor::Post(post.published, post.id),
+      OutboxItem::Comment(comment, _) => PersonOutboxCursor::Comment(comment.published, comment.id),
+    }
+  }
+
+  fn sort_key(&self) -> (NaiveDateTime, bool, i32) {
+    match self {
+      OutboxItem::Post(post, _) => (post.published, true, post.id.0),
+      OutboxItem::Comment(comment, _) => (comment.published, false, comment.id.0),
+    }
+  }
+}
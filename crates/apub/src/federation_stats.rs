@@ -0,0 +1,94 @@
+use activitypub_federation::APUB_JSON_CONTENT_TYPE;
+use http::{header::CONTENT_TYPE, Method};
+use lemmy_db_schema::{source::instance_federation_stats::InstanceFederationStats, utils::DbPool};
+use lemmy_utils::metrics::record_federation_activity;
+use reqwest::{Request, Response};
+use reqwest_middleware::{Middleware, Next};
+use serde_json::Value;
+use std::time::Instant;
+use task_local_extensions::Extensions;
+use tracing::warn;
+use url::Url;
+
+/// Records the result of an incoming activity for the sending instance. Anyone can claim to send
+/// as any actor, so the instance is only known once the HTTP signature was checked.
+pub(crate) async fn record_inbound(
+  actor: &Url,
+  kind: Option<&str>,
+  success: bool,
+  signature_checked: bool,
+  pool: &DbPool,
+) {
+  record_federation_activity(false, kind, success);
+  if !signature_checked {
+    return;
+  }
+  let domain = match actor.domain() {
+    Some(d) => d,
+    None => return,
+  };
+  let res = if success {
+    InstanceFederationStats::record_inbound_success(pool, domain, kind.unwrap_or("Unknown")).await
+  } else {
+    InstanceFederationStats::record_inbound_failure(pool, domain).await
+  };
+  if let Err(e) = res {
+    warn!("Failed to record federation stats for {}: {}", domain, e);
+  }
+}
+
+/// Reqwest middleware which records the result and duration of every activity delivered to a
+/// remote inbox. Activities are queued and sent by the federation library, so this is the only
+/// place where their delivery can be observed.
+pub struct FederationStatsMiddleware {
+  pool: DbPool,
+}
+
+impl FederationStatsMiddleware {
+  pub fn new(pool: DbPool) -> Self {
+    FederationStatsMiddleware { pool }
+  }
+}
+
+#[async_trait::async_trait]
+impl Middleware for FederationStatsMiddleware {
+  async fn handle(
+    &self,
+    req: Request,
+    extensions: &mut Extensions,
+    next: Next<'_>,
+  ) -> reqwest_middleware::Result<Response> {
+    // Activities are the only requests which are posted with the activitypub content type
+    let is_delivery = req.method() == Method::POST
+      && req
+        .headers()
+        .get(CONTENT_TYPE)
+        .map(|c| c == APUB_JSON_CONTENT_TYPE)
+        .unwrap_or(false);
+    let domain = match req.url().domain() {
+      Some(d) if is_delivery => d.to_string(),
+      _ => return next.run(req, extensions).await,
+    };
+    let kind = req
+      .body()
+      .and_then(|b| b.as_bytes())
+      .and_then(|b| serde_json::from_slice::<Value>(b).ok())
+      .and_then(|v| {
+        v.get("type")
+          .and_then(Value::as_str)
+          .map(ToString::to_string)
+      });
+
+    let start = Instant::now();
+    let res = next.run(req, extensions).await;
+    let duration_ms = start.elapsed().as_millis() as i64;
+    let success = matches!(&res, Ok(r) if r.status().is_success());
+    record_federation_activity(true, kind.as_deref(), success);
+    if let Err(e) =
+      InstanceFederationStats::record_outbound(&self.pool, &domain, success, duration_ms).await
+    {
+      warn!("Failed to record federation stats for {}: {}", domain, e);
+    }
+    res
+  }
+}
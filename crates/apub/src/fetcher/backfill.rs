@@ -0,0 +1,169 @@
+use crate::{
+  fetcher::replies::{fetch_with_delay, RepliesFetcher},
+  objects::community::ApubCommunity,
+  protocol::{
+    activities::community::announce::AnnounceActivity,
+    collections::group_outbox::{GroupOutbox, GroupOutboxPage},
+    objects::group::Group,
+    IdOrNestedObject,
+  },
+};
+use activitypub_federation::{data::Data, traits::ActivityHandler};
+use lemmy_api_common::context::LemmyContext;
+use lemmy_db_schema::{
+  source::{
+    community::Community,
+    community_backfill::{CommunityBackfill, CommunityBackfillUpdateForm},
+  },
+  traits::Crud,
+  utils::naive_now,
+};
+use lemmy_utils::error::LemmyError;
+use tracing::{info, warn};
+use url::Url;
+
+/// Maximum number of outbox pages which are fetched for a single community
+const MAX_PAGES: i32 = 25;
+/// Maximum number of comments which are fetched for a single community
+const MAX_COMMENTS: i32 = 5000;
+
+/// Starts fetching the history of a remote community in the background, unless this was already
+/// done before or is still running.
+#[tracing::instrument(skip_all)]
+pub(crate) async fn start_backfill(
+  community: &ApubCommunity,
+  context: &LemmyContext,
+) -> Result<(), LemmyError> {
+  if community.local {
+    return Ok(());
+  }
+  if let Some(backfill) = CommunityBackfill::start(context.pool(), community.id).await? {
+    let community = community.clone();
+    let context = context.clone();
+    actix_rt::spawn(async move {
+      let mut job = BackfillJob {
+        backfill,
+        context: &context,
+      };
+      let result = job.run(&community).await;
+      if let Err(e) = &result {
+        warn!("Backfill of {} failed: {}", community.actor_id, e);
+      }
+      if let Err(e) = job.finish(result.err()).await {
+        warn!("Failed to save backfill of {}: {}", community.actor_id, e);
+      }
+    });
+  }
+  Ok(())
+}
+
+/// Starts the backfills again which were cut off before they finished, eg by a restart of the
+/// server.
+#[tracing::instrument(skip_all)]
+pub async fn resume_backfills(context: &LemmyContext) -> Result<(), LemmyError> {
+  for community_id in CommunityBackfill::list_interrupted(context.pool()).await? {
+    let community: ApubCommunity = Community::read(context.pool(), community_id).await?.into();
+    start_backfill(&community, context).await?;
+  }
+  Ok(())
+}
+
+struct BackfillJob<'a> {
+  backfill: CommunityBackfill,
+  context: &'a LemmyContext,
+}
+
+impl<'a> BackfillJob<'a> {
+  async fn run(&mut self, community: &ApubCommunity) -> Result<(), LemmyError> {
+    let group: Group = fetch_with_delay(&community.actor_id.clone().into(), self.context).await?;
+    let outbox: GroupOutbox = fetch_with_delay(group.outbox.inner(), self.context).await?;
+    match outbox.first {
+      Some(first) => {
+        let mut next = Some(first);
+        while let Some(page_url) = next {
+          if self.backfill.pages_fetched >= MAX_PAGES {
+            break;
+          }
+          let page: GroupOutboxPage = fetch_with_delay(&page_url, self.context).await?;
+          self.backfill.pages_fetched += 1;
+          next = page.next;
+          self.receive_posts(page.ordered_items).await?;
+          self.save_progress().await?;
+        }
+      }
+      // Instances without outbox paging only provide the newest posts
+      None => {
+        self.backfill.pages_fetched += 1;
+        self.receive_posts(outbox.ordered_items).await?;
+        self.save_progress().await?;
+      }
+    }
+    info!(
+      "Backfilled {} posts and {} comments for {}",
+      self.backfill.posts_fetched, self.backfill.comments_fetched, community.actor_id
+    );
+    Ok(())
+  }
+
+  /// Receives the announced posts, and then the comments on each of them. Items which can't be
+  /// parsed are skipped, like when receiving the outbox. The progress is saved after each post, so
+  /// that backfills which were cut off can be told apart from running ones.
+  async fn receive_posts(&mut self, announces: Vec<AnnounceActivity>) -> Result<(), LemmyError> {
+    let data = Data::new(self.context.clone());
+    for announce in announces {
+      let replies = announced_replies(&announce);
+      let request_counter = &mut 0;
+      if announce.verify(&data, request_counter).await.is_err()
+        || announce.receive(&data, request_counter).await.is_err()
+      {
+        continue;
+      }
+      self.backfill.posts_fetched += 1;
+      if let Some(replies) = replies {
+        let remaining = MAX_COMMENTS - self.backfill.comments_fetched;
+        let mut fetcher = RepliesFetcher::new(self.context, remaining);
+        fetcher.fetch_subtree(replies).await;
+        self.backfill.comments_fetched += fetcher.comments_fetched;
+      }
+      self.save_progress().await?;
+    }
+    Ok(())
+  }
+
+  async fn save_progress(&self) -> Result<(), LemmyError> {
+    let form = CommunityBackfillUpdateForm::builder()
+      .pages_fetched(Some(self.backfill.pages_fetched))
+      .posts_fetched(Some(self.backfill.posts_fetched))
+      .comments_fetched(Some(self.backfill.comments_fetched))
+      .updated(Some(Some(naive_now())))
+      .build();
+    CommunityBackfill::update(self.context.pool(), self.backfill.id, &form).await?;
+    Ok(())
+  }
+
+  async fn finish(&self, error: Option<LemmyError>) -> Result<(), LemmyError> {
+    let form = CommunityBackfillUpdateForm::builder()
+      .pages_fetched(Some(self.backfill.pages_fetched))
+      .posts_fetched(Some(self.backfill.posts_fetched))
+      .comments_fetched(Some(self.backfill.comments_fetched))
+      .error(Some(error.map(|e| e.inner.to_string())))
+      .finished(Some(Some(naive_now())))
+      .build();
+    CommunityBackfill::update(self.context.pool(), self.backfill.id, &form).await?;
+    Ok(())
+  }
+}
+
+/// Returns the replies collection of the post which is created by the announced activity.
+fn announced_replies(announce: &AnnounceActivity) -> Option<Url> {
+  match &announce.object {
+    IdOrNestedObject::NestedObject(activity) => activity
+      .other
+      .get("object")?
+      .get("replies")?
+      .as_str()?
+      .parse()
+      .ok(),
+    IdOrNestedObject::Id(_) => None,
+  }
+}
@@ -0,0 +1,191 @@
+use crate::{check_apub_id_valid, fetch_local_site_data};
+use lemmy_api_common::context::LemmyContext;
+use lemmy_db_schema::{
+  newtypes::LanguageId,
+  source::{
+    directory_community::{DirectoryCommunity, DirectoryCommunityForm},
+    instance::Instance,
+    language::Language,
+    local_site::LocalSite,
+  },
+  utils::naive_now,
+};
+use lemmy_utils::{error::LemmyError, REQWEST_TIMEOUT};
+use serde::{de::DeserializeOwned, Deserialize};
+use tracing::{info, warn};
+use url::Url;
+
+/// Communities per page of the remote community listing, this is the maximum which Lemmy allows.
+const PAGE_LIMIT: usize = 50;
+/// Only the biggest communities of each instance are crawled.
+const MAX_PAGES: usize = 4;
+
+/// The parts of the remote `GetSiteResponse` which the crawler needs.
+#[derive(Deserialize)]
+struct RemoteSite {
+  all_languages: Vec<RemoteLanguage>,
+  discussion_languages: Vec<i32>,
+}
+
+#[derive(Deserialize)]
+struct RemoteLanguage {
+  id: i32,
+  code: String,
+}
+
+#[derive(Deserialize)]
+struct RemoteCommunityList {
+  communities: Vec<RemoteCommunityView>,
+}
+
+#[derive(Deserialize)]
+struct RemoteCommunityView {
+  community: RemoteCommunity,
+  counts: RemoteCommunityCounts,
+}
+
+#[derive(Deserialize)]
+struct RemoteCommunity {
+  actor_id: Url,
+  name: String,
+  title: String,
+  description: Option<String>,
+  icon: Option<Url>,
+  nsfw: bool,
+  removed: bool,
+  deleted: bool,
+}
+
+#[derive(Deserialize)]
+struct RemoteCommunityCounts {
+  subscribers: i64,
+  posts: i64,
+}
+
+/// Crawls the community listings of all linked Lemmy instances into the community directory, if
+/// it is enabled. Instances which are blocked or not in the allowlist are skipped.
+pub async fn crawl_community_directory(context: &LemmyContext) {
+  let enabled = LocalSite::read(context.pool())
+    .await
+    .map(|l| l.community_directory)
+    .unwrap_or(false);
+  if !enabled {
+    return;
+  }
+
+  info!("Crawling community directory ...");
+  let instances = match Instance::linked(context.pool()).await {
+    Ok(i) => i,
+    Err(e) => {
+      warn!("Failed to list instances for community directory: {}", e);
+      return;
+    }
+  };
+  for instance in instances {
+    // Only Lemmy has a compatible API
+    if instance.software.as_deref() != Some("lemmy")
+      || instance.domain == context.settings().hostname
+    {
+      continue;
+    }
+    if let Err(e) = crawl_instance(&instance, context).await {
+      warn!("Failed to crawl communities of {}: {}", instance.domain, e);
+    }
+  }
+  info!("Done.");
+}
+
+async fn crawl_instance(instance: &Instance, context: &LemmyContext) -> Result<(), LemmyError> {
+  let base_url = Url::parse(&format!(
+    "{}://{}",
+    context.settings().get_protocol_string(),
+    instance.domain
+  ))?;
+  // Checked again for every instance, in case it was blocked while crawling
+  let local_site_data = fetch_local_site_data(context.pool()).await?;
+  check_apub_id_valid(&base_url, &local_site_data, context.settings())?;
+
+  let site: RemoteSite = fetch_json(base_url.join("/api/v3/site")?, context).await?;
+  let language_ids = local_language_ids(&site, context).await?;
+
+  let crawl_started = naive_now();
+  for page in 1..=MAX_PAGES {
+    let mut url = base_url.join("/api/v3/community/list")?;
+    url
+      .query_pairs_mut()
+      .append_pair("type_", "Local")
+      .append_pair("sort", "TopAll")
+      .append_pair("limit", &PAGE_LIMIT.to_string())
+      .append_pair("page", &page.to_string());
+    let list: RemoteCommunityList = fetch_json(url, context).await?;
+    let count = list.communities.len();
+
+    for view in list.communities {
+      let community = view.community;
+      // Ignore communities which claim to be on a different instance
+      if community.removed
+        || community.deleted
+        || community.actor_id.domain() != Some(&instance.domain)
+      {
+        continue;
+      }
+      let form = DirectoryCommunityForm {
+        instance_id: instance.id,
+        actor_id: community.actor_id.into(),
+        name: community.name,
+        title: community.title,
+        description: community.description,
+        icon: community.icon.map(Into::into),
+        nsfw: community.nsfw,
+        subscribers: view.counts.subscribers,
+        posts: view.counts.posts,
+        language_ids: language_ids.clone(),
+        updated: naive_now(),
+      };
+      DirectoryCommunity::upsert(context.pool(), &form).await?;
+    }
+
+    if count < PAGE_LIMIT {
+      break;
+    }
+  }
+
+  DirectoryCommunity::delete_stale(context.pool(), instance.id, crawl_started).await?;
+  Ok(())
+}
+
+async fn fetch_json<T: DeserializeOwned>(
+  url: Url,
+  context: &LemmyContext,
+) -> Result<T, LemmyError> {
+  let res = context
+    .client()
+    .get(url.as_str())
+    .timeout(REQWEST_TIMEOUT)
+    .send()
+    .await?
+    .error_for_status()?;
+  Ok(res.json().await?)
+}
+
+/// Translates the discussion languages of the remote site into local language ids. Returns an
+/// empty list if the remote site allows all languages.
+async fn local_language_ids(
+  site: &RemoteSite,
+  context: &LemmyContext,
+) -> Result<Vec<LanguageId>, LemmyError> {
+  if site.discussion_languages.len() >= site.all_languages.len() {
+    return Ok(vec![]);
+  }
+  let mut language_ids = vec![];
+  for language in site
+    .all_languages
+    .iter()
+    .filter(|l| site.discussion_languages.contains(&l.id))
+  {
+    if let Some(id) = Language::read_id_from_code(context.pool(), Some(&language.code)).await? {
+      language_ids.push(id);
+    }
+  }
+  Ok(language_ids)
+}
@@ -5,8 +5,12 @@ use lemmy_api_common::context::LemmyContext;
 use lemmy_db_schema::traits::ApubActor;
 use lemmy_utils::error::LemmyError;
 
+pub mod backfill;
+pub mod community_directory;
 pub mod post_or_comment;
+pub(crate) mod replies;
 pub mod search;
+pub mod site_or_community_or_user;
 pub mod user_or_community;
 pub mod webfinger;
 
@@ -0,0 +1,161 @@
+use crate::{
+  local_instance,
+  objects::comment::ApubComment,
+  protocol::{
+    collections::replies::{Replies, RepliesPage},
+    objects::note::Note,
+  },
+};
+use activitypub_federation::{
+  deser::helpers::deserialize_skip_error,
+  traits::ApubObject,
+  utils::fetch_object_http,
+};
+use lemmy_api_common::context::LemmyContext;
+use lemmy_utils::error::LemmyError;
+use once_cell::sync::Lazy;
+use serde::{de::DeserializeOwned, Deserialize};
+use std::{collections::HashSet, sync::Mutex, time::Duration};
+use tracing::{info, warn};
+use url::Url;
+
+/// Maximum nesting level of comments which are fetched
+const MAX_REPLY_DEPTH: i32 = 10;
+/// Maximum number of pages which are fetched from a single replies collection
+const MAX_REPLY_PAGES: i32 = 10;
+/// Maximum number of comments which are fetched for a single thread that is resolved by a user
+const MAX_THREAD_COMMENTS: i32 = 1000;
+/// Pause before each request, so that the remote instance isn't overloaded
+const FETCH_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Posts and comments whose missing replies are currently being fetched
+static FETCHING_REPLIES: Lazy<Mutex<HashSet<Url>>> = Lazy::new(|| Mutex::new(HashSet::new()));
+
+/// Any post or comment, of which only the replies collection is needed.
+#[derive(Deserialize)]
+struct WithReplies {
+  #[serde(deserialize_with = "deserialize_skip_error", default)]
+  replies: Option<Url>,
+}
+
+/// Fetches the comments below a remote post or comment in the background, so that threads which
+/// started before this instance knew about them are complete. Does nothing if the replies of the
+/// object are already being fetched.
+pub(crate) fn spawn_fetch_missing_replies(object_id: Url, context: &LemmyContext) {
+  if !FETCHING_REPLIES
+    .lock()
+    .expect("lock fetching replies")
+    .insert(object_id.clone())
+  {
+    return;
+  }
+  let context = context.clone();
+  actix_rt::spawn(async move {
+    let result: Result<(), LemmyError> = async {
+      let object: WithReplies = fetch_with_delay(&object_id, &context).await?;
+      if let Some(replies) = object.replies {
+        let mut fetcher = RepliesFetcher::new(&context, MAX_THREAD_COMMENTS);
+        fetcher.fetch_subtree(replies).await;
+        info!(
+          "Fetched {} missing comments for {}",
+          fetcher.comments_fetched, object_id
+        );
+      }
+      Ok(())
+    }
+    .await;
+    if let Err(e) = result {
+      warn!("Failed to fetch replies for {}: {}", object_id, e);
+    }
+    FETCHING_REPLIES
+      .lock()
+      .expect("lock fetching replies")
+      .remove(&object_id);
+  });
+}
+
+/// Walks the replies collections of remote objects, and stores all comments which it finds.
+pub(crate) struct RepliesFetcher<'a> {
+  context: &'a LemmyContext,
+  /// Number of comments which may still be fetched
+  remaining: i32,
+  pub(crate) comments_fetched: i32,
+}
+
+impl<'a> RepliesFetcher<'a> {
+  pub(crate) fn new(context: &'a LemmyContext, max_comments: i32) -> Self {
+    RepliesFetcher {
+      context,
+      remaining: max_comments,
+      comments_fetched: 0,
+    }
+  }
+
+  /// Fetches the comment tree below the given replies collection. Comments which can't be fetched
+  /// or parsed are skipped, together with their replies.
+  pub(crate) async fn fetch_subtree(&mut self, replies: Url) {
+    let local_hostname = self.context.settings().get_hostname_without_port().ok();
+    let mut pending = vec![(replies, 1)];
+    while let Some((replies_url, depth)) = pending.pop() {
+      for comment_id in self.list_replies(&replies_url).await {
+        if self.remaining <= 0 {
+          return;
+        }
+        self.remaining -= 1;
+        // Local comments are already in the database
+        if comment_id.domain() == local_hostname.as_deref() {
+          continue;
+        }
+        if let Ok(Some(nested_replies)) = self.fetch_comment(&comment_id).await {
+          if depth < MAX_REPLY_DEPTH {
+            pending.push((nested_replies, depth + 1));
+          }
+        }
+      }
+    }
+  }
+
+  /// Returns the ids of all comments in a replies collection, going through its pages.
+  async fn list_replies(&self, replies_url: &Url) -> Vec<Url> {
+    let mut comment_ids = vec![];
+    let mut next = match fetch_with_delay::<Replies>(replies_url, self.context).await {
+      Ok(replies) => Some(replies.first),
+      Err(_) => None,
+    };
+    let mut pages_fetched = 0;
+    while let Some(page_url) = next {
+      if pages_fetched >= MAX_REPLY_PAGES || comment_ids.len() as i32 >= self.remaining {
+        break;
+      }
+      let page = match fetch_with_delay::<RepliesPage>(&page_url, self.context).await {
+        Ok(p) => p,
+        Err(_) => break,
+      };
+      pages_fetched += 1;
+      next = page.next;
+      comment_ids.extend(page.ordered_items.into_iter().map(|c| c.into_inner()));
+    }
+    comment_ids
+  }
+
+  /// Stores a single comment, and returns its own replies collection.
+  async fn fetch_comment(&mut self, comment_id: &Url) -> Result<Option<Url>, LemmyError> {
+    let note: Note = fetch_with_delay(comment_id, self.context).await?;
+    let nested_replies = note.replies.clone();
+    let request_counter = &mut 0;
+    ApubComment::verify(&note, comment_id, self.context, request_counter).await?;
+    ApubComment::from_apub(note, self.context, request_counter).await?;
+    self.comments_fetched += 1;
+    Ok(nested_replies)
+  }
+}
+
+/// Fetches an object over http, after waiting for a short time so that bulk fetches don't overload
+/// the remote instance.
+pub(crate) async fn fetch_with_delay<T: DeserializeOwned>(
+  url: &Url,
+  context: &LemmyContext,
+) -> Result<T, LemmyError> {
+  tokio::time::sleep(FETCH_INTERVAL).await;
+  Ok(fetch_object_http(url, local_instance(context).await, &mut 0).await?)
+}
@@ -7,7 +7,7 @@ use crate::{
 use activitypub_federation::{core::object_id::ObjectId, traits::ApubObject};
 use chrono::NaiveDateTime;
 use lemmy_api_common::context::LemmyContext;
-use lemmy_utils::error::LemmyError;
+use lemmy_utils::error::{LemmyError, LemmyErrorType};
 use serde::Deserialize;
 use url::Url;
 
@@ -38,7 +38,7 @@ pub(crate) async fn search_query_to_object_id(
           webfinger_resolve_actor::<ApubCommunity>(identifier, local_only, context, request_counter)
             .await?
         }
-        _ => return Err(LemmyError::from_message("invalid query")),
+        _ => return Err(LemmyErrorType::InvalidQuery.into()),
       };
       ObjectId::new(id)
     }
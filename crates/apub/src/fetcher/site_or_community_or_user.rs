@@ -0,0 +1,129 @@
+use crate::{
+  fetcher::user_or_community::{PersonOrGroup, UserOrCommunity},
+  objects::instance::ApubSite,
+  protocol::objects::instance::Instance,
+  ActorType,
+};
+use activitypub_federation::traits::{Actor, ApubObject};
+use chrono::NaiveDateTime;
+use lemmy_api_common::context::LemmyContext;
+use lemmy_utils::error::LemmyError;
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+/// Any actor which can sign a request. Instances usually sign fetches with their site actor.
+#[derive(Clone, Debug)]
+pub enum SiteOrCommunityOrUser {
+  Site(ApubSite),
+  UserOrCommunity(UserOrCommunity),
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(untagged)]
+pub enum SiteOrPersonOrGroup {
+  Instance(Instance),
+  PersonOrGroup(PersonOrGroup),
+}
+
+#[async_trait::async_trait(?Send)]
+impl ApubObject for SiteOrCommunityOrUser {
+  type DataType = LemmyContext;
+  type ApubType = SiteOrPersonOrGroup;
+  type DbType = ();
+  type Error = LemmyError;
+
+  fn last_refreshed_at(&self) -> Option<NaiveDateTime> {
+    match self {
+      SiteOrCommunityOrUser::Site(s) => s.last_refreshed_at(),
+      SiteOrCommunityOrUser::UserOrCommunity(u) => u.last_refreshed_at(),
+    }
+  }
+
+  #[tracing::instrument(skip_all)]
+  async fn read_from_apub_id(
+    object_id: Url,
+    data: &Self::DataType,
+  ) -> Result<Option<Self>, LemmyError> {
+    let site = ApubSite::read_from_apub_id(object_id.clone(), data).await?;
+    Ok(match site {
+      Some(o) => Some(SiteOrCommunityOrUser::Site(o)),
+      None => UserOrCommunity::read_from_apub_id(object_id, data)
+        .await?
+        .map(SiteOrCommunityOrUser::UserOrCommunity),
+    })
+  }
+
+  #[tracing::instrument(skip_all)]
+  async fn delete(self, data: &Self::DataType) -> Result<(), LemmyError> {
+    match self {
+      SiteOrCommunityOrUser::Site(s) => s.delete(data).await,
+      SiteOrCommunityOrUser::UserOrCommunity(u) => u.delete(data).await,
+    }
+  }
+
+  async fn into_apub(self, _data: &Self::DataType) -> Result<Self::ApubType, LemmyError> {
+    unimplemented!()
+  }
+
+  #[tracing::instrument(skip_all)]
+  async fn verify(
+    apub: &Self::ApubType,
+    expected_domain: &Url,
+    data: &Self::DataType,
+    request_counter: &mut i32,
+  ) -> Result<(), LemmyError> {
+    match apub {
+      SiteOrPersonOrGroup::Instance(i) => {
+        ApubSite::verify(i, expected_domain, data, request_counter).await
+      }
+      SiteOrPersonOrGroup::PersonOrGroup(p) => {
+        UserOrCommunity::verify(p, expected_domain, data, request_counter).await
+      }
+    }
+  }
+
+  #[tracing::instrument(skip_all)]
+  async fn from_apub(
+    apub: Self::ApubType,
+    data: &Self::DataType,
+    request_counter: &mut i32,
+  ) -> Result<Self, LemmyError> {
+    Ok(match apub {
+      SiteOrPersonOrGroup::Instance(i) => {
+        SiteOrCommunityOrUser::Site(ApubSite::from_apub(i, data, request_counter).await?)
+      }
+      SiteOrPersonOrGroup::PersonOrGroup(p) => SiteOrCommunityOrUser::UserOrCommunity(
+        UserOrCommunity::from_apub(p, data, request_counter).await?,
+      ),
+    })
+  }
+}
+
+impl Actor for SiteOrCommunityOrUser {
+  fn public_key(&self) -> &str {
+    match self {
+      SiteOrCommunityOrUser::Site(s) => s.public_key(),
+      SiteOrCommunityOrUser::UserOrCommunity(u) => u.public_key(),
+    }
+  }
+
+  fn inbox(&self) -> Url {
+    unimplemented!()
+  }
+}
+
+impl ActorType for SiteOrCommunityOrUser {
+  fn actor_id(&self) -> Url {
+    match self {
+      SiteOrCommunityOrUser::Site(s) => s.actor_id(),
+      SiteOrCommunityOrUser::UserOrCommunity(u) => u.actor_id(),
+    }
+  }
+
+  fn private_key(&self) -> Option<String> {
+    match self {
+      SiteOrCommunityOrUser::Site(s) => s.private_key(),
+      SiteOrCommunityOrUser::UserOrCommunity(u) => u.private_key(),
+    }
+  }
+}
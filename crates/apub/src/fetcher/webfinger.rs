@@ -4,7 +4,10 @@ use anyhow::anyhow;
 use itertools::Itertools;
 use lemmy_api_common::context::LemmyContext;
 use lemmy_db_schema::newtypes::DbUrl;
-use lemmy_utils::{error::LemmyError, WebfingerResponse};
+use lemmy_utils::{
+  error::{LemmyError, LemmyErrorExt, LemmyErrorType},
+  WebfingerResponse,
+};
 use tracing::debug;
 use url::Url;
 
@@ -24,13 +27,13 @@ where
   let (_, domain) = identifier
     .splitn(2, '@')
     .collect_tuple()
-    .ok_or_else(|| LemmyError::from_message("Invalid webfinger query, missing domain"))?;
+    .ok_or(LemmyErrorType::InvalidWebfingerQuery)?;
   let fetch_url = format!("{protocol}://{domain}/.well-known/webfinger?resource=acct:{identifier}");
   debug!("Fetching webfinger url: {}", &fetch_url);
 
   *request_counter += 1;
   if *request_counter > FEDERATION_HTTP_FETCH_LIMIT {
-    return Err(LemmyError::from_message("Request retry limit reached"));
+    return Err(LemmyErrorType::RequestRetryLimitReached.into());
   }
 
   let response = context.client().get(&fetch_url).send().await?;
@@ -63,5 +66,5 @@ where
     }
   }
   let err = anyhow!("Failed to resolve actor for {}", identifier);
-  Err(LemmyError::from_error_message(err, "failed_to_resolve"))
+  Err(err).with_lemmy_type(LemmyErrorType::FailedToResolve)
 }
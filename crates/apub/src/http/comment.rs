@@ -1,12 +1,19 @@
 use crate::{
-  http::{create_apub_response, create_apub_tombstone_response, err_object_not_local},
+  http::{
+    create_apub_response,
+    create_apub_tombstone_response,
+    err_object_not_local,
+    CollectionPageQuery,
+  },
   objects::comment::ApubComment,
+  protocol::collections::replies::{Replies, RepliesPage},
+  signed_fetch::check_signed_fetch,
 };
 use activitypub_federation::traits::ApubObject;
-use actix_web::{web, web::Path, HttpResponse};
-use lemmy_api_common::context::LemmyContext;
+use actix_web::{web, web::Path, HttpRequest, HttpResponse};
+use lemmy_api_common::{context::LemmyContext, utils::generate_replies_url};
 use lemmy_db_schema::{newtypes::CommentId, source::comment::Comment, traits::Crud};
-use lemmy_utils::error::LemmyError;
+use lemmy_utils::error::{LemmyError, LemmyErrorType};
 use serde::Deserialize;
 
 #[derive(Deserialize)]
@@ -19,7 +26,9 @@ pub(crate) struct CommentQuery {
 pub(crate) async fn get_apub_comment(
   info: Path<CommentQuery>,
   context: web::Data<LemmyContext>,
+  request: HttpRequest,
 ) -> Result<HttpResponse, LemmyError> {
+  check_signed_fetch(&request, &context).await?;
   let id = CommentId(info.comment_id.parse::<i32>()?);
   let comment: ApubComment = Comment::read(context.pool(), id).await?.into();
   if !comment.local {
@@ -32,3 +41,38 @@ pub(crate) async fn get_apub_comment(
     Ok(create_apub_tombstone_response(comment.ap_id.clone()))
   }
 }
+
+/// Returns the direct replies to a local comment, so that other instances can fetch the whole
+/// comment tree. The replies themselves are listed in pages, starting from `?page=true`.
+#[tracing::instrument(skip_all)]
+pub(crate) async fn get_apub_comment_replies(
+  info: Path<CommentQuery>,
+  query: web::Query<CollectionPageQuery>,
+  context: web::Data<LemmyContext>,
+  request: HttpRequest,
+) -> Result<HttpResponse, LemmyError> {
+  check_signed_fetch(&request, &context).await?;
+  let id = CommentId(info.comment_id.parse::<i32>()?);
+  let comment = Comment::read(context.pool(), id).await?;
+  if !comment.local {
+    return Err(err_object_not_local());
+  }
+  if comment.deleted || comment.removed {
+    return Err(LemmyErrorType::Deleted.into());
+  }
+
+  let replies_id = generate_replies_url(&comment.ap_id)?.into();
+  if query.page {
+    let page = RepliesPage::new(
+      replies_id,
+      comment.post_id,
+      Some(&comment),
+      query.before,
+      &context,
+    )
+    .await?;
+    return Ok(create_apub_response(&page));
+  }
+  let replies = Replies::new(replies_id, comment.post_id, Some(&comment), &context).await?;
+  Ok(create_apub_response(&replies))
+}
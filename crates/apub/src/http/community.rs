@@ -6,10 +6,16 @@ use crate::{
     community_outbox::ApubCommunityOutbox,
     CommunityContext,
   },
-  http::{create_apub_response, create_apub_tombstone_response, receive_lemmy_activity},
+  http::{
+    create_apub_response,
+    create_apub_tombstone_response,
+    receive_lemmy_activity,
+    CollectionPageQuery,
+  },
   local_instance,
   objects::{community::ApubCommunity, person::ApubPerson},
-  protocol::collections::group_followers::GroupFollowers,
+  protocol::collections::{group_followers::GroupFollowers, group_outbox::GroupOutboxPage},
+  signed_fetch::check_signed_fetch,
 };
 use activitypub_federation::{
   core::object_id::ObjectId,
@@ -22,7 +28,7 @@ use lemmy_api_common::{
   utils::{generate_featured_url, generate_outbox_url},
 };
 use lemmy_db_schema::{source::community::Community, traits::ApubActor};
-use lemmy_utils::error::LemmyError;
+use lemmy_utils::error::{LemmyError, LemmyErrorType};
 use serde::Deserialize;
 
 #[derive(Deserialize)]
@@ -35,7 +41,9 @@ pub(crate) struct CommunityQuery {
 pub(crate) async fn get_apub_community_http(
   info: web::Path<CommunityQuery>,
   context: web::Data<LemmyContext>,
+  request: HttpRequest,
 ) -> Result<HttpResponse, LemmyError> {
+  check_signed_fetch(&request, &context).await?;
   let community: ApubCommunity =
     Community::read_from_name(context.pool(), &info.community_name, true)
       .await?
@@ -65,21 +73,31 @@ pub async fn community_inbox(
 pub(crate) async fn get_apub_community_followers(
   info: web::Path<CommunityQuery>,
   context: web::Data<LemmyContext>,
+  request: HttpRequest,
 ) -> Result<HttpResponse, LemmyError> {
+  check_signed_fetch(&request, &context).await?;
   let community = Community::read_from_name(context.pool(), &info.community_name, false).await?;
   let followers = GroupFollowers::new(community, &context).await?;
   Ok(create_apub_response(&followers))
 }
 
 /// Returns the community outbox, which is populated by a maximum of 20 posts (but no other
-/// activites like votes or comments).
+/// activites like votes or comments). The entire history of posts is available in pages, starting
+/// from `?page=true`.
 pub(crate) async fn get_apub_community_outbox(
   info: web::Path<CommunityQuery>,
+  query: web::Query<CollectionPageQuery>,
   context: web::Data<LemmyContext>,
+  request: HttpRequest,
 ) -> Result<HttpResponse, LemmyError> {
+  check_signed_fetch(&request, &context).await?;
   let community = Community::read_from_name(context.pool(), &info.community_name, false).await?;
   if community.deleted || community.removed {
-    return Err(LemmyError::from_message("deleted"));
+    return Err(LemmyErrorType::Deleted.into());
+  }
+  if query.page {
+    let page = GroupOutboxPage::new(community.into(), query.before, &context).await?;
+    return Ok(create_apub_response(&page));
   }
   let id = ObjectId::new(generate_outbox_url(&community.actor_id)?);
   let outbox_data = CommunityContext(community.into(), context.get_ref().clone());
@@ -93,13 +111,15 @@ pub(crate) async fn get_apub_community_outbox(
 pub(crate) async fn get_apub_community_moderators(
   info: web::Path<CommunityQuery>,
   context: web::Data<LemmyContext>,
+  request: HttpRequest,
 ) -> Result<HttpResponse, LemmyError> {
+  check_signed_fetch(&request, &context).await?;
   let community: ApubCommunity =
     Community::read_from_name(context.pool(), &info.community_name, false)
       .await?
       .into();
   if community.deleted || community.removed {
-    return Err(LemmyError::from_message("deleted"));
+    return Err(LemmyErrorType::Deleted.into());
   }
   let id = ObjectId::new(generate_outbox_url(&community.actor_id)?);
   let outbox_data = CommunityContext(community, context.get_ref().clone());
@@ -115,10 +135,12 @@ pub(crate) async fn get_apub_community_moderators(
 pub(crate) async fn get_apub_community_featured(
   info: web::Path<CommunityQuery>,
   context: web::Data<LemmyContext>,
+  request: HttpRequest,
 ) -> Result<HttpResponse, LemmyError> {
+  check_signed_fetch(&request, &context).await?;
   let community = Community::read_from_name(context.pool(), &info.community_name, false).await?;
   if community.deleted || community.removed {
-    return Err(LemmyError::from_message("deleted"));
+    return Err(LemmyErrorType::Deleted.into());
   }
   let id = ObjectId::new(generate_featured_url(&community.actor_id)?);
   let data = CommunityContext(community.into(), context.get_ref().clone());
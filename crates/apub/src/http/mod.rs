@@ -1,9 +1,11 @@
 use crate::{
   activity_lists::SharedInboxActivities,
+  federation_stats::record_inbound,
   fetcher::user_or_community::UserOrCommunity,
   insert_activity,
   local_instance,
   protocol::objects::tombstone::Tombstone,
+  signed_fetch::check_signed_fetch,
   CONTEXT,
 };
 use activitypub_federation::{
@@ -17,11 +19,17 @@ use actix_web::{web, HttpRequest, HttpResponse};
 use http::StatusCode;
 use lemmy_api_common::context::LemmyContext;
 use lemmy_db_schema::source::activity::Activity;
-use lemmy_utils::error::LemmyError;
+use lemmy_utils::error::{LemmyError, LemmyErrorType};
 use once_cell::sync::OnceCell;
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 use serde_json::Value;
-use std::ops::Deref;
+use std::{
+  ops::Deref,
+  sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc,
+  },
+};
 use tracing::{debug, log::info};
 use url::Url;
 
@@ -57,22 +65,101 @@ where
   let activity_value: Value = serde_json::from_str(&payload)?;
   debug!("Parsing activity {}", payload);
   let activity: Activity = serde_json::from_value(activity_value.clone())?;
+  let kind = activity_value
+    .get("type")
+    .and_then(Value::as_str)
+    .map(ToString::to_string);
   // Log the activity, so we avoid receiving and parsing it twice.
-  let insert = insert_activity(activity.id(), activity_value, false, true, context.pool()).await?;
+  let insert = insert_activity(
+    activity.id(),
+    activity_value,
+    false,
+    true,
+    false,
+    context.pool(),
+  )
+  .await?;
   if !insert {
     debug!("Received duplicate activity {}", activity.id().to_string());
     return Ok(HttpResponse::BadRequest().finish());
   }
   info!("Received activity {}", payload);
 
+  let actor = activity.actor().clone();
   let data = DATA.get_or_init(|| Data::new(context.get_ref().clone()));
-  receive_activity::<Activity, ActorT, LemmyContext>(
+  let activity = SignatureChecked::new(activity);
+  let signature_checked = activity.checked.clone();
+  let res = receive_activity::<SignatureChecked<Activity>, ActorT, LemmyContext>(
     request,
     activity,
     local_instance(&context).await,
     data,
   )
-  .await
+  .await;
+  record_inbound(
+    &actor,
+    kind.as_deref(),
+    res.is_ok(),
+    signature_checked.load(Ordering::Relaxed),
+    context.pool(),
+  )
+  .await;
+  res
+}
+
+/// Marks the stored activity as authentic once its HTTP signature was checked. The federation
+/// library checks the signature right before it calls `verify`, so only activities with a valid
+/// signature get there.
+#[derive(Deserialize)]
+#[serde(transparent)]
+struct SignatureChecked<T> {
+  activity: T,
+  #[serde(skip)]
+  checked: Arc<AtomicBool>,
+}
+
+impl<T> SignatureChecked<T> {
+  fn new(activity: T) -> Self {
+    SignatureChecked {
+      activity,
+      checked: Arc::new(AtomicBool::new(false)),
+    }
+  }
+}
+
+#[async_trait::async_trait(?Send)]
+impl<T> ActivityHandler for SignatureChecked<T>
+where
+  T: ActivityHandler<DataType = LemmyContext, Error = LemmyError>,
+{
+  type DataType = LemmyContext;
+  type Error = LemmyError;
+
+  fn id(&self) -> &Url {
+    self.activity.id()
+  }
+
+  fn actor(&self) -> &Url {
+    self.activity.actor()
+  }
+
+  async fn verify(
+    &self,
+    data: &Data<LemmyContext>,
+    request_counter: &mut i32,
+  ) -> Result<(), LemmyError> {
+    self.checked.store(true, Ordering::Relaxed);
+    Activity::mark_signature_verified(data.pool(), &self.id().clone().into()).await?;
+    self.activity.verify(data, request_counter).await
+  }
+
+  async fn receive(
+    self,
+    data: &Data<LemmyContext>,
+    request_counter: &mut i32,
+  ) -> Result<(), LemmyError> {
+    self.activity.receive(data, request_counter).await
+  }
 }
 
 /// Convert the data to json and turn it into an HTTP Response with the correct ActivityPub
@@ -101,7 +188,20 @@ fn create_apub_tombstone_response<T: Into<Url>>(id: T) -> HttpResponse {
 }
 
 fn err_object_not_local() -> LemmyError {
-  LemmyError::from_message("Object not local, fetch it from original instance")
+  LemmyErrorType::ObjectNotLocal.into()
+}
+
+/// Query parameters for paged outboxes and replies collections
+#[derive(Deserialize)]
+pub(crate) struct CollectionPageQuery {
+  #[serde(default)]
+  page: bool,
+  /// Only return items published before this time, in microseconds since the epoch
+  before: Option<i64>,
+  /// For person outboxes, the id of the post published at `before` after which the page starts
+  before_post: Option<i32>,
+  /// For person outboxes, the id of the comment published at `before` after which the page starts
+  before_comment: Option<i32>,
 }
 
 #[derive(Deserialize)]
@@ -115,7 +215,9 @@ pub struct ActivityQuery {
 pub(crate) async fn get_activity(
   info: web::Path<ActivityQuery>,
   context: web::Data<LemmyContext>,
+  request: HttpRequest,
 ) -> Result<HttpResponse, LemmyError> {
+  check_signed_fetch(&request, &context).await?;
   let settings = context.settings();
   let activity_id = Url::parse(&format!(
     "{}/activities/{}/{}",
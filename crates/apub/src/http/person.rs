@@ -1,14 +1,24 @@
 use crate::{
   activity_lists::PersonInboxActivitiesWithAnnouncable,
+  collections::person_outbox::{PersonOutbox, PersonOutboxCursor, PersonOutboxPage},
   fetcher::user_or_community::UserOrCommunity,
-  http::{create_apub_response, create_apub_tombstone_response, receive_lemmy_activity},
+  http::{
+    create_apub_response,
+    create_apub_tombstone_response,
+    receive_lemmy_activity,
+    CollectionPageQuery,
+  },
   objects::person::ApubPerson,
   protocol::collections::empty_outbox::EmptyOutbox,
+  signed_fetch::check_signed_fetch,
 };
 use activitypub_federation::{deser::context::WithContext, traits::ApubObject};
 use actix_web::{web, HttpRequest, HttpResponse};
 use lemmy_api_common::{context::LemmyContext, utils::generate_outbox_url};
-use lemmy_db_schema::{source::person::Person, traits::ApubActor};
+use lemmy_db_schema::{
+  source::{local_site::LocalSite, person::Person},
+  traits::ApubActor,
+};
 use lemmy_utils::error::LemmyError;
 use serde::Deserialize;
 
@@ -22,7 +32,9 @@ pub struct PersonQuery {
 pub(crate) async fn get_apub_person_http(
   info: web::Path<PersonQuery>,
   context: web::Data<LemmyContext>,
+  request: HttpRequest,
 ) -> Result<HttpResponse, LemmyError> {
+  check_signed_fetch(&request, &context).await?;
   let user_name = info.into_inner().user_name;
   // TODO: this needs to be able to read deleted persons, so that it can send tombstones
   let person: ApubPerson = Person::read_from_name(context.pool(), &user_name, true)
@@ -50,13 +62,31 @@ pub async fn person_inbox(
   .await
 }
 
+/// Returns the posts and comments of a local person, as paged collection of create activities.
+/// Private instances only return an empty outbox.
 #[tracing::instrument(skip_all)]
 pub(crate) async fn get_apub_person_outbox(
   info: web::Path<PersonQuery>,
+  query: web::Query<CollectionPageQuery>,
   context: web::Data<LemmyContext>,
+  request: HttpRequest,
 ) -> Result<HttpResponse, LemmyError> {
+  check_signed_fetch(&request, &context).await?;
   let person = Person::read_from_name(context.pool(), &info.user_name, false).await?;
-  let outbox_id = generate_outbox_url(&person.actor_id)?.into();
-  let outbox = EmptyOutbox::new(outbox_id)?;
-  Ok(create_apub_response(&outbox))
+  let local_site = LocalSite::read(context.pool()).await?;
+  if local_site.private_instance {
+    let outbox_id = generate_outbox_url(&person.actor_id)?.into();
+    let outbox = EmptyOutbox::new(outbox_id)?;
+    return Ok(create_apub_response(&outbox));
+  }
+
+  if query.page {
+    let cursor =
+      PersonOutboxCursor::from_query(query.before, query.before_post, query.before_comment)?;
+    let page = PersonOutboxPage::new(person, cursor, &context).await?;
+    Ok(create_apub_response(&page))
+  } else {
+    let outbox = PersonOutbox::new(&person, &context).await?;
+    Ok(create_apub_response(&outbox))
+  }
 }
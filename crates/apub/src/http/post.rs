@@ -1,12 +1,19 @@
 use crate::{
-  http::{create_apub_response, create_apub_tombstone_response, err_object_not_local},
+  http::{
+    create_apub_response,
+    create_apub_tombstone_response,
+    err_object_not_local,
+    CollectionPageQuery,
+  },
   objects::post::ApubPost,
+  protocol::collections::replies::{Replies, RepliesPage},
+  signed_fetch::check_signed_fetch,
 };
 use activitypub_federation::traits::ApubObject;
-use actix_web::{web, HttpResponse};
-use lemmy_api_common::context::LemmyContext;
+use actix_web::{web, HttpRequest, HttpResponse};
+use lemmy_api_common::{context::LemmyContext, utils::generate_replies_url};
 use lemmy_db_schema::{newtypes::PostId, source::post::Post, traits::Crud};
-use lemmy_utils::error::LemmyError;
+use lemmy_utils::error::{LemmyError, LemmyErrorType};
 use serde::Deserialize;
 
 #[derive(Deserialize)]
@@ -19,7 +26,9 @@ pub(crate) struct PostQuery {
 pub(crate) async fn get_apub_post(
   info: web::Path<PostQuery>,
   context: web::Data<LemmyContext>,
+  request: HttpRequest,
 ) -> Result<HttpResponse, LemmyError> {
+  check_signed_fetch(&request, &context).await?;
   let id = PostId(info.post_id.parse::<i32>()?);
   let post: ApubPost = Post::read(context.pool(), id).await?.into();
   if !post.local {
@@ -32,3 +41,31 @@ pub(crate) async fn get_apub_post(
     Ok(create_apub_tombstone_response(post.ap_id.clone()))
   }
 }
+
+/// Returns the top level comments of a local post, so that other instances can fetch the whole
+/// comment tree. The comments themselves are listed in pages, starting from `?page=true`.
+#[tracing::instrument(skip_all)]
+pub(crate) async fn get_apub_post_replies(
+  info: web::Path<PostQuery>,
+  query: web::Query<CollectionPageQuery>,
+  context: web::Data<LemmyContext>,
+  request: HttpRequest,
+) -> Result<HttpResponse, LemmyError> {
+  check_signed_fetch(&request, &context).await?;
+  let id = PostId(info.post_id.parse::<i32>()?);
+  let post = Post::read(context.pool(), id).await?;
+  if !post.local {
+    return Err(err_object_not_local());
+  }
+  if post.deleted || post.removed {
+    return Err(LemmyErrorType::Deleted.into());
+  }
+
+  let replies_id = generate_replies_url(&post.ap_id)?.into();
+  if query.page {
+    let page = RepliesPage::new(replies_id, post.id, None, query.before, &context).await?;
+    return Ok(create_apub_response(&page));
+  }
+  let replies = Replies::new(replies_id, post.id, None, &context).await?;
+  Ok(create_apub_response(&replies))
+}
@@ -1,14 +1,17 @@
 use crate::{
-  activity_lists::SiteInboxActivities,
+  activity_lists::{RelayInboxActivities, SiteInboxActivities},
   http::{create_apub_response, receive_lemmy_activity},
-  objects::{instance::ApubSite, person::ApubPerson},
+  objects::{instance::ApubSite, person::ApubPerson, relay::ApubRelay},
   protocol::collections::empty_outbox::EmptyOutbox,
+  signed_fetch::check_signed_fetch,
 };
 use activitypub_federation::{deser::context::WithContext, traits::ApubObject};
 use actix_web::{web, HttpRequest, HttpResponse};
 use lemmy_api_common::context::LemmyContext;
+use lemmy_db_schema::source::relay::Relay;
 use lemmy_db_views::structs::SiteView;
 use lemmy_utils::error::LemmyError;
+use serde_json::Value;
 use url::Url;
 
 pub(crate) async fn get_apub_site_http(
@@ -23,7 +26,9 @@ pub(crate) async fn get_apub_site_http(
 #[tracing::instrument(skip_all)]
 pub(crate) async fn get_apub_site_outbox(
   context: web::Data<LemmyContext>,
+  request: HttpRequest,
 ) -> Result<HttpResponse, LemmyError> {
+  check_signed_fetch(&request, &context).await?;
   let outbox_id = format!(
     "{}/site_outbox",
     context.settings().get_protocol_and_hostname()
@@ -38,6 +43,26 @@ pub async fn get_apub_site_inbox(
   payload: String,
   context: web::Data<LemmyContext>,
 ) -> Result<HttpResponse, LemmyError> {
+  if is_relay_activity(&payload, &context).await? {
+    return receive_lemmy_activity::<WithContext<RelayInboxActivities>, ApubRelay>(
+      request, payload, context,
+    )
+    .await;
+  }
   receive_lemmy_activity::<WithContext<SiteInboxActivities>, ApubPerson>(request, payload, context)
     .await
 }
+
+/// Relays which the site follows deliver to its inbox. They aren't users, so their activities
+/// are verified with a different actor type.
+async fn is_relay_activity(payload: &str, context: &LemmyContext) -> Result<bool, LemmyError> {
+  let activity: Value = serde_json::from_str(payload)?;
+  let actor = activity
+    .get("actor")
+    .and_then(Value::as_str)
+    .and_then(|a| Url::parse(a).ok());
+  Ok(match actor {
+    Some(a) => Relay::read_from_apub_id(context.pool(), a).await?.is_some(),
+    None => false,
+  })
+}
@@ -1,4 +1,4 @@
-use crate::fetcher::post_or_comment::PostOrComment;
+use crate::{activity_lists::is_private_message_activity, fetcher::post_or_comment::PostOrComment};
 use activitypub_federation::{
   core::signatures::PublicKey,
   traits::{Actor, ApubObject},
@@ -12,7 +12,10 @@ use lemmy_db_schema::{
   source::{activity::Activity, instance::Instance, local_site::LocalSite},
   utils::DbPool,
 };
-use lemmy_utils::{error::LemmyError, settings::structs::Settings};
+use lemmy_utils::{
+  error::{LemmyError, LemmyErrorType},
+  settings::structs::Settings,
+};
 use once_cell::sync::Lazy;
 use tokio::sync::OnceCell;
 use url::Url;
@@ -21,11 +24,13 @@ pub mod activities;
 pub(crate) mod activity_lists;
 pub mod api;
 pub(crate) mod collections;
+pub mod federation_stats;
 pub mod fetcher;
 pub mod http;
 pub(crate) mod mentions;
 pub mod objects;
 pub mod protocol;
+pub mod signed_fetch;
 
 const FEDERATION_HTTP_FETCH_LIMIT: i32 = 25;
 
@@ -72,7 +77,7 @@ impl UrlVerifier for VerifyUrlData {
     let local_site_data = fetch_local_site_data(self.0.pool())
       .await
       .expect("read local site data");
-    check_apub_id_valid(url, &local_site_data, self.0.settings())
+    check_apub_id_valid(url, &local_site_data, self.0.settings()).map_err(Into::into)
   }
 }
 
@@ -91,7 +96,7 @@ fn check_apub_id_valid(
   apub_id: &Url,
   local_site_data: &LocalSiteData,
   settings: &Settings,
-) -> Result<(), &'static str> {
+) -> Result<(), LemmyErrorType> {
   let domain = apub_id.domain().expect("apud id has domain").to_string();
   let local_instance = settings
     .get_hostname_without_port()
@@ -106,22 +111,22 @@ fn check_apub_id_valid(
     .map(|l| l.federation_enabled)
     .unwrap_or(true)
   {
-    return Err("Federation disabled");
+    return Err(LemmyErrorType::FederationDisabled);
   }
 
   if apub_id.scheme() != settings.get_protocol_string() {
-    return Err("Invalid protocol scheme");
+    return Err(LemmyErrorType::InvalidProtocolScheme);
   }
 
   if let Some(blocked) = local_site_data.blocked_instances.as_ref() {
     if blocked.iter().any(|i| domain.eq(&i.domain)) {
-      return Err("Domain is blocked");
+      return Err(LemmyErrorType::DomainBlocked(domain));
     }
   }
 
   if let Some(allowed) = local_site_data.allowed_instances.as_ref() {
     if !allowed.iter().any(|i| domain.eq(&i.domain)) {
-      return Err("Domain is not in allowlist");
+      return Err(LemmyErrorType::DomainNotInAllowList(domain));
     }
   }
 
@@ -161,7 +166,7 @@ pub(crate) fn check_apub_id_valid_with_strictness(
   local_site_data: &LocalSiteData,
   settings: &Settings,
 ) -> Result<(), LemmyError> {
-  check_apub_id_valid(apub_id, local_site_data, settings).map_err(LemmyError::from_message)?;
+  check_apub_id_valid(apub_id, local_site_data, settings)?;
   let domain = apub_id.domain().expect("apud id has domain").to_string();
   let local_instance = settings
     .get_hostname_without_port()
@@ -182,9 +187,7 @@ pub(crate) fn check_apub_id_valid_with_strictness(
       allowed_and_local.push(local_instance);
 
       if !allowed_and_local.contains(&domain) {
-        return Err(LemmyError::from_message(
-          "Federation forbidden by strict allowlist",
-        ));
+        return Err(LemmyErrorType::FederationDisabledByStrictAllowList.into());
       }
     }
   }
@@ -199,10 +202,23 @@ async fn insert_activity(
   activity: serde_json::Value,
   local: bool,
   sensitive: bool,
+  signature_verified: bool,
   pool: &DbPool,
 ) -> Result<bool, LemmyError> {
   let ap_id = ap_id.clone().into();
-  Ok(Activity::insert(pool, ap_id, activity, local, Some(sensitive)).await?)
+  let private_message = is_private_message_activity(&activity);
+  Ok(
+    Activity::insert(
+      pool,
+      ap_id,
+      activity,
+      local,
+      Some(sensitive),
+      private_message,
+      signature_verified,
+    )
+    .await?,
+  )
 }
 
 /// Common methods provided by ActivityPub actors (community and person). Not all methods are
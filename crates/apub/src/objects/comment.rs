@@ -20,7 +20,10 @@ use activitypub_federation::{
 };
 use activitystreams_kinds::{object::NoteType, public};
 use chrono::NaiveDateTime;
-use lemmy_api_common::{context::LemmyContext, utils::local_site_opt_to_slur_regex};
+use lemmy_api_common::{
+  context::LemmyContext,
+  utils::{generate_replies_url, local_site_opt_to_slur_regex},
+};
 use lemmy_db_schema::{
   source::{
     comment::{Comment, CommentInsertForm, CommentUpdateForm},
@@ -32,8 +35,12 @@ use lemmy_db_schema::{
   traits::Crud,
 };
 use lemmy_utils::{
-  error::LemmyError,
-  utils::{markdown::markdown_to_html, slurs::remove_slurs, time::convert_datetime},
+  error::{LemmyError, LemmyErrorType},
+  utils::{
+    markdown::markdown_to_html,
+    slurs::{build_slur_regex, remove_slurs},
+    time::convert_datetime,
+  },
 };
 use std::ops::Deref;
 use url::Url;
@@ -111,6 +118,11 @@ impl ApubObject for ApubComment {
     )
     .await?;
 
+    let replies = if self.local {
+      Some(generate_replies_url(&self.ap_id)?.into())
+    } else {
+      None
+    };
     let note = Note {
       r#type: NoteType::Note,
       id: ObjectId::new(self.ap_id.clone()),
@@ -127,6 +139,7 @@ impl ApubObject for ApubComment {
       distinguished: Some(self.distinguished),
       language,
       audience: Some(ObjectId::new(community.actor_id)),
+      replies,
     };
 
     Ok(note)
@@ -155,7 +168,7 @@ impl ApubObject for ApubComment {
     verify_person_in_community(&note.attributed_to, &community, context, request_counter).await?;
     let (post, _) = note.get_parents(context, request_counter).await?;
     if post.locked {
-      return Err(LemmyError::from_message("Post is locked"));
+      return Err(LemmyErrorType::Locked.into());
     }
     Ok(())
   }
@@ -179,7 +192,10 @@ impl ApubObject for ApubComment {
 
     let local_site = LocalSite::read(context.pool()).await.ok();
     let slur_regex = &local_site_opt_to_slur_regex(&local_site);
-    let content_slurs_removed = remove_slurs(&content, slur_regex);
+    let community = Community::read(context.pool(), post.community_id).await?;
+    let community_slur_regex = &build_slur_regex(community.slur_filter_regex.as_deref());
+    let content_slurs_removed =
+      remove_slurs(&remove_slurs(&content, slur_regex), community_slur_regex);
     let language_id = LanguageTag::to_language_id_single(note.language, context.pool()).await?;
 
     let form = CommentInsertForm {
@@ -5,7 +5,11 @@ use crate::{
   local_instance,
   objects::instance::fetch_instance_actor_for_object,
   protocol::{
-    objects::{group::Group, Endpoints, LanguageTag},
+    objects::{
+      group::{Group, GroupRule},
+      Endpoints,
+      LanguageTag,
+    },
     ImageObject,
     Source,
   },
@@ -26,6 +30,7 @@ use lemmy_db_schema::{
   source::{
     actor_language::CommunityLanguage,
     community::{Community, CommunityUpdateForm},
+    community_rule::CommunityRule,
     instance::Instance,
   },
   traits::{ApubActor, Crud},
@@ -90,6 +95,14 @@ impl ApubObject for ApubCommunity {
     let community_id = self.id;
     let langs = CommunityLanguage::read(data.pool(), community_id).await?;
     let language = LanguageTag::new_multiple(langs, data.pool()).await?;
+    let rules = CommunityRule::for_community(data.pool(), community_id)
+      .await?
+      .into_iter()
+      .map(|r| GroupRule {
+        title: r.title,
+        body: r.body,
+      })
+      .collect();
 
     let group = Group {
       kind: GroupType::Group,
@@ -114,6 +127,8 @@ impl ApubObject for ApubCommunity {
       published: Some(convert_datetime(self.published)),
       updated: self.updated.map(convert_datetime),
       posting_restricted_to_mods: Some(self.posting_restricted_to_mods),
+      slur_filter_regex: self.slur_filter_regex.clone(),
+      rules: Some(rules),
       attributed_to: Some(generate_moderators_url(&self.actor_id)?.into()),
     };
     Ok(group)
@@ -140,10 +155,14 @@ impl ApubObject for ApubCommunity {
     let instance = Instance::create_from_actor_id(context.pool(), &apub_id).await?;
 
     let form = Group::into_insert_form(group.clone(), instance.id);
+    let rules = group.rules();
     let languages = LanguageTag::to_language_id_multiple(group.language, context.pool()).await?;
 
     let community = Community::create(context.pool(), &form).await?;
     CommunityLanguage::update(context.pool(), languages, community.id).await?;
+    if let Some(rules) = rules {
+      CommunityRule::update_from_remote(context.pool(), rules, community.id).await?;
+    }
 
     let community: ApubCommunity = community.into();
     let outbox_data = CommunityContext(community.clone(), context.clone());
@@ -268,6 +287,16 @@ pub(crate) mod tests {
     assert_eq!(community.title, "Ten Forward");
     assert!(!community.local);
     assert_eq!(community.description.as_ref().unwrap().len(), 132);
+    assert_eq!(
+      community.slur_filter_regex.as_deref(),
+      Some("(spam|scam)s?")
+    );
+    let rules = CommunityRule::for_community(context.pool(), community.id)
+      .await
+      .unwrap();
+    assert_eq!(rules.len(), 2);
+    assert_eq!(rules[0].title, "Be nice");
+    assert_eq!(rules[1].body, None);
 
     Community::delete(context.pool(), community.id)
       .await
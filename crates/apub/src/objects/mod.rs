@@ -11,6 +11,7 @@ pub mod instance;
 pub mod person;
 pub mod post;
 pub mod private_message;
+pub mod relay;
 
 pub(crate) fn read_from_string_or_source(
   content: &str,
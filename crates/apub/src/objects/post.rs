@@ -27,12 +27,13 @@ use html2md::parse_html;
 use lemmy_api_common::{
   context::LemmyContext,
   request::fetch_site_data,
-  utils::{is_mod_or_admin, local_site_opt_to_slur_regex},
+  utils::{generate_replies_url, is_mod_or_admin, local_site_opt_to_slur_regex},
 };
 use lemmy_db_schema::{
   self,
   source::{
     community::Community,
+    converted_note::ConvertedNote,
     local_site::LocalSite,
     moderator::{ModFeaturePost, ModFeaturePostForm, ModLockPost, ModLockPostForm},
     person::Person,
@@ -41,10 +42,11 @@ use lemmy_db_schema::{
   traits::Crud,
 };
 use lemmy_utils::{
-  error::LemmyError,
+  error::{LemmyError, LemmyErrorType},
   utils::{
     markdown::markdown_to_html,
-    slurs::{check_slurs_opt, remove_slurs},
+    mention::strip_leading_mentions,
+    slurs::{build_slur_regex, check_slurs_opt, remove_slurs},
     time::convert_datetime,
   },
 };
@@ -110,6 +112,11 @@ impl ApubObject for ApubPost {
     let community = Community::read(context.pool(), community_id).await?;
     let language = LanguageTag::new_single(self.language_id, context.pool()).await?;
 
+    let replies = if self.local {
+      Some(generate_replies_url(&self.ap_id)?.into())
+    } else {
+      None
+    };
     let page = Page {
       kind: PageType::Page,
       id: ObjectId::new(self.ap_id.clone()),
@@ -130,6 +137,9 @@ impl ApubObject for ApubPost {
       updated: self.updated.map(convert_datetime),
       audience: Some(ObjectId::new(community.actor_id)),
       in_reply_to: None,
+      replies,
+      summary: None,
+      tag: vec![],
     };
     Ok(page)
   }
@@ -149,6 +159,14 @@ impl ApubObject for ApubPost {
     };
 
     let local_site_data = fetch_local_site_data(context.pool()).await?;
+    let convert_notes = local_site_data
+      .local_site
+      .as_ref()
+      .map(|l| l.convert_notes_to_posts)
+      .unwrap_or(true);
+    if page.kind == PageType::Note && !convert_notes {
+      return Err(LemmyErrorType::NoteConversionDisabled.into());
+    }
 
     let community = page.community(context, request_counter).await?;
     check_apub_id_valid_with_strictness(
@@ -161,6 +179,8 @@ impl ApubObject for ApubPost {
 
     let slur_regex = &local_site_opt_to_slur_regex(&local_site_data.local_site);
     check_slurs_opt(&page.name, slur_regex)?;
+    let community_slur_regex = &build_slur_regex(community.slur_filter_regex.as_deref());
+    check_slurs_opt(&page.name, community_slur_regex)?;
 
     verify_domains_match(page.creator()?.inner(), page.id.inner())?;
     verify_is_public(&page.to, &page.cc)?;
@@ -184,13 +204,7 @@ impl ApubObject for ApubPost {
     let mut name = page
       .name
       .clone()
-      .or_else(|| {
-        page
-          .content
-          .clone()
-          .as_ref()
-          .and_then(|c| parse_html(c).lines().next().map(ToString::to_string))
-      })
+      .or_else(|| derive_title(&page))
       .ok_or_else(|| anyhow!("Object must have name or content"))?;
     if name.chars().count() > MAX_TITLE_LENGTH {
       name = name.chars().take(MAX_TITLE_LENGTH).collect();
@@ -216,10 +230,12 @@ impl ApubObject for ApubPost {
         .unwrap_or_default();
       let local_site = LocalSite::read(context.pool()).await.ok();
       let slur_regex = &local_site_opt_to_slur_regex(&local_site);
+      let community_slur_regex = &build_slur_regex(community.slur_filter_regex.as_deref());
 
       let body_slurs_removed =
         read_from_string_or_source_opt(&page.content, &page.media_type, &page.source)
-          .map(|s| remove_slurs(&s, slur_regex));
+          .map(|s| remove_slurs(&s, slur_regex))
+          .map(|s| remove_slurs(&s, community_slur_regex));
       let language_id = LanguageTag::to_language_id_single(page.language, context.pool()).await?;
 
       PostInsertForm {
@@ -262,6 +278,9 @@ impl ApubObject for ApubPost {
       .await;
 
     let post = Post::create(context.pool(), &form).await?;
+    if page.kind == PageType::Note {
+      ConvertedNote::record(context.pool(), post.id).await?;
+    }
 
     // write mod log entries for feature/lock
     if Page::is_featured_changed(&old_post, &page.stickied) {
@@ -286,13 +305,27 @@ impl ApubObject for ApubPost {
   }
 }
 
+/// Title for objects without name, like notes from microblogging software. Uses the content warning
+/// if there is one, otherwise the first line of text without the mentions which address it.
+fn derive_title(page: &Page) -> Option<String> {
+  if let Some(summary) = page.summary.as_ref().filter(|s| !s.trim().is_empty()) {
+    return Some(summary.trim().to_string());
+  }
+  let content = parse_html(page.content.as_ref()?);
+  content
+    .lines()
+    .map(|l| strip_leading_mentions(l).trim())
+    .find(|l| !l.is_empty())
+    .map(ToString::to_string)
+}
+
 #[cfg(test)]
 mod tests {
   use super::*;
   use crate::{
     objects::{
       community::tests::parse_lemmy_community,
-      person::tests::parse_lemmy_person,
+      person::{tests::parse_lemmy_person, ApubPerson},
       post::ApubPost,
       tests::init_context,
     },
@@ -333,4 +366,65 @@ mod tests {
       .unwrap();
     Site::delete(context.pool(), site.id).await.unwrap();
   }
+
+  #[actix_rt::test]
+  #[serial]
+  async fn test_convert_mastodon_note() {
+    let context = init_context().await;
+    let (person, site) = parse_lemmy_person(&context).await;
+    let community = parse_lemmy_community(&context).await;
+
+    let mastodon_person_json = file_to_json_object("assets/mastodon/objects/person.json").unwrap();
+    let mastodon_person_url = Url::parse("https://mastodon.madrid/users/felix").unwrap();
+    ApubPerson::verify(
+      &mastodon_person_json,
+      &mastodon_person_url,
+      &context,
+      &mut 0,
+    )
+    .await
+    .unwrap();
+    let mastodon_person = ApubPerson::from_apub(mastodon_person_json, &context, &mut 0)
+      .await
+      .unwrap();
+
+    let mut json: Page = file_to_json_object("assets/mastodon/objects/group_note.json").unwrap();
+    let url =
+      Url::parse("https://mastodon.madrid/users/felix/statuses/107224289116410646").unwrap();
+    let mut request_counter = 0;
+    ApubPost::verify(&json, &url, &context, &mut request_counter)
+      .await
+      .unwrap();
+    let post = ApubPost::from_apub(json.clone(), &context, &mut request_counter)
+      .await
+      .unwrap();
+
+    assert_eq!(post.name, "Is the replicator broken again?");
+    assert_eq!(post.community_id, community.id);
+    assert_eq!(request_counter, 0);
+    assert!(ConvertedNote::read_for_post(context.pool(), post.id)
+      .await
+      .is_ok());
+
+    // An edit which no longer mentions the community is applied to the same post
+    json.cc.pop();
+    json.tag.clear();
+    json.content = Some("<p>Fixed it, tea for everyone</p>".to_string());
+    let edited = ApubPost::from_apub(json, &context, &mut request_counter)
+      .await
+      .unwrap();
+    assert_eq!(edited.id, post.id);
+    assert_eq!(edited.community_id, community.id);
+    assert_eq!(edited.name, "Fixed it, tea for everyone");
+
+    Post::delete(context.pool(), post.id).await.unwrap();
+    Person::delete(context.pool(), mastodon_person.id)
+      .await
+      .unwrap();
+    Person::delete(context.pool(), person.id).await.unwrap();
+    Community::delete(context.pool(), community.id)
+      .await
+      .unwrap();
+    Site::delete(context.pool(), site.id).await.unwrap();
+  }
 }
@@ -24,7 +24,7 @@ use lemmy_db_schema::{
   traits::Crud,
 };
 use lemmy_utils::{
-  error::LemmyError,
+  error::{LemmyError, LemmyErrorType},
   utils::{markdown::markdown_to_html, time::convert_datetime},
 };
 use std::ops::Deref;
@@ -119,7 +119,7 @@ impl ApubObject for ApubPrivateMessage {
       .dereference(context, local_instance(context).await, request_counter)
       .await?;
     if person.banned {
-      return Err(LemmyError::from_message("Person is banned from site"));
+      return Err(LemmyErrorType::SiteBan.into());
     }
     Ok(())
   }
@@ -0,0 +1,120 @@
+use crate::{
+  check_apub_id_valid_with_strictness,
+  fetch_local_site_data,
+  protocol::objects::relay::Relay,
+  ActorType,
+};
+use activitypub_federation::{
+  traits::{Actor, ApubObject},
+  utils::verify_domains_match,
+};
+use chrono::NaiveDateTime;
+use lemmy_api_common::context::LemmyContext;
+use lemmy_db_schema::{
+  source::relay::{Relay as DbRelay, RelayInsertForm},
+  traits::Crud,
+  utils::naive_now,
+};
+use lemmy_utils::error::LemmyError;
+use std::ops::Deref;
+use url::Url;
+
+#[derive(Clone, Debug)]
+pub struct ApubRelay(DbRelay);
+
+impl Deref for ApubRelay {
+  type Target = DbRelay;
+  fn deref(&self) -> &Self::Target {
+    &self.0
+  }
+}
+
+impl From<DbRelay> for ApubRelay {
+  fn from(r: DbRelay) -> Self {
+    ApubRelay(r)
+  }
+}
+
+#[async_trait::async_trait(?Send)]
+impl ApubObject for ApubRelay {
+  type DataType = LemmyContext;
+  type ApubType = Relay;
+  type DbType = DbRelay;
+  type Error = LemmyError;
+
+  fn last_refreshed_at(&self) -> Option<NaiveDateTime> {
+    Some(self.last_refreshed_at)
+  }
+
+  #[tracing::instrument(skip_all)]
+  async fn read_from_apub_id(
+    object_id: Url,
+    data: &Self::DataType,
+  ) -> Result<Option<Self>, LemmyError> {
+    Ok(
+      DbRelay::read_from_apub_id(data.pool(), object_id)
+        .await?
+        .map(Into::into),
+    )
+  }
+
+  /// The relay is gone, so it is unsubscribed
+  #[tracing::instrument(skip_all)]
+  async fn delete(self, data: &Self::DataType) -> Result<(), LemmyError> {
+    DbRelay::delete(data.pool(), self.id).await?;
+    Ok(())
+  }
+
+  // Relays are always remote
+  async fn into_apub(self, _data: &Self::DataType) -> Result<Self::ApubType, LemmyError> {
+    unimplemented!()
+  }
+
+  #[tracing::instrument(skip_all)]
+  async fn verify(
+    apub: &Self::ApubType,
+    expected_domain: &Url,
+    data: &Self::DataType,
+    _request_counter: &mut i32,
+  ) -> Result<(), LemmyError> {
+    let local_site_data = fetch_local_site_data(data.pool()).await?;
+    check_apub_id_valid_with_strictness(apub.id.inner(), true, &local_site_data, data.settings())?;
+    verify_domains_match(expected_domain, apub.id.inner())?;
+    Ok(())
+  }
+
+  #[tracing::instrument(skip_all)]
+  async fn from_apub(
+    apub: Self::ApubType,
+    data: &Self::DataType,
+    _request_counter: &mut i32,
+  ) -> Result<Self, LemmyError> {
+    let inbox_url = apub.endpoints.map(|e| e.shared_inbox).unwrap_or(apub.inbox);
+    let form = RelayInsertForm {
+      actor_id: apub.id.into(),
+      inbox_url: inbox_url.into(),
+      public_key: apub.public_key.public_key_pem,
+      last_refreshed_at: Some(naive_now()),
+    };
+    Ok(DbRelay::create(data.pool(), &form).await?.into())
+  }
+}
+
+impl ActorType for ApubRelay {
+  fn actor_id(&self) -> Url {
+    self.actor_id.clone().into()
+  }
+  fn private_key(&self) -> Option<String> {
+    None
+  }
+}
+
+impl Actor for ApubRelay {
+  fn public_key(&self) -> &str {
+    &self.public_key
+  }
+
+  fn inbox(&self) -> Url {
+    self.inbox_url.clone().into()
+  }
+}
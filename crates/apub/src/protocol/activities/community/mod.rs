@@ -3,6 +3,7 @@ pub mod collection_add;
 pub mod collection_remove;
 pub mod lock_page;
 pub mod report;
+pub mod resolve_report;
 pub mod update;
 
 #[cfg(test)]
@@ -14,6 +15,7 @@ mod tests {
       collection_remove::CollectionRemove,
       lock_page::{LockPage, UndoLockPage},
       report::Report,
+      resolve_report::ResolveReport,
       update::UpdateCommunity,
     },
     tests::test_parse_lemmy_item,
@@ -50,5 +52,9 @@ mod tests {
     .unwrap();
 
     test_parse_lemmy_item::<Report>("assets/lemmy/activities/community/report_page.json").unwrap();
+    test_parse_lemmy_item::<ResolveReport>(
+      "assets/lemmy/activities/community/resolve_report_page.json",
+    )
+    .unwrap();
   }
 }
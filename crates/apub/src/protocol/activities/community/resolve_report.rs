@@ -0,0 +1,57 @@
+use crate::{
+  activities::verify_community_matches,
+  fetcher::post_or_comment::PostOrComment,
+  local_instance,
+  objects::{community::ApubCommunity, person::ApubPerson},
+  protocol::InCommunity,
+};
+use activitypub_federation::{core::object_id::ObjectId, deser::helpers::deserialize_one};
+use lemmy_api_common::context::LemmyContext;
+use lemmy_utils::error::LemmyError;
+use serde::{Deserialize, Serialize};
+use strum_macros::Display;
+use url::Url;
+
+/// Sent by a community moderator to the instance of a remote reporter, so that the report also
+/// gets marked as resolved there.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ResolveReport {
+  pub(crate) actor: ObjectId<ApubPerson>,
+  /// The person who created the report
+  #[serde(deserialize_with = "deserialize_one")]
+  pub(crate) to: [ObjectId<ApubPerson>; 1],
+  pub(crate) object: ObjectId<PostOrComment>,
+  /// Optional resolution reason
+  pub(crate) summary: Option<String>,
+  #[serde(rename = "type")]
+  pub(crate) kind: ResolveType,
+  pub(crate) id: Url,
+  pub(crate) audience: Option<ObjectId<ApubCommunity>>,
+}
+
+#[derive(Clone, Debug, Display, Deserialize, Serialize, PartialEq, Eq)]
+pub enum ResolveType {
+  Resolve,
+}
+
+#[async_trait::async_trait(?Send)]
+impl InCommunity for ResolveReport {
+  async fn community(
+    &self,
+    context: &LemmyContext,
+    request_counter: &mut i32,
+  ) -> Result<ApubCommunity, LemmyError> {
+    let local_instance = local_instance(context).await;
+    let community = self
+      .object
+      .dereference(context, local_instance, request_counter)
+      .await?
+      .community(context, request_counter)
+      .await?;
+    if let Some(audience) = &self.audience {
+      verify_community_matches(audience, community.actor_id.clone())?;
+    }
+    Ok(community)
+  }
+}
@@ -0,0 +1,18 @@
+use crate::{
+  objects::relay::ApubRelay,
+  protocol::{activities::relay::follow::FollowRelay, IdOrNestedObject},
+};
+use activitypub_federation::core::object_id::ObjectId;
+use activitystreams_kinds::activity::AcceptType;
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AcceptFollowRelay {
+  pub(crate) actor: ObjectId<ApubRelay>,
+  pub(crate) object: IdOrNestedObject<FollowRelay>,
+  #[serde(rename = "type")]
+  pub(crate) kind: AcceptType,
+  pub(crate) id: Url,
+}
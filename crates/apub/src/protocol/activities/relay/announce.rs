@@ -0,0 +1,17 @@
+use crate::objects::{post::ApubPost, relay::ApubRelay};
+use activitypub_federation::core::object_id::ObjectId;
+use activitystreams_kinds::activity::AnnounceType;
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+/// A public post which another instance published to the relay. The relay only sends its id, so
+/// the post is fetched from its origin.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RelayAnnounce {
+  pub(crate) actor: ObjectId<ApubRelay>,
+  pub(crate) object: ObjectId<ApubPost>,
+  #[serde(rename = "type")]
+  pub(crate) kind: AnnounceType,
+  pub(crate) id: Url,
+}
@@ -0,0 +1,16 @@
+use crate::objects::{instance::ApubSite, relay::ApubRelay};
+use activitypub_federation::core::object_id::ObjectId;
+use activitystreams_kinds::activity::FollowType;
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+/// Subscribes the site actor to a relay.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FollowRelay {
+  pub(crate) actor: ObjectId<ApubSite>,
+  pub(crate) object: ObjectId<ApubRelay>,
+  #[serde(rename = "type")]
+  pub(crate) kind: FollowType,
+  pub(crate) id: Url,
+}
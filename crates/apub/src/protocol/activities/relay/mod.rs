@@ -0,0 +1,30 @@
+pub(crate) mod accept;
+pub mod announce;
+pub mod follow;
+pub mod undo_follow;
+
+#[cfg(test)]
+mod tests {
+  use crate::protocol::{
+    activities::relay::{
+      accept::AcceptFollowRelay,
+      announce::RelayAnnounce,
+      follow::FollowRelay,
+      undo_follow::UndoFollowRelay,
+    },
+    tests::{test_json, test_parse_lemmy_item},
+  };
+
+  #[test]
+  fn test_parse_lemmy_relay_activities() {
+    test_parse_lemmy_item::<FollowRelay>("assets/lemmy/activities/relay/follow.json").unwrap();
+    test_parse_lemmy_item::<UndoFollowRelay>("assets/lemmy/activities/relay/undo_follow.json")
+      .unwrap();
+  }
+
+  #[test]
+  fn test_parse_pleroma_relay_activities() {
+    test_json::<AcceptFollowRelay>("assets/pleroma/activities/relay_accept.json").unwrap();
+    test_json::<RelayAnnounce>("assets/pleroma/activities/relay_announce.json").unwrap();
+  }
+}
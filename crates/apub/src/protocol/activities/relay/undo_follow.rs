@@ -0,0 +1,15 @@
+use crate::{objects::instance::ApubSite, protocol::activities::relay::follow::FollowRelay};
+use activitypub_federation::core::object_id::ObjectId;
+use activitystreams_kinds::activity::UndoType;
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UndoFollowRelay {
+  pub(crate) actor: ObjectId<ApubSite>,
+  pub(crate) object: FollowRelay,
+  #[serde(rename = "type")]
+  pub(crate) kind: UndoType,
+  pub(crate) id: Url,
+}
@@ -3,7 +3,8 @@ use lemmy_utils::error::LemmyError;
 use serde::{Deserialize, Serialize};
 use url::Url;
 
-/// Empty placeholder outbox used for Person, Instance, which dont implement a proper outbox yet.
+/// Empty placeholder outbox used for Instance, which doesnt implement a proper outbox yet, and for
+/// persons on private instances.
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub(crate) struct EmptyOutbox {
@@ -1,5 +1,15 @@
-use crate::protocol::activities::community::announce::AnnounceActivity;
-use activitystreams_kinds::collection::OrderedCollectionType;
+use crate::{
+  collections::community_outbox::announce_post,
+  objects::community::ApubCommunity,
+  protocol::{
+    activities::community::announce::AnnounceActivity,
+    collections::{from_timestamp_micros, page_url, COLLECTION_PAGE_SIZE},
+  },
+};
+use activitystreams_kinds::collection::{OrderedCollectionPageType, OrderedCollectionType};
+use lemmy_api_common::{context::LemmyContext, utils::generate_outbox_url};
+use lemmy_db_schema::source::post::Post;
+use lemmy_utils::error::LemmyError;
 use serde::{Deserialize, Serialize};
 use url::Url;
 
@@ -10,4 +20,59 @@ pub struct GroupOutbox {
   pub(crate) id: Url,
   pub(crate) total_items: i32,
   pub(crate) ordered_items: Vec<AnnounceActivity>,
+  /// First page of the entire community history. Older versions only provide `ordered_items`.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub(crate) first: Option<Url>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct GroupOutboxPage {
+  pub(crate) r#type: OrderedCollectionPageType,
+  pub(crate) id: Url,
+  pub(crate) part_of: Url,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub(crate) next: Option<Url>,
+  pub(crate) ordered_items: Vec<AnnounceActivity>,
+}
+
+impl GroupOutboxPage {
+  /// Returns the newest posts of the community which were published before the given time (in
+  /// microseconds since the epoch), or the newest overall if it is `None`.
+  pub(crate) async fn new(
+    community: ApubCommunity,
+    before: Option<i64>,
+    context: &LemmyContext,
+  ) -> Result<GroupOutboxPage, LemmyError> {
+    let outbox_id: Url = generate_outbox_url(&community.actor_id)?.into();
+    let before_time = before.map(from_timestamp_micros).transpose()?;
+    let posts = Post::list_for_community_before(
+      context.pool(),
+      community.id,
+      before_time,
+      COLLECTION_PAGE_SIZE,
+    )
+    .await?;
+
+    let next = match posts.last() {
+      Some(last) if posts.len() as i64 == COLLECTION_PAGE_SIZE => Some(page_url(
+        &outbox_id,
+        Some(last.published.timestamp_micros()),
+      )?),
+      _ => None,
+    };
+
+    let mut ordered_items = vec![];
+    for post in posts {
+      ordered_items.push(announce_post(post.into(), &community, context).await?);
+    }
+
+    Ok(GroupOutboxPage {
+      r#type: OrderedCollectionPageType::OrderedCollectionPage,
+      id: page_url(&outbox_id, before)?,
+      part_of: outbox_id,
+      next,
+      ordered_items,
+    })
+  }
 }
@@ -3,18 +3,48 @@ pub(crate) mod group_featured;
 pub(crate) mod group_followers;
 pub(crate) mod group_moderators;
 pub(crate) mod group_outbox;
+pub(crate) mod replies;
+
+use chrono::NaiveDateTime;
+use lemmy_utils::error::{LemmyError, LemmyErrorType};
+use url::Url;
+
+/// Number of items in each page of an outbox or replies collection
+pub(crate) const COLLECTION_PAGE_SIZE: i64 = 20;
+
+/// Url of a collection page which contains the items published before the given time (in
+/// microseconds since the epoch), or the newest items if it is `None`.
+pub(crate) fn page_url(collection_id: &Url, before: Option<i64>) -> Result<Url, LemmyError> {
+  let url = match before {
+    Some(before) => format!("{collection_id}?page=true&before={before}"),
+    None => format!("{collection_id}?page=true"),
+  };
+  Ok(Url::parse(&url)?)
+}
+
+pub(crate) fn from_timestamp_micros(micros: i64) -> Result<NaiveDateTime, LemmyError> {
+  NaiveDateTime::from_timestamp_opt(
+    micros.div_euclid(1_000_000),
+    (micros.rem_euclid(1_000_000) * 1_000) as u32,
+  )
+  .ok_or_else(|| LemmyErrorType::InvalidOutboxPage.into())
+}
 
 #[cfg(test)]
 mod tests {
-  use crate::protocol::{
-    collections::{
-      empty_outbox::EmptyOutbox,
-      group_featured::GroupFeatured,
-      group_followers::GroupFollowers,
-      group_moderators::GroupModerators,
-      group_outbox::GroupOutbox,
+  use crate::{
+    collections::person_outbox::{PersonOutbox, PersonOutboxActivity, PersonOutboxPage},
+    protocol::{
+      collections::{
+        empty_outbox::EmptyOutbox,
+        group_featured::GroupFeatured,
+        group_followers::GroupFollowers,
+        group_moderators::GroupModerators,
+        group_outbox::{GroupOutbox, GroupOutboxPage},
+        replies::{Replies, RepliesPage},
+      },
+      tests::{test_json, test_parse_lemmy_item},
     },
-    tests::{test_json, test_parse_lemmy_item},
   };
 
   #[test]
@@ -24,11 +54,35 @@ mod tests {
     let outbox =
       test_parse_lemmy_item::<GroupOutbox>("assets/lemmy/collections/group_outbox.json").unwrap();
     assert_eq!(outbox.ordered_items.len() as i32, outbox.total_items);
+    assert!(outbox.first.is_some());
+    let outbox_page =
+      test_parse_lemmy_item::<GroupOutboxPage>("assets/lemmy/collections/group_outbox_page.json")
+        .unwrap();
+    assert_eq!(outbox_page.part_of, outbox.id);
+    assert!(outbox_page.next.is_some());
+    let replies =
+      test_parse_lemmy_item::<Replies>("assets/lemmy/collections/replies.json").unwrap();
+    let replies_page =
+      test_parse_lemmy_item::<RepliesPage>("assets/lemmy/collections/replies_page.json").unwrap();
+    assert_eq!(replies_page.part_of, replies.id);
+    assert_eq!(replies_page.ordered_items.len() as i32, replies.total_items);
     test_parse_lemmy_item::<GroupFeatured>("assets/lemmy/collections/group_featured_posts.json")
       .unwrap();
     test_parse_lemmy_item::<GroupModerators>("assets/lemmy/collections/group_moderators.json")
       .unwrap();
-    test_parse_lemmy_item::<EmptyOutbox>("assets/lemmy/collections/person_outbox.json").unwrap();
+    test_parse_lemmy_item::<EmptyOutbox>("assets/lemmy/collections/site_outbox.json").unwrap();
+    test_parse_lemmy_item::<PersonOutbox>("assets/lemmy/collections/person_outbox.json").unwrap();
+    let page =
+      test_parse_lemmy_item::<PersonOutboxPage>("assets/lemmy/collections/person_outbox_page.json")
+        .unwrap();
+    assert!(matches!(
+      page.ordered_items[0],
+      PersonOutboxActivity::CreateOrUpdateComment(_)
+    ));
+    assert!(matches!(
+      page.ordered_items[1],
+      PersonOutboxActivity::CreateOrUpdatePost(_)
+    ));
   }
 
   #[test]
@@ -0,0 +1,93 @@
+use crate::{
+  objects::comment::ApubComment,
+  protocol::collections::{from_timestamp_micros, page_url, COLLECTION_PAGE_SIZE},
+};
+use activitypub_federation::core::object_id::ObjectId;
+use activitystreams_kinds::collection::{OrderedCollectionPageType, OrderedCollectionType};
+use lemmy_api_common::context::LemmyContext;
+use lemmy_db_schema::{newtypes::PostId, source::comment::Comment};
+use lemmy_utils::error::LemmyError;
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+/// Direct replies to a post or comment, which allows fetching entire comment threads. The replies
+/// themselves are served in pages, starting at `first`.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct Replies {
+  pub(crate) r#type: OrderedCollectionType,
+  pub(crate) id: Url,
+  pub(crate) total_items: i32,
+  pub(crate) first: Url,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct RepliesPage {
+  pub(crate) r#type: OrderedCollectionPageType,
+  pub(crate) id: Url,
+  pub(crate) part_of: Url,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub(crate) next: Option<Url>,
+  pub(crate) ordered_items: Vec<ObjectId<ApubComment>>,
+}
+
+impl Replies {
+  /// Replies to the given post, or to `parent` if it is a reply to a comment.
+  pub(crate) async fn new(
+    id: Url,
+    post_id: PostId,
+    parent: Option<&Comment>,
+    context: &LemmyContext,
+  ) -> Result<Replies, LemmyError> {
+    let total_items =
+      Comment::count_direct_replies(context.pool(), post_id, parent.map(|p| &p.path)).await?;
+    Ok(Replies {
+      r#type: OrderedCollectionType::OrderedCollection,
+      first: page_url(&id, None)?,
+      id,
+      total_items: total_items as i32,
+    })
+  }
+}
+
+impl RepliesPage {
+  /// Returns the newest replies which were published before the given time (in microseconds since
+  /// the epoch), or the newest overall if it is `None`.
+  pub(crate) async fn new(
+    replies_id: Url,
+    post_id: PostId,
+    parent: Option<&Comment>,
+    before: Option<i64>,
+    context: &LemmyContext,
+  ) -> Result<RepliesPage, LemmyError> {
+    let before_time = before.map(from_timestamp_micros).transpose()?;
+    let replies = Comment::list_direct_replies(
+      context.pool(),
+      post_id,
+      parent.map(|p| &p.path),
+      before_time,
+      COLLECTION_PAGE_SIZE,
+    )
+    .await?;
+
+    let next = match replies.last() {
+      Some(last) if replies.len() as i64 == COLLECTION_PAGE_SIZE => Some(page_url(
+        &replies_id,
+        Some(last.published.timestamp_micros()),
+      )?),
+      _ => None,
+    };
+
+    Ok(RepliesPage {
+      r#type: OrderedCollectionPageType::OrderedCollectionPage,
+      id: page_url(&replies_id, before)?,
+      part_of: replies_id,
+      next,
+      ordered_items: replies
+        .into_iter()
+        .map(|c| ObjectId::new(c.ap_id))
+        .collect(),
+    })
+  }
+}
@@ -28,7 +28,7 @@ use lemmy_db_schema::{
 };
 use lemmy_utils::{
   error::LemmyError,
-  utils::slurs::{check_slurs, check_slurs_opt},
+  utils::slurs::{check_slur_regex, check_slurs, check_slurs_opt},
 };
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
@@ -64,6 +64,10 @@ pub struct Group {
   pub(crate) attributed_to: Option<ObjectId<ApubCommunityModerators>>,
   // lemmy extension
   pub(crate) posting_restricted_to_mods: Option<bool>,
+  // lemmy extension
+  pub(crate) slur_filter_regex: Option<String>,
+  // lemmy extension
+  pub(crate) rules: Option<Vec<GroupRule>>,
   pub(crate) outbox: ObjectId<ApubCommunityOutbox>,
   pub(crate) endpoints: Option<Endpoints>,
   pub(crate) featured: Option<ObjectId<ApubCommunityFeatured>>,
@@ -73,6 +77,13 @@ pub struct Group {
   pub(crate) updated: Option<DateTime<FixedOffset>>,
 }
 
+#[skip_serializing_none]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct GroupRule {
+  pub(crate) title: String,
+  pub(crate) body: Option<String>,
+}
+
 impl Group {
   pub(crate) async fn verify(
     &self,
@@ -95,6 +106,10 @@ impl Group {
     check_slurs_opt(&self.name, slur_regex)?;
     let description = read_from_string_or_source_opt(&self.summary, &None, &self.source);
     check_slurs_opt(&description, slur_regex)?;
+    for rule in self.rules.iter().flatten() {
+      check_slurs(&rule.title, slur_regex)?;
+      check_slurs_opt(&rule.body, slur_regex)?;
+    }
     Ok(())
   }
 
@@ -122,10 +137,22 @@ impl Group {
       moderators_url: self.moderators.map(Into::into),
       posting_restricted_to_mods: self.posting_restricted_to_mods,
       instance_id,
+      slur_filter_regex: valid_slur_filter_regex(self.slur_filter_regex),
       featured_url: self.featured.map(Into::into),
     }
   }
 
+  /// Rules in the form expected by `CommunityRule::update_from_remote`, or `None` if the remote instance
+  /// doesn't federate rules.
+  pub(crate) fn rules(&self) -> Option<Vec<(String, Option<String>)>> {
+    self.rules.as_ref().map(|rules| {
+      rules
+        .iter()
+        .map(|r| (r.title.clone(), r.body.clone()))
+        .collect()
+    })
+  }
+
   pub(crate) fn into_update_form(self) -> CommunityUpdateForm {
     CommunityUpdateForm {
       title: Some(self.name.unwrap_or(self.preferred_username)),
@@ -153,6 +180,12 @@ impl Group {
       moderators_url: self.moderators.map(Into::into),
       posting_restricted_to_mods: self.posting_restricted_to_mods,
       featured_url: self.featured.map(Into::into),
+      slur_filter_regex: Some(valid_slur_filter_regex(self.slur_filter_regex)),
     }
   }
 }
+
+/// Drops a remote slur filter which doesn't compile, instead of rejecting the whole community.
+fn valid_slur_filter_regex(regex: Option<String>) -> Option<String> {
+  regex.filter(|r| check_slur_regex(r).is_ok())
+}
@@ -9,6 +9,7 @@ pub(crate) mod instance;
 pub(crate) mod note;
 pub(crate) mod page;
 pub(crate) mod person;
+pub(crate) mod relay;
 pub(crate) mod tombstone;
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
@@ -98,6 +99,7 @@ mod tests {
       note::Note,
       page::Page,
       person::Person,
+      relay::Relay,
       tombstone::Tombstone,
     },
     tests::{test_json, test_parse_lemmy_item},
@@ -119,6 +121,7 @@ mod tests {
     test_json::<Person>("assets/pleroma/objects/person.json").unwrap();
     test_json::<Note>("assets/pleroma/objects/note.json").unwrap();
     test_json::<ChatMessage>("assets/pleroma/objects/chat_message.json").unwrap();
+    test_json::<Relay>("assets/pleroma/objects/relay.json").unwrap();
   }
 
   #[test]
@@ -132,6 +135,7 @@ mod tests {
     test_json::<Person>("assets/mastodon/objects/person.json").unwrap();
     test_json::<Note>("assets/mastodon/objects/note.json").unwrap();
     test_json::<Page>("assets/mastodon/objects/page.json").unwrap();
+    test_json::<Page>("assets/mastodon/objects/group_note.json").unwrap();
   }
 
   #[test]
@@ -51,6 +51,9 @@ pub struct Note {
   pub(crate) distinguished: Option<bool>,
   pub(crate) language: Option<LanguageTag>,
   pub(crate) audience: Option<ObjectId<ApubCommunity>>,
+  /// Collection of direct replies
+  #[serde(deserialize_with = "deserialize_skip_error", default)]
+  pub(crate) replies: Option<Url>,
 }
 
 impl Note {
@@ -2,6 +2,7 @@ use crate::{
   activities::verify_community_matches,
   fetcher::user_or_community::{PersonOrGroupType, UserOrCommunity},
   local_instance,
+  mentions::MentionOrValue,
   objects::{community::ApubCommunity, person::ApubPerson, post::ApubPost},
   protocol::{objects::LanguageTag, ImageObject, InCommunity, Source},
 };
@@ -17,12 +18,17 @@ use activitypub_federation::{
 use activitystreams_kinds::{
   link::LinkType,
   object::{DocumentType, ImageType},
+  public,
 };
 use chrono::{DateTime, FixedOffset};
 use itertools::Itertools;
 use lemmy_api_common::context::LemmyContext;
-use lemmy_db_schema::newtypes::DbUrl;
-use lemmy_utils::error::LemmyError;
+use lemmy_db_schema::{
+  newtypes::DbUrl,
+  source::{community::Community, converted_note::ConvertedNote},
+  traits::Crud,
+};
+use lemmy_utils::error::{LemmyError, LemmyErrorType};
 use serde::{de::Error, Deserialize, Deserializer, Serialize};
 use serde_with::skip_serializing_none;
 use url::Url;
@@ -51,6 +57,8 @@ pub struct Page {
   pub(crate) in_reply_to: Option<String>,
 
   pub(crate) name: Option<String>,
+  /// Content warning of notes from microblogging software, used as title when converting them
+  pub(crate) summary: Option<String>,
   #[serde(deserialize_with = "deserialize_one_or_many", default)]
   pub(crate) cc: Vec<Url>,
   pub(crate) content: Option<String>,
@@ -70,6 +78,13 @@ pub struct Page {
   pub(crate) updated: Option<DateTime<FixedOffset>>,
   pub(crate) language: Option<LanguageTag>,
   pub(crate) audience: Option<ObjectId<ApubCommunity>>,
+  /// Collection of top level comments. Other software may embed the collection instead, which is
+  /// ignored.
+  #[serde(deserialize_with = "deserialize_skip_error", default)]
+  pub(crate) replies: Option<Url>,
+  /// Microblogging software addresses notes to a community by mentioning it
+  #[serde(default, skip_serializing_if = "Vec::is_empty")]
+  pub(crate) tag: Vec<MentionOrValue>,
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
@@ -172,6 +187,29 @@ impl Page {
     false
   }
 
+  /// Posts which were converted from notes stay in their community, even if an edit of the note
+  /// doesn't mention it anymore.
+  async fn converted_note_community(
+    &self,
+    context: &LemmyContext,
+  ) -> Result<Option<ApubCommunity>, LemmyError> {
+    if self.kind != PageType::Note {
+      return Ok(None);
+    }
+    let post = match self.id.dereference_local(context).await {
+      Ok(p) => p,
+      Err(_) => return Ok(None),
+    };
+    if ConvertedNote::read_for_post(context.pool(), post.id)
+      .await
+      .is_err()
+    {
+      return Ok(None);
+    }
+    let community = Community::read(context.pool(), post.community_id).await?;
+    Ok(Some(community.into()))
+  }
+
   pub(crate) fn creator(&self) -> Result<ObjectId<ApubPerson>, LemmyError> {
     match &self.attributed_to {
       AttributedTo::Lemmy(l) => Ok(l.clone()),
@@ -179,7 +217,7 @@ impl Page {
         .iter()
         .find(|a| a.kind == PersonOrGroupType::Person)
         .map(|a| ObjectId::<ApubPerson>::new(a.id.clone().into_inner()))
-        .ok_or_else(|| LemmyError::from_message("page does not specify creator person")),
+        .ok_or_else(|| LemmyErrorType::PageDoesNotSpecifyCreator.into()),
     }
   }
 }
@@ -231,23 +269,45 @@ impl InCommunity for Page {
     let instance = local_instance(context).await;
     let community = match &self.attributed_to {
       AttributedTo::Lemmy(_) => {
-        let mut iter = self.to.iter().merge(self.cc.iter());
-        loop {
-          if let Some(cid) = iter.next() {
-            let cid = ObjectId::new(cid.clone());
+        if let Some(community) = self.converted_note_community(context).await? {
+          return Ok(community);
+        }
+        let mentions = self.tag.iter().filter_map(|t| match t {
+          MentionOrValue::Mention(m) => Some(&m.href),
+          MentionOrValue::Value(_) => None,
+        });
+        let candidates: Vec<ObjectId<ApubCommunity>> = self
+          .to
+          .iter()
+          .merge(self.cc.iter())
+          .chain(mentions)
+          .filter(|c| *c != &public())
+          .unique()
+          .map(|c| ObjectId::new(c.clone()))
+          .collect();
+        // Prefer communities which are known already, to avoid fetching other actors
+        let mut community = None;
+        for cid in &candidates {
+          if let Ok(c) = cid.dereference_local(context).await {
+            community = Some(c);
+            break;
+          }
+        }
+        if community.is_none() {
+          for cid in &candidates {
             if let Ok(c) = cid.dereference(context, instance, request_counter).await {
-              break c;
+              community = Some(c);
+              break;
             }
-          } else {
-            return Err(LemmyError::from_message("No community found in cc"));
           }
         }
+        community.ok_or(LemmyErrorType::NoCommunityFoundInCc)?
       }
       AttributedTo::Peertube(p) => {
         p.iter()
           .find(|a| a.kind == PersonOrGroupType::Group)
           .map(|a| ObjectId::<ApubCommunity>::new(a.id.clone().into_inner()))
-          .ok_or_else(|| LemmyError::from_message("page does not specify group"))?
+          .ok_or(LemmyErrorType::PageDoesNotSpecifyGroup)?
           .dereference(context, instance, request_counter)
           .await?
       }
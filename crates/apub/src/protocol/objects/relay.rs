@@ -0,0 +1,17 @@
+use crate::{objects::relay::ApubRelay, protocol::objects::Endpoints};
+use activitypub_federation::core::{object_id::ObjectId, signatures::PublicKey};
+use activitystreams_kinds::actor::ApplicationType;
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+/// Actor of a LitePub relay. Only the fields which are necessary to exchange activities with it.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Relay {
+  #[serde(rename = "type")]
+  pub(crate) kind: ApplicationType,
+  pub(crate) id: ObjectId<ApubRelay>,
+  pub(crate) inbox: Url,
+  pub(crate) public_key: PublicKey,
+  pub(crate) endpoints: Option<Endpoints>,
+}
@@ -0,0 +1,197 @@
+use crate::{
+  check_apub_id_valid,
+  fetch_local_site_data,
+  fetcher::site_or_community_or_user::SiteOrCommunityOrUser,
+  local_instance,
+};
+use activitypub_federation::{
+  core::{object_id::ObjectId, signatures::verify_signature},
+  traits::Actor,
+  APUB_JSON_CONTENT_TYPE,
+};
+use actix_web::HttpRequest;
+use http::{
+  header::{ACCEPT, DATE},
+  HeaderValue,
+  Method,
+};
+use http_signature_normalization::Config;
+use lemmy_api_common::context::LemmyContext;
+use lemmy_db_schema::{source::local_site::LocalSite, utils::DbPool};
+use lemmy_db_views::structs::SiteView;
+use lemmy_utils::error::{LemmyError, LemmyErrorExt, LemmyErrorType};
+use once_cell::sync::Lazy;
+use openssl::{hash::MessageDigest, pkey::PKey, sign::Signer};
+use reqwest::{Request, Response};
+use reqwest_middleware::{Middleware, Next};
+use std::collections::BTreeMap;
+use task_local_extensions::Extensions;
+use tokio::sync::OnceCell;
+use tracing::warn;
+use url::Url;
+
+static SIGNATURE_CONFIG: Lazy<Config> = Lazy::new(|| Config::new().mastodon_compat());
+
+/// In authorized fetch mode, only answers ActivityPub fetches which are signed by an actor from an
+/// instance that is allowed to federate. Otherwise anyone can fetch.
+///
+/// The site actor is exempt, as other instances need its key to sign their own fetches.
+pub(crate) async fn check_signed_fetch(
+  request: &HttpRequest,
+  context: &LemmyContext,
+) -> Result<(), LemmyError> {
+  let authorized_fetch = LocalSite::read(context.pool())
+    .await
+    .map(|l| l.authorized_fetch)
+    .unwrap_or(false);
+  if !authorized_fetch {
+    return Ok(());
+  }
+
+  let signature = request
+    .headers()
+    .get("signature")
+    .and_then(|s| s.to_str().ok())
+    .ok_or(LemmyErrorType::MissingSignature)?;
+  let mut actor_id: Url = parse_key_id(signature)
+    .ok_or(LemmyErrorType::InvalidSignature)?
+    .parse()?;
+  actor_id.set_fragment(None);
+
+  let local_site_data = fetch_local_site_data(context.pool()).await?;
+  check_apub_id_valid(&actor_id, &local_site_data, context.settings())?;
+
+  let actor = ObjectId::<SiteOrCommunityOrUser>::new(actor_id)
+    .dereference(context, local_instance(context).await, &mut 0)
+    .await?;
+  verify_signature(request, actor.public_key())
+    .with_lemmy_type(LemmyErrorType::InvalidSignature)?;
+  Ok(())
+}
+
+/// Extracts the `keyId` parameter from a signature header.
+fn parse_key_id(signature: &str) -> Option<&str> {
+  signature.split(',').find_map(|param| {
+    let (key, value) = param.trim().split_once('=')?;
+    (key == "keyId").then(|| value.trim_matches('"'))
+  })
+}
+
+/// Reqwest middleware which signs outgoing ActivityPub fetches with the key of the local site, so
+/// that instances which require signed fetches answer them.
+pub struct SignedFetchMiddleware {
+  pool: DbPool,
+  site_key: OnceCell<SiteKey>,
+}
+
+struct SiteKey {
+  key_id: String,
+  private_key: String,
+}
+
+impl SignedFetchMiddleware {
+  pub fn new(pool: DbPool) -> Self {
+    SignedFetchMiddleware {
+      pool,
+      site_key: OnceCell::new(),
+    }
+  }
+
+  /// The site is only read once, as its key never changes. It may be missing before setup.
+  async fn site_key(&self) -> Result<&SiteKey, LemmyError> {
+    self
+      .site_key
+      .get_or_try_init(|| async {
+        let site = SiteView::read_local(&self.pool).await?.site;
+        let private_key = site
+          .private_key
+          .ok_or(LemmyErrorType::SiteMissingPrivateKey)?;
+        Ok(SiteKey {
+          key_id: format!("{}#main-key", site.actor_id),
+          private_key,
+        })
+      })
+      .await
+  }
+}
+
+#[async_trait::async_trait]
+impl Middleware for SignedFetchMiddleware {
+  async fn handle(
+    &self,
+    mut req: Request,
+    extensions: &mut Extensions,
+    next: Next<'_>,
+  ) -> reqwest_middleware::Result<Response> {
+    let is_fetch = req.method() == Method::GET
+      && req
+        .headers()
+        .get(ACCEPT)
+        .map(|a| a == APUB_JSON_CONTENT_TYPE)
+        .unwrap_or(false);
+    if is_fetch {
+      let res = match self.site_key().await {
+        Ok(key) => sign_request(&mut req, key),
+        Err(e) => Err(e),
+      };
+      if let Err(e) = res {
+        warn!("Sending unsigned fetch to {}: {}", req.url(), e);
+      }
+    }
+    next.run(req, extensions).await
+  }
+}
+
+fn sign_request(req: &mut Request, key: &SiteKey) -> Result<(), LemmyError> {
+  let date = chrono::Utc::now()
+    .format("%a, %d %b %Y %H:%M:%S GMT")
+    .to_string();
+  req
+    .headers_mut()
+    .insert(DATE, HeaderValue::from_str(&date)?);
+
+  let mut headers = BTreeMap::new();
+  for (name, value) in req.headers() {
+    headers.insert(name.to_string(), value.to_str()?.to_string());
+  }
+  // Reqwest only sets the host header when sending the request
+  let url = req.url();
+  let host = match url.port() {
+    Some(port) => format!("{}:{}", url.host_str().unwrap_or_default(), port),
+    None => url.host_str().unwrap_or_default().to_string(),
+  };
+  headers.insert("host".to_string(), host);
+  let path_and_query = match url.query() {
+    Some(query) => format!("{}?{}", url.path(), query),
+    None => url.path().to_string(),
+  };
+
+  let signed = SIGNATURE_CONFIG
+    .begin_sign(req.method().as_str(), &path_and_query, headers)?
+    .sign(key.key_id.clone(), |signing_string| {
+      let private_key = PKey::private_key_from_pem(key.private_key.as_bytes())?;
+      let mut signer = Signer::new(MessageDigest::sha256(), &private_key)?;
+      signer.update(signing_string.as_bytes())?;
+      Ok(base64::encode(signer.sign_to_vec()?)) as Result<_, LemmyError>
+    })?;
+  req.headers_mut().insert(
+    "signature",
+    HeaderValue::from_str(&signed.signature_header())?,
+  );
+  Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+  use super::parse_key_id;
+
+  #[test]
+  fn test_parse_key_id() {
+    let signature = r#"keyId="https://example.com/#main-key",algorithm="hs2019",headers="(request-target) host date",signature="abc=""#;
+    assert_eq!(
+      Some("https://example.com/#main-key"),
+      parse_key_id(signature)
+    );
+    assert_eq!(None, parse_key_id(r#"algorithm="hs2019""#));
+  }
+}
@@ -9,5 +9,7 @@ pub mod person_post_aggregates;
 #[cfg(feature = "full")]
 pub mod post_aggregates;
 #[cfg(feature = "full")]
+pub mod repair;
+#[cfg(feature = "full")]
 pub mod site_aggregates;
 pub mod structs;
@@ -0,0 +1,457 @@
+use crate::{
+  aggregates::structs::{AggregateDifference, AggregateDifferences},
+  utils::{get_conn, DbPool},
+};
+use diesel::{
+  dsl::sql_query,
+  result::Error,
+  sql_types::{BigInt, Integer, Nullable},
+  QueryableByName,
+};
+use diesel_async::{AsyncPgConnection, RunQueryDsl};
+
+/// Number of wrong rows which are listed for each column
+const DIFFERENCE_EXAMPLES: usize = 10;
+/// Number of ids whose values are repaired at once
+const REPAIR_BATCH_SIZE: i64 = 10_000;
+
+/// A column of an aggregates table, which is normally maintained by triggers
+struct AggregateColumn {
+  table: &'static str,
+  /// Column which references the source table
+  key: &'static str,
+  column: &'static str,
+  /// Calculates the correct `value` for each `id` of the source table, the same way as the triggers
+  actual: &'static str,
+}
+
+const fn column(
+  table: &'static str,
+  key: &'static str,
+  column: &'static str,
+  actual: &'static str,
+) -> AggregateColumn {
+  AggregateColumn {
+    table,
+    key,
+    column,
+    actual,
+  }
+}
+
+/// The active user counts are left out, they are recalculated by a scheduled task anyway.
+const AGGREGATE_COLUMNS: &[AggregateColumn] = &[
+  column(
+    "post_aggregates",
+    "post_id",
+    "score",
+    "select p.id, coalesce(sum(l.score), 0) as value from post p
+     left join post_like l on l.post_id = p.id group by p.id",
+  ),
+  column(
+    "post_aggregates",
+    "post_id",
+    "upvotes",
+    "select p.id, count(l.id) filter (where l.score = 1) as value from post p
+     left join post_like l on l.post_id = p.id group by p.id",
+  ),
+  column(
+    "post_aggregates",
+    "post_id",
+    "downvotes",
+    "select p.id, count(l.id) filter (where l.score = -1) as value from post p
+     left join post_like l on l.post_id = p.id group by p.id",
+  ),
+  // Removed comments are still counted for the post
+  column(
+    "post_aggregates",
+    "post_id",
+    "comments",
+    "select p.id, count(c.id) as value from post p
+     left join comment c on c.post_id = p.id and not c.deleted group by p.id",
+  ),
+  column(
+    "post_aggregates",
+    "post_id",
+    "newest_comment_time",
+    "select p.id, coalesce(max(c.published), p.published) as value from post p
+     left join comment c on c.post_id = p.id group by p.id",
+  ),
+  // Only comments of other users within two days of the post bump it
+  column(
+    "post_aggregates",
+    "post_id",
+    "newest_comment_time_necro",
+    "select p.id, coalesce(max(c.published), p.published) as value from post p
+     left join comment c on c.post_id = p.id and c.creator_id != p.creator_id
+       and c.published < p.published + interval '2 days'
+     group by p.id",
+  ),
+  column(
+    "comment_aggregates",
+    "comment_id",
+    "score",
+    "select c.id, coalesce(sum(l.score), 0) as value from comment c
+     left join comment_like l on l.comment_id = c.id group by c.id",
+  ),
+  column(
+    "comment_aggregates",
+    "comment_id",
+    "upvotes",
+    "select c.id, count(l.id) filter (where l.score = 1) as value from comment c
+     left join comment_like l on l.comment_id = c.id group by c.id",
+  ),
+  column(
+    "comment_aggregates",
+    "comment_id",
+    "downvotes",
+    "select c.id, count(l.id) filter (where l.score = -1) as value from comment c
+     left join comment_like l on l.comment_id = c.id group by c.id",
+  ),
+  column(
+    "comment_aggregates",
+    "comment_id",
+    "child_count",
+    "select c.id, count(c2.id) as value from comment c
+     left join comment c2 on c2.path <@ c.path and c2.path != c.path group by c.id",
+  ),
+  column(
+    "community_aggregates",
+    "community_id",
+    "subscribers",
+    "select c.id, count(f.id) as value from community c
+     left join community_follower f on f.community_id = c.id group by c.id",
+  ),
+  column(
+    "community_aggregates",
+    "community_id",
+    "posts",
+    "select c.id, count(p.id) as value from community c
+     left join post p on p.community_id = c.id and not p.deleted and not p.removed
+     group by c.id",
+  ),
+  column(
+    "community_aggregates",
+    "community_id",
+    "comments",
+    "select c.id, count(ct.id) as value from community c
+     left join post p on p.community_id = c.id and not p.deleted and not p.removed
+     left join comment ct on ct.post_id = p.id and not ct.deleted and not ct.removed
+     group by c.id",
+  ),
+  column(
+    "person_aggregates",
+    "person_id",
+    "post_count",
+    "select pe.id, count(p.id) as value from person pe
+     left join post p on p.creator_id = pe.id and not p.deleted and not p.removed
+     group by pe.id",
+  ),
+  column(
+    "person_aggregates",
+    "person_id",
+    "post_score",
+    "select pe.id, coalesce(sum(l.score), 0) as value from person pe
+     left join post p on p.creator_id = pe.id and not p.deleted and not p.removed
+     left join post_like l on l.post_id = p.id
+     group by pe.id",
+  ),
+  column(
+    "person_aggregates",
+    "person_id",
+    "comment_count",
+    "select pe.id, count(c.id) as value from person pe
+     left join comment c on c.creator_id = pe.id and not c.deleted and not c.removed
+     group by pe.id",
+  ),
+  column(
+    "person_aggregates",
+    "person_id",
+    "comment_score",
+    "select pe.id, coalesce(sum(l.score), 0) as value from person pe
+     left join comment c on c.creator_id = pe.id and not c.deleted and not c.removed
+     left join comment_like l on l.comment_id = c.id
+     group by pe.id",
+  ),
+  // Only the local site has meaningful counts
+  column(
+    "site_aggregates",
+    "site_id",
+    "users",
+    "select site_id as id, (select count(*) from person where local) as value from local_site",
+  ),
+  column(
+    "site_aggregates",
+    "site_id",
+    "posts",
+    "select site_id as id,
+       (select count(*) from post where local and not deleted and not removed) as value
+     from local_site",
+  ),
+  column(
+    "site_aggregates",
+    "site_id",
+    "comments",
+    "select site_id as id,
+       (select count(*) from comment where local and not deleted and not removed) as value
+     from local_site",
+  ),
+  column(
+    "site_aggregates",
+    "site_id",
+    "communities",
+    "select site_id as id,
+       (select count(*) from community where local and not deleted and not removed) as value
+     from local_site",
+  ),
+];
+
+impl AggregateColumn {
+  /// Rows whose stored value differs from the actual one. With a range, only ids from `start`
+  /// (inclusive) to `end` (exclusive) are compared. The range is applied to both sides of the
+  /// join, so that Postgres only calculates the actual values of these ids.
+  fn differences_query(&self, range: Option<(i64, i64)>) -> String {
+    let range_filter = match range {
+      Some((start, end)) => format!(
+        "and s.{key} >= {start} and s.{key} < {end} and a.id >= {start} and a.id < {end}",
+        key = self.key
+      ),
+      None => String::new(),
+    };
+    format!(
+      "select s.{key} as id, s.{column} as stored, a.value as actual from {table} s
+       join ({actual}) a on a.id = s.{key}
+       where s.{column} is distinct from a.value {range_filter}",
+      key = self.key,
+      column = self.column,
+      table = self.table,
+      actual = self.actual,
+    )
+  }
+
+  /// Overwrites the wrong values, in batches of ids
+  async fn repair(&self, conn: &mut AsyncPgConnection) -> Result<(), Error> {
+    let range = sql_query(format!(
+      "select min({key}) as min, max({key}) as max from {table}",
+      key = self.key,
+      table = self.table,
+    ))
+    .get_result::<KeyRange>(conn)
+    .await?;
+    let (min, max) = match (range.min, range.max) {
+      (Some(min), Some(max)) => (i64::from(min), i64::from(max)),
+      _ => return Ok(()),
+    };
+    let mut start = min;
+    while start <= max {
+      let end = start + REPAIR_BATCH_SIZE;
+      sql_query(format!(
+        "update {table} t set {column} = d.actual from ({differences}) d where t.{key} = d.id",
+        table = self.table,
+        column = self.column,
+        key = self.key,
+        differences = self.differences_query(Some((start, end))),
+      ))
+      .execute(conn)
+      .await?;
+      start = end;
+    }
+    Ok(())
+  }
+}
+
+#[derive(QueryableByName)]
+struct KeyRange {
+  #[diesel(sql_type = Nullable<Integer>)]
+  min: Option<i32>,
+  #[diesel(sql_type = Nullable<Integer>)]
+  max: Option<i32>,
+}
+
+/// The newest wrong rows of a column, together with the total number of wrong rows
+#[derive(QueryableByName)]
+struct DifferenceExample {
+  #[diesel(embed)]
+  difference: AggregateDifference,
+  #[diesel(sql_type = BigInt)]
+  count: i64,
+}
+
+impl AggregateDifferences {
+  /// Compares the aggregates tables with the values calculated from the source tables, and
+  /// returns the columns which differ. With `repair`, the wrong values are also overwritten.
+  pub async fn check(pool: &DbPool, repair: bool) -> Result<Vec<Self>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    let mut all = vec![];
+    for c in AGGREGATE_COLUMNS {
+      let query = format!(
+        "select d.id, d.stored::text as stored, d.actual::text as actual,
+           count(*) over () as count
+         from ({differences}) d order by d.id desc limit {DIFFERENCE_EXAMPLES}",
+        differences = c.differences_query(None),
+      );
+      let rows = sql_query(query).load::<DifferenceExample>(conn).await?;
+      let count = match rows.as_slice() {
+        [newest, ..] => newest.count,
+        [] => continue,
+      };
+      if repair {
+        c.repair(conn).await?;
+      }
+      all.push(AggregateDifferences {
+        table: c.table.to_string(),
+        column: c.column.to_string(),
+        count,
+        examples: rows.into_iter().map(|r| r.difference).collect(),
+      });
+    }
+    Ok(all)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::{
+    aggregates::structs::AggregateDifferences,
+    schema::{community_aggregates, person_aggregates, post_aggregates},
+    source::{
+      community::{Community, CommunityInsertForm},
+      instance::Instance,
+      person::{Person, PersonInsertForm},
+      post::{Post, PostInsertForm, PostLike, PostLikeForm},
+    },
+    traits::{Crud, Likeable},
+    utils::{build_db_pool_for_tests, get_conn},
+  };
+  use diesel::ExpressionMethods;
+  use diesel_async::RunQueryDsl;
+  use serial_test::serial;
+
+  #[tokio::test]
+  #[serial]
+  async fn test_repair() {
+    let pool = &build_db_pool_for_tests().await;
+
+    let inserted_instance = Instance::create(pool, "my_domain.tld").await.unwrap();
+
+    let new_person = PersonInsertForm::builder()
+      .name("thommy_repair_agg".into())
+      .public_key("pubkey".to_string())
+      .instance_id(inserted_instance.id)
+      .build();
+    let inserted_person = Person::create(pool, &new_person).await.unwrap();
+
+    let new_community = CommunityInsertForm::builder()
+      .name("TIL_repair_agg".into())
+      .title("nada".to_owned())
+      .public_key("pubkey".to_string())
+      .instance_id(inserted_instance.id)
+      .build();
+    let inserted_community = Community::create(pool, &new_community).await.unwrap();
+
+    let new_post = PostInsertForm::builder()
+      .name("A test post repair".into())
+      .creator_id(inserted_person.id)
+      .community_id(inserted_community.id)
+      .build();
+    let inserted_post = Post::create(pool, &new_post).await.unwrap();
+
+    let post_like = PostLikeForm {
+      post_id: inserted_post.id,
+      person_id: inserted_person.id,
+      score: 1,
+    };
+    PostLike::like(pool, &post_like).await.unwrap();
+
+    // Make the aggregates drift, like a failed trigger would
+    let conn = &mut get_conn(pool).await.unwrap();
+    diesel::update(post_aggregates::table)
+      .filter(post_aggregates::post_id.eq(inserted_post.id))
+      .set((post_aggregates::score.eq(5), post_aggregates::upvotes.eq(5)))
+      .execute(conn)
+      .await
+      .unwrap();
+    diesel::update(community_aggregates::table)
+      .filter(community_aggregates::community_id.eq(inserted_community.id))
+      .set(community_aggregates::posts.eq(0))
+      .execute(conn)
+      .await
+      .unwrap();
+    diesel::update(person_aggregates::table)
+      .filter(person_aggregates::person_id.eq(inserted_person.id))
+      .set(person_aggregates::post_count.eq(3))
+      .execute(conn)
+      .await
+      .unwrap();
+
+    let find = |differences: &[AggregateDifferences], table: &str, column: &str, id: i32| {
+      differences
+        .iter()
+        .filter(|d| d.table == table && d.column == column)
+        .flat_map(|d| d.examples.iter())
+        .find(|e| e.id == id)
+        .map(|e| (e.stored.clone(), e.actual.clone()))
+    };
+
+    let differences = AggregateDifferences::check(pool, false).await.unwrap();
+    let post_id = inserted_post.id.0;
+    let expected = Some(("5".to_string(), "1".to_string()));
+    assert_eq!(
+      expected,
+      find(&differences, "post_aggregates", "score", post_id)
+    );
+    assert_eq!(
+      expected,
+      find(&differences, "post_aggregates", "upvotes", post_id)
+    );
+    assert_eq!(
+      None,
+      find(&differences, "post_aggregates", "downvotes", post_id)
+    );
+    assert_eq!(
+      Some(("0".to_string(), "1".to_string())),
+      find(
+        &differences,
+        "community_aggregates",
+        "posts",
+        inserted_community.id.0
+      )
+    );
+    assert_eq!(
+      Some(("3".to_string(), "1".to_string())),
+      find(
+        &differences,
+        "person_aggregates",
+        "post_count",
+        inserted_person.id.0
+      )
+    );
+
+    // Checking doesn't change anything, repairing does
+    let differences = AggregateDifferences::check(pool, true).await.unwrap();
+    assert_eq!(
+      expected,
+      find(&differences, "post_aggregates", "score", post_id)
+    );
+    let differences = AggregateDifferences::check(pool, false).await.unwrap();
+    assert_eq!(
+      None,
+      find(&differences, "post_aggregates", "score", post_id)
+    );
+    assert_eq!(
+      None,
+      find(
+        &differences,
+        "community_aggregates",
+        "posts",
+        inserted_community.id.0
+      )
+    );
+
+    Person::delete(pool, inserted_person.id).await.unwrap();
+    Community::delete(pool, inserted_community.id)
+      .await
+      .unwrap();
+    Instance::delete(pool, inserted_instance.id).await.unwrap();
+  }
+}
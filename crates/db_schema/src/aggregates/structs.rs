@@ -8,9 +8,10 @@ use crate::schema::{
   post_aggregates,
   site_aggregates,
 };
+use doku::Document;
 use serde::{Deserialize, Serialize};
 
-#[derive(PartialEq, Eq, Debug, Serialize, Deserialize, Clone)]
+#[derive(PartialEq, Eq, Debug, Serialize, Deserialize, Clone, Document)]
 #[cfg_attr(feature = "full", derive(Queryable, Associations, Identifiable))]
 #[cfg_attr(feature = "full", diesel(table_name = comment_aggregates))]
 #[cfg_attr(feature = "full", diesel(belongs_to(crate::source::comment::Comment)))]
@@ -20,11 +21,12 @@ pub struct CommentAggregates {
   pub score: i64,
   pub upvotes: i64,
   pub downvotes: i64,
+  #[doku(as = "String")]
   pub published: chrono::NaiveDateTime,
   pub child_count: i32,
 }
 
-#[derive(PartialEq, Eq, Debug, Serialize, Deserialize, Clone)]
+#[derive(PartialEq, Eq, Debug, Serialize, Deserialize, Clone, Document)]
 #[cfg_attr(feature = "full", derive(Queryable, Associations, Identifiable))]
 #[cfg_attr(feature = "full", diesel(table_name = community_aggregates))]
 #[cfg_attr(
@@ -37,6 +39,7 @@ pub struct CommunityAggregates {
   pub subscribers: i64,
   pub posts: i64,
   pub comments: i64,
+  #[doku(as = "String")]
   pub published: chrono::NaiveDateTime,
   pub users_active_day: i64,
   pub users_active_week: i64,
@@ -44,7 +47,7 @@ pub struct CommunityAggregates {
   pub users_active_half_year: i64,
 }
 
-#[derive(PartialEq, Eq, Debug, Serialize, Deserialize, Clone, Default)]
+#[derive(PartialEq, Eq, Debug, Serialize, Deserialize, Clone, Default, Document)]
 #[cfg_attr(feature = "full", derive(Queryable, Associations, Identifiable))]
 #[cfg_attr(feature = "full", diesel(table_name = person_aggregates))]
 #[cfg_attr(feature = "full", diesel(belongs_to(crate::source::person::Person)))]
@@ -57,7 +60,7 @@ pub struct PersonAggregates {
   pub comment_score: i64,
 }
 
-#[derive(PartialEq, Eq, Debug, Serialize, Deserialize, Clone)]
+#[derive(PartialEq, Eq, Debug, Serialize, Deserialize, Clone, Document)]
 #[cfg_attr(feature = "full", derive(Queryable, Associations, Identifiable))]
 #[cfg_attr(feature = "full", diesel(table_name = post_aggregates))]
 #[cfg_attr(feature = "full", diesel(belongs_to(crate::source::post::Post)))]
@@ -68,14 +71,17 @@ pub struct PostAggregates {
   pub score: i64,
   pub upvotes: i64,
   pub downvotes: i64,
+  #[doku(as = "String")]
   pub published: chrono::NaiveDateTime,
+  #[doku(as = "String")]
   pub newest_comment_time_necro: chrono::NaiveDateTime, // A newest comment time, limited to 2 days, to prevent necrobumping
+  #[doku(as = "String")]
   pub newest_comment_time: chrono::NaiveDateTime,
   pub featured_community: bool,
   pub featured_local: bool,
 }
 
-#[derive(PartialEq, Eq, Debug, Serialize, Deserialize, Clone)]
+#[derive(PartialEq, Eq, Debug, Serialize, Deserialize, Clone, Document)]
 #[cfg_attr(feature = "full", derive(Queryable, Associations, Identifiable))]
 #[cfg_attr(feature = "full", diesel(table_name = person_post_aggregates))]
 #[cfg_attr(feature = "full", diesel(belongs_to(crate::source::person::Person)))]
@@ -84,6 +90,7 @@ pub struct PersonPostAggregates {
   pub person_id: PersonId,
   pub post_id: PostId,
   pub read_comments: i64,
+  #[doku(as = "String")]
   pub published: chrono::NaiveDateTime,
 }
 
@@ -97,7 +104,7 @@ pub struct PersonPostAggregatesForm {
   pub published: Option<chrono::NaiveDateTime>,
 }
 
-#[derive(PartialEq, Eq, Debug, Serialize, Deserialize, Clone)]
+#[derive(PartialEq, Eq, Debug, Serialize, Deserialize, Clone, Document)]
 #[cfg_attr(feature = "full", derive(Queryable, Associations, Identifiable))]
 #[cfg_attr(feature = "full", diesel(table_name = site_aggregates))]
 #[cfg_attr(feature = "full", diesel(belongs_to(crate::source::site::Site)))]
@@ -113,3 +120,29 @@ pub struct SiteAggregates {
   pub users_active_month: i64,
   pub users_active_half_year: i64,
 }
+
+/// Rows of an aggregates column which didn't match the value calculated from the source tables
+#[derive(PartialEq, Eq, Debug, Serialize, Deserialize, Clone, Document)]
+pub struct AggregateDifferences {
+  /// Eg `post_aggregates`
+  pub table: String,
+  pub column: String,
+  /// Number of rows with a wrong value
+  pub count: i64,
+  /// The newest of the wrong rows
+  pub examples: Vec<AggregateDifference>,
+}
+
+#[derive(PartialEq, Eq, Debug, Serialize, Deserialize, Clone, Document)]
+#[cfg_attr(feature = "full", derive(QueryableByName))]
+pub struct AggregateDifference {
+  /// Id of the post, comment, community, person or site
+  #[cfg_attr(feature = "full", diesel(sql_type = diesel::sql_types::Integer))]
+  pub id: i32,
+  /// The value which was stored in the aggregates table
+  #[cfg_attr(feature = "full", diesel(sql_type = diesel::sql_types::Text))]
+  pub stored: String,
+  /// The value calculated from the source tables
+  #[cfg_attr(feature = "full", diesel(sql_type = diesel::sql_types::Text))]
+  pub actual: String,
+}
@@ -1,18 +1,20 @@
 use crate::{
   newtypes::DbUrl,
-  schema::activity::dsl::{activity, ap_id},
+  schema::activity::dsl::{activity, ap_id, local, private_message, published, signature_verified},
   source::activity::{Activity, ActivityInsertForm, ActivityUpdateForm},
   traits::Crud,
-  utils::{get_conn, DbPool},
+  utils::{get_conn, limit_and_offset, DbPool},
 };
 use diesel::{
-  dsl::insert_into,
+  dsl::{insert_into, sql},
   result::{DatabaseErrorKind, Error},
+  sql_types::{Bool, Text},
   ExpressionMethods,
   QueryDsl,
 };
 use diesel_async::RunQueryDsl;
 use serde_json::Value;
+use typed_builder::TypedBuilder;
 
 #[async_trait]
 impl Crud for Activity {
@@ -60,6 +62,8 @@ impl Activity {
     data_: Value,
     local_: bool,
     sensitive_: Option<bool>,
+    private_message_: bool,
+    signature_verified_: bool,
   ) -> Result<bool, Error> {
     let activity_form = ActivityInsertForm {
       ap_id: ap_id_,
@@ -67,6 +71,8 @@ impl Activity {
       local: Some(local_),
       sensitive: sensitive_,
       updated: None,
+      private_message: Some(private_message_),
+      signature_verified: Some(signature_verified_),
     };
     match Activity::create(pool, &activity_form).await {
       Ok(_) => Ok(true),
@@ -86,6 +92,71 @@ impl Activity {
       .first::<Self>(conn)
       .await
   }
+
+  pub async fn mark_signature_verified(pool: &DbPool, object_id: &DbUrl) -> Result<usize, Error> {
+    let conn = &mut get_conn(pool).await?;
+    diesel::update(activity.filter(ap_id.eq(object_id)))
+      .set(signature_verified.eq(true))
+      .execute(conn)
+      .await
+  }
+}
+
+/// Searches the stored activities, newest first. Private messages are never returned.
+#[derive(TypedBuilder)]
+#[builder(field_defaults(default))]
+pub struct ActivityQuery<'a> {
+  #[builder(!default)]
+  pool: &'a DbPool,
+  actor: Option<String>,
+  /// Matches the object id, whether the object is embedded or not
+  object: Option<String>,
+  kind: Option<String>,
+  local_only: Option<bool>,
+  published_after: Option<chrono::NaiveDateTime>,
+  published_before: Option<chrono::NaiveDateTime>,
+  page: Option<i64>,
+  limit: Option<i64>,
+}
+
+impl<'a> ActivityQuery<'a> {
+  pub async fn list(self) -> Result<Vec<Activity>, Error> {
+    let conn = &mut get_conn(self.pool).await?;
+    let mut query = activity.filter(private_message.eq(false)).into_boxed();
+
+    if let Some(actor) = self.actor {
+      query = query.filter(sql::<Bool>("data ->> 'actor' = ").bind::<Text, _>(actor));
+    }
+    if let Some(object) = self.object {
+      query = query.filter(
+        sql::<Bool>("(data ->> 'object' = ")
+          .bind::<Text, _>(object.clone())
+          .sql(" or data -> 'object' ->> 'id' = ")
+          .bind::<Text, _>(object)
+          .sql(")"),
+      );
+    }
+    if let Some(kind) = self.kind {
+      query = query.filter(sql::<Bool>("data ->> 'type' = ").bind::<Text, _>(kind));
+    }
+    if let Some(local_only) = self.local_only {
+      query = query.filter(local.eq(local_only));
+    }
+    if let Some(after) = self.published_after {
+      query = query.filter(published.gt(after));
+    }
+    if let Some(before) = self.published_before {
+      query = query.filter(published.lt(before));
+    }
+
+    let (limit, offset) = limit_and_offset(self.page, self.limit)?;
+    query
+      .order_by(published.desc())
+      .limit(limit)
+      .offset(offset)
+      .load::<Activity>(conn)
+      .await
+  }
 }
 
 #[cfg(test)]
@@ -144,10 +215,31 @@ mod tests {
       local: Some(true),
       sensitive: Some(false),
       updated: None,
+      private_message: Some(false),
+      signature_verified: None,
     };
 
     let inserted_activity = Activity::create(pool, &activity_form).await.unwrap();
 
+    let private_ap_id: DbUrl = Url::parse(
+      "https://enterprise.lemmy.ml/activities/create/6e4a1f0d-2c55-4c3e-9f5e-6a3d9e3b1c2a",
+    )
+    .unwrap()
+    .into();
+    let private_form = ActivityInsertForm {
+      ap_id: private_ap_id,
+      data: serde_json::json!({
+        "type": "Create",
+        "actor": "https://enterprise.lemmy.ml/u/riker",
+      }),
+      local: Some(true),
+      sensitive: Some(true),
+      updated: None,
+      private_message: Some(true),
+      signature_verified: None,
+    };
+    let private_activity = Activity::create(pool, &private_form).await.unwrap();
+
     let expected_activity = Activity {
       ap_id: ap_id_.clone(),
       id: inserted_activity.id,
@@ -156,15 +248,48 @@ mod tests {
       sensitive: Some(false),
       published: inserted_activity.published,
       updated: None,
+      private_message: false,
+      signature_verified: false,
     };
 
     let read_activity = Activity::read(pool, inserted_activity.id).await.unwrap();
     let read_activity_by_apub_id = Activity::read_from_apub_id(pool, &ap_id_).await.unwrap();
+    let found_activities = ActivityQuery::builder()
+      .pool(pool)
+      .actor(Some("https://enterprise.lemmy.ml/u/riker".to_string()))
+      .object(Some("https://enterprise.lemmy.ml/post/32".to_string()))
+      .kind(Some("Delete".to_string()))
+      .local_only(Some(true))
+      .build()
+      .list()
+      .await
+      .unwrap();
+    let not_found_activities = ActivityQuery::builder()
+      .pool(pool)
+      .kind(Some("Delete".to_string()))
+      .local_only(Some(false))
+      .build()
+      .list()
+      .await
+      .unwrap();
+    let private_activities = ActivityQuery::builder()
+      .pool(pool)
+      .kind(Some("Create".to_string()))
+      .build()
+      .list()
+      .await
+      .unwrap();
     Person::delete(pool, inserted_creator.id).await.unwrap();
     Activity::delete(pool, inserted_activity.id).await.unwrap();
+    Activity::delete(pool, private_activity.id).await.unwrap();
 
     assert_eq!(expected_activity, read_activity);
     assert_eq!(expected_activity, read_activity_by_apub_id);
     assert_eq!(expected_activity, inserted_activity);
+    assert_eq!(vec![expected_activity], found_activities);
+    assert!(not_found_activities.is_empty());
+    assert!(!private_activities
+      .iter()
+      .any(|a| a.id == private_activity.id));
   }
 }
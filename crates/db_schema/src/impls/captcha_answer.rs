@@ -0,0 +1,82 @@
+use crate::{
+  schema::captcha_answer,
+  source::captcha_answer::{CaptchaAnswer, CaptchaAnswerForm},
+  utils::{get_conn, naive_now, DbPool},
+};
+use diesel::{dsl::insert_into, result::Error, ExpressionMethods, OptionalExtension, QueryDsl};
+use diesel_async::RunQueryDsl;
+
+impl CaptchaAnswer {
+  pub async fn insert(pool: &DbPool, form: &CaptchaAnswerForm) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    insert_into(captcha_answer::table)
+      .values(form)
+      .get_result::<Self>(conn)
+      .await
+  }
+
+  /// Returns true if the answer is correct and not expired. The captcha is removed, so that it can
+  /// only be checked once.
+  pub async fn check_captcha(pool: &DbPool, uuid: &str, answer: &str) -> Result<bool, Error> {
+    let conn = &mut get_conn(pool).await?;
+    let captcha = diesel::delete(captcha_answer::table.filter(captcha_answer::uuid.eq(uuid)))
+      .get_result::<Self>(conn)
+      .await
+      .optional()?;
+    Ok(
+      captcha.is_some_and(|c| {
+        c.expires > naive_now() && c.answer.to_lowercase() == answer.to_lowercase()
+      }),
+    )
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::{
+    source::captcha_answer::{CaptchaAnswer, CaptchaAnswerForm},
+    utils::{build_db_pool_for_tests, naive_now},
+  };
+  use chrono::Duration;
+  use serial_test::serial;
+
+  #[tokio::test]
+  #[serial]
+  async fn test_captcha_answer() {
+    let pool = &build_db_pool_for_tests().await;
+
+    let form = CaptchaAnswerForm {
+      uuid: "f6d8e1a2-captcha-valid".to_string(),
+      answer: "XyZ42".to_string(),
+      expires: naive_now() + Duration::minutes(10),
+    };
+    CaptchaAnswer::insert(pool, &form).await.unwrap();
+    let expired_form = CaptchaAnswerForm {
+      uuid: "f6d8e1a2-captcha-expired".to_string(),
+      expires: naive_now() - Duration::minutes(1),
+      ..form.clone()
+    };
+    CaptchaAnswer::insert(pool, &expired_form).await.unwrap();
+
+    let wrong = CaptchaAnswer::check_captcha(pool, &form.uuid, "abc")
+      .await
+      .unwrap();
+    // The wrong guess already used up the captcha
+    let retried = CaptchaAnswer::check_captcha(pool, &form.uuid, "xyz42")
+      .await
+      .unwrap();
+    let expired = CaptchaAnswer::check_captcha(pool, &expired_form.uuid, "xyz42")
+      .await
+      .unwrap();
+
+    CaptchaAnswer::insert(pool, &form).await.unwrap();
+    let correct = CaptchaAnswer::check_captcha(pool, &form.uuid, "xyz42")
+      .await
+      .unwrap();
+
+    assert!(!wrong);
+    assert!(!retried);
+    assert!(!expired);
+    assert!(correct);
+  }
+}
@@ -1,29 +1,159 @@
 use crate::{
-  newtypes::{CommentId, DbUrl, PersonId},
-  schema::comment::dsl::{ap_id, comment, content, creator_id, deleted, path, removed, updated},
-  source::comment::{
-    Comment,
-    CommentInsertForm,
-    CommentLike,
-    CommentLikeForm,
-    CommentSaved,
-    CommentSavedForm,
-    CommentUpdateForm,
+  newtypes::{CommentId, DbUrl, PersonId, PostId},
+  schema,
+  schema::comment::dsl::{
+    ap_id,
+    comment,
+    content,
+    creator_id,
+    deleted,
+    id,
+    path,
+    post_id,
+    published,
+    removed,
+    updated,
+  },
+  source::{
+    comment::{
+      Comment,
+      CommentInsertForm,
+      CommentLike,
+      CommentLikeForm,
+      CommentSaved,
+      CommentSavedForm,
+      CommentUpdateForm,
+    },
+    community::Community,
+    post::Post,
   },
   traits::{Crud, DeleteableOrRemoveable, Likeable, Saveable},
   utils::{get_conn, naive_now, DbPool},
 };
 use diesel::{
   dsl::{insert_into, sql_query},
+  pg::Pg,
   result::Error,
+  BoolExpressionMethods,
   ExpressionMethods,
   QueryDsl,
 };
 use diesel_async::RunQueryDsl;
-use diesel_ltree::Ltree;
+use diesel_ltree::{nlevel, Ltree, LtreeExtensions};
 use url::Url;
 
 impl Comment {
+  /// Public comments of a person together with their posts and communities, newest first.
+  /// Comments on removed or deleted posts, or in removed or deleted communities, are left out. If
+  /// `before` is given, only comments ordered after that (published, id) pair are returned. Used
+  /// to page through the person's outbox.
+  pub async fn list_for_creator(
+    pool: &DbPool,
+    for_creator_id: PersonId,
+    before: Option<(chrono::NaiveDateTime, CommentId)>,
+    limit: i64,
+  ) -> Result<Vec<(Self, Post, Community)>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    let mut query = comment
+      .inner_join(schema::post::table.inner_join(schema::community::table))
+      .filter(creator_id.eq(for_creator_id))
+      .filter(deleted.eq(false))
+      .filter(removed.eq(false))
+      .filter(schema::post::deleted.eq(false))
+      .filter(schema::post::removed.eq(false))
+      .filter(schema::community::deleted.eq(false))
+      .filter(schema::community::removed.eq(false))
+      .select((
+        schema::comment::all_columns,
+        schema::post::all_columns,
+        schema::community::all_columns,
+      ))
+      .into_boxed();
+    if let Some((before_published, before_id)) = before {
+      query = query.filter(
+        published
+          .lt(before_published)
+          .or(published.eq(before_published).and(id.lt(before_id))),
+      );
+    }
+    query
+      .order_by((published.desc(), id.desc()))
+      .limit(limit)
+      .load::<(Self, Post, Community)>(conn)
+      .await
+  }
+
+  /// Number of comments returned by [`Comment::list_for_creator`] without paging
+  pub async fn count_for_creator(pool: &DbPool, for_creator_id: PersonId) -> Result<i64, Error> {
+    let conn = &mut get_conn(pool).await?;
+    comment
+      .inner_join(schema::post::table.inner_join(schema::community::table))
+      .filter(creator_id.eq(for_creator_id))
+      .filter(deleted.eq(false))
+      .filter(removed.eq(false))
+      .filter(schema::post::deleted.eq(false))
+      .filter(schema::post::removed.eq(false))
+      .filter(schema::community::deleted.eq(false))
+      .filter(schema::community::removed.eq(false))
+      .count()
+      .get_result(conn)
+      .await
+  }
+
+  /// Direct replies to a post (if `parent_path` is `None`) or to the comment with the given path,
+  /// newest first, optionally only those published before the given time. Deleted and removed
+  /// comments are left out.
+  pub async fn list_direct_replies(
+    pool: &DbPool,
+    for_post_id: PostId,
+    parent_path: Option<&Ltree>,
+    before: Option<chrono::NaiveDateTime>,
+    limit: i64,
+  ) -> Result<Vec<Self>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    let mut query = Self::direct_replies(for_post_id, parent_path);
+    if let Some(before) = before {
+      query = query.filter(published.lt(before));
+    }
+    query
+      .order_by(published.desc())
+      .limit(limit)
+      .load::<Self>(conn)
+      .await
+  }
+
+  pub async fn count_direct_replies(
+    pool: &DbPool,
+    for_post_id: PostId,
+    parent_path: Option<&Ltree>,
+  ) -> Result<i64, Error> {
+    let conn = &mut get_conn(pool).await?;
+    Self::direct_replies(for_post_id, parent_path)
+      .count()
+      .get_result::<i64>(conn)
+      .await
+  }
+
+  fn direct_replies<'a>(
+    for_post_id: PostId,
+    parent_path: Option<&Ltree>,
+  ) -> schema::comment::BoxedQuery<'a, Pg> {
+    let mut query = comment
+      .filter(post_id.eq(for_post_id))
+      .filter(deleted.eq(false))
+      .filter(removed.eq(false))
+      .into_boxed();
+    // Top level comments have a path like 0.1
+    let parent_level = match parent_path {
+      Some(parent_path) => {
+        query = query.filter(path.contained_by(parent_path.clone()));
+        parent_path.0.split('.').count() as i32
+      }
+      None => 1,
+    };
+    query.filter(nlevel(path).eq(parent_level + 1))
+  }
+
   pub async fn permadelete_for_creator(
     pool: &DbPool,
     for_creator_id: PersonId,
@@ -247,7 +377,7 @@ impl DeleteableOrRemoveable for Comment {
 #[cfg(test)]
 mod tests {
   use crate::{
-    newtypes::LanguageId,
+    newtypes::{CommentId, LanguageId},
     source::{
       comment::{
         Comment,
@@ -261,7 +391,7 @@ mod tests {
       community::{Community, CommunityInsertForm},
       instance::Instance,
       person::{Person, PersonInsertForm},
-      post::{Post, PostInsertForm},
+      post::{Post, PostInsertForm, PostUpdateForm},
     },
     traits::{Crud, Likeable, Saveable},
     utils::build_db_pool_for_tests,
@@ -379,6 +509,42 @@ mod tests {
       .unwrap();
 
     let read_comment = Comment::read(pool, inserted_comment.id).await.unwrap();
+    let post_replies = Comment::list_direct_replies(pool, inserted_post.id, None, None, 10)
+      .await
+      .unwrap();
+    let comment_replies = Comment::list_direct_replies(
+      pool,
+      inserted_post.id,
+      Some(&inserted_comment.path),
+      None,
+      10,
+    )
+    .await
+    .unwrap();
+    let comment_replies_count =
+      Comment::count_direct_replies(pool, inserted_post.id, Some(&inserted_comment.path))
+        .await
+        .unwrap();
+    let creator_comments = Comment::list_for_creator(pool, inserted_person.id, None, 10)
+      .await
+      .unwrap();
+    let creator_comments_next = Comment::list_for_creator(
+      pool,
+      inserted_person.id,
+      Some((inserted_child_comment.published, inserted_child_comment.id)),
+      10,
+    )
+    .await
+    .unwrap();
+
+    // Comments on removed posts are not listed
+    let removed_post_form = PostUpdateForm::builder().removed(Some(true)).build();
+    Post::update(pool, inserted_post.id, &removed_post_form)
+      .await
+      .unwrap();
+    let removed_post_comment_count = Comment::count_for_creator(pool, inserted_person.id)
+      .await
+      .unwrap();
     let like_removed = CommentLike::remove(pool, inserted_person.id, inserted_comment.id)
       .await
       .unwrap();
@@ -405,8 +571,22 @@ mod tests {
       format!("0.{}.{}", expected_comment.id, inserted_child_comment.id),
       inserted_child_comment.path.0,
     );
+    assert_eq!(vec![inserted_comment.id], ids(&post_replies));
+    assert_eq!(2, creator_comments.len());
+    assert_eq!(inserted_child_comment.id, creator_comments[0].0.id);
+    assert_eq!(inserted_post.id, creator_comments[0].1.id);
+    assert_eq!(inserted_community.id, creator_comments[0].2.id);
+    assert_eq!(1, creator_comments_next.len());
+    assert_eq!(inserted_comment.id, creator_comments_next[0].0.id);
+    assert_eq!(0, removed_post_comment_count);
+    assert_eq!(vec![inserted_child_comment.id], ids(&comment_replies));
+    assert_eq!(1, comment_replies_count);
     assert_eq!(1, like_removed);
     assert_eq!(1, saved_removed);
     assert_eq!(1, num_deleted);
   }
+
+  fn ids(comments: &[Comment]) -> Vec<CommentId> {
+    comments.iter().map(|c| c.id).collect()
+  }
 }
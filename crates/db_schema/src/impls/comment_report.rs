@@ -1,6 +1,16 @@
 use crate::{
-  newtypes::{CommentReportId, PersonId},
-  schema::comment_report::dsl::{comment_report, resolved, resolver_id, updated},
+  newtypes::{CommentId, CommentReportId, PersonId},
+  schema::comment_report::dsl::{
+    assignee_id,
+    comment_id,
+    comment_report,
+    creator_id,
+    escalated,
+    resolution_reason,
+    resolved,
+    resolver_id,
+    updated,
+  },
   source::comment_report::{CommentReport, CommentReportForm},
   traits::Reportable,
   utils::{get_conn, naive_now, DbPool},
@@ -17,6 +27,7 @@ use diesel_async::RunQueryDsl;
 impl Reportable for CommentReport {
   type Form = CommentReportForm;
   type IdType = CommentReportId;
+  type ObjectIdType = CommentId;
   /// creates a comment report and returns it
   ///
   /// * `conn` - the postgres connection
@@ -34,22 +45,53 @@ impl Reportable for CommentReport {
   /// * `conn` - the postgres connection
   /// * `report_id` - the id of the report to resolve
   /// * `by_resolver_id` - the id of the user resolving the report
+  /// * `reason` - an optional resolution reason shown to the other moderators
   async fn resolve(
     pool: &DbPool,
     report_id_: Self::IdType,
     by_resolver_id: PersonId,
+    reason: Option<String>,
   ) -> Result<usize, Error> {
     let conn = &mut get_conn(pool).await?;
     update(comment_report.find(report_id_))
       .set((
         resolved.eq(true),
         resolver_id.eq(by_resolver_id),
+        resolution_reason.eq(reason),
         updated.eq(naive_now()),
       ))
       .execute(conn)
       .await
   }
 
+  /// resolve all open reports for a comment
+  ///
+  /// * `conn` - the postgres connection
+  /// * `comment_id_` - the id of the reported comment
+  /// * `by_resolver_id` - the id of the user resolving the reports
+  /// * `reason` - an optional resolution reason shown to the other moderators
+  async fn resolve_all_for_object(
+    pool: &DbPool,
+    comment_id_: Self::ObjectIdType,
+    by_resolver_id: PersonId,
+    reason: Option<String>,
+  ) -> Result<Vec<Self>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    update(
+      comment_report
+        .filter(comment_id.eq(comment_id_))
+        .filter(resolved.eq(false)),
+    )
+    .set((
+      resolved.eq(true),
+      resolver_id.eq(by_resolver_id),
+      resolution_reason.eq(reason),
+      updated.eq(naive_now()),
+    ))
+    .get_results::<Self>(conn)
+    .await
+  }
+
   /// unresolve a comment report
   ///
   /// * `conn` - the postgres connection
@@ -65,9 +107,72 @@ impl Reportable for CommentReport {
       .set((
         resolved.eq(false),
         resolver_id.eq(by_resolver_id),
+        resolution_reason.eq(None::<String>),
         updated.eq(naive_now()),
       ))
       .execute(conn)
       .await
   }
+
+  /// assign a comment report to a moderator
+  ///
+  /// * `conn` - the postgres connection
+  /// * `report_id` - the id of the report to assign
+  /// * `for_assignee_id` - the moderator the report is assigned to, or None to unassign it
+  async fn assign(
+    pool: &DbPool,
+    report_id_: Self::IdType,
+    for_assignee_id: Option<PersonId>,
+  ) -> Result<usize, Error> {
+    let conn = &mut get_conn(pool).await?;
+    update(comment_report.find(report_id_))
+      .set((assignee_id.eq(for_assignee_id), updated.eq(naive_now())))
+      .execute(conn)
+      .await
+  }
+
+  /// escalate a comment report to the site admins, or de-escalate it
+  ///
+  /// * `conn` - the postgres connection
+  /// * `report_id` - the id of the report to escalate
+  /// * `is_escalated` - whether the report should be handled by the admins
+  async fn escalate(
+    pool: &DbPool,
+    report_id_: Self::IdType,
+    is_escalated: bool,
+  ) -> Result<usize, Error> {
+    let conn = &mut get_conn(pool).await?;
+    update(comment_report.find(report_id_))
+      .set((escalated.eq(is_escalated), updated.eq(naive_now())))
+      .execute(conn)
+      .await
+  }
+}
+
+impl CommentReport {
+  /// Resolves the open reports which a person made on a comment. This is used when the resolution
+  /// is federated back to the instance of the reporter.
+  pub async fn resolve_for_creator(
+    pool: &DbPool,
+    for_comment_id: CommentId,
+    for_creator_id: PersonId,
+    by_resolver_id: PersonId,
+    reason: Option<String>,
+  ) -> Result<Vec<Self>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    update(
+      comment_report
+        .filter(comment_id.eq(for_comment_id))
+        .filter(creator_id.eq(for_creator_id))
+        .filter(resolved.eq(false)),
+    )
+    .set((
+      resolved.eq(true),
+      resolver_id.eq(by_resolver_id),
+      resolution_reason.eq(reason),
+      updated.eq(naive_now()),
+    ))
+    .get_results::<Self>(conn)
+    .await
+  }
 }
@@ -20,7 +20,13 @@ use crate::{
   utils::{functions::lower, get_conn, DbPool},
   SubscribedType,
 };
-use diesel::{dsl::insert_into, result::Error, ExpressionMethods, QueryDsl, TextExpressionMethods};
+use diesel::{
+  dsl::{insert_into, now},
+  result::Error,
+  ExpressionMethods,
+  QueryDsl,
+  TextExpressionMethods,
+};
 use diesel_async::RunQueryDsl;
 
 mod safe_type {
@@ -40,6 +46,7 @@ mod safe_type {
       posting_restricted_to_mods,
       published,
       removed,
+      slur_filter_regex,
       title,
       updated,
     },
@@ -64,6 +71,7 @@ mod safe_type {
     hidden,
     posting_restricted_to_mods,
     instance_id,
+    slur_filter_regex,
   );
 
   impl ToSafe for Community {
@@ -86,6 +94,7 @@ mod safe_type {
         hidden,
         posting_restricted_to_mods,
         instance_id,
+        slur_filter_regex,
       )
     }
   }
@@ -287,6 +296,18 @@ impl Bannable for CommunityPersonBan {
   }
 }
 
+impl CommunityPersonBan {
+  /// Lists the temporary community bans which have run out, but were not lifted yet.
+  pub async fn list_expired(pool: &DbPool) -> Result<Vec<Self>, Error> {
+    use crate::schema::community_person_ban::dsl::{community_person_ban, expires};
+    let conn = &mut get_conn(pool).await?;
+    community_person_ban
+      .filter(expires.lt(now))
+      .load::<Self>(conn)
+      .await
+  }
+}
+
 impl CommunityFollower {
   pub fn to_subscribed_type(follower: &Option<Self>) -> SubscribedType {
     match follower {
@@ -414,8 +435,9 @@ mod tests {
       person::{Person, PersonInsertForm},
     },
     traits::{Bannable, Crud, Followable, Joinable},
-    utils::build_db_pool_for_tests,
+    utils::{build_db_pool_for_tests, naive_now},
   };
+  use chrono::Duration;
   use serial_test::serial;
 
   #[tokio::test]
@@ -467,6 +489,7 @@ mod tests {
       hidden: false,
       posting_restricted_to_mods: false,
       instance_id: inserted_instance.id,
+      slur_filter_regex: None,
     };
 
     let community_follower_form = CommunityFollowerForm {
@@ -521,6 +544,20 @@ mod tests {
       expires: None,
     };
 
+    // Permanent bans never show up as expired
+    let expired_bans_permanent = CommunityPersonBan::list_expired(pool).await.unwrap();
+
+    let expired_community_person_ban_form = CommunityPersonBanForm {
+      community_id: inserted_community.id,
+      person_id: inserted_person.id,
+      expires: Some(Some(naive_now() - Duration::days(1))),
+    };
+    let expired_community_person_ban =
+      CommunityPersonBan::ban(pool, &expired_community_person_ban_form)
+        .await
+        .unwrap();
+    let expired_bans = CommunityPersonBan::list_expired(pool).await.unwrap();
+
     let read_community = Community::read(pool, inserted_community.id).await.unwrap();
 
     let update_community_form = CommunityUpdateForm::builder()
@@ -551,6 +588,8 @@ mod tests {
     assert_eq!(expected_community_follower, inserted_community_follower);
     assert_eq!(expected_community_moderator, inserted_community_moderator);
     assert_eq!(expected_community_person_ban, inserted_community_person_ban);
+    assert!(!expired_bans_permanent.contains(&inserted_community_person_ban));
+    assert!(expired_bans.contains(&expired_community_person_ban));
     assert_eq!(1, ignored_community);
     assert_eq!(1, left_community);
     assert_eq!(1, unban);
@@ -0,0 +1,218 @@
+use crate::{
+  newtypes::{CommunityBackfillId, CommunityId},
+  schema::community_backfill::dsl::{
+    community_backfill,
+    community_id,
+    finished,
+    published,
+    updated,
+  },
+  source::community_backfill::{
+    CommunityBackfill,
+    CommunityBackfillInsertForm,
+    CommunityBackfillUpdateForm,
+  },
+  traits::Crud,
+  utils::{get_conn, naive_now, DbPool},
+};
+use diesel::{
+  dsl::{now, IntervalDsl},
+  insert_into,
+  result::Error,
+  BoolExpressionMethods,
+  ExpressionMethods,
+  NullableExpressionMethods,
+  OptionalExtension,
+  QueryDsl,
+};
+use diesel_async::RunQueryDsl;
+
+#[async_trait]
+impl Crud for CommunityBackfill {
+  type InsertForm = CommunityBackfillInsertForm;
+  type UpdateForm = CommunityBackfillUpdateForm;
+  type IdType = CommunityBackfillId;
+  async fn read(pool: &DbPool, backfill_id: CommunityBackfillId) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    community_backfill
+      .find(backfill_id)
+      .first::<Self>(conn)
+      .await
+  }
+
+  async fn create(pool: &DbPool, form: &Self::InsertForm) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    insert_into(community_backfill)
+      .values(form)
+      .get_result::<Self>(conn)
+      .await
+  }
+
+  async fn update(
+    pool: &DbPool,
+    backfill_id: CommunityBackfillId,
+    form: &Self::UpdateForm,
+  ) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    diesel::update(community_backfill.find(backfill_id))
+      .set(form)
+      .get_result::<Self>(conn)
+      .await
+  }
+}
+
+impl CommunityBackfill {
+  /// Starts the backfill of a community, unless it was already started before. Returns `None` in
+  /// that case, so that each community is only backfilled once. A backfill which was cut off, eg
+  /// by a restart, is started again from the beginning.
+  pub async fn start(pool: &DbPool, for_community_id: CommunityId) -> Result<Option<Self>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    let form = CommunityBackfillInsertForm {
+      community_id: for_community_id,
+    };
+    let inserted = insert_into(community_backfill)
+      .values(form)
+      .on_conflict(community_id)
+      .do_nothing()
+      .get_result::<Self>(conn)
+      .await
+      .optional()?;
+    if inserted.is_some() {
+      return Ok(inserted);
+    }
+
+    // Running backfills save their progress after every post, so one which didn't do that for an
+    // hour was cut off. Only one process gets to restart it.
+    let restart = CommunityBackfillUpdateForm::builder()
+      .pages_fetched(Some(0))
+      .posts_fetched(Some(0))
+      .comments_fetched(Some(0))
+      .error(Some(None))
+      .updated(Some(Some(naive_now())))
+      .build();
+    diesel::update(
+      community_backfill
+        .filter(community_id.eq(for_community_id))
+        .filter(finished.is_null())
+        .filter(
+          updated
+            .lt((now - 1.hours()).nullable())
+            .or(updated.is_null().and(published.lt(now - 1.hours()))),
+        ),
+    )
+    .set(restart)
+    .get_result::<Self>(conn)
+    .await
+    .optional()
+  }
+
+  /// Communities whose backfill was cut off before it finished
+  pub async fn list_interrupted(pool: &DbPool) -> Result<Vec<CommunityId>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    community_backfill
+      .filter(finished.is_null())
+      .filter(
+        updated
+          .lt((now - 1.hours()).nullable())
+          .or(updated.is_null().and(published.lt(now - 1.hours()))),
+      )
+      .select(community_id)
+      .load::<CommunityId>(conn)
+      .await
+  }
+
+  pub async fn read_for_community(
+    pool: &DbPool,
+    for_community_id: CommunityId,
+  ) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    community_backfill
+      .filter(community_id.eq(for_community_id))
+      .first::<Self>(conn)
+      .await
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::{
+    source::{
+      community::{Community, CommunityInsertForm},
+      community_backfill::{CommunityBackfill, CommunityBackfillUpdateForm},
+      instance::Instance,
+    },
+    traits::Crud,
+    utils::{build_db_pool_for_tests, naive_now},
+  };
+  use serial_test::serial;
+
+  #[tokio::test]
+  #[serial]
+  async fn test_community_backfill() {
+    let pool = &build_db_pool_for_tests().await;
+
+    let inserted_instance = Instance::create(pool, "my_domain.tld").await.unwrap();
+
+    let community_form = CommunityInsertForm::builder()
+      .name("test_community_backfill".to_string())
+      .title("nada".to_owned())
+      .public_key("pubkey".to_string())
+      .instance_id(inserted_instance.id)
+      .build();
+    let inserted_community = Community::create(pool, &community_form).await.unwrap();
+
+    let started = CommunityBackfill::start(pool, inserted_community.id)
+      .await
+      .unwrap()
+      .unwrap();
+    // The second attempt doesnt start another backfill
+    let started_again = CommunityBackfill::start(pool, inserted_community.id)
+      .await
+      .unwrap();
+
+    // A backfill which stopped saving its progress was cut off, and is started again
+    let stale_form = CommunityBackfillUpdateForm::builder()
+      .pages_fetched(Some(3))
+      .updated(Some(Some(naive_now() - chrono::Duration::hours(2))))
+      .build();
+    CommunityBackfill::update(pool, started.id, &stale_form)
+      .await
+      .unwrap();
+    let interrupted = CommunityBackfill::list_interrupted(pool).await.unwrap();
+    let restarted = CommunityBackfill::start(pool, inserted_community.id)
+      .await
+      .unwrap()
+      .unwrap();
+    let restarted_again = CommunityBackfill::start(pool, inserted_community.id)
+      .await
+      .unwrap();
+
+    let form = CommunityBackfillUpdateForm::builder()
+      .pages_fetched(Some(2))
+      .posts_fetched(Some(40))
+      .finished(Some(Some(naive_now())))
+      .build();
+    let updated = CommunityBackfill::update(pool, started.id, &form)
+      .await
+      .unwrap();
+    let read = CommunityBackfill::read_for_community(pool, inserted_community.id)
+      .await
+      .unwrap();
+
+    Community::delete(pool, inserted_community.id)
+      .await
+      .unwrap();
+    Instance::delete(pool, inserted_instance.id).await.unwrap();
+
+    assert_eq!(0, started.pages_fetched);
+    assert!(started.finished.is_none());
+    assert!(started_again.is_none());
+    assert!(interrupted.contains(&inserted_community.id));
+    assert_eq!(started.id, restarted.id);
+    assert_eq!(0, restarted.pages_fetched);
+    assert!(restarted_again.is_none());
+    assert_eq!(40, updated.posts_fetched);
+    assert!(updated.finished.is_some());
+    assert_eq!(updated, read);
+  }
+}
@@ -0,0 +1,243 @@
+use crate::{
+  newtypes::{CommunityId, CommunityRuleId},
+  schema::community_rule::dsl::{body, community_id, community_rule, id, position, title, updated},
+  source::community_rule::{CommunityRule, CommunityRuleForm},
+  utils::{get_conn, naive_now, DbPool},
+};
+use diesel::{delete, insert_into, result::Error, update, ExpressionMethods, QueryDsl};
+use diesel_async::RunQueryDsl;
+use std::collections::HashSet;
+
+impl CommunityRule {
+  pub async fn read(pool: &DbPool, rule_id: CommunityRuleId) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    community_rule.find(rule_id).first::<Self>(conn).await
+  }
+
+  /// Returns the rules of a community, in the order they should be displayed
+  pub async fn for_community(
+    pool: &DbPool,
+    for_community_id: CommunityId,
+  ) -> Result<Vec<Self>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    community_rule
+      .filter(community_id.eq(for_community_id))
+      .order_by(position)
+      .load::<Self>(conn)
+      .await
+  }
+
+  /// Replaces the rules of a community with the given (id, title, body) triples, in display
+  /// order. Rules with an id are updated in place, so that reports and modlog entries citing them
+  /// follow the new wording and position. Rules without an id are inserted, and existing rules
+  /// whose id is missing from the list are deleted.
+  pub async fn update(
+    pool: &DbPool,
+    rules: Vec<(Option<CommunityRuleId>, String, Option<String>)>,
+    for_community_id: CommunityId,
+  ) -> Result<(), Error> {
+    let conn = &mut get_conn(pool).await?;
+
+    conn
+      .build_transaction()
+      .run(|conn| {
+        Box::pin(async move {
+          let existing = community_rule
+            .filter(community_id.eq(for_community_id))
+            .load::<Self>(conn)
+            .await?;
+
+          // Ids of another community, or given twice, can't be updated
+          let kept_ids: Vec<CommunityRuleId> = rules.iter().filter_map(|r| r.0).collect();
+          let mut seen_ids = HashSet::new();
+          for kept_id in &kept_ids {
+            if !existing.iter().any(|r| &r.id == kept_id) || !seen_ids.insert(kept_id) {
+              return Err(Error::NotFound);
+            }
+          }
+
+          delete(
+            community_rule
+              .filter(community_id.eq(for_community_id))
+              .filter(id.ne_all(&kept_ids)),
+          )
+          .execute(conn)
+          .await?;
+
+          // Move the remaining rules out of the way, so that reordering them doesn't run into the
+          // unique position constraint
+          update(community_rule.filter(community_id.eq(for_community_id)))
+            .set(position.eq(position * -1 - 1))
+            .execute(conn)
+            .await?;
+
+          for (i, (rule_id, new_title, new_body)) in rules.into_iter().enumerate() {
+            let new_position = i as i32;
+            match rule_id {
+              Some(rule_id) => {
+                let rule = existing
+                  .iter()
+                  .find(|r| r.id == rule_id)
+                  .ok_or(Error::NotFound)?;
+                if rule.title == new_title && rule.body == new_body {
+                  update(community_rule.find(rule_id))
+                    .set(position.eq(new_position))
+                    .execute(conn)
+                    .await?;
+                } else {
+                  update(community_rule.find(rule_id))
+                    .set((
+                      position.eq(new_position),
+                      title.eq(new_title),
+                      body.eq(new_body),
+                      updated.eq(naive_now()),
+                    ))
+                    .execute(conn)
+                    .await?;
+                }
+              }
+              None => {
+                let form = CommunityRuleForm {
+                  community_id: for_community_id,
+                  position: new_position,
+                  title: new_title,
+                  body: new_body,
+                };
+                insert_into(community_rule)
+                  .values(form)
+                  .execute(conn)
+                  .await?;
+              }
+            }
+          }
+          Ok(())
+        }) as _
+      })
+      .await
+  }
+
+  /// Replaces the rules of a remote community with the given (title, body) pairs. Remote rules
+  /// carry no local id, so they are matched to the existing rules by title. A rule whose title
+  /// changed is replaced, and reports citing it lose the reference instead of pointing to another
+  /// rule.
+  pub async fn update_from_remote(
+    pool: &DbPool,
+    rules: Vec<(String, Option<String>)>,
+    for_community_id: CommunityId,
+  ) -> Result<(), Error> {
+    let mut existing = Self::for_community(pool, for_community_id).await?;
+    let rules = rules
+      .into_iter()
+      .map(|(new_title, new_body)| {
+        let rule_id = existing
+          .iter()
+          .position(|r| r.title == new_title)
+          .map(|i| existing.remove(i).id);
+        (rule_id, new_title, new_body)
+      })
+      .collect();
+    Self::update(pool, rules, for_community_id).await
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::{
+    newtypes::CommunityRuleId,
+    source::{
+      community::{Community, CommunityInsertForm},
+      community_rule::CommunityRule,
+      instance::Instance,
+    },
+    traits::Crud,
+    utils::build_db_pool_for_tests,
+  };
+  use serial_test::serial;
+
+  #[tokio::test]
+  #[serial]
+  async fn test_update_community_rules() {
+    let pool = &build_db_pool_for_tests().await;
+
+    let inserted_instance = Instance::create(pool, "my_domain.tld").await.unwrap();
+
+    let community_form = CommunityInsertForm::builder()
+      .name("test_community_rules".to_string())
+      .title("nada".to_owned())
+      .public_key("pubkey".to_string())
+      .instance_id(inserted_instance.id)
+      .build();
+    let inserted_community = Community::create(pool, &community_form).await.unwrap();
+
+    let rules = vec![
+      (None, "Be nice".to_string(), None),
+      (None, "No politics".to_string(), None),
+      (
+        None,
+        "No spam".to_string(),
+        Some("Including self promotion".to_string()),
+      ),
+    ];
+    CommunityRule::update(pool, rules, inserted_community.id)
+      .await
+      .unwrap();
+    let read_rules = CommunityRule::for_community(pool, inserted_community.id)
+      .await
+      .unwrap();
+
+    // Dropping the second rule and moving the third to the front keeps the ids of the others, and
+    // rewording the first rule keeps its id as well
+    let rules = vec![
+      (
+        Some(read_rules[2].id),
+        read_rules[2].title.clone(),
+        read_rules[2].body.clone(),
+      ),
+      (
+        Some(read_rules[0].id),
+        "Be excellent to each other".to_string(),
+        None,
+      ),
+      (None, "No bots".to_string(), None),
+    ];
+    CommunityRule::update(pool, rules, inserted_community.id)
+      .await
+      .unwrap();
+    let updated_rules = CommunityRule::for_community(pool, inserted_community.id)
+      .await
+      .unwrap();
+
+    // Rules of other communities can't be referenced
+    let foreign_rules = vec![(Some(CommunityRuleId::default()), "Other".to_string(), None)];
+    let foreign_update = CommunityRule::update(pool, foreign_rules, inserted_community.id).await;
+
+    // Remote rules are matched by title
+    let remote_rules = vec![("No bots".to_string(), None), ("No spam".to_string(), None)];
+    CommunityRule::update_from_remote(pool, remote_rules, inserted_community.id)
+      .await
+      .unwrap();
+    let remote_updated_rules = CommunityRule::for_community(pool, inserted_community.id)
+      .await
+      .unwrap();
+
+    Community::delete(pool, inserted_community.id)
+      .await
+      .unwrap();
+    Instance::delete(pool, inserted_instance.id).await.unwrap();
+
+    assert_eq!(3, read_rules.len());
+    assert_eq!("Be nice", read_rules[0].title);
+    assert_eq!(1, read_rules[1].position);
+    assert_eq!(3, updated_rules.len());
+    assert_eq!(read_rules[2].id, updated_rules[0].id);
+    assert_eq!(None, updated_rules[0].updated);
+    assert_eq!(read_rules[0].id, updated_rules[1].id);
+    assert_eq!("Be excellent to each other", updated_rules[1].title);
+    assert!(updated_rules[1].updated.is_some());
+    assert_eq!("No bots", updated_rules[2].title);
+    assert!(foreign_update.is_err());
+    assert_eq!(2, remote_updated_rules.len());
+    assert_eq!(updated_rules[2].id, remote_updated_rules[0].id);
+    assert_eq!(updated_rules[0].id, remote_updated_rules[1].id);
+  }
+}
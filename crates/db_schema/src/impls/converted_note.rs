@@ -0,0 +1,101 @@
+use crate::{
+  newtypes::PostId,
+  schema::converted_note::dsl::{converted_note, post_id, updated},
+  source::converted_note::{ConvertedNote, ConvertedNoteForm},
+  utils::{get_conn, naive_now, DbPool},
+};
+use diesel::{insert_into, result::Error, ExpressionMethods, QueryDsl};
+use diesel_async::RunQueryDsl;
+
+impl ConvertedNote {
+  /// Records that the post was converted from a note. If this was already recorded before, eg
+  /// because the note was edited, only the update time is changed.
+  pub async fn record(pool: &DbPool, for_post_id: PostId) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    let form = ConvertedNoteForm {
+      post_id: for_post_id,
+    };
+    insert_into(converted_note)
+      .values(form)
+      .on_conflict(post_id)
+      .do_update()
+      .set(updated.eq(naive_now()))
+      .get_result::<Self>(conn)
+      .await
+  }
+
+  pub async fn read_for_post(pool: &DbPool, for_post_id: PostId) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    converted_note
+      .filter(post_id.eq(for_post_id))
+      .first::<Self>(conn)
+      .await
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::{
+    source::{
+      community::{Community, CommunityInsertForm},
+      converted_note::ConvertedNote,
+      instance::Instance,
+      person::{Person, PersonInsertForm},
+      post::{Post, PostInsertForm},
+    },
+    traits::Crud,
+    utils::build_db_pool_for_tests,
+  };
+  use serial_test::serial;
+
+  #[tokio::test]
+  #[serial]
+  async fn test_converted_note() {
+    let pool = &build_db_pool_for_tests().await;
+
+    let inserted_instance = Instance::create(pool, "my_domain.tld").await.unwrap();
+
+    let person_form = PersonInsertForm::builder()
+      .name("test_converted_note".into())
+      .public_key("pubkey".to_string())
+      .instance_id(inserted_instance.id)
+      .build();
+    let inserted_person = Person::create(pool, &person_form).await.unwrap();
+
+    let community_form = CommunityInsertForm::builder()
+      .name("test_converted_note".to_string())
+      .title("nada".to_owned())
+      .public_key("pubkey".to_string())
+      .instance_id(inserted_instance.id)
+      .build();
+    let inserted_community = Community::create(pool, &community_form).await.unwrap();
+
+    let post_form = PostInsertForm::builder()
+      .name("A note".into())
+      .creator_id(inserted_person.id)
+      .community_id(inserted_community.id)
+      .build();
+    let inserted_post = Post::create(pool, &post_form).await.unwrap();
+
+    let recorded = ConvertedNote::record(pool, inserted_post.id).await.unwrap();
+    // Recording an edit keeps the same row
+    let recorded_again = ConvertedNote::record(pool, inserted_post.id).await.unwrap();
+    let read = ConvertedNote::read_for_post(pool, inserted_post.id)
+      .await
+      .unwrap();
+
+    Post::delete(pool, inserted_post.id).await.unwrap();
+    let read_after_delete = ConvertedNote::read_for_post(pool, inserted_post.id).await;
+    Person::delete(pool, inserted_person.id).await.unwrap();
+    Community::delete(pool, inserted_community.id)
+      .await
+      .unwrap();
+    Instance::delete(pool, inserted_instance.id).await.unwrap();
+
+    assert!(recorded.updated.is_none());
+    assert_eq!(recorded.id, recorded_again.id);
+    assert!(recorded_again.updated.is_some());
+    assert_eq!(recorded_again, read);
+    assert!(read_after_delete.is_err());
+  }
+}
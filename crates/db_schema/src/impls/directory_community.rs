@@ -0,0 +1,231 @@
+use crate::{
+  newtypes::{InstanceId, LanguageId},
+  schema::{directory_community, federation_allowlist, federation_blocklist},
+  source::directory_community::{DirectoryCommunity, DirectoryCommunityForm},
+  utils::{fuzzy_search, get_conn, limit_and_offset, DbPool},
+};
+use diesel::{
+  dsl::{insert_into, not},
+  result::Error,
+  BoolExpressionMethods,
+  ExpressionMethods,
+  PgArrayExpressionMethods,
+  PgTextExpressionMethods,
+  QueryDsl,
+};
+use diesel_async::RunQueryDsl;
+use typed_builder::TypedBuilder;
+
+impl DirectoryCommunity {
+  /// Inserts or refreshes a crawled community.
+  pub async fn upsert(pool: &DbPool, form: &DirectoryCommunityForm) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    insert_into(directory_community::table)
+      .values(form)
+      .on_conflict(directory_community::actor_id)
+      .do_update()
+      .set(form)
+      .get_result::<Self>(conn)
+      .await
+  }
+
+  /// Removes the communities of an instance which were not seen again since the given time, as
+  /// they were deleted or dropped out of its listing.
+  pub async fn delete_stale(
+    pool: &DbPool,
+    instance_id: InstanceId,
+    crawl_started: chrono::NaiveDateTime,
+  ) -> Result<usize, Error> {
+    let conn = &mut get_conn(pool).await?;
+    diesel::delete(
+      directory_community::table
+        .filter(directory_community::instance_id.eq(instance_id))
+        .filter(directory_community::updated.lt(crawl_started)),
+    )
+    .execute(conn)
+    .await
+  }
+}
+
+/// Browses the crawled communities of instances which this instance federates with, ordered by
+/// subscribers.
+#[derive(TypedBuilder)]
+#[builder(field_defaults(default))]
+pub struct DirectoryCommunityQuery<'a> {
+  #[builder(!default)]
+  pool: &'a DbPool,
+  search_term: Option<String>,
+  /// Only communities of instances which allow this language
+  language_id: Option<LanguageId>,
+  show_nsfw: Option<bool>,
+  page: Option<i64>,
+  limit: Option<i64>,
+}
+
+impl<'a> DirectoryCommunityQuery<'a> {
+  pub async fn list(self) -> Result<Vec<DirectoryCommunity>, Error> {
+    let conn = &mut get_conn(self.pool).await?;
+    // Instances may have been blocked, or dropped from the allowlist, since they were crawled
+    let allowed_instances = federation_allowlist::table
+      .select(federation_allowlist::instance_id)
+      .load::<InstanceId>(conn)
+      .await?;
+    let mut query = directory_community::table
+      .filter(not(directory_community::instance_id.eq_any(
+        federation_blocklist::table.select(federation_blocklist::instance_id),
+      )))
+      .into_boxed();
+    if !allowed_instances.is_empty() {
+      query = query.filter(directory_community::instance_id.eq_any(allowed_instances));
+    }
+
+    if let Some(search_term) = self.search_term {
+      let searcher = fuzzy_search(&search_term);
+      query = query.filter(
+        directory_community::name
+          .ilike(searcher.clone())
+          .or(directory_community::title.ilike(searcher)),
+      );
+    }
+    if let Some(language_id) = self.language_id {
+      // An empty list means that the instance allows all languages
+      query = query.filter(
+        directory_community::language_ids
+          .contains(vec![language_id])
+          .or(directory_community::language_ids.eq(Vec::<LanguageId>::new())),
+      );
+    }
+    if !self.show_nsfw.unwrap_or(false) {
+      query = query.filter(directory_community::nsfw.eq(false));
+    }
+
+    let (limit, offset) = limit_and_offset(self.page, self.limit)?;
+    query
+      .order_by(directory_community::subscribers.desc())
+      .then_order_by(directory_community::id)
+      .limit(limit)
+      .offset(offset)
+      .load::<DirectoryCommunity>(conn)
+      .await
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::{
+    impls::directory_community::DirectoryCommunityQuery,
+    newtypes::LanguageId,
+    source::{
+      directory_community::{DirectoryCommunity, DirectoryCommunityForm},
+      federation_blocklist::FederationBlockList,
+      instance::Instance,
+    },
+    utils::{build_db_pool_for_tests, naive_now},
+  };
+  use chrono::Duration;
+  use serial_test::serial;
+  use url::Url;
+
+  #[tokio::test]
+  #[serial]
+  async fn test_directory_community() {
+    let pool = &build_db_pool_for_tests().await;
+
+    let inserted_instance = Instance::create(pool, "directory.tld").await.unwrap();
+    let form = DirectoryCommunityForm {
+      instance_id: inserted_instance.id,
+      actor_id: Url::parse("https://directory.tld/c/gardening")
+        .unwrap()
+        .into(),
+      name: "gardening".to_string(),
+      title: "All about plants".to_string(),
+      description: None,
+      icon: None,
+      nsfw: false,
+      subscribers: 10,
+      posts: 3,
+      language_ids: vec![LanguageId(37)],
+      updated: naive_now() - Duration::days(1),
+    };
+    let inserted = DirectoryCommunity::upsert(pool, &form).await.unwrap();
+    let other_form = DirectoryCommunityForm {
+      actor_id: Url::parse("https://directory.tld/c/cooking")
+        .unwrap()
+        .into(),
+      name: "cooking".to_string(),
+      title: "Recipes".to_string(),
+      subscribers: 20,
+      language_ids: vec![],
+      ..form.clone()
+    };
+    let other = DirectoryCommunity::upsert(pool, &other_form).await.unwrap();
+    // Crawling the same community again doesnt create a duplicate
+    let updated_form = DirectoryCommunityForm {
+      subscribers: 11,
+      ..form.clone()
+    };
+    let updated = DirectoryCommunity::upsert(pool, &updated_form)
+      .await
+      .unwrap();
+
+    let all = DirectoryCommunityQuery::builder()
+      .pool(pool)
+      .build()
+      .list()
+      .await
+      .unwrap();
+    let searched = DirectoryCommunityQuery::builder()
+      .pool(pool)
+      .search_term(Some("plant".to_string()))
+      .build()
+      .list()
+      .await
+      .unwrap();
+    let by_language = DirectoryCommunityQuery::builder()
+      .pool(pool)
+      .language_id(Some(LanguageId(38)))
+      .build()
+      .list()
+      .await
+      .unwrap();
+
+    // Communities of blocked instances are hidden
+    FederationBlockList::replace(pool, Some(vec!["directory.tld".to_string()]))
+      .await
+      .unwrap();
+    let blocked = DirectoryCommunityQuery::builder()
+      .pool(pool)
+      .build()
+      .list()
+      .await
+      .unwrap();
+    FederationBlockList::replace(pool, Some(vec![]))
+      .await
+      .unwrap();
+
+    // Only the cooking community is refreshed in the next crawl
+    let crawl_started = naive_now();
+    let refreshed_form = DirectoryCommunityForm {
+      updated: naive_now(),
+      ..other_form
+    };
+    DirectoryCommunity::upsert(pool, &refreshed_form)
+      .await
+      .unwrap();
+    let num_deleted = DirectoryCommunity::delete_stale(pool, inserted_instance.id, crawl_started)
+      .await
+      .unwrap();
+    Instance::delete(pool, inserted_instance.id).await.unwrap();
+
+    assert_eq!(inserted.id, updated.id);
+    assert_eq!(11, updated.subscribers);
+    assert_eq!(vec![other.clone(), updated.clone()], all);
+    assert_eq!(vec![updated], searched);
+    assert_eq!(
+      vec![other.id],
+      by_language.iter().map(|c| c.id).collect::<Vec<_>>()
+    );
+    assert!(blocked.is_empty());
+    assert_eq!(1, num_deleted);
+  }
+}
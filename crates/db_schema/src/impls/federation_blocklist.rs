@@ -1,4 +1,5 @@
 use crate::{
+  newtypes::InstanceId,
   schema::federation_blocklist,
   source::{
     federation_blocklist::{FederationBlockList, FederationBlockListForm},
@@ -6,7 +7,7 @@ use crate::{
   },
   utils::{get_conn, DbPool},
 };
-use diesel::{dsl::insert_into, result::Error};
+use diesel::{dsl::insert_into, result::Error, ExpressionMethods, QueryDsl};
 use diesel_async::{AsyncPgConnection, RunQueryDsl};
 
 impl FederationBlockList {
@@ -41,6 +42,31 @@ impl FederationBlockList {
       .await
   }
 
+  pub async fn block(pool: &DbPool, instance_id: InstanceId) -> Result<(), Error> {
+    let conn = &mut get_conn(pool).await?;
+    let form = FederationBlockListForm {
+      instance_id,
+      updated: None,
+    };
+    insert_into(federation_blocklist::table)
+      .values(form)
+      .on_conflict(federation_blocklist::instance_id)
+      .do_nothing()
+      .execute(conn)
+      .await?;
+    Ok(())
+  }
+
+  pub async fn unblock(pool: &DbPool, instance_id: InstanceId) -> Result<(), Error> {
+    let conn = &mut get_conn(pool).await?;
+    diesel::delete(
+      federation_blocklist::table.filter(federation_blocklist::instance_id.eq(instance_id)),
+    )
+    .execute(conn)
+    .await?;
+    Ok(())
+  }
+
   async fn clear(conn: &mut AsyncPgConnection) -> Result<usize, Error> {
     diesel::delete(federation_blocklist::table)
       .execute(conn)
@@ -1,10 +1,29 @@
 use crate::{
-  newtypes::InstanceId,
-  schema::{federation_allowlist, federation_blocklist, instance},
-  source::instance::{Instance, InstanceForm},
+  newtypes::{CommentId, CommunityId, InstanceId, PersonId, PostId},
+  schema::{
+    comment,
+    community,
+    community_follower,
+    federation_allowlist,
+    federation_blocklist,
+    instance,
+    person,
+    post,
+  },
+  source::{
+    community::Community,
+    instance::{Instance, InstanceForm},
+    person::Person,
+  },
   utils::{get_conn, naive_now, DbPool},
 };
-use diesel::{dsl::insert_into, result::Error, ExpressionMethods, QueryDsl};
+use diesel::{
+  dsl::{insert_into, not},
+  result::Error,
+  BoolExpressionMethods,
+  ExpressionMethods,
+  QueryDsl,
+};
 use diesel_async::{AsyncPgConnection, RunQueryDsl};
 use url::Url;
 
@@ -65,6 +84,143 @@ impl Instance {
       .await
   }
 
+  pub async fn read_from_domain(pool: &DbPool, domain: &str) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    instance::table
+      .filter(instance::domain.eq(domain))
+      .first::<Self>(conn)
+      .await
+  }
+
+  /// Removes all follows of communities on the instance.
+  pub async fn remove_community_follows(
+    pool: &DbPool,
+    instance_id: InstanceId,
+  ) -> Result<usize, Error> {
+    let conn = &mut get_conn(pool).await?;
+    let instance_communities = community::table
+      .filter(community::instance_id.eq(instance_id))
+      .select(community::id);
+    diesel::delete(
+      community_follower::table
+        .filter(community_follower::community_id.eq_any(instance_communities)),
+    )
+    .execute(conn)
+    .await
+  }
+
+  /// Deletes up to `limit` comments, posts, communities or persons of the instance. Only content
+  /// created by persons of the instance is deleted, together with the replies to it. Communities
+  /// of the instance are kept as long as they contain posts of other instances. Returns the number
+  /// of deleted items, zero once everything is purged.
+  pub async fn purge_content_batch(
+    pool: &DbPool,
+    instance_id: InstanceId,
+    limit: i64,
+  ) -> Result<usize, Error> {
+    let conn = &mut get_conn(pool).await?;
+    let instance_persons = || {
+      person::table
+        .filter(person::instance_id.eq(instance_id))
+        .select(person::id)
+    };
+    let instance_posts = || {
+      post::table
+        .filter(post::creator_id.eq_any(instance_persons()))
+        .select(post::id)
+    };
+
+    // Comments are deleted before the posts, so that deleting a post doesn't cascade to an
+    // unbounded number of comments
+    let comment_ids = comment::table
+      .filter(
+        comment::creator_id
+          .eq_any(instance_persons())
+          .or(comment::post_id.eq_any(instance_posts())),
+      )
+      .select(comment::id)
+      .limit(limit)
+      .load::<CommentId>(conn)
+      .await?;
+    if !comment_ids.is_empty() {
+      return diesel::delete(comment::table.filter(comment::id.eq_any(comment_ids)))
+        .execute(conn)
+        .await;
+    }
+
+    let post_ids = instance_posts().limit(limit).load::<PostId>(conn).await?;
+    if !post_ids.is_empty() {
+      return diesel::delete(post::table.filter(post::id.eq_any(post_ids)))
+        .execute(conn)
+        .await;
+    }
+
+    let community_ids = community::table
+      .filter(community::instance_id.eq(instance_id))
+      .filter(not(
+        community::id.eq_any(post::table.select(post::community_id)),
+      ))
+      .select(community::id)
+      .limit(limit)
+      .load::<CommunityId>(conn)
+      .await?;
+    if !community_ids.is_empty() {
+      return diesel::delete(community::table.filter(community::id.eq_any(community_ids)))
+        .execute(conn)
+        .await;
+    }
+
+    let person_ids = instance_persons()
+      .limit(limit)
+      .load::<PersonId>(conn)
+      .await?;
+    diesel::delete(person::table.filter(person::id.eq_any(person_ids)))
+      .execute(conn)
+      .await
+  }
+
+  /// Returns up to `limit` persons of the instance with an id above `after`, ordered by id, so
+  /// that their images can be purged before [`Instance::purge_content_batch`] deletes them.
+  pub async fn persons_batch(
+    pool: &DbPool,
+    instance_id: InstanceId,
+    after: Option<PersonId>,
+    limit: i64,
+  ) -> Result<Vec<Person>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    let mut query = person::table
+      .filter(person::instance_id.eq(instance_id))
+      .into_boxed();
+    if let Some(after) = after {
+      query = query.filter(person::id.gt(after));
+    }
+    query
+      .order_by(person::id)
+      .limit(limit)
+      .load::<Person>(conn)
+      .await
+  }
+
+  /// The communities which [`Instance::purge_content_batch`] deletes, because they only contain
+  /// posts of persons of the instance
+  pub async fn communities_to_purge(
+    pool: &DbPool,
+    instance_id: InstanceId,
+  ) -> Result<Vec<Community>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    let instance_persons = person::table
+      .filter(person::instance_id.eq(instance_id))
+      .select(person::id);
+    let foreign_posts = post::table
+      .filter(not(post::creator_id.eq_any(instance_persons)))
+      .select(post::community_id);
+    community::table
+      .filter(community::instance_id.eq(instance_id))
+      .filter(not(community::id.eq_any(foreign_posts)))
+      .load::<Community>(conn)
+      .await
+  }
+
   pub async fn linked(pool: &DbPool) -> Result<Vec<Self>, Error> {
     let conn = &mut get_conn(pool).await?;
     instance::table
@@ -0,0 +1,453 @@
+use crate::{
+  newtypes::{CommentId, CommunityId, InstanceId, PersonId, PostId},
+  schema::{
+    comment,
+    community,
+    instance_block_hidden,
+    mod_ban,
+    mod_remove_comment,
+    mod_remove_community,
+    mod_remove_post,
+    person,
+    post,
+  },
+  source::instance_block_hidden::{InstanceBlockHidden, InstanceBlockHiddenForm},
+  utils::{get_conn, DbPool},
+};
+use chrono::NaiveDateTime;
+use diesel::{dsl::insert_into, result::Error, ExpressionMethods, QueryDsl};
+use diesel_async::{AsyncPgConnection, RunQueryDsl};
+
+impl InstanceBlockHidden {
+  /// Removes up to `limit` communities, posts or comments from the instance, or bans up to `limit`
+  /// of its persons, and remembers them so that they can be restored later. Returns the number of
+  /// hidden items, zero once everything is hidden.
+  pub async fn hide_batch(
+    pool: &DbPool,
+    for_instance_id: InstanceId,
+    limit: i64,
+  ) -> Result<usize, Error> {
+    let conn = &mut get_conn(pool).await?;
+    conn
+      .build_transaction()
+      .run(|conn| {
+        Box::pin(async move {
+          let hidden = hide_communities(conn, for_instance_id, limit).await?;
+          if hidden > 0 {
+            return Ok(hidden);
+          }
+          let hidden = hide_posts(conn, for_instance_id, limit).await?;
+          if hidden > 0 {
+            return Ok(hidden);
+          }
+          let hidden = hide_comments(conn, for_instance_id, limit).await?;
+          if hidden > 0 {
+            return Ok(hidden);
+          }
+          hide_persons(conn, for_instance_id, limit).await
+        }) as _
+      })
+      .await
+  }
+
+  /// Restores up to `limit` items which were hidden by [`InstanceBlockHidden::hide_batch`], unless
+  /// a moderator removed them after they were hidden. Returns the number of processed items, zero
+  /// once everything is restored.
+  pub async fn restore_batch(
+    pool: &DbPool,
+    for_instance_id: InstanceId,
+    limit: i64,
+  ) -> Result<usize, Error> {
+    let conn = &mut get_conn(pool).await?;
+    conn
+      .build_transaction()
+      .run(|conn| {
+        Box::pin(async move {
+          let hidden = instance_block_hidden::table
+            .filter(instance_block_hidden::instance_id.eq(for_instance_id))
+            .limit(limit)
+            .load::<Self>(conn)
+            .await?;
+          let community_ids: Vec<CommunityId> =
+            hidden.iter().filter_map(|h| h.community_id).collect();
+          let post_ids: Vec<PostId> = hidden.iter().filter_map(|h| h.post_id).collect();
+          let comment_ids: Vec<CommentId> = hidden.iter().filter_map(|h| h.comment_id).collect();
+          let person_ids: Vec<PersonId> = hidden.iter().filter_map(|h| h.person_id).collect();
+
+          // Items which a moderator removed while they were hidden stay removed
+          let community_removals = mod_remove_community::table
+            .filter(mod_remove_community::community_id.eq_any(&community_ids))
+            .select((
+              mod_remove_community::community_id,
+              mod_remove_community::when_,
+            ))
+            .load::<(CommunityId, NaiveDateTime)>(conn)
+            .await?;
+          let post_removals = mod_remove_post::table
+            .filter(mod_remove_post::post_id.eq_any(&post_ids))
+            .select((mod_remove_post::post_id, mod_remove_post::when_))
+            .load::<(PostId, NaiveDateTime)>(conn)
+            .await?;
+          let comment_removals = mod_remove_comment::table
+            .filter(mod_remove_comment::comment_id.eq_any(&comment_ids))
+            .select((mod_remove_comment::comment_id, mod_remove_comment::when_))
+            .load::<(CommentId, NaiveDateTime)>(conn)
+            .await?;
+          let person_bans = mod_ban::table
+            .filter(mod_ban::other_person_id.eq_any(&person_ids))
+            .select((mod_ban::other_person_id, mod_ban::when_))
+            .load::<(PersonId, NaiveDateTime)>(conn)
+            .await?;
+          let removed_later = |h: &InstanceBlockHidden| {
+            community_removals
+              .iter()
+              .any(|(id, when)| Some(*id) == h.community_id && when > &h.published)
+              || post_removals
+                .iter()
+                .any(|(id, when)| Some(*id) == h.post_id && when > &h.published)
+              || comment_removals
+                .iter()
+                .any(|(id, when)| Some(*id) == h.comment_id && when > &h.published)
+              || person_bans
+                .iter()
+                .any(|(id, when)| Some(*id) == h.person_id && when > &h.published)
+          };
+          let restored: Vec<&Self> = hidden.iter().filter(|h| !removed_later(h)).collect();
+          let community_ids: Vec<CommunityId> =
+            restored.iter().filter_map(|h| h.community_id).collect();
+          let post_ids: Vec<PostId> = restored.iter().filter_map(|h| h.post_id).collect();
+          let comment_ids: Vec<CommentId> = restored.iter().filter_map(|h| h.comment_id).collect();
+          let person_ids: Vec<PersonId> = restored.iter().filter_map(|h| h.person_id).collect();
+
+          diesel::update(community::table.filter(community::id.eq_any(community_ids)))
+            .set(community::removed.eq(false))
+            .execute(conn)
+            .await?;
+          diesel::update(post::table.filter(post::id.eq_any(post_ids)))
+            .set(post::removed.eq(false))
+            .execute(conn)
+            .await?;
+          diesel::update(comment::table.filter(comment::id.eq_any(comment_ids)))
+            .set(comment::removed.eq(false))
+            .execute(conn)
+            .await?;
+          diesel::update(person::table.filter(person::id.eq_any(person_ids)))
+            .set(person::banned.eq(false))
+            .execute(conn)
+            .await?;
+          let ids: Vec<i32> = hidden.iter().map(|h| h.id).collect();
+          diesel::delete(instance_block_hidden::table.filter(instance_block_hidden::id.eq_any(ids)))
+            .execute(conn)
+            .await
+        }) as _
+      })
+      .await
+  }
+}
+
+async fn hide_communities(
+  conn: &mut AsyncPgConnection,
+  for_instance_id: InstanceId,
+  limit: i64,
+) -> Result<usize, Error> {
+  let ids = community::table
+    .filter(community::instance_id.eq(for_instance_id))
+    .filter(community::removed.eq(false))
+    .select(community::id)
+    .limit(limit)
+    .load::<CommunityId>(conn)
+    .await?;
+  let forms: Vec<_> = ids
+    .iter()
+    .map(|id| InstanceBlockHiddenForm {
+      instance_id: for_instance_id,
+      community_id: Some(*id),
+      ..Default::default()
+    })
+    .collect();
+  insert_into(instance_block_hidden::table)
+    .values(forms)
+    .execute(conn)
+    .await?;
+  diesel::update(community::table.filter(community::id.eq_any(ids)))
+    .set(community::removed.eq(true))
+    .execute(conn)
+    .await
+}
+
+/// Posts which were created by users of the instance. Posts of other users in its communities are
+/// hidden together with the community.
+async fn hide_posts(
+  conn: &mut AsyncPgConnection,
+  for_instance_id: InstanceId,
+  limit: i64,
+) -> Result<usize, Error> {
+  let instance_persons = person::table
+    .filter(person::instance_id.eq(for_instance_id))
+    .select(person::id);
+  let ids = post::table
+    .filter(post::creator_id.eq_any(instance_persons))
+    .filter(post::removed.eq(false))
+    .select(post::id)
+    .limit(limit)
+    .load::<PostId>(conn)
+    .await?;
+  let forms: Vec<_> = ids
+    .iter()
+    .map(|id| InstanceBlockHiddenForm {
+      instance_id: for_instance_id,
+      post_id: Some(*id),
+      ..Default::default()
+    })
+    .collect();
+  insert_into(instance_block_hidden::table)
+    .values(forms)
+    .execute(conn)
+    .await?;
+  diesel::update(post::table.filter(post::id.eq_any(ids)))
+    .set(post::removed.eq(true))
+    .execute(conn)
+    .await
+}
+
+/// Comments which were created by users of the instance
+async fn hide_comments(
+  conn: &mut AsyncPgConnection,
+  for_instance_id: InstanceId,
+  limit: i64,
+) -> Result<usize, Error> {
+  let instance_persons = person::table
+    .filter(person::instance_id.eq(for_instance_id))
+    .select(person::id);
+  let ids = comment::table
+    .filter(comment::creator_id.eq_any(instance_persons))
+    .filter(comment::removed.eq(false))
+    .select(comment::id)
+    .limit(limit)
+    .load::<CommentId>(conn)
+    .await?;
+  let forms: Vec<_> = ids
+    .iter()
+    .map(|id| InstanceBlockHiddenForm {
+      instance_id: for_instance_id,
+      comment_id: Some(*id),
+      ..Default::default()
+    })
+    .collect();
+  insert_into(instance_block_hidden::table)
+    .values(forms)
+    .execute(conn)
+    .await?;
+  diesel::update(comment::table.filter(comment::id.eq_any(ids)))
+    .set(comment::removed.eq(true))
+    .execute(conn)
+    .await
+}
+
+/// Persons of the instance are hidden by banning them from the site
+async fn hide_persons(
+  conn: &mut AsyncPgConnection,
+  for_instance_id: InstanceId,
+  limit: i64,
+) -> Result<usize, Error> {
+  let ids = person::table
+    .filter(person::instance_id.eq(for_instance_id))
+    .filter(person::banned.eq(false))
+    .select(person::id)
+    .limit(limit)
+    .load::<PersonId>(conn)
+    .await?;
+  let forms: Vec<_> = ids
+    .iter()
+    .map(|id| InstanceBlockHiddenForm {
+      instance_id: for_instance_id,
+      person_id: Some(*id),
+      ..Default::default()
+    })
+    .collect();
+  insert_into(instance_block_hidden::table)
+    .values(forms)
+    .execute(conn)
+    .await?;
+  diesel::update(person::table.filter(person::id.eq_any(ids)))
+    .set(person::banned.eq(true))
+    .execute(conn)
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::{
+    source::{
+      comment::{Comment, CommentInsertForm},
+      community::{Community, CommunityFollower, CommunityFollowerForm, CommunityInsertForm},
+      instance::Instance,
+      instance_block_hidden::InstanceBlockHidden,
+      moderator::{ModRemovePost, ModRemovePostForm},
+      person::{Person, PersonInsertForm},
+      post::{Post, PostInsertForm},
+    },
+    traits::{Crud, Followable},
+    utils::build_db_pool_for_tests,
+  };
+  use serial_test::serial;
+
+  #[tokio::test]
+  #[serial]
+  async fn test_hide_and_purge_instance_content() {
+    let pool = &build_db_pool_for_tests().await;
+
+    let local_instance = Instance::create(pool, "my_domain.tld").await.unwrap();
+    let blocked_instance = Instance::create(pool, "blocked_domain.tld").await.unwrap();
+
+    let local_person_form = PersonInsertForm::builder()
+      .name("block_instance_local".into())
+      .public_key("pubkey".to_string())
+      .instance_id(local_instance.id)
+      .build();
+    let local_person = Person::create(pool, &local_person_form).await.unwrap();
+
+    let remote_person_form = PersonInsertForm::builder()
+      .name("block_instance_remote".into())
+      .public_key("pubkey".to_string())
+      .instance_id(blocked_instance.id)
+      .build();
+    let remote_person = Person::create(pool, &remote_person_form).await.unwrap();
+
+    let community_form = CommunityInsertForm::builder()
+      .name("block_instance_remote".to_string())
+      .title("nada".to_owned())
+      .public_key("pubkey".to_string())
+      .instance_id(blocked_instance.id)
+      .build();
+    let remote_community = Community::create(pool, &community_form).await.unwrap();
+
+    let follower_form = CommunityFollowerForm {
+      community_id: remote_community.id,
+      person_id: local_person.id,
+      pending: false,
+    };
+    CommunityFollower::follow(pool, &follower_form)
+      .await
+      .unwrap();
+
+    // A post by a local user in the remote community
+    let post_form = PostInsertForm::builder()
+      .name("A post".into())
+      .creator_id(local_person.id)
+      .community_id(remote_community.id)
+      .build();
+    let post = Post::create(pool, &post_form).await.unwrap();
+
+    // A comment by a remote user on it
+    let comment_form = CommentInsertForm::builder()
+      .content("A comment".into())
+      .creator_id(remote_person.id)
+      .post_id(post.id)
+      .build();
+    let comment = Comment::create(pool, &comment_form, None).await.unwrap();
+
+    // A post by the remote user, which a moderator removes while it is hidden
+    let remote_post_form = PostInsertForm::builder()
+      .name("A remote post".into())
+      .creator_id(remote_person.id)
+      .community_id(remote_community.id)
+      .build();
+    let remote_post = Post::create(pool, &remote_post_form).await.unwrap();
+
+    let removed_follows = Instance::remove_community_follows(pool, blocked_instance.id)
+      .await
+      .unwrap();
+
+    let mut hidden = 0;
+    loop {
+      let batch = InstanceBlockHidden::hide_batch(pool, blocked_instance.id, 1)
+        .await
+        .unwrap();
+      if batch == 0 {
+        break;
+      }
+      hidden += batch;
+    }
+    let hidden_community = Community::read(pool, remote_community.id).await.unwrap();
+    let hidden_post = Post::read(pool, post.id).await.unwrap();
+    let hidden_remote_post = Post::read(pool, remote_post.id).await.unwrap();
+    let hidden_comment = Comment::read(pool, comment.id).await.unwrap();
+    let hidden_person = Person::read(pool, remote_person.id).await.unwrap();
+    let hidden_local_person = Person::read(pool, local_person.id).await.unwrap();
+
+    // The hidden timestamp has to be before the removal
+    tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+    let mod_remove_form = ModRemovePostForm {
+      mod_person_id: local_person.id,
+      post_id: remote_post.id,
+      reason: None,
+      removed: Some(true),
+      rule_id: None,
+    };
+    ModRemovePost::create(pool, &mod_remove_form).await.unwrap();
+
+    let mut restored = 0;
+    loop {
+      let batch = InstanceBlockHidden::restore_batch(pool, blocked_instance.id, 1)
+        .await
+        .unwrap();
+      if batch == 0 {
+        break;
+      }
+      restored += batch;
+    }
+    let restored_community = Community::read(pool, remote_community.id).await.unwrap();
+    let restored_remote_post = Post::read(pool, remote_post.id).await.unwrap();
+    let restored_comment = Comment::read(pool, comment.id).await.unwrap();
+    let restored_person = Person::read(pool, remote_person.id).await.unwrap();
+
+    let persons_to_purge = Instance::persons_batch(pool, blocked_instance.id, None, 10)
+      .await
+      .unwrap();
+    let communities_to_purge = Instance::communities_to_purge(pool, blocked_instance.id)
+      .await
+      .unwrap();
+    while Instance::purge_content_batch(pool, blocked_instance.id, 1)
+      .await
+      .unwrap()
+      > 0
+    {}
+    let remaining_community = Community::read(pool, remote_community.id).await;
+    let purged_person = Person::read(pool, remote_person.id).await;
+    let remaining_post = Post::read(pool, post.id).await;
+    let purged_remote_post = Post::read(pool, remote_post.id).await;
+    let purged_comment = Comment::read(pool, comment.id).await;
+    let remaining_local_person = Person::read(pool, local_person.id).await;
+
+    // The community still contains the local post, so it is only deleted with the instance
+    Person::delete(pool, local_person.id).await.unwrap();
+    Instance::delete(pool, blocked_instance.id).await.unwrap();
+    Instance::delete(pool, local_instance.id).await.unwrap();
+
+    assert_eq!(1, removed_follows);
+    assert_eq!(4, hidden);
+    assert!(hidden_community.removed);
+    assert!(!hidden_post.removed);
+    assert!(hidden_remote_post.removed);
+    assert!(hidden_comment.removed);
+    assert!(hidden_person.banned);
+    assert!(!hidden_local_person.banned);
+    assert_eq!(4, restored);
+    assert!(!restored_community.removed);
+    assert!(restored_remote_post.removed);
+    assert!(!restored_comment.removed);
+    assert!(!restored_person.banned);
+    assert_eq!(
+      vec![remote_person.id],
+      persons_to_purge.iter().map(|p| p.id).collect::<Vec<_>>()
+    );
+    assert!(communities_to_purge.is_empty());
+    assert!(remaining_community.is_ok());
+    assert!(purged_person.is_err());
+    assert!(remaining_post.is_ok());
+    assert!(purged_remote_post.is_err());
+    assert!(purged_comment.is_err());
+    assert!(remaining_local_person.is_ok());
+  }
+}
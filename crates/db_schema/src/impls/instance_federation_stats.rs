@@ -0,0 +1,265 @@
+use crate::{
+  newtypes::InstanceId,
+  schema::{instance, instance_federation_stats as stats, instance_received_activity as received},
+  source::instance_federation_stats::{
+    InstanceFederationStats,
+    InstanceFederationStatsForm,
+    InstanceReceivedActivity,
+    InstanceReceivedActivityForm,
+  },
+  utils::{get_conn, naive_now, DbPool},
+};
+use diesel::{dsl::insert_into, result::Error, ExpressionMethods, OptionalExtension, QueryDsl};
+use diesel_async::{AsyncPgConnection, RunQueryDsl};
+
+impl InstanceFederationStats {
+  /// Records an activity which was received and processed successfully. Activities from unknown
+  /// domains are ignored.
+  pub async fn record_inbound_success(
+    pool: &DbPool,
+    domain: &str,
+    kind: &str,
+  ) -> Result<(), Error> {
+    let conn = &mut get_conn(pool).await?;
+    let instance_id = match read_instance_id(conn, domain).await? {
+      Some(i) => i,
+      None => return Ok(()),
+    };
+    let now = naive_now();
+    let form = InstanceFederationStatsForm {
+      instance_id,
+      last_inbound_success: Some(now),
+      ..Default::default()
+    };
+    insert_into(stats::table)
+      .values(form)
+      .on_conflict(stats::instance_id)
+      .do_update()
+      .set((stats::last_inbound_success.eq(now), stats::updated.eq(now)))
+      .execute(conn)
+      .await?;
+
+    let form = InstanceReceivedActivityForm {
+      instance_id,
+      kind: kind.to_string(),
+      count: 1,
+    };
+    insert_into(received::table)
+      .values(form)
+      .on_conflict((received::instance_id, received::kind))
+      .do_update()
+      .set(received::count.eq(received::count + 1))
+      .execute(conn)
+      .await?;
+    Ok(())
+  }
+
+  /// Records an activity which was received, but rejected.
+  pub async fn record_inbound_failure(pool: &DbPool, domain: &str) -> Result<(), Error> {
+    let conn = &mut get_conn(pool).await?;
+    let instance_id = match read_instance_id(conn, domain).await? {
+      Some(i) => i,
+      None => return Ok(()),
+    };
+    let form = InstanceFederationStatsForm {
+      instance_id,
+      inbound_failure_count: 1,
+      ..Default::default()
+    };
+    insert_into(stats::table)
+      .values(form)
+      .on_conflict(stats::instance_id)
+      .do_update()
+      .set((
+        stats::inbound_failure_count.eq(stats::inbound_failure_count + 1),
+        stats::updated.eq(naive_now()),
+      ))
+      .execute(conn)
+      .await?;
+    Ok(())
+  }
+
+  /// Records the result of delivering an activity to the domain, and how long the delivery took.
+  pub async fn record_outbound(
+    pool: &DbPool,
+    domain: &str,
+    success: bool,
+    duration_ms: i64,
+  ) -> Result<(), Error> {
+    let conn = &mut get_conn(pool).await?;
+    let instance_id = match read_instance_id(conn, domain).await? {
+      Some(i) => i,
+      None => return Ok(()),
+    };
+    let now = naive_now();
+    if success {
+      let form = InstanceFederationStatsForm {
+        instance_id,
+        last_outbound_success: Some(now),
+        outbound_success_count: 1,
+        delivery_time_total_ms: duration_ms,
+        ..Default::default()
+      };
+      insert_into(stats::table)
+        .values(form)
+        .on_conflict(stats::instance_id)
+        .do_update()
+        .set((
+          stats::last_outbound_success.eq(now),
+          stats::outbound_success_count.eq(stats::outbound_success_count + 1),
+          stats::delivery_time_total_ms.eq(stats::delivery_time_total_ms + duration_ms),
+          stats::updated.eq(now),
+        ))
+        .execute(conn)
+        .await?;
+    } else {
+      let form = InstanceFederationStatsForm {
+        instance_id,
+        last_outbound_failure: Some(now),
+        outbound_failure_count: 1,
+        ..Default::default()
+      };
+      insert_into(stats::table)
+        .values(form)
+        .on_conflict(stats::instance_id)
+        .do_update()
+        .set((
+          stats::last_outbound_failure.eq(now),
+          stats::outbound_failure_count.eq(stats::outbound_failure_count + 1),
+          stats::updated.eq(now),
+        ))
+        .execute(conn)
+        .await?;
+    }
+    Ok(())
+  }
+
+  /// Average time taken by successful deliveries, in milliseconds.
+  pub fn average_delivery_time_ms(&self) -> Option<i64> {
+    if self.outbound_success_count > 0 {
+      Some(self.delivery_time_total_ms / self.outbound_success_count)
+    } else {
+      None
+    }
+  }
+
+  pub async fn read_for_instance(
+    pool: &DbPool,
+    for_instance_id: InstanceId,
+  ) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    stats::table
+      .filter(stats::instance_id.eq(for_instance_id))
+      .first::<Self>(conn)
+      .await
+  }
+}
+
+impl InstanceReceivedActivity {
+  pub async fn list_for_instance(
+    pool: &DbPool,
+    for_instance_id: InstanceId,
+  ) -> Result<Vec<Self>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    received::table
+      .filter(received::instance_id.eq(for_instance_id))
+      .order_by(received::kind)
+      .load::<Self>(conn)
+      .await
+  }
+
+  pub async fn list_for_instances(
+    pool: &DbPool,
+    instance_ids: &[InstanceId],
+  ) -> Result<Vec<Self>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    received::table
+      .filter(received::instance_id.eq_any(instance_ids))
+      .order_by(received::kind)
+      .load::<Self>(conn)
+      .await
+  }
+}
+
+async fn read_instance_id(
+  conn: &mut AsyncPgConnection,
+  domain: &str,
+) -> Result<Option<InstanceId>, Error> {
+  instance::table
+    .filter(instance::domain.eq(domain))
+    .select(instance::id)
+    .first::<InstanceId>(conn)
+    .await
+    .optional()
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::{
+    source::{
+      instance::Instance,
+      instance_federation_stats::{InstanceFederationStats, InstanceReceivedActivity},
+    },
+    utils::build_db_pool_for_tests,
+  };
+  use serial_test::serial;
+
+  #[tokio::test]
+  #[serial]
+  async fn test_instance_federation_stats() {
+    let pool = &build_db_pool_for_tests().await;
+
+    let inserted_instance = Instance::create(pool, "federation-stats.tld")
+      .await
+      .unwrap();
+    let domain = inserted_instance.domain.as_str();
+
+    InstanceFederationStats::record_inbound_success(pool, domain, "Create")
+      .await
+      .unwrap();
+    InstanceFederationStats::record_inbound_success(pool, domain, "Create")
+      .await
+      .unwrap();
+    InstanceFederationStats::record_inbound_success(pool, domain, "Like")
+      .await
+      .unwrap();
+    InstanceFederationStats::record_inbound_failure(pool, domain)
+      .await
+      .unwrap();
+    InstanceFederationStats::record_outbound(pool, domain, true, 100)
+      .await
+      .unwrap();
+    InstanceFederationStats::record_outbound(pool, domain, true, 300)
+      .await
+      .unwrap();
+    InstanceFederationStats::record_outbound(pool, domain, false, 5000)
+      .await
+      .unwrap();
+    // Unknown instances are ignored
+    InstanceFederationStats::record_outbound(pool, "unknown.tld", true, 100)
+      .await
+      .unwrap();
+
+    let stats = InstanceFederationStats::read_for_instance(pool, inserted_instance.id)
+      .await
+      .unwrap();
+    let received = InstanceReceivedActivity::list_for_instance(pool, inserted_instance.id)
+      .await
+      .unwrap();
+
+    Instance::delete(pool, inserted_instance.id).await.unwrap();
+
+    assert!(stats.last_inbound_success.is_some());
+    assert_eq!(1, stats.inbound_failure_count);
+    assert!(stats.last_outbound_success.is_some());
+    assert!(stats.last_outbound_failure.is_some());
+    assert_eq!(2, stats.outbound_success_count);
+    assert_eq!(1, stats.outbound_failure_count);
+    assert_eq!(Some(200), stats.average_delivery_time_ms());
+    assert_eq!(2, received.len());
+    assert_eq!("Create", received[0].kind);
+    assert_eq!(2, received[0].count);
+    assert_eq!("Like", received[1].kind);
+    assert_eq!(1, received[1].count);
+  }
+}
@@ -1,14 +1,21 @@
 pub mod activity;
 pub mod actor_language;
+pub mod captcha_answer;
 pub mod comment;
 pub mod comment_reply;
 pub mod comment_report;
 pub mod community;
+pub mod community_backfill;
 pub mod community_block;
+pub mod community_rule;
+pub mod converted_note;
+pub mod directory_community;
 pub mod email_verification;
 pub mod federation_allowlist;
 pub mod federation_blocklist;
 pub mod instance;
+pub mod instance_block_hidden;
+pub mod instance_federation_stats;
 pub mod language;
 pub mod local_site;
 pub mod local_site_rate_limit;
@@ -22,7 +29,11 @@ pub mod post;
 pub mod post_report;
 pub mod private_message;
 pub mod private_message_report;
+pub mod rate_limit_bucket;
 pub mod registration_application;
+pub mod relay;
+pub mod report_comment;
 pub mod secret;
 pub mod site;
 pub mod tagline;
+pub mod websocket_message;
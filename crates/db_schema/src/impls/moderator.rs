@@ -1,5 +1,8 @@
 use crate::{
+  newtypes::{CommunityId, PersonId},
   source::moderator::{
+    AdminBlockInstance,
+    AdminBlockInstanceForm,
     AdminPurgeComment,
     AdminPurgeCommentForm,
     AdminPurgeCommunity,
@@ -34,7 +37,7 @@ use crate::{
   traits::Crud,
   utils::{get_conn, DbPool},
 };
-use diesel::{dsl::insert_into, result::Error, QueryDsl};
+use diesel::{dsl::insert_into, result::Error, ExpressionMethods, QueryDsl};
 use diesel_async::RunQueryDsl;
 
 #[async_trait]
@@ -228,6 +231,31 @@ impl Crud for ModBanFromCommunity {
   }
 }
 
+impl ModBanFromCommunity {
+  /// Reads the most recent community ban of a person, to find out which mod issued it.
+  pub async fn read_latest_ban(
+    pool: &DbPool,
+    for_community_id: CommunityId,
+    for_person_id: PersonId,
+  ) -> Result<Self, Error> {
+    use crate::schema::mod_ban_from_community::dsl::{
+      banned,
+      community_id,
+      mod_ban_from_community,
+      other_person_id,
+      when_,
+    };
+    let conn = &mut get_conn(pool).await?;
+    mod_ban_from_community
+      .filter(community_id.eq(for_community_id))
+      .filter(other_person_id.eq(for_person_id))
+      .filter(banned.eq(true))
+      .order_by(when_.desc())
+      .first::<Self>(conn)
+      .await
+  }
+}
+
 #[async_trait]
 impl Crud for ModBan {
   type InsertForm = ModBanForm;
@@ -509,6 +537,36 @@ impl Crud for AdminPurgeComment {
   }
 }
 
+#[async_trait]
+impl Crud for AdminBlockInstance {
+  type InsertForm = AdminBlockInstanceForm;
+  type UpdateForm = AdminBlockInstanceForm;
+  type IdType = i32;
+  async fn read(pool: &DbPool, from_id: i32) -> Result<Self, Error> {
+    use crate::schema::admin_block_instance::dsl::admin_block_instance;
+    let conn = &mut get_conn(pool).await?;
+    admin_block_instance.find(from_id).first::<Self>(conn).await
+  }
+
+  async fn create(pool: &DbPool, form: &Self::InsertForm) -> Result<Self, Error> {
+    use crate::schema::admin_block_instance::dsl::admin_block_instance;
+    let conn = &mut get_conn(pool).await?;
+    insert_into(admin_block_instance)
+      .values(form)
+      .get_result::<Self>(conn)
+      .await
+  }
+
+  async fn update(pool: &DbPool, from_id: i32, form: &Self::InsertForm) -> Result<Self, Error> {
+    use crate::schema::admin_block_instance::dsl::admin_block_instance;
+    let conn = &mut get_conn(pool).await?;
+    diesel::update(admin_block_instance.find(from_id))
+      .set(form)
+      .get_result::<Self>(conn)
+      .await
+  }
+}
+
 #[cfg(test)]
 mod tests {
   use crate::{
@@ -600,6 +658,7 @@ mod tests {
       post_id: inserted_post.id,
       reason: None,
       removed: None,
+      rule_id: None,
     };
     let inserted_mod_remove_post = ModRemovePost::create(pool, &mod_remove_post_form)
       .await
@@ -614,6 +673,7 @@ mod tests {
       reason: None,
       removed: Some(true),
       when_: inserted_mod_remove_post.when_,
+      rule_id: None,
     };
 
     // lock post
@@ -667,6 +727,7 @@ mod tests {
       comment_id: inserted_comment.id,
       reason: None,
       removed: None,
+      rule_id: None,
     };
     let inserted_mod_remove_comment = ModRemoveComment::create(pool, &mod_remove_comment_form)
       .await
@@ -681,6 +742,7 @@ mod tests {
       reason: None,
       removed: Some(true),
       when_: inserted_mod_remove_comment.when_,
+      rule_id: None,
     };
 
     // community
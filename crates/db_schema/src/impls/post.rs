@@ -1,5 +1,6 @@
 use crate::{
   newtypes::{CommunityId, DbUrl, PersonId, PostId},
+  schema,
   schema::post::dsl::{
     ap_id,
     body,
@@ -7,6 +8,7 @@ use crate::{
     creator_id,
     deleted,
     featured_community,
+    id,
     name,
     post,
     published,
@@ -15,22 +17,33 @@ use crate::{
     updated,
     url,
   },
-  source::post::{
-    Post,
-    PostInsertForm,
-    PostLike,
-    PostLikeForm,
-    PostRead,
-    PostReadForm,
-    PostSaved,
-    PostSavedForm,
-    PostUpdateForm,
+  source::{
+    community::Community,
+    post::{
+      Post,
+      PostInsertForm,
+      PostLike,
+      PostLikeForm,
+      PostRead,
+      PostReadForm,
+      PostSaved,
+      PostSavedForm,
+      PostUpdateForm,
+    },
   },
   traits::{Crud, DeleteableOrRemoveable, Likeable, Readable, Saveable},
   utils::{get_conn, naive_now, DbPool, FETCH_LIMIT_MAX},
 };
 use ::url::Url;
-use diesel::{dsl::insert_into, result::Error, ExpressionMethods, QueryDsl, TextExpressionMethods};
+use diesel::{
+  dsl::{exists, insert_into},
+  result::Error,
+  select,
+  BoolExpressionMethods,
+  ExpressionMethods,
+  QueryDsl,
+  TextExpressionMethods,
+};
 use diesel_async::RunQueryDsl;
 
 #[async_trait]
@@ -89,6 +102,79 @@ impl Post {
       .await
   }
 
+  /// Posts of a community, newest first, optionally only those published before the given time.
+  /// Unlike `list_for_community` this ignores featured posts, and is used to page through the
+  /// whole community history.
+  pub async fn list_for_community_before(
+    pool: &DbPool,
+    the_community_id: CommunityId,
+    before: Option<chrono::NaiveDateTime>,
+    limit: i64,
+  ) -> Result<Vec<Self>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    let mut query = post
+      .filter(community_id.eq(the_community_id))
+      .filter(deleted.eq(false))
+      .filter(removed.eq(false))
+      .into_boxed();
+    if let Some(before) = before {
+      query = query.filter(published.lt(before));
+    }
+    query
+      .order_by(published.desc())
+      .limit(limit)
+      .load::<Self>(conn)
+      .await
+  }
+
+  /// Public posts of a person together with their communities, newest first. Posts in removed
+  /// or deleted communities are left out. If `before` is given, only posts ordered after that
+  /// (published, id) pair are returned. Used to page through the person's outbox.
+  pub async fn list_for_creator(
+    pool: &DbPool,
+    for_creator_id: PersonId,
+    before: Option<(chrono::NaiveDateTime, PostId)>,
+    limit: i64,
+  ) -> Result<Vec<(Self, Community)>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    let mut query = post
+      .inner_join(schema::community::table)
+      .filter(creator_id.eq(for_creator_id))
+      .filter(deleted.eq(false))
+      .filter(removed.eq(false))
+      .filter(schema::community::deleted.eq(false))
+      .filter(schema::community::removed.eq(false))
+      .select((schema::post::all_columns, schema::community::all_columns))
+      .into_boxed();
+    if let Some((before_published, before_id)) = before {
+      query = query.filter(
+        published
+          .lt(before_published)
+          .or(published.eq(before_published).and(id.lt(before_id))),
+      );
+    }
+    query
+      .order_by((published.desc(), id.desc()))
+      .limit(limit)
+      .load::<(Self, Community)>(conn)
+      .await
+  }
+
+  /// Number of posts returned by [`Post::list_for_creator`] without paging
+  pub async fn count_for_creator(pool: &DbPool, for_creator_id: PersonId) -> Result<i64, Error> {
+    let conn = &mut get_conn(pool).await?;
+    post
+      .inner_join(schema::community::table)
+      .filter(creator_id.eq(for_creator_id))
+      .filter(deleted.eq(false))
+      .filter(removed.eq(false))
+      .filter(schema::community::deleted.eq(false))
+      .filter(schema::community::removed.eq(false))
+      .count()
+      .get_result(conn)
+      .await
+  }
+
   pub async fn list_featured_for_community(
     pool: &DbPool,
     the_community_id: CommunityId,
@@ -232,6 +318,156 @@ impl Post {
     .get_results::<Self>(conn)
     .await
   }
+
+  /// Whether any post links to the image or uses it as thumbnail
+  pub async fn uses_image(pool: &DbPool, image_url: &DbUrl) -> Result<bool, Error> {
+    let conn = &mut get_conn(pool).await?;
+    select(exists(
+      post.filter(url.eq(image_url).or(thumbnail_url.eq(image_url))),
+    ))
+    .get_result(conn)
+    .await
+  }
+
+  /// Deletes up to `limit` remote posts, with their comments, which were neither published nor
+  /// commented since `older_than`, in remote communities without local followers. Posts are kept
+  /// if a local user created, saved, voted on, read or reported them or one of their comments, or
+  /// was mentioned or replied to in them. Posts with local modlog entries about them or their
+  /// comments are kept as well, as deleting them would erase the entries. Returns the deleted
+  /// posts, empty once nothing is left.
+  pub async fn prune_remote_batch(
+    pool: &DbPool,
+    older_than: chrono::NaiveDateTime,
+    limit: i64,
+  ) -> Result<Vec<Self>, Error> {
+    use crate::schema::{
+      admin_purge_comment,
+      comment,
+      comment_like,
+      comment_reply,
+      comment_report,
+      comment_saved,
+      community,
+      community_follower,
+      mod_feature_post,
+      mod_lock_post,
+      mod_remove_comment,
+      mod_remove_post,
+      person,
+      person_mention,
+      post as post_table,
+      post_aggregates,
+      post_like,
+      post_read,
+      post_report,
+      post_saved,
+    };
+    use diesel::dsl::not;
+    let conn = &mut get_conn(pool).await?;
+
+    let local_persons = || person::table.filter(person::local).select(person::id);
+    let followed_communities = community_follower::table
+      .filter(community_follower::person_id.eq_any(local_persons()))
+      .select(community_follower::community_id);
+    let unfollowed_communities = community::table
+      .filter(not(community::local))
+      .filter(not(community::id.eq_any(followed_communities)))
+      .select(community::id);
+    let prunable = post_table::table
+      .inner_join(post_aggregates::table)
+      .filter(not(post_table::local))
+      .filter(post_table::community_id.eq_any(unfollowed_communities))
+      .filter(post_table::published.lt(older_than))
+      .filter(post_aggregates::newest_comment_time.lt(older_than))
+      .filter(not(
+        post_table::id.eq_any(post_saved::table.select(post_saved::post_id)),
+      ))
+      .filter(not(
+        post_table::id.eq_any(
+          post_like::table
+            .filter(post_like::person_id.eq_any(local_persons()))
+            .select(post_like::post_id),
+        ),
+      ))
+      .filter(not(
+        post_table::id.eq_any(post_read::table.select(post_read::post_id)),
+      ))
+      .filter(not(
+        post_table::id.eq_any(post_report::table.select(post_report::post_id)),
+      ))
+      .filter(not(
+        post_table::id.eq_any(
+          comment::table
+            .filter(comment::creator_id.eq_any(local_persons()))
+            .select(comment::post_id),
+        ),
+      ))
+      .filter(not(
+        post_table::id.eq_any(
+          comment_like::table
+            .filter(comment_like::person_id.eq_any(local_persons()))
+            .select(comment_like::post_id),
+        ),
+      ))
+      .filter(not(
+        post_table::id.eq_any(
+          comment::table
+            .filter(
+              comment::id
+                .eq_any(comment_saved::table.select(comment_saved::comment_id))
+                .or(comment::id.eq_any(comment_report::table.select(comment_report::comment_id)))
+                .or(comment::id.eq_any(comment_reply::table.select(comment_reply::comment_id)))
+                .or(comment::id.eq_any(person_mention::table.select(person_mention::comment_id))),
+            )
+            .select(comment::post_id),
+        ),
+      ))
+      .filter(not(
+        post_table::id.eq_any(
+          mod_remove_post::table
+            .filter(mod_remove_post::mod_person_id.eq_any(local_persons()))
+            .select(mod_remove_post::post_id),
+        ),
+      ))
+      .filter(not(
+        post_table::id.eq_any(
+          mod_lock_post::table
+            .filter(mod_lock_post::mod_person_id.eq_any(local_persons()))
+            .select(mod_lock_post::post_id),
+        ),
+      ))
+      .filter(not(
+        post_table::id.eq_any(
+          mod_feature_post::table
+            .filter(mod_feature_post::mod_person_id.eq_any(local_persons()))
+            .select(mod_feature_post::post_id),
+        ),
+      ))
+      .filter(not(post_table::id.eq_any(
+        admin_purge_comment::table.select(admin_purge_comment::post_id),
+      )))
+      .filter(not(
+        post_table::id.eq_any(
+          comment::table
+            .filter(
+              comment::id.eq_any(
+                mod_remove_comment::table
+                  .filter(mod_remove_comment::mod_person_id.eq_any(local_persons()))
+                  .select(mod_remove_comment::comment_id),
+              ),
+            )
+            .select(comment::post_id),
+        ),
+      ))
+      .select(post_table::id)
+      .limit(limit)
+      .load::<PostId>(conn)
+      .await?;
+
+    diesel::delete(post_table::table.filter(post_table::id.eq_any(prunable)))
+      .get_results::<Self>(conn)
+      .await
+  }
 }
 
 #[async_trait]
@@ -337,6 +573,7 @@ mod tests {
     source::{
       community::{Community, CommunityInsertForm},
       instance::Instance,
+      moderator::{ModRemovePost, ModRemovePostForm},
       person::{Person, PersonInsertForm},
       post::{
         Post,
@@ -351,8 +588,9 @@ mod tests {
       },
     },
     traits::{Crud, Likeable, Readable, Saveable},
-    utils::build_db_pool_for_tests,
+    utils::{build_db_pool_for_tests, naive_now},
   };
+  use chrono::Duration;
   use serial_test::serial;
 
   #[tokio::test]
@@ -460,6 +698,30 @@ mod tests {
 
     let read_post = Post::read(pool, inserted_post.id).await.unwrap();
 
+    // A second post published at the same time must not be skipped when paging
+    let same_time_post_form = PostInsertForm::builder()
+      .name("Another test post".into())
+      .creator_id(inserted_person.id)
+      .community_id(inserted_community.id)
+      .published(Some(inserted_post.published))
+      .build();
+    let same_time_post = Post::create(pool, &same_time_post_form).await.unwrap();
+    let creator_posts = Post::list_for_creator(pool, inserted_person.id, None, 1)
+      .await
+      .unwrap();
+    let creator_posts_next = Post::list_for_creator(
+      pool,
+      inserted_person.id,
+      Some((same_time_post.published, same_time_post.id)),
+      10,
+    )
+    .await
+    .unwrap();
+    let creator_post_count = Post::count_for_creator(pool, inserted_person.id)
+      .await
+      .unwrap();
+    Post::delete(pool, same_time_post.id).await.unwrap();
+
     let new_post_update = PostUpdateForm::builder()
       .name(Some("A test post".into()))
       .build();
@@ -482,6 +744,11 @@ mod tests {
     Instance::delete(pool, inserted_instance.id).await.unwrap();
 
     assert_eq!(expected_post, read_post);
+    assert_eq!(same_time_post.id, creator_posts[0].0.id);
+    assert_eq!(inserted_community.id, creator_posts[0].1.id);
+    assert_eq!(1, creator_posts_next.len());
+    assert_eq!(expected_post, creator_posts_next[0].0);
+    assert_eq!(2, creator_post_count);
     assert_eq!(expected_post, inserted_post);
     assert_eq!(expected_post, updated_post);
     assert_eq!(expected_post_like, inserted_post_like);
@@ -492,4 +759,100 @@ mod tests {
     assert_eq!(1, read_removed);
     assert_eq!(1, num_deleted);
   }
+
+  #[tokio::test]
+  #[serial]
+  async fn test_prune_remote_batch() {
+    let pool = &build_db_pool_for_tests().await;
+
+    let inserted_instance = Instance::create(pool, "my_domain.tld").await.unwrap();
+
+    let local_person = PersonInsertForm::builder()
+      .name("prune_local".into())
+      .public_key("pubkey".to_string())
+      .instance_id(inserted_instance.id)
+      .build();
+    let local_person = Person::create(pool, &local_person).await.unwrap();
+
+    let remote_person = PersonInsertForm::builder()
+      .name("prune_remote".into())
+      .public_key("pubkey".to_string())
+      .local(Some(false))
+      .instance_id(inserted_instance.id)
+      .build();
+    let remote_person = Person::create(pool, &remote_person).await.unwrap();
+
+    let remote_community = CommunityInsertForm::builder()
+      .name("prune_community".to_string())
+      .title("nada".to_owned())
+      .public_key("pubkey".to_string())
+      .local(Some(false))
+      .instance_id(inserted_instance.id)
+      .build();
+    let remote_community = Community::create(pool, &remote_community).await.unwrap();
+
+    let remote_post = |name: &str, published: chrono::NaiveDateTime| {
+      PostInsertForm::builder()
+        .name(name.into())
+        .creator_id(remote_person.id)
+        .community_id(remote_community.id)
+        .local(Some(false))
+        .published(Some(published))
+        .build()
+    };
+    let old = naive_now() - Duration::days(60);
+    let old_post = Post::create(pool, &remote_post("old post", old))
+      .await
+      .unwrap();
+    let saved_post = Post::create(pool, &remote_post("saved post", old))
+      .await
+      .unwrap();
+    let new_post = Post::create(pool, &remote_post("new post", naive_now()))
+      .await
+      .unwrap();
+
+    let post_saved_form = PostSavedForm {
+      post_id: saved_post.id,
+      person_id: local_person.id,
+    };
+    PostSaved::save(pool, &post_saved_form).await.unwrap();
+
+    // A post which a local moderator removed stays in the modlog
+    let removed_post = Post::create(pool, &remote_post("removed post", old))
+      .await
+      .unwrap();
+    let mod_remove_form = ModRemovePostForm {
+      mod_person_id: local_person.id,
+      post_id: removed_post.id,
+      reason: None,
+      removed: Some(true),
+      rule_id: None,
+    };
+    ModRemovePost::create(pool, &mod_remove_form).await.unwrap();
+
+    let older_than = naive_now() - Duration::days(30);
+    let pruned = Post::prune_remote_batch(pool, older_than, 10)
+      .await
+      .unwrap();
+    let pruned_again = Post::prune_remote_batch(pool, older_than, 10)
+      .await
+      .unwrap();
+    let saved_post_read = Post::read(pool, saved_post.id).await;
+    let new_post_read = Post::read(pool, new_post.id).await;
+    let removed_post_read = Post::read(pool, removed_post.id).await;
+
+    Community::delete(pool, remote_community.id).await.unwrap();
+    Person::delete(pool, local_person.id).await.unwrap();
+    Person::delete(pool, remote_person.id).await.unwrap();
+    Instance::delete(pool, inserted_instance.id).await.unwrap();
+
+    assert_eq!(
+      vec![old_post.id],
+      pruned.iter().map(|p| p.id).collect::<Vec<_>>()
+    );
+    assert!(pruned_again.is_empty());
+    assert!(saved_post_read.is_ok());
+    assert!(new_post_read.is_ok());
+    assert!(removed_post_read.is_ok());
+  }
 }
@@ -1,6 +1,16 @@
 use crate::{
-  newtypes::{PersonId, PostReportId},
-  schema::post_report::dsl::{post_report, resolved, resolver_id, updated},
+  newtypes::{PersonId, PostId, PostReportId},
+  schema::post_report::dsl::{
+    assignee_id,
+    creator_id,
+    escalated,
+    post_id,
+    post_report,
+    resolution_reason,
+    resolved,
+    resolver_id,
+    updated,
+  },
   source::post_report::{PostReport, PostReportForm},
   traits::Reportable,
   utils::{get_conn, naive_now, DbPool},
@@ -17,6 +27,7 @@ use diesel_async::RunQueryDsl;
 impl Reportable for PostReport {
   type Form = PostReportForm;
   type IdType = PostReportId;
+  type ObjectIdType = PostId;
 
   async fn report(pool: &DbPool, post_report_form: &PostReportForm) -> Result<Self, Error> {
     let conn = &mut get_conn(pool).await?;
@@ -30,18 +41,42 @@ impl Reportable for PostReport {
     pool: &DbPool,
     report_id: Self::IdType,
     by_resolver_id: PersonId,
+    reason: Option<String>,
   ) -> Result<usize, Error> {
     let conn = &mut get_conn(pool).await?;
     update(post_report.find(report_id))
       .set((
         resolved.eq(true),
         resolver_id.eq(by_resolver_id),
+        resolution_reason.eq(reason),
         updated.eq(naive_now()),
       ))
       .execute(conn)
       .await
   }
 
+  async fn resolve_all_for_object(
+    pool: &DbPool,
+    object_id: Self::ObjectIdType,
+    by_resolver_id: PersonId,
+    reason: Option<String>,
+  ) -> Result<Vec<Self>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    update(
+      post_report
+        .filter(post_id.eq(object_id))
+        .filter(resolved.eq(false)),
+    )
+    .set((
+      resolved.eq(true),
+      resolver_id.eq(by_resolver_id),
+      resolution_reason.eq(reason),
+      updated.eq(naive_now()),
+    ))
+    .get_results::<Self>(conn)
+    .await
+  }
+
   async fn unresolve(
     pool: &DbPool,
     report_id: Self::IdType,
@@ -52,9 +87,62 @@ impl Reportable for PostReport {
       .set((
         resolved.eq(false),
         resolver_id.eq(by_resolver_id),
+        resolution_reason.eq(None::<String>),
         updated.eq(naive_now()),
       ))
       .execute(conn)
       .await
   }
+
+  async fn assign(
+    pool: &DbPool,
+    report_id: Self::IdType,
+    for_assignee_id: Option<PersonId>,
+  ) -> Result<usize, Error> {
+    let conn = &mut get_conn(pool).await?;
+    update(post_report.find(report_id))
+      .set((assignee_id.eq(for_assignee_id), updated.eq(naive_now())))
+      .execute(conn)
+      .await
+  }
+
+  async fn escalate(
+    pool: &DbPool,
+    report_id: Self::IdType,
+    is_escalated: bool,
+  ) -> Result<usize, Error> {
+    let conn = &mut get_conn(pool).await?;
+    update(post_report.find(report_id))
+      .set((escalated.eq(is_escalated), updated.eq(naive_now())))
+      .execute(conn)
+      .await
+  }
+}
+
+impl PostReport {
+  /// Resolves the open reports which a person made on a post. This is used when the resolution
+  /// is federated back to the instance of the reporter.
+  pub async fn resolve_for_creator(
+    pool: &DbPool,
+    for_post_id: PostId,
+    for_creator_id: PersonId,
+    by_resolver_id: PersonId,
+    reason: Option<String>,
+  ) -> Result<Vec<Self>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    update(
+      post_report
+        .filter(post_id.eq(for_post_id))
+        .filter(creator_id.eq(for_creator_id))
+        .filter(resolved.eq(false)),
+    )
+    .set((
+      resolved.eq(true),
+      resolver_id.eq(by_resolver_id),
+      resolution_reason.eq(reason),
+      updated.eq(naive_now()),
+    ))
+    .get_results::<Self>(conn)
+    .await
+  }
 }
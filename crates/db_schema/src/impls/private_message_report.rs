@@ -1,6 +1,15 @@
 use crate::{
-  newtypes::{PersonId, PrivateMessageReportId},
-  schema::private_message_report::dsl::{private_message_report, resolved, resolver_id, updated},
+  newtypes::{PersonId, PrivateMessageId, PrivateMessageReportId},
+  schema::private_message_report::dsl::{
+    assignee_id,
+    escalated,
+    private_message_id,
+    private_message_report,
+    resolution_reason,
+    resolved,
+    resolver_id,
+    updated,
+  },
   source::private_message_report::{PrivateMessageReport, PrivateMessageReportForm},
   traits::Reportable,
   utils::{get_conn, naive_now, DbPool},
@@ -17,6 +26,7 @@ use diesel_async::RunQueryDsl;
 impl Reportable for PrivateMessageReport {
   type Form = PrivateMessageReportForm;
   type IdType = PrivateMessageReportId;
+  type ObjectIdType = PrivateMessageId;
 
   async fn report(pool: &DbPool, pm_report_form: &PrivateMessageReportForm) -> Result<Self, Error> {
     let conn = &mut get_conn(pool).await?;
@@ -30,18 +40,42 @@ impl Reportable for PrivateMessageReport {
     pool: &DbPool,
     report_id: Self::IdType,
     by_resolver_id: PersonId,
+    reason: Option<String>,
   ) -> Result<usize, Error> {
     let conn = &mut get_conn(pool).await?;
     update(private_message_report.find(report_id))
       .set((
         resolved.eq(true),
         resolver_id.eq(by_resolver_id),
+        resolution_reason.eq(reason),
         updated.eq(naive_now()),
       ))
       .execute(conn)
       .await
   }
 
+  async fn resolve_all_for_object(
+    pool: &DbPool,
+    object_id: Self::ObjectIdType,
+    by_resolver_id: PersonId,
+    reason: Option<String>,
+  ) -> Result<Vec<Self>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    update(
+      private_message_report
+        .filter(private_message_id.eq(object_id))
+        .filter(resolved.eq(false)),
+    )
+    .set((
+      resolved.eq(true),
+      resolver_id.eq(by_resolver_id),
+      resolution_reason.eq(reason),
+      updated.eq(naive_now()),
+    ))
+    .get_results::<Self>(conn)
+    .await
+  }
+
   async fn unresolve(
     pool: &DbPool,
     report_id: Self::IdType,
@@ -52,9 +86,34 @@ impl Reportable for PrivateMessageReport {
       .set((
         resolved.eq(false),
         resolver_id.eq(by_resolver_id),
+        resolution_reason.eq(None::<String>),
         updated.eq(naive_now()),
       ))
       .execute(conn)
       .await
   }
+
+  async fn assign(
+    pool: &DbPool,
+    report_id: Self::IdType,
+    for_assignee_id: Option<PersonId>,
+  ) -> Result<usize, Error> {
+    let conn = &mut get_conn(pool).await?;
+    update(private_message_report.find(report_id))
+      .set((assignee_id.eq(for_assignee_id), updated.eq(naive_now())))
+      .execute(conn)
+      .await
+  }
+
+  async fn escalate(
+    pool: &DbPool,
+    report_id: Self::IdType,
+    is_escalated: bool,
+  ) -> Result<usize, Error> {
+    let conn = &mut get_conn(pool).await?;
+    update(private_message_report.find(report_id))
+      .set((escalated.eq(is_escalated), updated.eq(naive_now())))
+      .execute(conn)
+      .await
+  }
 }
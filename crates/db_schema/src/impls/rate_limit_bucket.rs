@@ -0,0 +1,106 @@
+use crate::{
+  schema::rate_limit_bucket,
+  source::rate_limit_bucket::{RateLimitBucket, RateLimitBucketForm},
+  utils::{get_conn, naive_now, DbPool},
+};
+use diesel::{dsl::insert_into, result::Error, OptionalExtension, QueryDsl};
+use diesel_async::RunQueryDsl;
+use lemmy_utils::rate_limit::rate_limiter::next_allowance;
+
+impl RateLimitBucket {
+  /// Returns true if the request passed the rate limit, false if it failed and should be rejected.
+  /// The bucket is locked while it is updated, so that concurrent requests to different processes
+  /// are counted correctly.
+  pub async fn check(
+    pool: &DbPool,
+    rate_limit_type: &str,
+    ip: &str,
+    rate: i32,
+    per: i32,
+  ) -> Result<bool, Error> {
+    let conn = &mut get_conn(pool).await?;
+    let rate_limit_type = rate_limit_type.to_string();
+    let ip = ip.to_string();
+
+    conn
+      .build_transaction()
+      .run(|conn| {
+        Box::pin(async move {
+          let bucket = rate_limit_bucket::table
+            .find((&rate_limit_type, &ip))
+            .for_update()
+            .first::<Self>(conn)
+            .await
+            .optional()?;
+
+          let current = naive_now();
+          let (allowance, time_passed) = match bucket {
+            Some(b) => (
+              Some(b.allowance),
+              (current - b.last_checked).num_seconds() as f64,
+            ),
+            None => (None, 0f64),
+          };
+          let (allowance, passed) = next_allowance(allowance, time_passed, rate, per);
+
+          let form = RateLimitBucketForm {
+            rate_limit_type,
+            ip,
+            allowance,
+            last_checked: current,
+          };
+          insert_into(rate_limit_bucket::table)
+            .values(&form)
+            .on_conflict((rate_limit_bucket::rate_limit_type, rate_limit_bucket::ip))
+            .do_update()
+            .set(&form)
+            .execute(conn)
+            .await?;
+          Ok(passed)
+        }) as _
+      })
+      .await
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::{
+    schema::rate_limit_bucket,
+    source::rate_limit_bucket::RateLimitBucket,
+    utils::{build_db_pool_for_tests, get_conn},
+  };
+  use diesel::{ExpressionMethods, QueryDsl};
+  use diesel_async::RunQueryDsl;
+  use serial_test::serial;
+
+  #[tokio::test]
+  #[serial]
+  async fn test_rate_limit_bucket() {
+    let pool = &build_db_pool_for_tests().await;
+
+    let mut results = vec![];
+    for _ in 0..3 {
+      let passed = RateLimitBucket::check(pool, "Register", "192.0.2.17", 2, 3600)
+        .await
+        .unwrap();
+      results.push(passed);
+    }
+    // Other IPs have their own allowance
+    let other_ip = RateLimitBucket::check(pool, "Register", "192.0.2.18", 2, 3600)
+      .await
+      .unwrap();
+
+    let conn = &mut get_conn(pool).await.unwrap();
+    let num_deleted = diesel::delete(
+      rate_limit_bucket::table.filter(rate_limit_bucket::ip.eq_any(["192.0.2.17", "192.0.2.18"])),
+    )
+    .execute(conn)
+    .await
+    .unwrap();
+
+    assert_eq!(vec![true, true, false], results);
+    assert!(other_ip);
+    assert_eq!(2, num_deleted);
+  }
+}
@@ -0,0 +1,136 @@
+use crate::{
+  newtypes::{DbUrl, RelayId},
+  schema::relay,
+  source::relay::{Relay, RelayInsertForm, RelayUpdateForm},
+  traits::Crud,
+  utils::{get_conn, DbPool},
+};
+use diesel::{dsl::insert_into, result::Error, ExpressionMethods, OptionalExtension, QueryDsl};
+use diesel_async::RunQueryDsl;
+use url::Url;
+
+#[async_trait]
+impl Crud for Relay {
+  type InsertForm = RelayInsertForm;
+  type UpdateForm = RelayUpdateForm;
+  type IdType = RelayId;
+
+  async fn read(pool: &DbPool, relay_id: RelayId) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    relay::table.find(relay_id).first::<Self>(conn).await
+  }
+
+  /// Upserts the relay, as it is refetched whenever it is added again.
+  async fn create(pool: &DbPool, form: &Self::InsertForm) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    insert_into(relay::table)
+      .values(form)
+      .on_conflict(relay::actor_id)
+      .do_update()
+      .set(form)
+      .get_result::<Self>(conn)
+      .await
+  }
+
+  async fn update(
+    pool: &DbPool,
+    relay_id: RelayId,
+    form: &Self::UpdateForm,
+  ) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    diesel::update(relay::table.find(relay_id))
+      .set(form)
+      .get_result::<Self>(conn)
+      .await
+  }
+
+  async fn delete(pool: &DbPool, relay_id: RelayId) -> Result<usize, Error> {
+    let conn = &mut get_conn(pool).await?;
+    diesel::delete(relay::table.find(relay_id))
+      .execute(conn)
+      .await
+  }
+}
+
+impl Relay {
+  pub async fn read_from_apub_id(pool: &DbPool, object_id: Url) -> Result<Option<Self>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    let object_id: DbUrl = object_id.into();
+    relay::table
+      .filter(relay::actor_id.eq(object_id))
+      .first::<Self>(conn)
+      .await
+      .optional()
+  }
+
+  pub async fn list(pool: &DbPool) -> Result<Vec<Self>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    relay::table
+      .order_by(relay::published)
+      .load::<Self>(conn)
+      .await
+  }
+
+  /// Inboxes of the relays which accepted our follow, and should receive local community activity.
+  pub async fn list_publish_inboxes(pool: &DbPool) -> Result<Vec<DbUrl>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    relay::table
+      .filter(relay::pending.eq(false))
+      .filter(relay::publish.eq(true))
+      .select(relay::inbox_url)
+      .load::<DbUrl>(conn)
+      .await
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::{
+    source::relay::{Relay, RelayInsertForm, RelayUpdateForm},
+    traits::Crud,
+    utils::build_db_pool_for_tests,
+  };
+  use serial_test::serial;
+  use url::Url;
+
+  #[tokio::test]
+  #[serial]
+  async fn test_crud() {
+    let pool = &build_db_pool_for_tests().await;
+
+    let actor_id = Url::parse("https://relay.example.com/actor").unwrap();
+    let form = RelayInsertForm {
+      actor_id: actor_id.clone().into(),
+      inbox_url: Url::parse("https://relay.example.com/inbox")
+        .unwrap()
+        .into(),
+      public_key: "pubkey".to_string(),
+      last_refreshed_at: None,
+    };
+    let inserted_relay = Relay::create(pool, &form).await.unwrap();
+    // Adding the same relay again doesnt create a duplicate
+    let upserted_relay = Relay::create(pool, &form).await.unwrap();
+    let read_relay = Relay::read_from_apub_id(pool, actor_id).await.unwrap();
+    let inboxes_pending = Relay::list_publish_inboxes(pool).await.unwrap();
+
+    let update_form = RelayUpdateForm {
+      pending: Some(false),
+      publish: Some(true),
+    };
+    let updated_relay = Relay::update(pool, inserted_relay.id, &update_form)
+      .await
+      .unwrap();
+    let inboxes_accepted = Relay::list_publish_inboxes(pool).await.unwrap();
+    let num_deleted = Relay::delete(pool, inserted_relay.id).await.unwrap();
+
+    assert!(inserted_relay.pending);
+    assert!(!inserted_relay.publish);
+    assert_eq!(inserted_relay.id, upserted_relay.id);
+    assert_eq!(Some(inserted_relay.clone()), read_relay);
+    assert!(inboxes_pending.is_empty());
+    assert!(!updated_relay.pending);
+    assert!(updated_relay.publish);
+    assert_eq!(vec![inserted_relay.inbox_url], inboxes_accepted);
+    assert_eq!(1, num_deleted);
+  }
+}
@@ -0,0 +1,50 @@
+use crate::{
+  newtypes::ReportCommentId,
+  schema::report_comment::dsl::report_comment,
+  source::report_comment::{ReportComment, ReportCommentForm},
+  traits::Crud,
+  utils::{get_conn, DbPool},
+};
+use diesel::{dsl::insert_into, result::Error, QueryDsl};
+use diesel_async::RunQueryDsl;
+
+#[async_trait]
+impl Crud for ReportComment {
+  type InsertForm = ReportCommentForm;
+  type UpdateForm = ReportCommentForm;
+  type IdType = ReportCommentId;
+  async fn read(pool: &DbPool, report_comment_id: ReportCommentId) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    report_comment
+      .find(report_comment_id)
+      .first::<Self>(conn)
+      .await
+  }
+
+  async fn create(pool: &DbPool, form: &ReportCommentForm) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    insert_into(report_comment)
+      .values(form)
+      .get_result::<Self>(conn)
+      .await
+  }
+
+  async fn update(
+    pool: &DbPool,
+    report_comment_id: ReportCommentId,
+    form: &ReportCommentForm,
+  ) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    diesel::update(report_comment.find(report_comment_id))
+      .set(form)
+      .get_result::<Self>(conn)
+      .await
+  }
+
+  async fn delete(pool: &DbPool, report_comment_id: ReportCommentId) -> Result<usize, Error> {
+    let conn = &mut get_conn(pool).await?;
+    diesel::delete(report_comment.find(report_comment_id))
+      .execute(conn)
+      .await
+  }
+}
@@ -0,0 +1,23 @@
+use crate::{
+  schema::websocket_message,
+  source::websocket_message::{WebsocketMessage, WebsocketMessageForm},
+  utils::{get_conn, DbPool},
+};
+use diesel::{dsl::insert_into, result::Error, QueryDsl};
+use diesel_async::RunQueryDsl;
+
+impl WebsocketMessage {
+  /// Stores the message, which notifies the other processes through a trigger.
+  pub async fn create(pool: &DbPool, form: &WebsocketMessageForm) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    insert_into(websocket_message::table)
+      .values(form)
+      .get_result::<Self>(conn)
+      .await
+  }
+
+  pub async fn read(pool: &DbPool, id: i32) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    websocket_message::table.find(id).first::<Self>(conn).await
+  }
+}
@@ -1,5 +1,6 @@
 #![recursion_limit = "256"]
 
+use doku::Document;
 #[cfg(feature = "full")]
 #[macro_use]
 extern crate diesel;
@@ -30,7 +31,7 @@ pub mod utils;
 use serde::{Deserialize, Serialize};
 use strum_macros::{Display, EnumString};
 
-#[derive(EnumString, Display, Debug, Serialize, Deserialize, Clone, Copy)]
+#[derive(EnumString, Display, Debug, Serialize, Deserialize, Clone, Copy, Document)]
 pub enum SortType {
   Active,
   Hot,
@@ -45,7 +46,7 @@ pub enum SortType {
   NewComments,
 }
 
-#[derive(EnumString, Display, Debug, Serialize, Deserialize, Clone, Copy)]
+#[derive(EnumString, Display, Debug, Serialize, Deserialize, Clone, Copy, Document)]
 pub enum CommentSortType {
   Hot,
   Top,
@@ -53,14 +54,16 @@ pub enum CommentSortType {
   Old,
 }
 
-#[derive(EnumString, Display, Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[derive(
+  EnumString, Display, Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Document,
+)]
 pub enum ListingType {
   All,
   Local,
   Subscribed,
 }
 
-#[derive(EnumString, Display, Debug, Serialize, Deserialize, Clone, Copy)]
+#[derive(EnumString, Display, Debug, Serialize, Deserialize, Clone, Copy, Document)]
 pub enum SearchType {
   All,
   Comments,
@@ -70,14 +73,18 @@ pub enum SearchType {
   Url,
 }
 
-#[derive(EnumString, Display, Debug, PartialEq, Eq, Serialize, Deserialize, Clone, Copy)]
+#[derive(
+  EnumString, Display, Debug, PartialEq, Eq, Serialize, Deserialize, Clone, Copy, Document,
+)]
 pub enum SubscribedType {
   Subscribed,
   NotSubscribed,
   Pending,
 }
 
-#[derive(EnumString, Display, Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[derive(
+  EnumString, Display, Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Document,
+)]
 pub enum ModlogActionType {
   All,
   ModRemovePost,
@@ -95,10 +102,11 @@ pub enum ModlogActionType {
   AdminPurgeCommunity,
   AdminPurgePost,
   AdminPurgeComment,
+  AdminBlockInstance,
 }
 
 #[derive(
-  EnumString, Display, Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq,
+  EnumString, Display, Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Document,
 )]
 pub enum PostFeatureType {
   #[default]
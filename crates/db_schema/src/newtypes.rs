@@ -2,6 +2,7 @@
 use activitypub_federation::{core::object_id::ObjectId, traits::ApubObject};
 #[cfg(feature = "full")]
 use diesel_ltree::Ltree;
+use doku::Document;
 use serde::{Deserialize, Serialize};
 use std::{
   fmt,
@@ -10,7 +11,7 @@ use std::{
 };
 use url::Url;
 
-#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Default, Serialize, Deserialize)]
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Default, Serialize, Deserialize, Document)]
 #[cfg_attr(feature = "full", derive(DieselNewType))]
 pub struct PostId(pub i32);
 
@@ -20,11 +21,11 @@ impl fmt::Display for PostId {
   }
 }
 
-#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Default, Serialize, Deserialize)]
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Default, Serialize, Deserialize, Document)]
 #[cfg_attr(feature = "full", derive(DieselNewType))]
 pub struct PersonId(pub i32);
 
-#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Serialize, Deserialize, Default, Document)]
 #[cfg_attr(feature = "full", derive(DieselNewType))]
 pub struct CommentId(pub i32);
 
@@ -34,15 +35,15 @@ impl fmt::Display for CommentId {
   }
 }
 
-#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Default, Serialize, Deserialize)]
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Default, Serialize, Deserialize, Document)]
 #[cfg_attr(feature = "full", derive(DieselNewType))]
 pub struct CommunityId(pub i32);
 
-#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Default, Serialize, Deserialize)]
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Default, Serialize, Deserialize, Document)]
 #[cfg_attr(feature = "full", derive(DieselNewType))]
 pub struct LocalUserId(pub i32);
 
-#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Serialize, Deserialize, Default, Document)]
 #[cfg_attr(feature = "full", derive(DieselNewType))]
 pub struct PrivateMessageId(i32);
 
@@ -52,64 +53,96 @@ impl fmt::Display for PrivateMessageId {
   }
 }
 
-#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Serialize, Deserialize, Default, Document)]
 #[cfg_attr(feature = "full", derive(DieselNewType))]
 pub struct PersonMentionId(i32);
 
-#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Serialize, Deserialize, Default, Document)]
 #[cfg_attr(feature = "full", derive(DieselNewType))]
 pub struct PersonBlockId(i32);
 
-#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Serialize, Deserialize, Default, Document)]
 #[cfg_attr(feature = "full", derive(DieselNewType))]
 pub struct CommunityBlockId(i32);
 
-#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Serialize, Deserialize, Default, Document)]
 #[cfg_attr(feature = "full", derive(DieselNewType))]
 pub struct CommentReportId(i32);
 
-#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Serialize, Deserialize, Default, Document)]
 #[cfg_attr(feature = "full", derive(DieselNewType))]
 pub struct PostReportId(i32);
 
-#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Serialize, Deserialize, Default, Document)]
 #[cfg_attr(feature = "full", derive(DieselNewType))]
 pub struct PrivateMessageReportId(i32);
 
-#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Serialize, Deserialize, Default, Document)]
+#[cfg_attr(feature = "full", derive(DieselNewType))]
+pub struct ReportCommentId(i32);
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Serialize, Deserialize, Default, Document)]
+#[cfg_attr(feature = "full", derive(DieselNewType))]
+pub struct CommunityRuleId(i32);
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Serialize, Deserialize, Default, Document)]
+#[cfg_attr(feature = "full", derive(DieselNewType))]
+pub struct CommunityBackfillId(i32);
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Serialize, Deserialize, Default, Document)]
+#[cfg_attr(feature = "full", derive(DieselNewType))]
+pub struct ConvertedNoteId(i32);
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Serialize, Deserialize, Default, Document)]
+#[cfg_attr(feature = "full", derive(DieselNewType))]
+pub struct InstanceFederationStatsId(i32);
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Serialize, Deserialize, Default, Document)]
+#[cfg_attr(feature = "full", derive(DieselNewType))]
+pub struct InstanceReceivedActivityId(i32);
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Serialize, Deserialize, Default, Document)]
+#[cfg_attr(feature = "full", derive(DieselNewType))]
+pub struct RelayId(i32);
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Serialize, Deserialize, Default, Document)]
+#[cfg_attr(feature = "full", derive(DieselNewType))]
+pub struct DirectoryCommunityId(i32);
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Serialize, Deserialize, Default, Document)]
 #[cfg_attr(feature = "full", derive(DieselNewType))]
 pub struct SiteId(i32);
 
-#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Serialize, Deserialize, Default, Document)]
 #[cfg_attr(feature = "full", derive(DieselNewType))]
 pub struct LanguageId(pub i32);
 
-#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Serialize, Deserialize, Default, Document)]
 #[cfg_attr(feature = "full", derive(DieselNewType))]
 pub struct LocalUserLanguageId(pub i32);
 
-#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Serialize, Deserialize, Default, Document)]
 #[cfg_attr(feature = "full", derive(DieselNewType))]
 pub struct SiteLanguageId(pub i32);
 
-#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Serialize, Deserialize, Default, Document)]
 #[cfg_attr(feature = "full", derive(DieselNewType))]
 pub struct CommunityLanguageId(pub i32);
 
-#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Serialize, Deserialize, Default, Document)]
 #[cfg_attr(feature = "full", derive(DieselNewType))]
 pub struct CommentReplyId(i32);
 
-#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Serialize, Deserialize, Default, Document)]
 #[cfg_attr(feature = "full", derive(DieselNewType))]
 pub struct InstanceId(i32);
 
-#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Serialize, Deserialize, Default, Document)]
 #[cfg_attr(feature = "full", derive(DieselNewType))]
 pub struct LocalSiteId(i32);
 
 #[repr(transparent)]
-#[derive(Clone, PartialEq, Eq, Serialize, Deserialize, Debug)]
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize, Debug, Document)]
 #[cfg_attr(feature = "full", derive(AsExpression, FromSqlRow))]
 #[cfg_attr(feature = "full", diesel(sql_type = diesel::sql_types::Text))]
 pub struct DbUrl(pub(crate) Box<Url>);
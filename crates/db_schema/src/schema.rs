@@ -7,6 +7,8 @@ table! {
         updated -> Nullable<Timestamp>,
         ap_id -> Text,
         sensitive -> Nullable<Bool>,
+        private_message -> Bool,
+        signature_verified -> Bool,
     }
 }
 
@@ -65,6 +67,10 @@ table! {
         resolver_id -> Nullable<Int4>,
         published -> Timestamp,
         updated -> Nullable<Timestamp>,
+        assignee_id -> Nullable<Int4>,
+        escalated -> Bool,
+        resolution_reason -> Nullable<Text>,
+        rule_id -> Nullable<Int4>,
     }
 }
 
@@ -103,6 +109,7 @@ table! {
         hidden -> Bool,
         posting_restricted_to_mods -> Bool,
         instance_id -> Int4,
+        slur_filter_regex -> Nullable<Text>,
     }
 }
 
@@ -248,6 +255,7 @@ table! {
         reason -> Nullable<Text>,
         removed -> Nullable<Bool>,
         when_ -> Timestamp,
+        rule_id -> Nullable<Int4>,
     }
 }
 
@@ -271,6 +279,7 @@ table! {
         reason -> Nullable<Text>,
         removed -> Nullable<Bool>,
         when_ -> Timestamp,
+        rule_id -> Nullable<Int4>,
     }
 }
 
@@ -444,6 +453,10 @@ table! {
         resolver_id -> Nullable<Int4>,
         published -> Timestamp,
         updated -> Nullable<Timestamp>,
+        assignee_id -> Nullable<Int4>,
+        escalated -> Bool,
+        resolution_reason -> Nullable<Text>,
+        rule_id -> Nullable<Int4>,
     }
 }
 
@@ -482,6 +495,9 @@ table! {
         resolver_id -> Nullable<Int4>,
         published -> Timestamp,
         updated -> Nullable<Timestamp>,
+        assignee_id -> Nullable<Int4>,
+        escalated -> Bool,
+        resolution_reason -> Nullable<Text>,
     }
 }
 
@@ -564,6 +580,19 @@ table! {
   }
 }
 
+table! {
+  admin_block_instance (id) {
+    id -> Int4,
+    admin_person_id -> Int4,
+    instance_id -> Int4,
+    blocked -> Bool,
+    hide_content -> Bool,
+    purge_content -> Bool,
+    reason -> Nullable<Text>,
+    when_ -> Timestamp,
+  }
+}
+
 table! {
   admin_purge_community (id) {
     id -> Int4,
@@ -703,6 +732,9 @@ table! {
     captcha_difficulty -> Text,
     registration_mode -> RegistrationModeType,
     reports_email_admins -> Bool,
+    convert_notes_to_posts -> Bool,
+    authorized_fetch -> Bool,
+    community_directory -> Bool,
     published -> Timestamp,
     updated -> Nullable<Timestamp>,
   }
@@ -749,6 +781,156 @@ table! {
     }
 }
 
+table! {
+  report_comment (id) {
+    id -> Int4,
+    creator_id -> Int4,
+    post_report_id -> Nullable<Int4>,
+    comment_report_id -> Nullable<Int4>,
+    private_message_report_id -> Nullable<Int4>,
+    content -> Text,
+    published -> Timestamp,
+  }
+}
+
+table! {
+  community_rule (id) {
+    id -> Int4,
+    community_id -> Int4,
+    position -> Int4,
+    title -> Text,
+    body -> Nullable<Text>,
+    published -> Timestamp,
+    updated -> Nullable<Timestamp>,
+  }
+}
+
+table! {
+  community_backfill (id) {
+    id -> Int4,
+    community_id -> Int4,
+    pages_fetched -> Int4,
+    posts_fetched -> Int4,
+    comments_fetched -> Int4,
+    error -> Nullable<Text>,
+    published -> Timestamp,
+    updated -> Nullable<Timestamp>,
+    finished -> Nullable<Timestamp>,
+  }
+}
+
+table! {
+  converted_note (id) {
+    id -> Int4,
+    post_id -> Int4,
+    published -> Timestamp,
+    updated -> Nullable<Timestamp>,
+  }
+}
+
+table! {
+  instance_federation_stats (id) {
+    id -> Int4,
+    instance_id -> Int4,
+    last_inbound_success -> Nullable<Timestamp>,
+    inbound_failure_count -> Int8,
+    last_outbound_success -> Nullable<Timestamp>,
+    last_outbound_failure -> Nullable<Timestamp>,
+    outbound_success_count -> Int8,
+    outbound_failure_count -> Int8,
+    delivery_time_total_ms -> Int8,
+    published -> Timestamp,
+    updated -> Nullable<Timestamp>,
+  }
+}
+
+table! {
+  instance_received_activity (id) {
+    id -> Int4,
+    instance_id -> Int4,
+    kind -> Text,
+    count -> Int8,
+  }
+}
+
+table! {
+  instance_block_hidden (id) {
+    id -> Int4,
+    instance_id -> Int4,
+    community_id -> Nullable<Int4>,
+    post_id -> Nullable<Int4>,
+    comment_id -> Nullable<Int4>,
+    person_id -> Nullable<Int4>,
+    published -> Timestamp,
+  }
+}
+
+table! {
+  relay (id) {
+    id -> Int4,
+    actor_id -> Varchar,
+    inbox_url -> Varchar,
+    public_key -> Text,
+    pending -> Bool,
+    publish -> Bool,
+    published -> Timestamp,
+    last_refreshed_at -> Timestamp,
+  }
+}
+
+table! {
+  directory_community (id) {
+    id -> Int4,
+    instance_id -> Int4,
+    actor_id -> Varchar,
+    name -> Varchar,
+    title -> Varchar,
+    description -> Nullable<Text>,
+    icon -> Nullable<Text>,
+    nsfw -> Bool,
+    subscribers -> Int8,
+    posts -> Int8,
+    language_ids -> Array<Int4>,
+    published -> Timestamp,
+    updated -> Timestamp,
+  }
+}
+
+table! {
+  captcha_answer (id) {
+    id -> Int4,
+    uuid -> Varchar,
+    answer -> Text,
+    expires -> Timestamp,
+  }
+}
+
+table! {
+  rate_limit_bucket (rate_limit_type, ip) {
+    rate_limit_type -> Varchar,
+    ip -> Varchar,
+    allowance -> Float8,
+    last_checked -> Timestamp,
+  }
+}
+
+table! {
+  websocket_message (id) {
+    id -> Int4,
+    node_id -> Varchar,
+    room -> Jsonb,
+    message -> Text,
+    published -> Timestamp,
+  }
+}
+
+table! {
+  person_recent_write (person_id) {
+    person_id -> Int4,
+    written -> Timestamp,
+  }
+}
+
 joinable!(person_block -> person (person_id));
 
 joinable!(comment -> person (creator_id));
@@ -822,6 +1004,8 @@ joinable!(person_follower -> person (follower_id));
 joinable!(admin_purge_comment -> person (admin_person_id));
 joinable!(admin_purge_comment -> post (post_id));
 joinable!(admin_purge_community -> person (admin_person_id));
+joinable!(admin_block_instance -> person (admin_person_id));
+joinable!(admin_block_instance -> instance (instance_id));
 joinable!(admin_purge_person -> person (admin_person_id));
 joinable!(admin_purge_post -> community (community_id));
 joinable!(admin_purge_post -> person (admin_person_id));
@@ -834,6 +1018,25 @@ joinable!(federation_blocklist -> instance (instance_id));
 joinable!(local_site -> site (site_id));
 joinable!(local_site_rate_limit -> local_site (local_site_id));
 joinable!(tagline -> local_site (local_site_id));
+joinable!(report_comment -> person (creator_id));
+joinable!(report_comment -> post_report (post_report_id));
+joinable!(report_comment -> comment_report (comment_report_id));
+joinable!(report_comment -> private_message_report (private_message_report_id));
+joinable!(community_rule -> community (community_id));
+joinable!(post_report -> community_rule (rule_id));
+joinable!(comment_report -> community_rule (rule_id));
+joinable!(mod_remove_post -> community_rule (rule_id));
+joinable!(mod_remove_comment -> community_rule (rule_id));
+joinable!(community_backfill -> community (community_id));
+joinable!(converted_note -> post (post_id));
+joinable!(instance_federation_stats -> instance (instance_id));
+joinable!(instance_received_activity -> instance (instance_id));
+joinable!(instance_block_hidden -> instance (instance_id));
+joinable!(instance_block_hidden -> community (community_id));
+joinable!(instance_block_hidden -> post (post_id));
+joinable!(instance_block_hidden -> comment (comment_id));
+joinable!(instance_block_hidden -> person (person_id));
+joinable!(directory_community -> instance (instance_id));
 
 allow_tables_to_appear_in_same_query!(
   activity,
@@ -894,5 +1097,19 @@ allow_tables_to_appear_in_same_query!(
   federation_blocklist,
   local_site,
   local_site_rate_limit,
-  person_follower
+  person_follower,
+  report_comment,
+  community_rule,
+  community_backfill,
+  converted_note,
+  instance_federation_stats,
+  instance_received_activity,
+  admin_block_instance,
+  instance_block_hidden,
+  relay,
+  directory_community,
+  captcha_answer,
+  rate_limit_bucket,
+  websocket_message,
+  person_recent_write
 );
@@ -1,34 +1,51 @@
-use crate::{newtypes::DbUrl, schema::activity};
+use crate::newtypes::DbUrl;
+#[cfg(feature = "full")]
+use crate::schema::activity;
+use doku::Document;
+use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use std::fmt::Debug;
 
-#[derive(PartialEq, Eq, Debug, Queryable, Identifiable)]
-#[diesel(table_name = activity)]
+#[derive(PartialEq, Eq, Debug, Clone, Serialize, Deserialize, Document)]
+#[cfg_attr(feature = "full", derive(Queryable, Identifiable))]
+#[cfg_attr(feature = "full", diesel(table_name = activity))]
 pub struct Activity {
   pub id: i32,
+  /// Any JSON value
+  #[doku(as = "String", meta("json = any"))]
   pub data: Value,
   pub local: bool,
+  #[doku(as = "String")]
   pub published: chrono::NaiveDateTime,
+  #[doku(as = "Option<String>")]
   pub updated: Option<chrono::NaiveDateTime>,
   pub ap_id: DbUrl,
   pub sensitive: Option<bool>,
+  /// A private message, or the deletion of one
+  pub private_message: bool,
+  /// For received activities, whether their HTTP signature was valid
+  pub signature_verified: bool,
 }
 
-#[derive(Insertable)]
-#[diesel(table_name = activity)]
+#[cfg_attr(feature = "full", derive(Insertable))]
+#[cfg_attr(feature = "full", diesel(table_name = activity))]
 pub struct ActivityInsertForm {
   pub data: Value,
   pub local: Option<bool>,
   pub updated: Option<chrono::NaiveDateTime>,
   pub ap_id: DbUrl,
   pub sensitive: Option<bool>,
+  pub private_message: Option<bool>,
+  pub signature_verified: Option<bool>,
 }
 
-#[derive(AsChangeset)]
-#[diesel(table_name = activity)]
+#[cfg_attr(feature = "full", derive(AsChangeset))]
+#[cfg_attr(feature = "full", diesel(table_name = activity))]
 pub struct ActivityUpdateForm {
   pub data: Option<Value>,
   pub local: Option<bool>,
   pub updated: Option<Option<chrono::NaiveDateTime>>,
   pub sensitive: Option<Option<bool>>,
+  pub private_message: Option<bool>,
+  pub signature_verified: Option<bool>,
 }
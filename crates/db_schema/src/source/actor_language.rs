@@ -9,9 +9,10 @@ use crate::newtypes::{
 };
 #[cfg(feature = "full")]
 use crate::schema::local_user_language;
+use doku::Document;
 use serde::{Deserialize, Serialize};
 
-#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize, Document)]
 #[cfg_attr(feature = "full", derive(Queryable, Identifiable))]
 #[cfg_attr(feature = "full", diesel(table_name = local_user_language))]
 pub struct LocalUserLanguage {
@@ -32,7 +33,7 @@ pub struct LocalUserLanguageForm {
 #[cfg(feature = "full")]
 use crate::schema::community_language;
 
-#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize, Document)]
 #[cfg_attr(feature = "full", derive(Queryable, Identifiable))]
 #[cfg_attr(feature = "full", diesel(table_name = community_language))]
 pub struct CommunityLanguage {
@@ -53,7 +54,7 @@ pub struct CommunityLanguageForm {
 #[cfg(feature = "full")]
 use crate::schema::site_language;
 
-#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize, Document)]
 #[cfg_attr(feature = "full", derive(Queryable, Identifiable))]
 #[cfg_attr(feature = "full", diesel(table_name = site_language))]
 pub struct SiteLanguage {
@@ -0,0 +1,25 @@
+#[cfg(feature = "full")]
+use crate::schema::captcha_answer;
+use doku::Document;
+use serde::{Deserialize, Serialize};
+
+/// The answer to a captcha which was handed out, so that it can be checked by any process.
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize, Document)]
+#[cfg_attr(feature = "full", derive(Queryable, Identifiable))]
+#[cfg_attr(feature = "full", diesel(table_name = captcha_answer))]
+pub struct CaptchaAnswer {
+  pub id: i32,
+  pub uuid: String,
+  pub answer: String,
+  #[doku(as = "String")]
+  pub expires: chrono::NaiveDateTime,
+}
+
+#[derive(Clone)]
+#[cfg_attr(feature = "full", derive(Insertable, AsChangeset))]
+#[cfg_attr(feature = "full", diesel(table_name = captcha_answer))]
+pub struct CaptchaAnswerForm {
+  pub uuid: String,
+  pub answer: String,
+  pub expires: chrono::NaiveDateTime,
+}
@@ -5,10 +5,11 @@ use crate::newtypes::{CommentId, DbUrl, LanguageId, PersonId, PostId};
 use crate::schema::{comment, comment_like, comment_saved};
 #[cfg(feature = "full")]
 use diesel_ltree::Ltree;
+use doku::Document;
 use serde::{Deserialize, Serialize};
 use typed_builder::TypedBuilder;
 
-#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize, Document)]
 #[cfg_attr(feature = "full", derive(Queryable, Associations, Identifiable))]
 #[cfg_attr(feature = "full", diesel(belongs_to(crate::source::post::Post)))]
 #[cfg_attr(feature = "full", diesel(table_name = comment))]
@@ -18,13 +19,16 @@ pub struct Comment {
   pub post_id: PostId,
   pub content: String,
   pub removed: bool,
+  #[doku(as = "String")]
   pub published: chrono::NaiveDateTime,
+  #[doku(as = "Option<String>")]
   pub updated: Option<chrono::NaiveDateTime>,
   pub deleted: bool,
   pub ap_id: DbUrl,
   pub local: bool,
   #[cfg(feature = "full")]
   #[serde(with = "LtreeDef")]
+  #[doku(as = "String")]
   pub path: Ltree,
   pub distinguished: bool,
   pub language_id: LanguageId,
@@ -1,9 +1,10 @@
 use crate::newtypes::{CommentId, CommentReplyId, PersonId};
 #[cfg(feature = "full")]
 use crate::schema::comment_reply;
+use doku::Document;
 use serde::{Deserialize, Serialize};
 
-#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize, Document)]
 #[cfg_attr(feature = "full", derive(Queryable, Associations, Identifiable))]
 #[cfg_attr(feature = "full", diesel(belongs_to(crate::source::comment::Comment)))]
 #[cfg_attr(feature = "full", diesel(table_name = comment_reply))]
@@ -13,6 +14,7 @@ pub struct CommentReply {
   pub recipient_id: PersonId,
   pub comment_id: CommentId,
   pub read: bool,
+  #[doku(as = "String")]
   pub published: chrono::NaiveDateTime,
 }
 
@@ -1,9 +1,10 @@
-use crate::newtypes::{CommentId, CommentReportId, PersonId};
+use crate::newtypes::{CommentId, CommentReportId, CommunityRuleId, PersonId};
 #[cfg(feature = "full")]
 use crate::schema::comment_report;
+use doku::Document;
 use serde::{Deserialize, Serialize};
 
-#[derive(PartialEq, Eq, Serialize, Deserialize, Debug, Clone)]
+#[derive(PartialEq, Eq, Serialize, Deserialize, Debug, Clone, Document)]
 #[cfg_attr(feature = "full", derive(Queryable, Associations, Identifiable))]
 #[cfg_attr(feature = "full", diesel(belongs_to(crate::source::comment::Comment)))]
 #[cfg_attr(feature = "full", diesel(table_name = comment_report))]
@@ -15,8 +16,15 @@ pub struct CommentReport {
   pub reason: String,
   pub resolved: bool,
   pub resolver_id: Option<PersonId>,
+  #[doku(as = "String")]
   pub published: chrono::NaiveDateTime,
+  #[doku(as = "Option<String>")]
   pub updated: Option<chrono::NaiveDateTime>,
+  pub assignee_id: Option<PersonId>,
+  pub escalated: bool,
+  pub resolution_reason: Option<String>,
+  /// The community rule which the comment breaks, if the reporter picked one
+  pub rule_id: Option<CommunityRuleId>,
 }
 
 #[derive(Clone)]
@@ -27,4 +35,5 @@ pub struct CommentReportForm {
   pub comment_id: CommentId,
   pub original_comment_text: String,
   pub reason: String,
+  pub rule_id: Option<CommunityRuleId>,
 }
@@ -1,10 +1,11 @@
 use crate::newtypes::{CommunityId, DbUrl, InstanceId, PersonId};
 #[cfg(feature = "full")]
 use crate::schema::{community, community_follower, community_moderator, community_person_ban};
+use doku::Document;
 use serde::{Deserialize, Serialize};
 use typed_builder::TypedBuilder;
 
-#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize, Document)]
 #[cfg_attr(feature = "full", derive(Queryable, Identifiable))]
 #[cfg_attr(feature = "full", diesel(table_name = community))]
 pub struct Community {
@@ -13,7 +14,9 @@ pub struct Community {
   pub title: String,
   pub description: Option<String>,
   pub removed: bool,
+  #[doku(as = "String")]
   pub published: chrono::NaiveDateTime,
+  #[doku(as = "Option<String>")]
   pub updated: Option<chrono::NaiveDateTime>,
   pub deleted: bool,
   pub nsfw: bool,
@@ -21,6 +24,7 @@ pub struct Community {
   pub local: bool,
   pub private_key: Option<String>,
   pub public_key: String,
+  #[doku(as = "String")]
   pub last_refreshed_at: chrono::NaiveDateTime,
   pub icon: Option<DbUrl>,
   pub banner: Option<DbUrl>,
@@ -36,10 +40,12 @@ pub struct Community {
   pub hidden: bool,
   pub posting_restricted_to_mods: bool,
   pub instance_id: InstanceId,
+  /// Words filtered from posts and comments in this community, on top of the site slur filter
+  pub slur_filter_regex: Option<String>,
 }
 
 /// A safe representation of community, without the sensitive info
-#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize, Document)]
 #[cfg_attr(feature = "full", derive(Queryable, Identifiable))]
 #[cfg_attr(feature = "full", diesel(table_name = community))]
 pub struct CommunitySafe {
@@ -48,7 +54,9 @@ pub struct CommunitySafe {
   pub title: String,
   pub description: Option<String>,
   pub removed: bool,
+  #[doku(as = "String")]
   pub published: chrono::NaiveDateTime,
+  #[doku(as = "Option<String>")]
   pub updated: Option<chrono::NaiveDateTime>,
   pub deleted: bool,
   pub nsfw: bool,
@@ -59,6 +67,8 @@ pub struct CommunitySafe {
   pub hidden: bool,
   pub posting_restricted_to_mods: bool,
   pub instance_id: InstanceId,
+  /// Words filtered from posts and comments in this community, on top of the site slur filter
+  pub slur_filter_regex: Option<String>,
 }
 
 #[derive(Debug, Clone, TypedBuilder)]
@@ -92,6 +102,7 @@ pub struct CommunityInsertForm {
   pub posting_restricted_to_mods: Option<bool>,
   #[builder(!default)]
   pub instance_id: InstanceId,
+  pub slur_filter_regex: Option<String>,
 }
 
 #[derive(Debug, Clone, TypedBuilder)]
@@ -120,6 +131,7 @@ pub struct CommunityUpdateForm {
   pub featured_url: Option<DbUrl>,
   pub hidden: Option<bool>,
   pub posting_restricted_to_mods: Option<bool>,
+  pub slur_filter_regex: Option<Option<String>>,
 }
 
 #[derive(PartialEq, Eq, Debug)]
@@ -144,7 +156,7 @@ pub struct CommunityModeratorForm {
   pub person_id: PersonId,
 }
 
-#[derive(PartialEq, Eq, Debug)]
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize, Document)]
 #[cfg_attr(feature = "full", derive(Identifiable, Queryable, Associations))]
 #[cfg_attr(
   feature = "full",
@@ -155,7 +167,9 @@ pub struct CommunityPersonBan {
   pub id: i32,
   pub community_id: CommunityId,
   pub person_id: PersonId,
+  #[doku(as = "String")]
   pub published: chrono::NaiveDateTime,
+  #[doku(as = "Option<String>")]
   pub expires: Option<chrono::NaiveDateTime>,
 }
 
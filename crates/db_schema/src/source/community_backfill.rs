@@ -0,0 +1,46 @@
+use crate::newtypes::{CommunityBackfillId, CommunityId};
+#[cfg(feature = "full")]
+use crate::schema::community_backfill;
+use doku::Document;
+use serde::{Deserialize, Serialize};
+use typed_builder::TypedBuilder;
+
+/// Progress of fetching the history of a remote community. `finished` is set once the backfill
+/// stopped, either successfully or with an `error`.
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize, Document)]
+#[cfg_attr(feature = "full", derive(Queryable, Identifiable))]
+#[cfg_attr(feature = "full", diesel(table_name = community_backfill))]
+pub struct CommunityBackfill {
+  pub id: CommunityBackfillId,
+  pub community_id: CommunityId,
+  pub pages_fetched: i32,
+  pub posts_fetched: i32,
+  pub comments_fetched: i32,
+  pub error: Option<String>,
+  #[doku(as = "String")]
+  pub published: chrono::NaiveDateTime,
+  #[doku(as = "Option<String>")]
+  pub updated: Option<chrono::NaiveDateTime>,
+  #[doku(as = "Option<String>")]
+  pub finished: Option<chrono::NaiveDateTime>,
+}
+
+#[derive(Clone)]
+#[cfg_attr(feature = "full", derive(Insertable, AsChangeset))]
+#[cfg_attr(feature = "full", diesel(table_name = community_backfill))]
+pub struct CommunityBackfillInsertForm {
+  pub community_id: CommunityId,
+}
+
+#[derive(Clone, TypedBuilder)]
+#[builder(field_defaults(default))]
+#[cfg_attr(feature = "full", derive(AsChangeset))]
+#[cfg_attr(feature = "full", diesel(table_name = community_backfill))]
+pub struct CommunityBackfillUpdateForm {
+  pub pages_fetched: Option<i32>,
+  pub posts_fetched: Option<i32>,
+  pub comments_fetched: Option<i32>,
+  pub error: Option<Option<String>>,
+  pub updated: Option<Option<chrono::NaiveDateTime>>,
+  pub finished: Option<Option<chrono::NaiveDateTime>>,
+}
@@ -1,9 +1,10 @@
 use crate::newtypes::{CommunityBlockId, CommunityId, PersonId};
 #[cfg(feature = "full")]
 use crate::schema::community_block;
+use doku::Document;
 use serde::{Deserialize, Serialize};
 
-#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize, Document)]
 #[cfg_attr(feature = "full", derive(Queryable, Associations, Identifiable))]
 #[cfg_attr(
   feature = "full",
@@ -14,6 +15,7 @@ pub struct CommunityBlock {
   pub id: CommunityBlockId,
   pub person_id: PersonId,
   pub community_id: CommunityId,
+  #[doku(as = "String")]
   pub published: chrono::NaiveDateTime,
 }
 
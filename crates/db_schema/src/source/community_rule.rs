@@ -0,0 +1,31 @@
+use crate::newtypes::{CommunityId, CommunityRuleId};
+#[cfg(feature = "full")]
+use crate::schema::community_rule;
+use doku::Document;
+use serde::{Deserialize, Serialize};
+
+/// A single rule of a community. Rules are shown ordered by their position.
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize, Document)]
+#[cfg_attr(feature = "full", derive(Queryable, Identifiable))]
+#[cfg_attr(feature = "full", diesel(table_name = community_rule))]
+pub struct CommunityRule {
+  pub id: CommunityRuleId,
+  pub community_id: CommunityId,
+  pub position: i32,
+  pub title: String,
+  pub body: Option<String>,
+  #[doku(as = "String")]
+  pub published: chrono::NaiveDateTime,
+  #[doku(as = "Option<String>")]
+  pub updated: Option<chrono::NaiveDateTime>,
+}
+
+#[derive(Clone)]
+#[cfg_attr(feature = "full", derive(Insertable, AsChangeset))]
+#[cfg_attr(feature = "full", diesel(table_name = community_rule))]
+pub struct CommunityRuleForm {
+  pub community_id: CommunityId,
+  pub position: i32,
+  pub title: String,
+  pub body: Option<String>,
+}
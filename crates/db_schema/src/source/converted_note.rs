@@ -0,0 +1,25 @@
+use crate::newtypes::{ConvertedNoteId, PostId};
+#[cfg(feature = "full")]
+use crate::schema::converted_note;
+use doku::Document;
+use serde::{Deserialize, Serialize};
+
+/// Marks a post which was converted from a top level note, as sent by microblogging software.
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize, Document)]
+#[cfg_attr(feature = "full", derive(Queryable, Identifiable))]
+#[cfg_attr(feature = "full", diesel(table_name = converted_note))]
+pub struct ConvertedNote {
+  pub id: ConvertedNoteId,
+  pub post_id: PostId,
+  #[doku(as = "String")]
+  pub published: chrono::NaiveDateTime,
+  #[doku(as = "Option<String>")]
+  pub updated: Option<chrono::NaiveDateTime>,
+}
+
+#[derive(Clone)]
+#[cfg_attr(feature = "full", derive(Insertable, AsChangeset))]
+#[cfg_attr(feature = "full", diesel(table_name = converted_note))]
+pub struct ConvertedNoteForm {
+  pub post_id: PostId,
+}
@@ -0,0 +1,47 @@
+use crate::newtypes::{DbUrl, DirectoryCommunityId, InstanceId, LanguageId};
+#[cfg(feature = "full")]
+use crate::schema::directory_community;
+use doku::Document;
+use serde::{Deserialize, Serialize};
+
+/// A community of another instance which was found by crawling its community listing. It only
+/// serves for discovery, and is not fetched over ActivityPub until a user resolves it.
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize, Document)]
+#[cfg_attr(feature = "full", derive(Queryable, Identifiable))]
+#[cfg_attr(feature = "full", diesel(table_name = directory_community))]
+pub struct DirectoryCommunity {
+  pub id: DirectoryCommunityId,
+  pub instance_id: InstanceId,
+  pub actor_id: DbUrl,
+  pub name: String,
+  pub title: String,
+  pub description: Option<String>,
+  pub icon: Option<DbUrl>,
+  pub nsfw: bool,
+  pub subscribers: i64,
+  pub posts: i64,
+  /// Languages which are allowed on the instance, empty if all of them are allowed
+  pub language_ids: Vec<LanguageId>,
+  #[doku(as = "String")]
+  pub published: chrono::NaiveDateTime,
+  /// When the community was last seen by the crawler
+  #[doku(as = "String")]
+  pub updated: chrono::NaiveDateTime,
+}
+
+#[derive(Clone)]
+#[cfg_attr(feature = "full", derive(Insertable, AsChangeset))]
+#[cfg_attr(feature = "full", diesel(table_name = directory_community))]
+pub struct DirectoryCommunityForm {
+  pub instance_id: InstanceId,
+  pub actor_id: DbUrl,
+  pub name: String,
+  pub title: String,
+  pub description: Option<String>,
+  pub icon: Option<DbUrl>,
+  pub nsfw: bool,
+  pub subscribers: i64,
+  pub posts: i64,
+  pub language_ids: Vec<LanguageId>,
+  pub updated: chrono::NaiveDateTime,
+}
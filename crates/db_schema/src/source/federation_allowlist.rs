@@ -1,10 +1,11 @@
 use crate::newtypes::InstanceId;
 #[cfg(feature = "full")]
 use crate::schema::federation_allowlist;
+use doku::Document;
 use serde::{Deserialize, Serialize};
 use std::fmt::Debug;
 
-#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize, Document)]
 #[cfg_attr(feature = "full", derive(Queryable, Associations, Identifiable))]
 #[cfg_attr(
   feature = "full",
@@ -14,7 +15,9 @@ use std::fmt::Debug;
 pub struct FederationAllowList {
   pub id: i32,
   pub instance_id: InstanceId,
+  #[doku(as = "String")]
   pub published: chrono::NaiveDateTime,
+  #[doku(as = "Option<String>")]
   pub updated: Option<chrono::NaiveDateTime>,
 }
 
@@ -1,11 +1,12 @@
 use crate::newtypes::InstanceId;
 #[cfg(feature = "full")]
 use crate::schema::instance;
+use doku::Document;
 use serde::{Deserialize, Serialize};
 use std::fmt::Debug;
 use typed_builder::TypedBuilder;
 
-#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize, Document)]
 #[cfg_attr(feature = "full", derive(Queryable, Identifiable))]
 #[cfg_attr(feature = "full", diesel(table_name = instance))]
 pub struct Instance {
@@ -13,7 +14,9 @@ pub struct Instance {
   pub domain: String,
   pub software: Option<String>,
   pub version: Option<String>,
+  #[doku(as = "String")]
   pub published: chrono::NaiveDateTime,
+  #[doku(as = "Option<String>")]
   pub updated: Option<chrono::NaiveDateTime>,
 }
 
@@ -0,0 +1,32 @@
+use crate::newtypes::{CommentId, CommunityId, InstanceId, PersonId, PostId};
+#[cfg(feature = "full")]
+use crate::schema::instance_block_hidden;
+use doku::Document;
+use serde::{Deserialize, Serialize};
+
+/// A community, post, comment or person which was removed or banned because its instance was
+/// blocked.
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize, Document)]
+#[cfg_attr(feature = "full", derive(Queryable, Identifiable))]
+#[cfg_attr(feature = "full", diesel(table_name = instance_block_hidden))]
+pub struct InstanceBlockHidden {
+  pub id: i32,
+  pub instance_id: InstanceId,
+  pub community_id: Option<CommunityId>,
+  pub post_id: Option<PostId>,
+  pub comment_id: Option<CommentId>,
+  pub person_id: Option<PersonId>,
+  #[doku(as = "String")]
+  pub published: chrono::NaiveDateTime,
+}
+
+#[derive(Clone, Default)]
+#[cfg_attr(feature = "full", derive(Insertable, AsChangeset))]
+#[cfg_attr(feature = "full", diesel(table_name = instance_block_hidden))]
+pub struct InstanceBlockHiddenForm {
+  pub instance_id: InstanceId,
+  pub community_id: Option<CommunityId>,
+  pub post_id: Option<PostId>,
+  pub comment_id: Option<CommentId>,
+  pub person_id: Option<PersonId>,
+}
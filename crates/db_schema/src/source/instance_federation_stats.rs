@@ -0,0 +1,62 @@
+use crate::newtypes::{InstanceFederationStatsId, InstanceId, InstanceReceivedActivityId};
+#[cfg(feature = "full")]
+use crate::schema::{instance_federation_stats, instance_received_activity};
+use doku::Document;
+use serde::{Deserialize, Serialize};
+
+/// How well federation with a remote instance works, in both directions.
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize, Document)]
+#[cfg_attr(feature = "full", derive(Queryable, Identifiable))]
+#[cfg_attr(feature = "full", diesel(table_name = instance_federation_stats))]
+pub struct InstanceFederationStats {
+  pub id: InstanceFederationStatsId,
+  pub instance_id: InstanceId,
+  #[doku(as = "Option<String>")]
+  pub last_inbound_success: Option<chrono::NaiveDateTime>,
+  pub inbound_failure_count: i64,
+  #[doku(as = "Option<String>")]
+  pub last_outbound_success: Option<chrono::NaiveDateTime>,
+  #[doku(as = "Option<String>")]
+  pub last_outbound_failure: Option<chrono::NaiveDateTime>,
+  pub outbound_success_count: i64,
+  pub outbound_failure_count: i64,
+  pub delivery_time_total_ms: i64,
+  #[doku(as = "String")]
+  pub published: chrono::NaiveDateTime,
+  #[doku(as = "Option<String>")]
+  pub updated: Option<chrono::NaiveDateTime>,
+}
+
+#[derive(Clone, Default)]
+#[cfg_attr(feature = "full", derive(Insertable, AsChangeset))]
+#[cfg_attr(feature = "full", diesel(table_name = instance_federation_stats))]
+pub struct InstanceFederationStatsForm {
+  pub instance_id: InstanceId,
+  pub last_inbound_success: Option<chrono::NaiveDateTime>,
+  pub inbound_failure_count: i64,
+  pub last_outbound_success: Option<chrono::NaiveDateTime>,
+  pub last_outbound_failure: Option<chrono::NaiveDateTime>,
+  pub outbound_success_count: i64,
+  pub outbound_failure_count: i64,
+  pub delivery_time_total_ms: i64,
+}
+
+/// Number of activities of a given type which were received from a remote instance.
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize, Document)]
+#[cfg_attr(feature = "full", derive(Queryable, Identifiable))]
+#[cfg_attr(feature = "full", diesel(table_name = instance_received_activity))]
+pub struct InstanceReceivedActivity {
+  pub id: InstanceReceivedActivityId,
+  pub instance_id: InstanceId,
+  pub kind: String,
+  pub count: i64,
+}
+
+#[derive(Clone)]
+#[cfg_attr(feature = "full", derive(Insertable, AsChangeset))]
+#[cfg_attr(feature = "full", diesel(table_name = instance_received_activity))]
+pub struct InstanceReceivedActivityForm {
+  pub instance_id: InstanceId,
+  pub kind: String,
+  pub count: i64,
+}
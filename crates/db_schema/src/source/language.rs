@@ -1,9 +1,10 @@
 use crate::newtypes::LanguageId;
 #[cfg(feature = "full")]
 use crate::schema::language;
+use doku::Document;
 use serde::{Deserialize, Serialize};
 
-#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize, Document)]
 #[cfg_attr(feature = "full", derive(Queryable, Identifiable))]
 #[cfg_attr(feature = "full", diesel(table_name = language))]
 pub struct Language {
@@ -1,10 +1,11 @@
 use crate::newtypes::{LocalSiteId, SiteId};
 #[cfg(feature = "full")]
 use crate::schema::local_site;
+use doku::Document;
 use serde::{Deserialize, Serialize};
 use typed_builder::TypedBuilder;
 
-#[derive(PartialEq, Eq, Debug, Clone, Serialize, Deserialize)]
+#[derive(PartialEq, Eq, Debug, Clone, Serialize, Deserialize, Document)]
 #[cfg_attr(feature = "full", derive(Queryable, Identifiable))]
 #[cfg_attr(feature = "full", diesel(table_name = local_site))]
 #[cfg_attr(feature = "full", diesel(belongs_to(crate::source::site::Site)))]
@@ -32,7 +33,12 @@ pub struct LocalSite {
   pub captcha_difficulty: String,
   pub registration_mode: RegistrationMode,
   pub reports_email_admins: bool,
+  pub convert_notes_to_posts: bool,
+  pub authorized_fetch: bool,
+  pub community_directory: bool,
+  #[doku(as = "String")]
   pub published: chrono::NaiveDateTime,
+  #[doku(as = "Option<String>")]
   pub updated: Option<chrono::NaiveDateTime>,
 }
 
@@ -64,6 +70,9 @@ pub struct LocalSiteInsertForm {
   pub captcha_difficulty: Option<String>,
   pub registration_mode: Option<RegistrationMode>,
   pub reports_email_admins: Option<bool>,
+  pub convert_notes_to_posts: Option<bool>,
+  pub authorized_fetch: Option<bool>,
+  pub community_directory: Option<bool>,
 }
 
 #[derive(Clone, TypedBuilder)]
@@ -92,6 +101,9 @@ pub struct LocalSiteUpdateForm {
   pub captcha_difficulty: Option<String>,
   pub registration_mode: Option<RegistrationMode>,
   pub reports_email_admins: Option<bool>,
+  pub convert_notes_to_posts: Option<bool>,
+  pub authorized_fetch: Option<bool>,
+  pub community_directory: Option<bool>,
   pub updated: Option<Option<chrono::NaiveDateTime>>,
 }
 
@@ -100,7 +112,7 @@ pub struct LocalSiteUpdateForm {
 #[diesel(postgres_type(name = "registration_mode_enum"))]
 pub struct RegistrationModeType;
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, Document)]
 #[cfg_attr(feature = "full", derive(FromSqlRow, AsExpression))]
 #[cfg_attr(feature = "full", diesel(sql_type = RegistrationModeType))]
 #[serde(rename_all = "lowercase")]
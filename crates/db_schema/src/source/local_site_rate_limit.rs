@@ -1,10 +1,11 @@
 use crate::newtypes::LocalSiteId;
 #[cfg(feature = "full")]
 use crate::schema::local_site_rate_limit;
+use doku::Document;
 use serde::{Deserialize, Serialize};
 use typed_builder::TypedBuilder;
 
-#[derive(PartialEq, Eq, Debug, Clone, Serialize, Deserialize)]
+#[derive(PartialEq, Eq, Debug, Clone, Serialize, Deserialize, Document)]
 #[cfg_attr(feature = "full", derive(Queryable, Identifiable))]
 #[cfg_attr(feature = "full", diesel(table_name = local_site_rate_limit))]
 #[cfg_attr(
@@ -26,7 +27,9 @@ pub struct LocalSiteRateLimit {
   pub comment_per_second: i32,
   pub search: i32,
   pub search_per_second: i32,
+  #[doku(as = "String")]
   pub published: chrono::NaiveDateTime,
+  #[doku(as = "Option<String>")]
   pub updated: Option<chrono::NaiveDateTime>,
 }
 
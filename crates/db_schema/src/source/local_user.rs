@@ -1,10 +1,11 @@
 use crate::newtypes::{LocalUserId, PersonId};
 #[cfg(feature = "full")]
 use crate::schema::local_user;
+use doku::Document;
 use serde::{Deserialize, Serialize};
 use typed_builder::TypedBuilder;
 
-#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize, Document)]
 #[cfg_attr(feature = "full", derive(Queryable, Identifiable))]
 #[cfg_attr(feature = "full", diesel(table_name = local_user))]
 pub struct LocalUser {
@@ -19,6 +20,7 @@ pub struct LocalUser {
   pub interface_language: String,
   pub show_avatars: bool,
   pub send_notifications_to_email: bool,
+  #[doku(as = "String")]
   pub validator_time: chrono::NaiveDateTime,
   pub show_bot_accounts: bool,
   pub show_scores: bool,
@@ -29,7 +31,7 @@ pub struct LocalUser {
 }
 
 /// A local user view that removes password encrypted
-#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize, Document)]
 #[cfg_attr(feature = "full", derive(Queryable, Identifiable))]
 #[cfg_attr(feature = "full", diesel(table_name = local_user))]
 pub struct LocalUserSettings {
@@ -43,6 +45,7 @@ pub struct LocalUserSettings {
   pub interface_language: String,
   pub show_avatars: bool,
   pub send_notifications_to_email: bool,
+  #[doku(as = "String")]
   pub validator_time: chrono::NaiveDateTime,
   pub show_bot_accounts: bool,
   pub show_scores: bool,
@@ -1,10 +1,11 @@
+use doku::Document;
 use crate::newtypes::{LanguageId, LocalUserId, LocalUserLanguageId};
 use serde::{Deserialize, Serialize};
 
 #[cfg(feature = "full")]
 use crate::schema::local_user_language;
 
-#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize, Document)]
 #[cfg_attr(feature = "full", derive(Queryable, Identifiable))]
 #[cfg_attr(feature = "full", diesel(table_name = local_user_language))]
 pub struct LocalUserLanguage {
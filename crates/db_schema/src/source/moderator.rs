@@ -1,6 +1,7 @@
-use crate::newtypes::{CommentId, CommunityId, PersonId, PostId};
+use crate::newtypes::{CommentId, CommunityId, CommunityRuleId, InstanceId, PersonId, PostId};
 #[cfg(feature = "full")]
 use crate::schema::{
+  admin_block_instance,
   admin_purge_comment,
   admin_purge_community,
   admin_purge_person,
@@ -17,9 +18,10 @@ use crate::schema::{
   mod_remove_post,
   mod_transfer_community,
 };
+use doku::Document;
 use serde::{Deserialize, Serialize};
 
-#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize, Document)]
 #[cfg_attr(feature = "full", derive(Queryable, Identifiable))]
 #[cfg_attr(feature = "full", diesel(table_name = mod_remove_post))]
 pub struct ModRemovePost {
@@ -28,7 +30,10 @@ pub struct ModRemovePost {
   pub post_id: PostId,
   pub reason: Option<String>,
   pub removed: Option<bool>,
+  #[doku(as = "String")]
   pub when_: chrono::NaiveDateTime,
+  /// The community rule which was cited for the removal
+  pub rule_id: Option<CommunityRuleId>,
 }
 
 #[cfg_attr(feature = "full", derive(Insertable, AsChangeset))]
@@ -38,9 +43,10 @@ pub struct ModRemovePostForm {
   pub post_id: PostId,
   pub reason: Option<String>,
   pub removed: Option<bool>,
+  pub rule_id: Option<CommunityRuleId>,
 }
 
-#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize, Document)]
 #[cfg_attr(feature = "full", derive(Queryable, Identifiable))]
 #[cfg_attr(feature = "full", diesel(table_name = mod_lock_post))]
 pub struct ModLockPost {
@@ -48,6 +54,7 @@ pub struct ModLockPost {
   pub mod_person_id: PersonId,
   pub post_id: PostId,
   pub locked: Option<bool>,
+  #[doku(as = "String")]
   pub when_: chrono::NaiveDateTime,
 }
 
@@ -59,7 +66,7 @@ pub struct ModLockPostForm {
   pub locked: Option<bool>,
 }
 
-#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize, Document)]
 #[cfg_attr(feature = "full", derive(Queryable, Identifiable))]
 #[cfg_attr(feature = "full", diesel(table_name = mod_feature_post))]
 pub struct ModFeaturePost {
@@ -67,6 +74,7 @@ pub struct ModFeaturePost {
   pub mod_person_id: PersonId,
   pub post_id: PostId,
   pub featured: bool,
+  #[doku(as = "String")]
   pub when_: chrono::NaiveDateTime,
   pub is_featured_community: bool,
 }
@@ -80,7 +88,7 @@ pub struct ModFeaturePostForm {
   pub is_featured_community: bool,
 }
 
-#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize, Document)]
 #[cfg_attr(feature = "full", derive(Queryable, Identifiable))]
 #[cfg_attr(feature = "full", diesel(table_name = mod_remove_comment))]
 pub struct ModRemoveComment {
@@ -89,7 +97,10 @@ pub struct ModRemoveComment {
   pub comment_id: CommentId,
   pub reason: Option<String>,
   pub removed: Option<bool>,
+  #[doku(as = "String")]
   pub when_: chrono::NaiveDateTime,
+  /// The community rule which was cited for the removal
+  pub rule_id: Option<CommunityRuleId>,
 }
 
 #[cfg_attr(feature = "full", derive(Insertable, AsChangeset))]
@@ -99,9 +110,10 @@ pub struct ModRemoveCommentForm {
   pub comment_id: CommentId,
   pub reason: Option<String>,
   pub removed: Option<bool>,
+  pub rule_id: Option<CommunityRuleId>,
 }
 
-#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize, Document)]
 #[cfg_attr(feature = "full", derive(Queryable, Identifiable))]
 #[cfg_attr(feature = "full", diesel(table_name = mod_remove_community))]
 pub struct ModRemoveCommunity {
@@ -110,7 +122,9 @@ pub struct ModRemoveCommunity {
   pub community_id: CommunityId,
   pub reason: Option<String>,
   pub removed: Option<bool>,
+  #[doku(as = "Option<String>")]
   pub expires: Option<chrono::NaiveDateTime>,
+  #[doku(as = "String")]
   pub when_: chrono::NaiveDateTime,
 }
 
@@ -124,7 +138,7 @@ pub struct ModRemoveCommunityForm {
   pub expires: Option<chrono::NaiveDateTime>,
 }
 
-#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize, Document)]
 #[cfg_attr(feature = "full", derive(Queryable, Identifiable))]
 #[cfg_attr(feature = "full", diesel(table_name = mod_ban_from_community))]
 pub struct ModBanFromCommunity {
@@ -134,7 +148,9 @@ pub struct ModBanFromCommunity {
   pub community_id: CommunityId,
   pub reason: Option<String>,
   pub banned: Option<bool>,
+  #[doku(as = "Option<String>")]
   pub expires: Option<chrono::NaiveDateTime>,
+  #[doku(as = "String")]
   pub when_: chrono::NaiveDateTime,
 }
 
@@ -149,7 +165,7 @@ pub struct ModBanFromCommunityForm {
   pub expires: Option<chrono::NaiveDateTime>,
 }
 
-#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize, Document)]
 #[cfg_attr(feature = "full", derive(Queryable, Identifiable))]
 #[cfg_attr(feature = "full", diesel(table_name = mod_ban))]
 pub struct ModBan {
@@ -158,7 +174,9 @@ pub struct ModBan {
   pub other_person_id: PersonId,
   pub reason: Option<String>,
   pub banned: Option<bool>,
+  #[doku(as = "Option<String>")]
   pub expires: Option<chrono::NaiveDateTime>,
+  #[doku(as = "String")]
   pub when_: chrono::NaiveDateTime,
 }
 
@@ -170,7 +188,7 @@ pub struct ModHideCommunityForm {
   pub hidden: Option<bool>,
   pub reason: Option<String>,
 }
-#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize, Document)]
 #[cfg_attr(feature = "full", derive(Queryable, Identifiable))]
 #[cfg_attr(feature = "full", diesel(table_name = mod_hide_community))]
 pub struct ModHideCommunity {
@@ -179,6 +197,7 @@ pub struct ModHideCommunity {
   pub mod_person_id: PersonId,
   pub reason: Option<String>,
   pub hidden: Option<bool>,
+  #[doku(as = "String")]
   pub when_: chrono::NaiveDateTime,
 }
 
@@ -192,7 +211,7 @@ pub struct ModBanForm {
   pub expires: Option<chrono::NaiveDateTime>,
 }
 
-#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize, Document)]
 #[cfg_attr(feature = "full", derive(Queryable, Identifiable))]
 #[cfg_attr(feature = "full", diesel(table_name = mod_add_community))]
 pub struct ModAddCommunity {
@@ -201,6 +220,7 @@ pub struct ModAddCommunity {
   pub other_person_id: PersonId,
   pub community_id: CommunityId,
   pub removed: Option<bool>,
+  #[doku(as = "String")]
   pub when_: chrono::NaiveDateTime,
 }
 
@@ -213,7 +233,7 @@ pub struct ModAddCommunityForm {
   pub removed: Option<bool>,
 }
 
-#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize, Document)]
 #[cfg_attr(feature = "full", derive(Queryable, Identifiable))]
 #[cfg_attr(feature = "full", diesel(table_name = mod_transfer_community))]
 pub struct ModTransferCommunity {
@@ -222,6 +242,7 @@ pub struct ModTransferCommunity {
   pub other_person_id: PersonId,
   pub community_id: CommunityId,
   pub removed: Option<bool>,
+  #[doku(as = "String")]
   pub when_: chrono::NaiveDateTime,
 }
 
@@ -234,7 +255,7 @@ pub struct ModTransferCommunityForm {
   pub removed: Option<bool>,
 }
 
-#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize, Document)]
 #[cfg_attr(feature = "full", derive(Queryable, Identifiable))]
 #[cfg_attr(feature = "full", diesel(table_name = mod_add))]
 pub struct ModAdd {
@@ -242,6 +263,7 @@ pub struct ModAdd {
   pub mod_person_id: PersonId,
   pub other_person_id: PersonId,
   pub removed: Option<bool>,
+  #[doku(as = "String")]
   pub when_: chrono::NaiveDateTime,
 }
 
@@ -253,13 +275,14 @@ pub struct ModAddForm {
   pub removed: Option<bool>,
 }
 
-#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize, Document)]
 #[cfg_attr(feature = "full", derive(Queryable, Identifiable))]
 #[cfg_attr(feature = "full", diesel(table_name = admin_purge_person))]
 pub struct AdminPurgePerson {
   pub id: i32,
   pub admin_person_id: PersonId,
   pub reason: Option<String>,
+  #[doku(as = "String")]
   pub when_: chrono::NaiveDateTime,
 }
 
@@ -270,13 +293,14 @@ pub struct AdminPurgePersonForm {
   pub reason: Option<String>,
 }
 
-#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize, Document)]
 #[cfg_attr(feature = "full", derive(Queryable, Identifiable))]
 #[cfg_attr(feature = "full", diesel(table_name = admin_purge_community))]
 pub struct AdminPurgeCommunity {
   pub id: i32,
   pub admin_person_id: PersonId,
   pub reason: Option<String>,
+  #[doku(as = "String")]
   pub when_: chrono::NaiveDateTime,
 }
 
@@ -287,7 +311,7 @@ pub struct AdminPurgeCommunityForm {
   pub reason: Option<String>,
 }
 
-#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize, Document)]
 #[cfg_attr(feature = "full", derive(Queryable, Identifiable))]
 #[cfg_attr(feature = "full", diesel(table_name = admin_purge_post))]
 pub struct AdminPurgePost {
@@ -295,6 +319,7 @@ pub struct AdminPurgePost {
   pub admin_person_id: PersonId,
   pub community_id: CommunityId,
   pub reason: Option<String>,
+  #[doku(as = "String")]
   pub when_: chrono::NaiveDateTime,
 }
 
@@ -306,7 +331,7 @@ pub struct AdminPurgePostForm {
   pub reason: Option<String>,
 }
 
-#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize, Document)]
 #[cfg_attr(feature = "full", derive(Queryable, Identifiable))]
 #[cfg_attr(feature = "full", diesel(table_name = admin_purge_comment))]
 pub struct AdminPurgeComment {
@@ -314,6 +339,7 @@ pub struct AdminPurgeComment {
   pub admin_person_id: PersonId,
   pub post_id: PostId,
   pub reason: Option<String>,
+  #[doku(as = "String")]
   pub when_: chrono::NaiveDateTime,
 }
 
@@ -324,3 +350,29 @@ pub struct AdminPurgeCommentForm {
   pub post_id: PostId,
   pub reason: Option<String>,
 }
+
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize, Document)]
+#[cfg_attr(feature = "full", derive(Queryable, Identifiable))]
+#[cfg_attr(feature = "full", diesel(table_name = admin_block_instance))]
+pub struct AdminBlockInstance {
+  pub id: i32,
+  pub admin_person_id: PersonId,
+  pub instance_id: InstanceId,
+  pub blocked: bool,
+  pub hide_content: bool,
+  pub purge_content: bool,
+  pub reason: Option<String>,
+  #[doku(as = "String")]
+  pub when_: chrono::NaiveDateTime,
+}
+
+#[cfg_attr(feature = "full", derive(Insertable, AsChangeset))]
+#[cfg_attr(feature = "full", diesel(table_name = admin_block_instance))]
+pub struct AdminBlockInstanceForm {
+  pub admin_person_id: PersonId,
+  pub instance_id: InstanceId,
+  pub blocked: bool,
+  pub hide_content: bool,
+  pub purge_content: bool,
+  pub reason: Option<String>,
+}
@@ -1,10 +1,11 @@
 use crate::newtypes::{DbUrl, InstanceId, PersonId};
 #[cfg(feature = "full")]
 use crate::schema::{person, person_follower};
+use doku::Document;
 use serde::{Deserialize, Serialize};
 use typed_builder::TypedBuilder;
 
-#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize, Document)]
 #[cfg_attr(feature = "full", derive(Queryable, Identifiable))]
 #[cfg_attr(feature = "full", diesel(table_name = person))]
 pub struct Person {
@@ -13,13 +14,16 @@ pub struct Person {
   pub display_name: Option<String>,
   pub avatar: Option<DbUrl>,
   pub banned: bool,
+  #[doku(as = "String")]
   pub published: chrono::NaiveDateTime,
+  #[doku(as = "Option<String>")]
   pub updated: Option<chrono::NaiveDateTime>,
   pub actor_id: DbUrl,
   pub bio: Option<String>,
   pub local: bool,
   pub private_key: Option<String>,
   pub public_key: String,
+  #[doku(as = "String")]
   pub last_refreshed_at: chrono::NaiveDateTime,
   pub banner: Option<DbUrl>,
   pub deleted: bool,
@@ -28,12 +32,13 @@ pub struct Person {
   pub matrix_user_id: Option<String>,
   pub admin: bool,
   pub bot_account: bool,
+  #[doku(as = "Option<String>")]
   pub ban_expires: Option<chrono::NaiveDateTime>,
   pub instance_id: InstanceId,
 }
 
 /// A safe representation of person, without the sensitive info
-#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize, Document)]
 #[cfg_attr(feature = "full", derive(Queryable, Identifiable))]
 #[cfg_attr(feature = "full", diesel(table_name = person))]
 pub struct PersonSafe {
@@ -42,7 +47,9 @@ pub struct PersonSafe {
   pub display_name: Option<String>,
   pub avatar: Option<DbUrl>,
   pub banned: bool,
+  #[doku(as = "String")]
   pub published: chrono::NaiveDateTime,
+  #[doku(as = "Option<String>")]
   pub updated: Option<chrono::NaiveDateTime>,
   pub actor_id: DbUrl,
   pub bio: Option<String>,
@@ -54,6 +61,7 @@ pub struct PersonSafe {
   pub matrix_user_id: Option<String>,
   pub admin: bool,
   pub bot_account: bool,
+  #[doku(as = "Option<String>")]
   pub ban_expires: Option<chrono::NaiveDateTime>,
   pub instance_id: InstanceId,
 }
@@ -1,9 +1,10 @@
 use crate::newtypes::{PersonBlockId, PersonId};
 #[cfg(feature = "full")]
 use crate::schema::person_block;
+use doku::Document;
 use serde::{Deserialize, Serialize};
 
-#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize, Document)]
 #[cfg_attr(feature = "full", derive(Queryable, Associations, Identifiable))]
 #[cfg_attr(feature = "full", diesel(belongs_to(crate::source::person::Person)))]
 #[cfg_attr(feature = "full", diesel(table_name = person_block))]
@@ -11,6 +12,7 @@ pub struct PersonBlock {
   pub id: PersonBlockId,
   pub person_id: PersonId,
   pub target_id: PersonId,
+  #[doku(as = "String")]
   pub published: chrono::NaiveDateTime,
 }
 
@@ -1,9 +1,10 @@
 use crate::newtypes::{CommentId, PersonId, PersonMentionId};
 #[cfg(feature = "full")]
 use crate::schema::person_mention;
+use doku::Document;
 use serde::{Deserialize, Serialize};
 
-#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize, Document)]
 #[cfg_attr(feature = "full", derive(Queryable, Associations, Identifiable))]
 #[cfg_attr(feature = "full", diesel(belongs_to(crate::source::comment::Comment)))]
 #[cfg_attr(feature = "full", diesel(table_name = person_mention))]
@@ -12,6 +13,7 @@ pub struct PersonMention {
   pub recipient_id: PersonId,
   pub comment_id: CommentId,
   pub read: bool,
+  #[doku(as = "String")]
   pub published: chrono::NaiveDateTime,
 }
 
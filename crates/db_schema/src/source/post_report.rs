@@ -1,9 +1,10 @@
-use crate::newtypes::{DbUrl, PersonId, PostId, PostReportId};
+use crate::newtypes::{CommunityRuleId, DbUrl, PersonId, PostId, PostReportId};
 #[cfg(feature = "full")]
 use crate::schema::post_report;
+use doku::Document;
 use serde::{Deserialize, Serialize};
 
-#[derive(PartialEq, Eq, Serialize, Deserialize, Debug, Clone)]
+#[derive(PartialEq, Eq, Serialize, Deserialize, Debug, Clone, Document)]
 #[cfg_attr(feature = "full", derive(Identifiable, Queryable, Associations))]
 #[cfg_attr(feature = "full", diesel(belongs_to(crate::source::post::Post)))] // Is this the right assoc?
 #[cfg_attr(feature = "full", diesel(table_name = post_report))]
@@ -17,8 +18,15 @@ pub struct PostReport {
   pub reason: String,
   pub resolved: bool,
   pub resolver_id: Option<PersonId>,
+  #[doku(as = "String")]
   pub published: chrono::NaiveDateTime,
+  #[doku(as = "Option<String>")]
   pub updated: Option<chrono::NaiveDateTime>,
+  pub assignee_id: Option<PersonId>,
+  pub escalated: bool,
+  pub resolution_reason: Option<String>,
+  /// The community rule which the post breaks, if the reporter picked one
+  pub rule_id: Option<CommunityRuleId>,
 }
 
 #[derive(Clone)]
@@ -31,4 +39,5 @@ pub struct PostReportForm {
   pub original_post_url: Option<DbUrl>,
   pub original_post_body: Option<String>,
   pub reason: String,
+  pub rule_id: Option<CommunityRuleId>,
 }
@@ -1,10 +1,11 @@
 use crate::newtypes::{DbUrl, PersonId, PrivateMessageId};
 #[cfg(feature = "full")]
 use crate::schema::private_message;
+use doku::Document;
 use serde::{Deserialize, Serialize};
 use typed_builder::TypedBuilder;
 
-#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize, Document)]
 #[cfg_attr(feature = "full", derive(Queryable, Associations, Identifiable))]
 #[cfg_attr(
   feature = "full",
@@ -18,7 +19,9 @@ pub struct PrivateMessage {
   pub content: String,
   pub deleted: bool,
   pub read: bool,
+  #[doku(as = "String")]
   pub published: chrono::NaiveDateTime,
+  #[doku(as = "Option<String>")]
   pub updated: Option<chrono::NaiveDateTime>,
   pub ap_id: DbUrl,
   pub local: bool,
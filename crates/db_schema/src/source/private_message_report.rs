@@ -1,9 +1,10 @@
 use crate::newtypes::{PersonId, PrivateMessageId, PrivateMessageReportId};
 #[cfg(feature = "full")]
 use crate::schema::private_message_report;
+use doku::Document;
 use serde::{Deserialize, Serialize};
 
-#[derive(PartialEq, Eq, Serialize, Deserialize, Debug, Clone)]
+#[derive(PartialEq, Eq, Serialize, Deserialize, Debug, Clone, Document)]
 #[cfg_attr(feature = "full", derive(Queryable, Associations, Identifiable))]
 #[cfg_attr(
   feature = "full",
@@ -18,8 +19,13 @@ pub struct PrivateMessageReport {
   pub reason: String,
   pub resolved: bool,
   pub resolver_id: Option<PersonId>,
+  #[doku(as = "String")]
   pub published: chrono::NaiveDateTime,
+  #[doku(as = "Option<String>")]
   pub updated: Option<chrono::NaiveDateTime>,
+  pub assignee_id: Option<PersonId>,
+  pub escalated: bool,
+  pub resolution_reason: Option<String>,
 }
 
 #[derive(Clone)]
@@ -0,0 +1,27 @@
+#[cfg(feature = "full")]
+use crate::schema::rate_limit_bucket;
+use doku::Document;
+use serde::{Deserialize, Serialize};
+
+/// The rate limit allowance of an IP, shared by all processes.
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize, Document)]
+#[cfg_attr(feature = "full", derive(Queryable, Identifiable))]
+#[cfg_attr(feature = "full", diesel(table_name = rate_limit_bucket))]
+#[cfg_attr(feature = "full", diesel(primary_key(rate_limit_type, ip)))]
+pub struct RateLimitBucket {
+  pub rate_limit_type: String,
+  pub ip: String,
+  pub allowance: f64,
+  #[doku(as = "String")]
+  pub last_checked: chrono::NaiveDateTime,
+}
+
+#[derive(Clone)]
+#[cfg_attr(feature = "full", derive(Insertable, AsChangeset))]
+#[cfg_attr(feature = "full", diesel(table_name = rate_limit_bucket))]
+pub struct RateLimitBucketForm {
+  pub rate_limit_type: String,
+  pub ip: String,
+  pub allowance: f64,
+  pub last_checked: chrono::NaiveDateTime,
+}
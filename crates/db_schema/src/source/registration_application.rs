@@ -1,9 +1,10 @@
 use crate::newtypes::{LocalUserId, PersonId};
 #[cfg(feature = "full")]
 use crate::schema::registration_application;
+use doku::Document;
 use serde::{Deserialize, Serialize};
 
-#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize, Document)]
 #[cfg_attr(feature = "full", derive(Queryable, Identifiable))]
 #[cfg_attr(feature = "full", diesel(table_name = registration_application))]
 pub struct RegistrationApplication {
@@ -12,6 +13,7 @@ pub struct RegistrationApplication {
   pub answer: String,
   pub admin_id: Option<PersonId>,
   pub deny_reason: Option<String>,
+  #[doku(as = "String")]
   pub published: chrono::NaiveDateTime,
 }
 
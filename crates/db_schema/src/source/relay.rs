@@ -0,0 +1,43 @@
+use crate::newtypes::{DbUrl, RelayId};
+#[cfg(feature = "full")]
+use crate::schema::relay;
+use doku::Document;
+use serde::{Deserialize, Serialize};
+
+/// An ActivityPub relay which the site actor follows, to learn about posts from instances which
+/// no local user follows.
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize, Document)]
+#[cfg_attr(feature = "full", derive(Queryable, Identifiable))]
+#[cfg_attr(feature = "full", diesel(table_name = relay))]
+pub struct Relay {
+  pub id: RelayId,
+  pub actor_id: DbUrl,
+  pub inbox_url: DbUrl,
+  pub public_key: String,
+  /// True until the relay accepts the follow
+  pub pending: bool,
+  /// Also send activities of local communities to the relay
+  pub publish: bool,
+  #[doku(as = "String")]
+  pub published: chrono::NaiveDateTime,
+  #[doku(as = "String")]
+  pub last_refreshed_at: chrono::NaiveDateTime,
+}
+
+#[derive(Clone)]
+#[cfg_attr(feature = "full", derive(Insertable, AsChangeset))]
+#[cfg_attr(feature = "full", diesel(table_name = relay))]
+pub struct RelayInsertForm {
+  pub actor_id: DbUrl,
+  pub inbox_url: DbUrl,
+  pub public_key: String,
+  pub last_refreshed_at: Option<chrono::NaiveDateTime>,
+}
+
+#[derive(Clone, Default)]
+#[cfg_attr(feature = "full", derive(AsChangeset))]
+#[cfg_attr(feature = "full", diesel(table_name = relay))]
+pub struct RelayUpdateForm {
+  pub pending: Option<bool>,
+  pub publish: Option<bool>,
+}
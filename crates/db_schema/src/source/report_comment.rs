@@ -0,0 +1,37 @@
+use crate::newtypes::{
+  CommentReportId,
+  PersonId,
+  PostReportId,
+  PrivateMessageReportId,
+  ReportCommentId,
+};
+#[cfg(feature = "full")]
+use crate::schema::report_comment;
+use doku::Document;
+use serde::{Deserialize, Serialize};
+
+/// An internal note left by a moderator or admin on a report. Exactly one of the report ids is set.
+#[derive(PartialEq, Eq, Serialize, Deserialize, Debug, Clone, Document)]
+#[cfg_attr(feature = "full", derive(Queryable, Identifiable))]
+#[cfg_attr(feature = "full", diesel(table_name = report_comment))]
+pub struct ReportComment {
+  pub id: ReportCommentId,
+  pub creator_id: PersonId,
+  pub post_report_id: Option<PostReportId>,
+  pub comment_report_id: Option<CommentReportId>,
+  pub private_message_report_id: Option<PrivateMessageReportId>,
+  pub content: String,
+  #[doku(as = "String")]
+  pub published: chrono::NaiveDateTime,
+}
+
+#[derive(Clone, Default)]
+#[cfg_attr(feature = "full", derive(Insertable, AsChangeset))]
+#[cfg_attr(feature = "full", diesel(table_name = report_comment))]
+pub struct ReportCommentForm {
+  pub creator_id: PersonId,
+  pub post_report_id: Option<PostReportId>,
+  pub comment_report_id: Option<CommentReportId>,
+  pub private_message_report_id: Option<PrivateMessageReportId>,
+  pub content: String,
+}
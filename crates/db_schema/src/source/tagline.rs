@@ -1,9 +1,10 @@
 use crate::newtypes::LocalSiteId;
 #[cfg(feature = "full")]
 use crate::schema::tagline;
+use doku::Document;
 use serde::{Deserialize, Serialize};
 
-#[derive(PartialEq, Eq, Debug, Clone, Serialize, Deserialize)]
+#[derive(PartialEq, Eq, Debug, Clone, Serialize, Deserialize, Document)]
 #[cfg_attr(feature = "full", derive(Queryable, Associations, Identifiable))]
 #[cfg_attr(feature = "full", diesel(table_name = tagline))]
 #[cfg_attr(
@@ -14,7 +15,9 @@ pub struct Tagline {
   pub id: i32,
   pub local_site_id: LocalSiteId,
   pub content: String,
+  #[doku(as = "String")]
   pub published: chrono::NaiveDateTime,
+  #[doku(as = "Option<String>")]
   pub updated: Option<chrono::NaiveDateTime>,
 }
 
@@ -0,0 +1,26 @@
+#[cfg(feature = "full")]
+use crate::schema::websocket_message;
+use serde::{Deserialize, Serialize};
+
+/// A websocket message which is relayed to the other processes, so that they can deliver it to
+/// their own connections.
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "full", derive(Queryable, Identifiable))]
+#[cfg_attr(feature = "full", diesel(table_name = websocket_message))]
+pub struct WebsocketMessage {
+  pub id: i32,
+  /// The process which sent the message, and already delivered it
+  pub node_id: String,
+  pub room: serde_json::Value,
+  pub message: String,
+  pub published: chrono::NaiveDateTime,
+}
+
+#[derive(Clone)]
+#[cfg_attr(feature = "full", derive(Insertable, AsChangeset))]
+#[cfg_attr(feature = "full", diesel(table_name = websocket_message))]
+pub struct WebsocketMessageForm {
+  pub node_id: String,
+  pub room: serde_json::Value,
+  pub message: String,
+}
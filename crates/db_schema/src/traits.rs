@@ -121,6 +121,7 @@ pub trait Readable {
 pub trait Reportable {
   type Form;
   type IdType;
+  type ObjectIdType;
   async fn report(pool: &DbPool, form: &Self::Form) -> Result<Self, Error>
   where
     Self: Sized;
@@ -128,7 +129,18 @@ pub trait Reportable {
     pool: &DbPool,
     report_id: Self::IdType,
     resolver_id: PersonId,
+    resolution_reason: Option<String>,
   ) -> Result<usize, Error>
+  where
+    Self: Sized;
+  /// Resolves every open report on the same item, so that grouped reports are closed together.
+  /// Returns the reports which were resolved by this call.
+  async fn resolve_all_for_object(
+    pool: &DbPool,
+    object_id: Self::ObjectIdType,
+    resolver_id: PersonId,
+    resolution_reason: Option<String>,
+  ) -> Result<Vec<Self>, Error>
   where
     Self: Sized;
   async fn unresolve(
@@ -138,6 +150,21 @@ pub trait Reportable {
   ) -> Result<usize, Error>
   where
     Self: Sized;
+  /// when you want to unassign a report, send None
+  async fn assign(
+    pool: &DbPool,
+    report_id: Self::IdType,
+    assignee_id: Option<PersonId>,
+  ) -> Result<usize, Error>
+  where
+    Self: Sized;
+  async fn escalate(
+    pool: &DbPool,
+    report_id: Self::IdType,
+    escalated: bool,
+  ) -> Result<usize, Error>
+  where
+    Self: Sized;
 }
 
 // TODO these should be removed, there should be another way to do this
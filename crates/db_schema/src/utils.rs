@@ -1,7 +1,7 @@
 use crate::{
   diesel::Connection,
   diesel_migrations::MigrationHarness,
-  newtypes::DbUrl,
+  newtypes::{DbUrl, PersonId},
   CommentSortType,
   SortType,
 };
@@ -22,22 +22,178 @@ use diesel_async::{
   pooled_connection::{bb8::Pool, AsyncDieselConnectionManager},
 };
 use diesel_migrations::EmbeddedMigrations;
-use lemmy_utils::{error::LemmyError, settings::structs::Settings};
+use lemmy_utils::{
+  error::{LemmyError, LemmyErrorExt, LemmyErrorType},
+  settings::structs::{Settings, SharedStateBackend},
+};
 use once_cell::sync::Lazy;
 use regex::Regex;
-use std::{env, env::VarError};
-use tracing::info;
+use std::{
+  collections::HashMap,
+  env,
+  env::VarError,
+  sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+    Mutex,
+    PoisonError,
+  },
+  time::{Duration, Instant},
+};
+use tracing::{error, info, warn};
 use url::Url;
 
 const FETCH_LIMIT_DEFAULT: i64 = 10;
 pub const FETCH_LIMIT_MAX: i64 = 50;
 
-pub type DbPool = Pool<AsyncPgConnection>;
+/// Connections to the primary database, and to its read replicas if any are configured
+#[derive(Clone)]
+pub struct DbPool {
+  primary: Pool<AsyncPgConnection>,
+  replicas: Option<Arc<ReadReplicas>>,
+}
+
+struct ReadReplicas {
+  pools: Vec<Pool<AsyncPgConnection>>,
+  next: AtomicUsize,
+  /// When each person last wrote something, entries are dropped once they are older than
+  /// `read_your_writes`
+  recent_writes: Mutex<HashMap<PersonId, Instant>>,
+  read_your_writes: Duration,
+  /// Also keep the recent writes in the person_recent_write table, so that they are seen by
+  /// all processes when the shared state is in postgres
+  shared: bool,
+}
+
+impl DbPool {
+  /// Connection statistics of the primary database
+  pub fn state(&self) -> bb8::State {
+    self.primary.state()
+  }
+
+  pub fn has_read_replicas(&self) -> bool {
+    self.replicas.is_some()
+  }
+
+  /// Sends the reads of this person to the primary for a while, so that they see their own
+  /// changes even if the replicas lag behind.
+  pub async fn record_write(&self, person_id: PersonId) {
+    if let Some(replicas) = &self.replicas {
+      {
+        let now = Instant::now();
+        let mut recent_writes = replicas
+          .recent_writes
+          .lock()
+          .unwrap_or_else(PoisonError::into_inner);
+        recent_writes.retain(|_, written| now.duration_since(*written) < replicas.read_your_writes);
+        recent_writes.insert(person_id, now);
+      }
+      if replicas.shared {
+        if let Err(e) = self.store_recent_write(person_id).await {
+          warn!("Failed to store recent write: {}", e);
+        }
+      }
+    }
+  }
+
+  async fn store_recent_write(&self, person_id_: PersonId) -> Result<(), DieselError> {
+    use crate::schema::person_recent_write::dsl::{person_id, person_recent_write, written};
+    use diesel::{dsl::now, ExpressionMethods};
+    use diesel_async::RunQueryDsl;
+    let conn = &mut get_conn(self).await?;
+    diesel::insert_into(person_recent_write)
+      .values(person_id.eq(person_id_))
+      .on_conflict(person_id)
+      .do_update()
+      .set(written.eq(now))
+      .execute(conn)
+      .await?;
+    Ok(())
+  }
+
+  /// Whether another process recorded a write of this person within the read-your-writes window
+  async fn has_shared_recent_write(
+    &self,
+    reader: PersonId,
+    read_your_writes: Duration,
+  ) -> Result<bool, DieselError> {
+    use crate::schema::person_recent_write::dsl::{person_id, person_recent_write, written};
+    use diesel::{
+      dsl::{exists, now, IntervalDsl},
+      select,
+      ExpressionMethods,
+      QueryDsl,
+    };
+    use diesel_async::RunQueryDsl;
+    let seconds = i32::try_from(read_your_writes.as_secs()).unwrap_or(i32::MAX);
+    let conn = &mut get_conn(self).await?;
+    select(exists(
+      person_recent_write
+        .filter(person_id.eq(reader))
+        .filter(written.gt(now - seconds.seconds())),
+    ))
+    .get_result(conn)
+    .await
+  }
+
+  /// The replica which should serve the next read of `reader`, or none to use the primary
+  async fn read_replica(&self, reader: Option<PersonId>) -> Option<&Pool<AsyncPgConnection>> {
+    let replicas = self.replicas.as_ref()?;
+    if let Some(reader) = reader {
+      let written_here = replicas
+        .recent_writes
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .get(&reader)
+        .is_some_and(|written| written.elapsed() < replicas.read_your_writes);
+      if written_here {
+        return None;
+      }
+      if replicas.shared {
+        match self
+          .has_shared_recent_write(reader, replicas.read_your_writes)
+          .await
+        {
+          Ok(false) => {}
+          Ok(true) => return None,
+          Err(e) => {
+            warn!("Failed to read recent writes, using primary instead: {}", e);
+            return None;
+          }
+        }
+      }
+    }
+    let next = replicas.next.fetch_add(1, Ordering::Relaxed);
+    replicas.pools.get(next % replicas.pools.len())
+  }
+}
 
 pub async fn get_conn(
   pool: &DbPool,
-) -> Result<PooledConnection<AsyncDieselConnectionManager<AsyncPgConnection>>, DieselError> {
-  pool.get().await.map_err(|e| QueryBuilderError(e.into()))
+) -> Result<PooledConnection<'_, AsyncDieselConnectionManager<AsyncPgConnection>>, DieselError> {
+  pool
+    .primary
+    .get()
+    .await
+    .map_err(|e| QueryBuilderError(e.into()))
+}
+
+/// Connection for listings and other read-only queries. It comes from a read replica if any are
+/// configured, except when `reader` wrote something recently.
+pub async fn get_read_conn(
+  pool: &DbPool,
+  reader: Option<PersonId>,
+) -> Result<PooledConnection<'_, AsyncDieselConnectionManager<AsyncPgConnection>>, DieselError> {
+  if let Some(replica) = pool.read_replica(reader).await {
+    match replica.get().await {
+      Ok(conn) => return Ok(conn),
+      Err(e) => warn!(
+        "Failed to connect to read replica, using primary instead: {}",
+        e
+      ),
+    }
+  }
+  get_conn(pool).await
 }
 
 pub fn get_database_url_from_env() -> Result<String, VarError> {
@@ -111,7 +267,7 @@ pub fn diesel_option_overwrite_to_url(
     Some("") => Ok(Some(None)),
     Some(str_url) => match Url::parse(str_url) {
       Ok(url) => Ok(Some(Some(url.into()))),
-      Err(e) => Err(LemmyError::from_error_message(e, "invalid_url")),
+      Err(e) => Err(e).with_lemmy_type(LemmyErrorType::InvalidUrl),
     },
     None => Ok(None),
   }
@@ -125,7 +281,7 @@ pub fn diesel_option_overwrite_to_url_create(
     Some("") => Ok(None),
     Some(str_url) => match Url::parse(str_url) {
       Ok(url) => Ok(Some(url.into())),
-      Err(e) => Err(LemmyError::from_error_message(e, "invalid_url")),
+      Err(e) => Err(e).with_lemmy_type(LemmyErrorType::InvalidUrl),
     },
     None => Ok(None),
   }
@@ -134,19 +290,45 @@ pub fn diesel_option_overwrite_to_url_create(
 async fn build_db_pool_settings_opt(settings: Option<&Settings>) -> Result<DbPool, LemmyError> {
   let db_url = get_database_url(settings);
   let pool_size = settings.map(|s| s.database.pool_size).unwrap_or(5);
-  let manager = AsyncDieselConnectionManager::<AsyncPgConnection>::new(&db_url);
-  let pool = Pool::builder()
-    .max_size(pool_size)
-    .min_idle(Some(1))
-    .build(manager)
-    .await?;
+  let primary = build_pool(&db_url, pool_size).await?;
+
+  let replica_config = settings.and_then(|s| s.database.read_replicas.as_ref());
+  let replicas = match replica_config {
+    Some(config) if !config.urls.is_empty() => {
+      let mut pools = vec![];
+      for url in &config.urls {
+        pools.push(build_pool(url, config.pool_size).await?);
+      }
+      Some(Arc::new(ReadReplicas {
+        pools,
+        next: AtomicUsize::new(0),
+        recent_writes: Mutex::new(HashMap::new()),
+        read_your_writes: Duration::from_secs(config.read_your_writes_seconds),
+        shared: settings
+          .map(|s| s.shared_state == SharedStateBackend::Postgres)
+          .unwrap_or(false),
+      }))
+    }
+    _ => None,
+  };
 
   // If there's no settings, that means its a unit test, and migrations need to be run
   if settings.is_none() {
     run_migrations(&db_url);
   }
 
-  Ok(pool)
+  Ok(DbPool { primary, replicas })
+}
+
+async fn build_pool(db_url: &str, pool_size: u32) -> Result<Pool<AsyncPgConnection>, LemmyError> {
+  let manager = AsyncDieselConnectionManager::<AsyncPgConnection>::new(db_url);
+  Ok(
+    Pool::builder()
+      .max_size(pool_size)
+      .min_idle(Some(1))
+      .build(manager)
+      .await?,
+  )
 }
 
 pub const MIGRATIONS: EmbeddedMigrations = embed_migrations!();
@@ -162,6 +344,23 @@ pub fn run_migrations(db_url: &str) {
   info!("Database migrations complete.");
 }
 
+/// Reverts the last `count` migrations which were applied, newest first. Returns their names.
+pub fn revert_migrations(db_url: &str, count: usize) -> Result<Vec<String>, LemmyError> {
+  let mut conn = PgConnection::establish(db_url)?;
+  let mut reverted = vec![];
+  for _ in 0..count {
+    let version = conn
+      .revert_last_migration(MIGRATIONS)
+      .map_err(|e| {
+        error!("Couldn't revert DB migration: {}", e);
+        LemmyErrorType::CouldntRevertMigration
+      })?;
+    info!("Reverted migration {}", version);
+    reverted.push(version.to_string());
+  }
+  Ok(reverted)
+}
+
 pub async fn build_db_pool(settings: &Settings) -> Result<DbPool, LemmyError> {
   build_db_pool_settings_opt(Some(settings)).await
 }
@@ -245,6 +444,7 @@ where
 mod tests {
   use super::{fuzzy_search, *};
   use crate::utils::is_email_regex;
+  use serial_test::serial;
 
   #[test]
   fn test_fuzzy_search() {
@@ -271,6 +471,68 @@ mod tests {
     );
   }
 
+  fn unchecked_pool(url: &str) -> Pool<AsyncPgConnection> {
+    Pool::builder().build_unchecked(AsyncDieselConnectionManager::<AsyncPgConnection>::new(url))
+  }
+
+  fn with_replica(primary: Pool<AsyncPgConnection>, shared: bool) -> DbPool {
+    DbPool {
+      primary,
+      replicas: Some(Arc::new(ReadReplicas {
+        pools: vec![unchecked_pool("postgres://replica/lemmy")],
+        next: AtomicUsize::new(0),
+        recent_writes: Mutex::new(HashMap::new()),
+        read_your_writes: Duration::from_secs(60),
+        shared,
+      })),
+    }
+  }
+
+  #[tokio::test]
+  async fn test_read_your_writes() {
+    let db_pool = with_replica(unchecked_pool("postgres://primary/lemmy"), false);
+    let writer = PersonId(1);
+    let other = PersonId(2);
+    assert!(db_pool.read_replica(None).await.is_some());
+    assert!(db_pool.read_replica(Some(writer)).await.is_some());
+
+    db_pool.record_write(writer).await;
+    assert!(db_pool.read_replica(Some(writer)).await.is_none());
+    assert!(db_pool.read_replica(Some(other)).await.is_some());
+    assert!(db_pool.read_replica(None).await.is_some());
+
+    let without_replicas = DbPool {
+      primary: unchecked_pool("postgres://primary/lemmy"),
+      replicas: None,
+    };
+    without_replicas.record_write(writer).await;
+    assert!(without_replicas.read_replica(None).await.is_none());
+  }
+
+  #[tokio::test]
+  #[serial]
+  async fn test_read_your_writes_shared() {
+    let primary = build_db_pool_for_tests().await.primary;
+    // Two processes which share their state through the same primary
+    let first_process = with_replica(primary.clone(), true);
+    let second_process = with_replica(primary, true);
+    let writer = PersonId(-1);
+    let other = PersonId(-2);
+
+    first_process.record_write(writer).await;
+    assert!(second_process.read_replica(Some(writer)).await.is_none());
+    assert!(second_process.read_replica(Some(other)).await.is_some());
+    assert!(second_process.read_replica(None).await.is_some());
+
+    let conn = &mut get_conn(&first_process).await.unwrap();
+    diesel_async::RunQueryDsl::execute(
+      diesel::delete(crate::schema::person_recent_write::table),
+      conn,
+    )
+    .await
+    .unwrap();
+  }
+
   #[test]
   fn test_diesel_option_overwrite_to_url() {
     assert!(matches!(diesel_option_overwrite_to_url(&None), Ok(None)));
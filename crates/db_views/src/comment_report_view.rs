@@ -11,7 +11,7 @@ use diesel::{
 use diesel_async::RunQueryDsl;
 use lemmy_db_schema::{
   aggregates::structs::CommentAggregates,
-  newtypes::{CommentReportId, CommunityId, PersonId},
+  newtypes::{CommentId, CommentReportId, CommunityId, PersonId},
   schema::{
     comment,
     comment_aggregates,
@@ -31,7 +31,7 @@ use lemmy_db_schema::{
     post::Post,
   },
   traits::{ToSafe, ViewToVec},
-  utils::{get_conn, limit_and_offset, DbPool},
+  utils::{get_conn, get_read_conn, limit_and_offset, DbPool},
 };
 use typed_builder::TypedBuilder;
 
@@ -46,6 +46,7 @@ type CommentReportViewTuple = (
   Option<CommunityPersonBan>,
   Option<i16>,
   Option<PersonSafe>,
+  Option<PersonSafe>,
 );
 
 impl CommentReportView {
@@ -59,7 +60,8 @@ impl CommentReportView {
   ) -> Result<Self, Error> {
     let conn = &mut get_conn(pool).await?;
 
-    let (person_alias_1, person_alias_2) = diesel::alias!(person as person1, person as person2);
+    let (person_alias_1, person_alias_2, person_alias_3) =
+      diesel::alias!(person as person1, person as person2, person as person3);
 
     let (
       comment_report,
@@ -72,6 +74,7 @@ impl CommentReportView {
       creator_banned_from_community,
       comment_like,
       resolver,
+      assignee,
     ) = comment_report::table
       .find(report_id)
       .inner_join(comment::table)
@@ -105,6 +108,10 @@ impl CommentReportView {
         person_alias_2
           .on(comment_report::resolver_id.eq(person_alias_2.field(person::id).nullable())),
       )
+      .left_join(
+        person_alias_3
+          .on(comment_report::assignee_id.eq(person_alias_3.field(person::id).nullable())),
+      )
       .select((
         comment_report::all_columns,
         comment::all_columns,
@@ -118,6 +125,9 @@ impl CommentReportView {
         person_alias_2
           .fields(Person::safe_columns_tuple())
           .nullable(),
+        person_alias_3
+          .fields(Person::safe_columns_tuple())
+          .nullable(),
       ))
       .first::<CommentReportViewTuple>(conn)
       .await?;
@@ -135,6 +145,7 @@ impl CommentReportView {
       creator_banned_from_community: creator_banned_from_community.is_some(),
       my_vote,
       resolver,
+      assignee,
     })
   }
 
@@ -147,7 +158,7 @@ impl CommentReportView {
   ) -> Result<i64, Error> {
     use diesel::dsl::count;
 
-    let conn = &mut get_conn(pool).await?;
+    let conn = &mut get_read_conn(pool, Some(my_person_id)).await?;
 
     let mut query = comment_report::table
       .inner_join(comment::table)
@@ -194,13 +205,17 @@ pub struct CommentReportQuery<'a> {
   page: Option<i64>,
   limit: Option<i64>,
   unresolved_only: Option<bool>,
+  comment_id: Option<CommentId>,
+  assignee_id: Option<PersonId>,
+  escalated_only: Option<bool>,
 }
 
 impl<'a> CommentReportQuery<'a> {
   pub async fn list(self) -> Result<Vec<CommentReportView>, Error> {
-    let conn = &mut get_conn(self.pool).await?;
+    let conn = &mut get_read_conn(self.pool, Some(self.my_person_id)).await?;
 
-    let (person_alias_1, person_alias_2) = diesel::alias!(person as person1, person as person2);
+    let (person_alias_1, person_alias_2, person_alias_3) =
+      diesel::alias!(person as person1, person as person2, person as person3);
 
     let mut query = comment_report::table
       .inner_join(comment::table)
@@ -234,6 +249,10 @@ impl<'a> CommentReportQuery<'a> {
         person_alias_2
           .on(comment_report::resolver_id.eq(person_alias_2.field(person::id).nullable())),
       )
+      .left_join(
+        person_alias_3
+          .on(comment_report::assignee_id.eq(person_alias_3.field(person::id).nullable())),
+      )
       .select((
         comment_report::all_columns,
         comment::all_columns,
@@ -247,6 +266,9 @@ impl<'a> CommentReportQuery<'a> {
         person_alias_2
           .fields(Person::safe_columns_tuple())
           .nullable(),
+        person_alias_3
+          .fields(Person::safe_columns_tuple())
+          .nullable(),
       ))
       .into_boxed();
 
@@ -254,10 +276,22 @@ impl<'a> CommentReportQuery<'a> {
       query = query.filter(post::community_id.eq(community_id));
     }
 
+    if let Some(comment_id) = self.comment_id {
+      query = query.filter(comment_report::comment_id.eq(comment_id));
+    }
+
+    if let Some(assignee_id) = self.assignee_id {
+      query = query.filter(comment_report::assignee_id.eq(assignee_id));
+    }
+
     if self.unresolved_only.unwrap_or(true) {
       query = query.filter(comment_report::resolved.eq(false));
     }
 
+    if self.escalated_only.unwrap_or(false) {
+      query = query.filter(comment_report::escalated.eq(true));
+    }
+
     let (limit, offset) = limit_and_offset(self.page, self.limit)?;
 
     query = query
@@ -301,6 +335,7 @@ impl ViewToVec for CommentReportView {
         creator_banned_from_community: a.7.is_some(),
         my_vote: a.8,
         resolver: a.9,
+        assignee: a.10,
       })
       .collect::<Vec<Self>>()
   }
@@ -403,6 +438,7 @@ mod tests {
       comment_id: inserted_comment.id,
       original_comment_text: "this was it at time of creation".into(),
       reason: "from sara".into(),
+      rule_id: None,
     };
 
     let inserted_sara_report = CommentReport::report(pool, &sara_report_form)
@@ -415,6 +451,7 @@ mod tests {
       comment_id: inserted_comment.id,
       original_comment_text: "this was it at time of creation".into(),
       reason: "from jessica".into(),
+      rule_id: None,
     };
 
     let inserted_jessica_report = CommentReport::report(pool, &jessica_report_form)
@@ -450,6 +487,7 @@ mod tests {
         posting_restricted_to_mods: false,
         published: inserted_community.published,
         instance_id: inserted_instance.id,
+        slur_filter_regex: None,
       },
       creator: PersonSafe {
         id: inserted_jessica.id,
@@ -505,6 +543,7 @@ mod tests {
       },
       my_vote: None,
       resolver: None,
+      assignee: None,
     };
 
     assert_eq!(read_jessica_report_view, expected_jessica_report_view);
@@ -558,7 +597,7 @@ mod tests {
     assert_eq!(2, report_count);
 
     // Try to resolve the report
-    CommentReport::resolve(pool, inserted_jessica_report.id, inserted_timmy.id)
+    CommentReport::resolve(pool, inserted_jessica_report.id, inserted_timmy.id, None)
       .await
       .unwrap();
     let read_jessica_report_view_after_resolve =
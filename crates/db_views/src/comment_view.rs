@@ -37,7 +37,14 @@ use lemmy_db_schema::{
     post::Post,
   },
   traits::{ToSafe, ViewToVec},
-  utils::{functions::hot_rank, fuzzy_search, get_conn, limit_and_offset_unlimited, DbPool},
+  utils::{
+    functions::hot_rank,
+    fuzzy_search,
+    get_conn,
+    get_read_conn,
+    limit_and_offset_unlimited,
+    DbPool,
+  },
   CommentSortType,
   ListingType,
 };
@@ -185,7 +192,7 @@ pub struct CommentQuery<'a> {
 
 impl<'a> CommentQuery<'a> {
   pub async fn list(self) -> Result<Vec<CommentView>, Error> {
-    let conn = &mut get_conn(self.pool).await?;
+    let conn = &mut get_read_conn(self.pool, self.local_user.map(|l| l.person_id)).await?;
 
     // The left join below will return None in this case
     let person_id_join = self.local_user.map(|l| l.person_id).unwrap_or(PersonId(-1));
@@ -903,6 +910,7 @@ mod tests {
         posting_restricted_to_mods: false,
         published: data.inserted_community.published,
         instance_id: data.inserted_instance.id,
+        slur_filter_regex: None,
       },
       counts: CommentAggregates {
         id: agg.id,
@@ -0,0 +1,57 @@
+use crate::structs::InstanceFederationStatsView;
+use diesel::{result::Error, ExpressionMethods, QueryDsl};
+use diesel_async::RunQueryDsl;
+use lemmy_db_schema::{
+  schema::{instance, instance_federation_stats},
+  source::{
+    instance::Instance,
+    instance_federation_stats::{InstanceFederationStats, InstanceReceivedActivity},
+  },
+  utils::{get_read_conn, limit_and_offset, DbPool},
+};
+
+type InstanceFederationStatsViewTuple = (Instance, InstanceFederationStats);
+
+impl InstanceFederationStatsView {
+  /// Lists all instances which this instance federated with, ordered by domain
+  pub async fn list(
+    pool: &DbPool,
+    page: Option<i64>,
+    limit: Option<i64>,
+  ) -> Result<Vec<Self>, Error> {
+    let conn = &mut get_read_conn(pool, None).await?;
+    let (limit, offset) = limit_and_offset(page, limit)?;
+    let res = instance::table
+      .inner_join(instance_federation_stats::table)
+      .select((
+        instance::all_columns,
+        instance_federation_stats::all_columns,
+      ))
+      .order_by(instance::domain.asc())
+      .limit(limit)
+      .offset(offset)
+      .load::<InstanceFederationStatsViewTuple>(conn)
+      .await?;
+
+    let instance_ids: Vec<_> = res.iter().map(|(i, _)| i.id).collect();
+    let mut received = InstanceReceivedActivity::list_for_instances(pool, &instance_ids).await?;
+
+    Ok(
+      res
+        .into_iter()
+        .map(|(instance, federation_stats)| {
+          let (received_activities, rest) = received
+            .drain(..)
+            .partition(|r| r.instance_id == instance.id);
+          received = rest;
+          Self {
+            average_delivery_time_ms: federation_stats.average_delivery_time_ms(),
+            instance,
+            federation_stats,
+            received_activities,
+          }
+        })
+        .collect(),
+    )
+  }
+}
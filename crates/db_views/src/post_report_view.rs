@@ -11,7 +11,7 @@ use diesel::{
 use diesel_async::RunQueryDsl;
 use lemmy_db_schema::{
   aggregates::structs::PostAggregates,
-  newtypes::{CommunityId, PersonId, PostReportId},
+  newtypes::{CommunityId, PersonId, PostId, PostReportId},
   schema::{
     community,
     community_moderator,
@@ -29,7 +29,7 @@ use lemmy_db_schema::{
     post_report::PostReport,
   },
   traits::{ToSafe, ViewToVec},
-  utils::{get_conn, limit_and_offset, DbPool},
+  utils::{get_conn, get_read_conn, limit_and_offset, DbPool},
 };
 use typed_builder::TypedBuilder;
 
@@ -43,6 +43,7 @@ type PostReportViewTuple = (
   Option<i16>,
   PostAggregates,
   Option<PersonSafe>,
+  Option<PersonSafe>,
 );
 
 impl PostReportView {
@@ -55,7 +56,8 @@ impl PostReportView {
     my_person_id: PersonId,
   ) -> Result<Self, Error> {
     let conn = &mut get_conn(pool).await?;
-    let (person_alias_1, person_alias_2) = diesel::alias!(person as person1, person as person2);
+    let (person_alias_1, person_alias_2, person_alias_3) =
+      diesel::alias!(person as person1, person as person2, person as person3);
 
     let (
       post_report,
@@ -67,6 +69,7 @@ impl PostReportView {
       post_like,
       counts,
       resolver,
+      assignee,
     ) = post_report::table
       .find(report_id)
       .inner_join(post::table)
@@ -96,6 +99,9 @@ impl PostReportView {
       .left_join(
         person_alias_2.on(post_report::resolver_id.eq(person_alias_2.field(person::id).nullable())),
       )
+      .left_join(
+        person_alias_3.on(post_report::assignee_id.eq(person_alias_3.field(person::id).nullable())),
+      )
       .select((
         post_report::all_columns,
         post::all_columns,
@@ -106,6 +112,7 @@ impl PostReportView {
         post_like::score.nullable(),
         post_aggregates::all_columns,
         person_alias_2.fields(Person::safe_columns_tuple().nullable()),
+        person_alias_3.fields(Person::safe_columns_tuple().nullable()),
       ))
       .first::<PostReportViewTuple>(conn)
       .await?;
@@ -122,6 +129,7 @@ impl PostReportView {
       my_vote,
       counts,
       resolver,
+      assignee,
     })
   }
 
@@ -133,7 +141,7 @@ impl PostReportView {
     community_id: Option<CommunityId>,
   ) -> Result<i64, Error> {
     use diesel::dsl::count;
-    let conn = &mut get_conn(pool).await?;
+    let conn = &mut get_read_conn(pool, Some(my_person_id)).await?;
     let mut query = post_report::table
       .inner_join(post::table)
       .filter(post_report::resolved.eq(false))
@@ -178,12 +186,16 @@ pub struct PostReportQuery<'a> {
   page: Option<i64>,
   limit: Option<i64>,
   unresolved_only: Option<bool>,
+  post_id: Option<PostId>,
+  assignee_id: Option<PersonId>,
+  escalated_only: Option<bool>,
 }
 
 impl<'a> PostReportQuery<'a> {
   pub async fn list(self) -> Result<Vec<PostReportView>, Error> {
-    let conn = &mut get_conn(self.pool).await?;
-    let (person_alias_1, person_alias_2) = diesel::alias!(person as person1, person as person2);
+    let conn = &mut get_read_conn(self.pool, Some(self.my_person_id)).await?;
+    let (person_alias_1, person_alias_2, person_alias_3) =
+      diesel::alias!(person as person1, person as person2, person as person3);
 
     let mut query = post_report::table
       .inner_join(post::table)
@@ -213,6 +225,9 @@ impl<'a> PostReportQuery<'a> {
       .left_join(
         person_alias_2.on(post_report::resolver_id.eq(person_alias_2.field(person::id).nullable())),
       )
+      .left_join(
+        person_alias_3.on(post_report::assignee_id.eq(person_alias_3.field(person::id).nullable())),
+      )
       .select((
         post_report::all_columns,
         post::all_columns,
@@ -225,6 +240,9 @@ impl<'a> PostReportQuery<'a> {
         person_alias_2
           .fields(Person::safe_columns_tuple())
           .nullable(),
+        person_alias_3
+          .fields(Person::safe_columns_tuple())
+          .nullable(),
       ))
       .into_boxed();
 
@@ -232,10 +250,22 @@ impl<'a> PostReportQuery<'a> {
       query = query.filter(post::community_id.eq(community_id));
     }
 
+    if let Some(post_id) = self.post_id {
+      query = query.filter(post_report::post_id.eq(post_id));
+    }
+
+    if let Some(assignee_id) = self.assignee_id {
+      query = query.filter(post_report::assignee_id.eq(assignee_id));
+    }
+
     if self.unresolved_only.unwrap_or(true) {
       query = query.filter(post_report::resolved.eq(false));
     }
 
+    if self.escalated_only.unwrap_or(false) {
+      query = query.filter(post_report::escalated.eq(true));
+    }
+
     let (limit, offset) = limit_and_offset(self.page, self.limit)?;
 
     query = query
@@ -278,6 +308,7 @@ impl ViewToVec for PostReportView {
         my_vote: a.6,
         counts: a.7,
         resolver: a.8,
+        assignee: a.9,
       })
       .collect::<Vec<Self>>()
   }
@@ -373,6 +404,7 @@ mod tests {
       original_post_url: None,
       original_post_body: None,
       reason: "from sara".into(),
+      rule_id: None,
     };
 
     let inserted_sara_report = PostReport::report(pool, &sara_report_form).await.unwrap();
@@ -385,6 +417,7 @@ mod tests {
       original_post_url: None,
       original_post_body: None,
       reason: "from jessica".into(),
+      rule_id: None,
     };
 
     let inserted_jessica_report = PostReport::report(pool, &jessica_report_form)
@@ -417,6 +450,7 @@ mod tests {
         posting_restricted_to_mods: false,
         published: inserted_community.published,
         instance_id: inserted_instance.id,
+        slur_filter_regex: None,
       },
       creator: PersonSafe {
         id: inserted_jessica.id,
@@ -476,12 +510,13 @@ mod tests {
         featured_local: false,
       },
       resolver: None,
+      assignee: None,
     };
 
     assert_eq!(read_jessica_report_view, expected_jessica_report_view);
 
     let mut expected_sara_report_view = expected_jessica_report_view.clone();
-    expected_sara_report_view.post_report = inserted_sara_report;
+    expected_sara_report_view.post_report = inserted_sara_report.clone();
     expected_sara_report_view.my_vote = None;
     expected_sara_report_view.creator = PersonSafe {
       id: inserted_sara.id,
@@ -530,7 +565,7 @@ mod tests {
     assert_eq!(2, report_count);
 
     // Try to resolve the report
-    PostReport::resolve(pool, inserted_jessica_report.id, inserted_timmy.id)
+    PostReport::resolve(pool, inserted_jessica_report.id, inserted_timmy.id, None)
       .await
       .unwrap();
     let read_jessica_report_view_after_resolve =
@@ -594,6 +629,47 @@ mod tests {
         .unwrap();
     assert_eq!(1, report_count_after_resolved);
 
+    // Assign saras report to timmy, and escalate it to the admins
+    PostReport::assign(pool, inserted_sara_report.id, Some(inserted_timmy.id))
+      .await
+      .unwrap();
+    PostReport::escalate(pool, inserted_sara_report.id, true)
+      .await
+      .unwrap();
+    let escalated_reports = PostReportQuery::builder()
+      .pool(pool)
+      .my_person_id(inserted_timmy.id)
+      .admin(false)
+      .assignee_id(Some(inserted_timmy.id))
+      .escalated_only(Some(true))
+      .build()
+      .list()
+      .await
+      .unwrap();
+    assert_eq!(1, escalated_reports.len());
+    assert!(escalated_reports[0].post_report.escalated);
+    assert_eq!(
+      Some(inserted_timmy.id),
+      escalated_reports[0].assignee.as_ref().map(|a| a.id)
+    );
+
+    // Resolving all the reports for the post also resolves saras
+    let resolved_reports = PostReport::resolve_all_for_object(
+      pool,
+      inserted_post.id,
+      inserted_timmy.id,
+      Some("spam".into()),
+    )
+    .await
+    .unwrap();
+    assert_eq!(1, resolved_reports.len());
+    assert_eq!(inserted_sara_report.id, resolved_reports[0].id);
+    let report_count_after_resolve_all =
+      PostReportView::get_report_count(pool, inserted_timmy.id, false, None)
+        .await
+        .unwrap();
+    assert_eq!(0, report_count_after_resolve_all);
+
     Person::delete(pool, inserted_timmy.id).await.unwrap();
     Person::delete(pool, inserted_sara.id).await.unwrap();
     Person::delete(pool, inserted_jessica.id).await.unwrap();
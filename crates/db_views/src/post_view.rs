@@ -40,7 +40,7 @@ use lemmy_db_schema::{
     post::{Post, PostRead, PostSaved},
   },
   traits::{ToSafe, ViewToVec},
-  utils::{functions::hot_rank, fuzzy_search, get_conn, limit_and_offset, DbPool},
+  utils::{functions::hot_rank, fuzzy_search, get_conn, get_read_conn, limit_and_offset, DbPool},
   ListingType,
   SortType,
 };
@@ -207,7 +207,7 @@ pub struct PostQuery<'a> {
 
 impl<'a> PostQuery<'a> {
   pub async fn list(self) -> Result<Vec<PostView>, Error> {
-    let conn = &mut get_conn(self.pool).await?;
+    let conn = &mut get_read_conn(self.pool, self.local_user.map(|l| l.person_id)).await?;
 
     // The left join below will return None in this case
     let person_id_join = self.local_user.map(|l| l.person_id).unwrap_or(PersonId(-1));
@@ -920,6 +920,7 @@ mod tests {
         posting_restricted_to_mods: false,
         published: inserted_community.published,
         instance_id: data.inserted_instance.id,
+        slur_filter_regex: None,
       },
       counts: PostAggregates {
         id: agg.id,
@@ -2,7 +2,7 @@ use crate::structs::PrivateMessageReportView;
 use diesel::{result::Error, ExpressionMethods, JoinOnDsl, NullableExpressionMethods, QueryDsl};
 use diesel_async::RunQueryDsl;
 use lemmy_db_schema::{
-  newtypes::PrivateMessageReportId,
+  newtypes::{PersonId, PrivateMessageReportId},
   schema::{person, private_message, private_message_report},
   source::{
     person::{Person, PersonSafe},
@@ -10,7 +10,7 @@ use lemmy_db_schema::{
     private_message_report::PrivateMessageReport,
   },
   traits::{ToSafe, ViewToVec},
-  utils::{get_conn, limit_and_offset, DbPool},
+  utils::{get_conn, get_read_conn, limit_and_offset, DbPool},
 };
 use typed_builder::TypedBuilder;
 
@@ -20,6 +20,7 @@ type PrivateMessageReportViewTuple = (
   PersonSafe,
   PersonSafe,
   Option<PersonSafe>,
+  Option<PersonSafe>,
 );
 
 impl PrivateMessageReportView {
@@ -28,33 +29,45 @@ impl PrivateMessageReportView {
   /// * `report_id` - the report id to obtain
   pub async fn read(pool: &DbPool, report_id: PrivateMessageReportId) -> Result<Self, Error> {
     let conn = &mut get_conn(pool).await?;
-    let (person_alias_1, person_alias_2) = diesel::alias!(person as person1, person as person2);
-
-    let (private_message_report, private_message, private_message_creator, creator, resolver) =
-      private_message_report::table
-        .find(report_id)
-        .inner_join(private_message::table)
-        .inner_join(person::table.on(private_message::creator_id.eq(person::id)))
-        .inner_join(
-          person_alias_1
-            .on(private_message_report::creator_id.eq(person_alias_1.field(person::id))),
-        )
-        .left_join(
-          person_alias_2.on(
-            private_message_report::resolver_id.eq(person_alias_2.field(person::id).nullable()),
-          ),
-        )
-        .select((
-          private_message_report::all_columns,
-          private_message::all_columns,
-          Person::safe_columns_tuple(),
-          person_alias_1.fields(Person::safe_columns_tuple()),
-          person_alias_2
-            .fields(Person::safe_columns_tuple())
-            .nullable(),
-        ))
-        .first::<PrivateMessageReportViewTuple>(conn)
-        .await?;
+    let (person_alias_1, person_alias_2, person_alias_3) =
+      diesel::alias!(person as person1, person as person2, person as person3);
+
+    let (
+      private_message_report,
+      private_message,
+      private_message_creator,
+      creator,
+      resolver,
+      assignee,
+    ) = private_message_report::table
+      .find(report_id)
+      .inner_join(private_message::table)
+      .inner_join(person::table.on(private_message::creator_id.eq(person::id)))
+      .inner_join(
+        person_alias_1.on(private_message_report::creator_id.eq(person_alias_1.field(person::id))),
+      )
+      .left_join(
+        person_alias_2
+          .on(private_message_report::resolver_id.eq(person_alias_2.field(person::id).nullable())),
+      )
+      .left_join(
+        person_alias_3
+          .on(private_message_report::assignee_id.eq(person_alias_3.field(person::id).nullable())),
+      )
+      .select((
+        private_message_report::all_columns,
+        private_message::all_columns,
+        Person::safe_columns_tuple(),
+        person_alias_1.fields(Person::safe_columns_tuple()),
+        person_alias_2
+          .fields(Person::safe_columns_tuple())
+          .nullable(),
+        person_alias_3
+          .fields(Person::safe_columns_tuple())
+          .nullable(),
+      ))
+      .first::<PrivateMessageReportViewTuple>(conn)
+      .await?;
 
     Ok(Self {
       private_message_report,
@@ -62,6 +75,7 @@ impl PrivateMessageReportView {
       private_message_creator,
       creator,
       resolver,
+      assignee,
     })
   }
 
@@ -85,15 +99,18 @@ impl PrivateMessageReportView {
 pub struct PrivateMessageReportQuery<'a> {
   #[builder(!default)]
   pool: &'a DbPool,
+  my_person_id: Option<PersonId>,
   page: Option<i64>,
   limit: Option<i64>,
   unresolved_only: Option<bool>,
+  assignee_id: Option<PersonId>,
 }
 
 impl<'a> PrivateMessageReportQuery<'a> {
   pub async fn list(self) -> Result<Vec<PrivateMessageReportView>, Error> {
-    let conn = &mut get_conn(self.pool).await?;
-    let (person_alias_1, person_alias_2) = diesel::alias!(person as person1, person as person2);
+    let conn = &mut get_read_conn(self.pool, self.my_person_id).await?;
+    let (person_alias_1, person_alias_2, person_alias_3) =
+      diesel::alias!(person as person1, person as person2, person as person3);
 
     let mut query = private_message_report::table
       .inner_join(private_message::table)
@@ -105,6 +122,10 @@ impl<'a> PrivateMessageReportQuery<'a> {
         person_alias_2
           .on(private_message_report::resolver_id.eq(person_alias_2.field(person::id).nullable())),
       )
+      .left_join(
+        person_alias_3
+          .on(private_message_report::assignee_id.eq(person_alias_3.field(person::id).nullable())),
+      )
       .select((
         private_message_report::all_columns,
         private_message::all_columns,
@@ -113,9 +134,16 @@ impl<'a> PrivateMessageReportQuery<'a> {
         person_alias_2
           .fields(Person::safe_columns_tuple())
           .nullable(),
+        person_alias_3
+          .fields(Person::safe_columns_tuple())
+          .nullable(),
       ))
       .into_boxed();
 
+    if let Some(assignee_id) = self.assignee_id {
+      query = query.filter(private_message_report::assignee_id.eq(assignee_id));
+    }
+
     if self.unresolved_only.unwrap_or(true) {
       query = query.filter(private_message_report::resolved.eq(false));
     }
@@ -144,6 +172,7 @@ impl ViewToVec for PrivateMessageReportView {
         private_message_creator: a.2,
         creator: a.3,
         resolver: a.4,
+        assignee: a.5,
       })
       .collect::<Vec<Self>>()
   }
@@ -225,7 +254,7 @@ mod tests {
     let inserted_admin = Person::create(pool, &new_person_3).await.unwrap();
 
     // admin resolves the report (after taking appropriate action)
-    PrivateMessageReport::resolve(pool, pm_report.id, inserted_admin.id)
+    PrivateMessageReport::resolve(pool, pm_report.id, inserted_admin.id, None)
       .await
       .unwrap();
 
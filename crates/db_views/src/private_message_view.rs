@@ -17,7 +17,7 @@ use lemmy_db_schema::{
     private_message::PrivateMessage,
   },
   traits::{ToSafe, ViewToVec},
-  utils::{get_conn, limit_and_offset, DbPool},
+  utils::{get_conn, get_read_conn, limit_and_offset, DbPool},
 };
 use tracing::debug;
 use typed_builder::TypedBuilder;
@@ -54,7 +54,7 @@ impl PrivateMessageView {
   /// Gets the number of unread messages
   pub async fn get_unread_messages(pool: &DbPool, my_person_id: PersonId) -> Result<i64, Error> {
     use diesel::dsl::count;
-    let conn = &mut get_conn(pool).await?;
+    let conn = &mut get_read_conn(pool, Some(my_person_id)).await?;
     private_message::table
       .filter(private_message::read.eq(false))
       .filter(private_message::recipient_id.eq(my_person_id))
@@ -79,7 +79,7 @@ pub struct PrivateMessageQuery<'a> {
 
 impl<'a> PrivateMessageQuery<'a> {
   pub async fn list(self) -> Result<Vec<PrivateMessageView>, Error> {
-    let conn = &mut get_conn(self.pool).await?;
+    let conn = &mut get_read_conn(self.pool, Some(self.recipient_id)).await?;
     let person_alias_1 = diesel::alias!(person as person1);
 
     let mut query = private_message::table
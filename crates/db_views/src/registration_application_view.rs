@@ -9,6 +9,7 @@ use diesel::{
 };
 use diesel_async::RunQueryDsl;
 use lemmy_db_schema::{
+  newtypes::PersonId,
   schema::{local_user, person, registration_application},
   source::{
     local_user::{LocalUser, LocalUserSettings},
@@ -16,7 +17,7 @@ use lemmy_db_schema::{
     registration_application::RegistrationApplication,
   },
   traits::{ToSafe, ToSafeSettings, ViewToVec},
-  utils::{get_conn, limit_and_offset, DbPool},
+  utils::{get_conn, get_read_conn, limit_and_offset, DbPool},
 };
 use typed_builder::TypedBuilder;
 
@@ -94,6 +95,7 @@ impl RegistrationApplicationView {
 pub struct RegistrationApplicationQuery<'a> {
   #[builder(!default)]
   pool: &'a DbPool,
+  my_person_id: Option<PersonId>,
   unread_only: Option<bool>,
   verified_email_only: Option<bool>,
   page: Option<i64>,
@@ -102,7 +104,7 @@ pub struct RegistrationApplicationQuery<'a> {
 
 impl<'a> RegistrationApplicationQuery<'a> {
   pub async fn list(self) -> Result<Vec<RegistrationApplicationView>, Error> {
-    let conn = &mut get_conn(self.pool).await?;
+    let conn = &mut get_read_conn(self.pool, self.my_person_id).await?;
     let person_alias_1 = diesel::alias!(person as person1);
 
     let mut query = registration_application::table
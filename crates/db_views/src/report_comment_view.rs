@@ -0,0 +1,94 @@
+use crate::structs::ReportCommentView;
+use diesel::{result::Error, ExpressionMethods, QueryDsl};
+use diesel_async::RunQueryDsl;
+use lemmy_db_schema::{
+  newtypes::{CommentReportId, PersonId, PostReportId, PrivateMessageReportId, ReportCommentId},
+  schema::{person, report_comment},
+  source::{
+    person::{Person, PersonSafe},
+    report_comment::ReportComment,
+  },
+  traits::{ToSafe, ViewToVec},
+  utils::{get_conn, get_read_conn, limit_and_offset, DbPool},
+};
+use typed_builder::TypedBuilder;
+
+type ReportCommentViewTuple = (ReportComment, PersonSafe);
+
+impl ReportCommentView {
+  pub async fn read(pool: &DbPool, report_comment_id: ReportCommentId) -> Result<Self, Error> {
+    let conn = &mut get_conn(pool).await?;
+    let (report_comment, creator) = report_comment::table
+      .find(report_comment_id)
+      .inner_join(person::table)
+      .select((report_comment::all_columns, Person::safe_columns_tuple()))
+      .first::<ReportCommentViewTuple>(conn)
+      .await?;
+
+    Ok(Self {
+      report_comment,
+      creator,
+    })
+  }
+}
+
+/// Lists the internal discussion of a single report, oldest first
+#[derive(TypedBuilder)]
+#[builder(field_defaults(default))]
+pub struct ReportCommentQuery<'a> {
+  #[builder(!default)]
+  pool: &'a DbPool,
+  my_person_id: Option<PersonId>,
+  post_report_id: Option<PostReportId>,
+  comment_report_id: Option<CommentReportId>,
+  private_message_report_id: Option<PrivateMessageReportId>,
+  page: Option<i64>,
+  limit: Option<i64>,
+}
+
+impl<'a> ReportCommentQuery<'a> {
+  pub async fn list(self) -> Result<Vec<ReportCommentView>, Error> {
+    let conn = &mut get_read_conn(self.pool, self.my_person_id).await?;
+
+    let mut query = report_comment::table
+      .inner_join(person::table)
+      .select((report_comment::all_columns, Person::safe_columns_tuple()))
+      .into_boxed();
+
+    if let Some(post_report_id) = self.post_report_id {
+      query = query.filter(report_comment::post_report_id.eq(post_report_id));
+    }
+
+    if let Some(comment_report_id) = self.comment_report_id {
+      query = query.filter(report_comment::comment_report_id.eq(comment_report_id));
+    }
+
+    if let Some(private_message_report_id) = self.private_message_report_id {
+      query = query.filter(report_comment::private_message_report_id.eq(private_message_report_id));
+    }
+
+    let (limit, offset) = limit_and_offset(self.page, self.limit)?;
+
+    let res = query
+      .order_by(report_comment::published.asc())
+      .limit(limit)
+      .offset(offset)
+      .load::<ReportCommentViewTuple>(conn)
+      .await?;
+
+    Ok(ReportCommentView::from_tuple_to_vec(res))
+  }
+}
+
+impl ViewToVec for ReportCommentView {
+  type DbTuple = ReportCommentViewTuple;
+  fn from_tuple_to_vec(items: Vec<Self::DbTuple>) -> Vec<Self> {
+    items
+      .into_iter()
+      .map(|a| Self {
+        report_comment: a.0,
+        creator: a.1,
+      })
+      .collect::<Vec<Self>>()
+  }
+}
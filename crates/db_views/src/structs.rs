@@ -1,9 +1,12 @@
+use doku::Document;
 use lemmy_db_schema::{
   aggregates::structs::{CommentAggregates, PersonAggregates, PostAggregates, SiteAggregates},
   source::{
     comment::Comment,
     comment_report::CommentReport,
     community::CommunitySafe,
+    instance::Instance,
+    instance_federation_stats::{InstanceFederationStats, InstanceReceivedActivity},
     local_site::LocalSite,
     local_site_rate_limit::LocalSiteRateLimit,
     local_user::{LocalUser, LocalUserSettings},
@@ -13,13 +16,14 @@ use lemmy_db_schema::{
     private_message::PrivateMessage,
     private_message_report::PrivateMessageReport,
     registration_application::RegistrationApplication,
+    report_comment::ReportComment,
     site::Site,
   },
   SubscribedType,
 };
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, PartialEq, Eq, Serialize, Deserialize, Clone)]
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize, Clone, Document)]
 pub struct CommentReportView {
   pub comment_report: CommentReport,
   pub comment: Comment,
@@ -31,9 +35,10 @@ pub struct CommentReportView {
   pub creator_banned_from_community: bool, // Left Join to CommunityPersonBan
   pub my_vote: Option<i16>,                // Left join to CommentLike
   pub resolver: Option<PersonSafe>,
+  pub assignee: Option<PersonSafe>,
 }
 
-#[derive(Debug, PartialEq, Eq, Serialize, Deserialize, Clone)]
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize, Clone, Document)]
 pub struct CommentView {
   pub comment: Comment,
   pub creator: PersonSafe,
@@ -47,21 +52,29 @@ pub struct CommentView {
   pub my_vote: Option<i16>,                // Left join to CommentLike
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize, Clone, Document)]
+pub struct InstanceFederationStatsView {
+  pub instance: Instance,
+  pub federation_stats: InstanceFederationStats,
+  pub average_delivery_time_ms: Option<i64>,
+  pub received_activities: Vec<InstanceReceivedActivity>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Document)]
 pub struct LocalUserView {
   pub local_user: LocalUser,
   pub person: Person,
   pub counts: PersonAggregates,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, Document)]
 pub struct LocalUserSettingsView {
   pub local_user: LocalUserSettings,
   pub person: PersonSafe,
   pub counts: PersonAggregates,
 }
 
-#[derive(Debug, PartialEq, Eq, Serialize, Deserialize, Clone)]
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize, Clone, Document)]
 pub struct PostReportView {
   pub post_report: PostReport,
   pub post: Post,
@@ -72,9 +85,10 @@ pub struct PostReportView {
   pub my_vote: Option<i16>,
   pub counts: PostAggregates,
   pub resolver: Option<PersonSafe>,
+  pub assignee: Option<PersonSafe>,
 }
 
-#[derive(Debug, PartialEq, Eq, Serialize, Deserialize, Clone)]
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize, Clone, Document)]
 pub struct PostView {
   pub post: Post,
   pub creator: PersonSafe,
@@ -89,23 +103,24 @@ pub struct PostView {
   pub unread_comments: i64,       // Left join to PersonPostAggregates
 }
 
-#[derive(Debug, PartialEq, Eq, Serialize, Deserialize, Clone)]
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize, Clone, Document)]
 pub struct PrivateMessageView {
   pub private_message: PrivateMessage,
   pub creator: PersonSafe,
   pub recipient: PersonSafe,
 }
 
-#[derive(Debug, PartialEq, Eq, Serialize, Deserialize, Clone)]
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize, Clone, Document)]
 pub struct PrivateMessageReportView {
   pub private_message_report: PrivateMessageReport,
   pub private_message: PrivateMessage,
   pub private_message_creator: PersonSafe,
   pub creator: PersonSafe,
   pub resolver: Option<PersonSafe>,
+  pub assignee: Option<PersonSafe>,
 }
 
-#[derive(Debug, PartialEq, Eq, Serialize, Deserialize, Clone)]
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize, Clone, Document)]
 pub struct RegistrationApplicationView {
   pub registration_application: RegistrationApplication,
   pub creator_local_user: LocalUserSettings,
@@ -113,7 +128,13 @@ pub struct RegistrationApplicationView {
   pub admin: Option<PersonSafe>,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize, Clone, Document)]
+pub struct ReportCommentView {
+  pub report_comment: ReportComment,
+  pub creator: PersonSafe,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Document)]
 pub struct SiteView {
   pub site: Site,
   pub local_site: LocalSite,
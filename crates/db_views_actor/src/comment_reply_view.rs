@@ -34,7 +34,7 @@ use lemmy_db_schema::{
     post::Post,
   },
   traits::{ToSafe, ViewToVec},
-  utils::{functions::hot_rank, get_conn, limit_and_offset, DbPool},
+  utils::{functions::hot_rank, get_conn, get_read_conn, limit_and_offset, DbPool},
   CommentSortType,
 };
 use typed_builder::TypedBuilder;
@@ -164,7 +164,7 @@ impl CommentReplyView {
   pub async fn get_unread_replies(pool: &DbPool, my_person_id: PersonId) -> Result<i64, Error> {
     use diesel::dsl::count;
 
-    let conn = &mut get_conn(pool).await?;
+    let conn = &mut get_read_conn(pool, Some(my_person_id)).await?;
 
     comment_reply::table
       .inner_join(comment::table)
@@ -194,7 +194,7 @@ pub struct CommentReplyQuery<'a> {
 
 impl<'a> CommentReplyQuery<'a> {
   pub async fn list(self) -> Result<Vec<CommentReplyView>, Error> {
-    let conn = &mut get_conn(self.pool).await?;
+    let conn = &mut get_read_conn(self.pool, self.my_person_id).await?;
 
     let person_alias_1 = diesel::alias!(person as person1);
 
@@ -0,0 +1,52 @@
+use crate::structs::CommunityBackfillView;
+use diesel::{result::Error, ExpressionMethods, QueryDsl};
+use diesel_async::RunQueryDsl;
+use lemmy_db_schema::{
+  schema::{community, community_backfill},
+  source::{
+    community::{Community, CommunitySafe},
+    community_backfill::CommunityBackfill,
+  },
+  traits::{ToSafe, ViewToVec},
+  utils::{get_conn, limit_and_offset, DbPool},
+};
+
+type CommunityBackfillViewTuple = (CommunityBackfill, CommunitySafe);
+
+impl CommunityBackfillView {
+  /// Lists the backfills of remote communities, newest first
+  pub async fn list(
+    pool: &DbPool,
+    page: Option<i64>,
+    limit: Option<i64>,
+  ) -> Result<Vec<Self>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    let (limit, offset) = limit_and_offset(page, limit)?;
+    let res = community_backfill::table
+      .inner_join(community::table)
+      .select((
+        community_backfill::all_columns,
+        Community::safe_columns_tuple(),
+      ))
+      .order_by(community_backfill::published.desc())
+      .limit(limit)
+      .offset(offset)
+      .load::<CommunityBackfillViewTuple>(conn)
+      .await?;
+
+    Ok(Self::from_tuple_to_vec(res))
+  }
+}
+
+impl ViewToVec for CommunityBackfillView {
+  type DbTuple = CommunityBackfillViewTuple;
+  fn from_tuple_to_vec(items: Vec<Self::DbTuple>) -> Vec<Self> {
+    items
+      .into_iter()
+      .map(|a| Self {
+        community_backfill: a.0,
+        community: a.1,
+      })
+      .collect::<Vec<Self>>()
+  }
+}
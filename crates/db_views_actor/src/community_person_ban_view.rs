@@ -5,13 +5,15 @@ use lemmy_db_schema::{
   newtypes::{CommunityId, PersonId},
   schema::{community, community_person_ban, person},
   source::{
-    community::{Community, CommunitySafe},
+    community::{Community, CommunityPersonBan, CommunitySafe},
     person::{Person, PersonSafe},
   },
-  traits::ToSafe,
+  traits::{ToSafe, ViewToVec},
   utils::{get_conn, DbPool},
 };
 
+type CommunityPersonBanViewTuple = (CommunityPersonBan, CommunitySafe, PersonSafe);
+
 impl CommunityPersonBanView {
   pub async fn get(
     pool: &DbPool,
@@ -19,10 +21,11 @@ impl CommunityPersonBanView {
     from_community_id: CommunityId,
   ) -> Result<Self, Error> {
     let conn = &mut get_conn(pool).await?;
-    let (community, person) = community_person_ban::table
+    let (community_person_ban, community, person) = community_person_ban::table
       .inner_join(community::table)
       .inner_join(person::table)
       .select((
+        community_person_ban::all_columns,
         Community::safe_columns_tuple(),
         Person::safe_columns_tuple(),
       ))
@@ -34,9 +37,53 @@ impl CommunityPersonBanView {
           .or(community_person_ban::expires.gt(now)),
       )
       .order_by(community_person_ban::published)
-      .first::<(CommunitySafe, PersonSafe)>(conn)
+      .first::<CommunityPersonBanViewTuple>(conn)
+      .await?;
+
+    Ok(CommunityPersonBanView {
+      community_person_ban,
+      community,
+      person,
+    })
+  }
+
+  /// Lists the active community bans of a person, the ones which end soonest first.
+  pub async fn for_person(pool: &DbPool, for_person_id: PersonId) -> Result<Vec<Self>, Error> {
+    let conn = &mut get_conn(pool).await?;
+    let res = community_person_ban::table
+      .inner_join(community::table)
+      .inner_join(person::table)
+      .select((
+        community_person_ban::all_columns,
+        Community::safe_columns_tuple(),
+        Person::safe_columns_tuple(),
+      ))
+      .filter(community_person_ban::person_id.eq(for_person_id))
+      .filter(
+        community_person_ban::expires
+          .is_null()
+          .or(community_person_ban::expires.gt(now)),
+      )
+      // Postgres sorts nulls last in ascending order, so permanent bans come at the end
+      .order_by(community_person_ban::expires.asc())
+      .then_order_by(community_person_ban::published.desc())
+      .load::<CommunityPersonBanViewTuple>(conn)
       .await?;
 
-    Ok(CommunityPersonBanView { community, person })
+    Ok(Self::from_tuple_to_vec(res))
+  }
+}
+
+impl ViewToVec for CommunityPersonBanView {
+  type DbTuple = CommunityPersonBanViewTuple;
+  fn from_tuple_to_vec(items: Vec<Self::DbTuple>) -> Vec<Self> {
+    items
+      .into_iter()
+      .map(|a| Self {
+        community_person_ban: a.0,
+        community: a.1,
+        person: a.2,
+      })
+      .collect::<Vec<Self>>()
   }
 }
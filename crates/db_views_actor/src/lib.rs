@@ -1,6 +1,8 @@
 #[cfg(feature = "full")]
 pub mod comment_reply_view;
 #[cfg(feature = "full")]
+pub mod community_backfill_view;
+#[cfg(feature = "full")]
 pub mod community_block_view;
 #[cfg(feature = "full")]
 pub mod community_follower_view;
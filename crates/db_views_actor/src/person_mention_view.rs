@@ -34,7 +34,7 @@ use lemmy_db_schema::{
     post::Post,
   },
   traits::{ToSafe, ViewToVec},
-  utils::{functions::hot_rank, get_conn, limit_and_offset, DbPool},
+  utils::{functions::hot_rank, get_conn, get_read_conn, limit_and_offset, DbPool},
   CommentSortType,
 };
 use typed_builder::TypedBuilder;
@@ -163,7 +163,7 @@ impl PersonMentionView {
   /// Gets the number of unread mentions
   pub async fn get_unread_mentions(pool: &DbPool, my_person_id: PersonId) -> Result<i64, Error> {
     use diesel::dsl::count;
-    let conn = &mut get_conn(pool).await?;
+    let conn = &mut get_read_conn(pool, Some(my_person_id)).await?;
 
     person_mention::table
       .inner_join(comment::table)
@@ -193,7 +193,7 @@ pub struct PersonMentionQuery<'a> {
 
 impl<'a> PersonMentionQuery<'a> {
   pub async fn list(self) -> Result<Vec<PersonMentionView>, Error> {
-    let conn = &mut get_conn(self.pool).await?;
+    let conn = &mut get_read_conn(self.pool, self.my_person_id).await?;
 
     let person_alias_1 = diesel::alias!(person as person1);
 
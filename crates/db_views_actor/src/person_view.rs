@@ -14,7 +14,7 @@ use lemmy_db_schema::{
   schema::{person, person_aggregates},
   source::person::{Person, PersonSafe},
   traits::{ToSafe, ViewToVec},
-  utils::{fuzzy_search, get_conn, limit_and_offset, DbPool},
+  utils::{fuzzy_search, get_conn, get_read_conn, limit_and_offset, DbPool},
   SortType,
 };
 use std::iter::Iterator;
@@ -81,7 +81,7 @@ pub struct PersonQuery<'a> {
 
 impl<'a> PersonQuery<'a> {
   pub async fn list(self) -> Result<Vec<PersonViewSafe>, Error> {
-    let conn = &mut get_conn(self.pool).await?;
+    let conn = &mut get_read_conn(self.pool, None).await?;
     let mut query = person::table
       .inner_join(person_aggregates::table)
       .select((Person::safe_columns_tuple(), person_aggregates::all_columns))
@@ -1,9 +1,11 @@
+use doku::Document;
 use lemmy_db_schema::{
   aggregates::structs::{CommentAggregates, CommunityAggregates, PersonAggregates},
   source::{
     comment::Comment,
     comment_reply::CommentReply,
-    community::CommunitySafe,
+    community::{CommunityPersonBan, CommunitySafe},
+    community_backfill::CommunityBackfill,
     person::PersonSafe,
     person_mention::PersonMention,
     post::Post,
@@ -12,31 +14,38 @@ use lemmy_db_schema::{
 };
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, Document)]
+pub struct CommunityBackfillView {
+  pub community_backfill: CommunityBackfill,
+  pub community: CommunitySafe,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Document)]
 pub struct CommunityBlockView {
   pub person: PersonSafe,
   pub community: CommunitySafe,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, Document)]
 pub struct CommunityFollowerView {
   pub community: CommunitySafe,
   pub follower: PersonSafe,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, Document)]
 pub struct CommunityModeratorView {
   pub community: CommunitySafe,
   pub moderator: PersonSafe,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, Document)]
 pub struct CommunityPersonBanView {
+  pub community_person_ban: CommunityPersonBan,
   pub community: CommunitySafe,
   pub person: PersonSafe,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, Document)]
 pub struct CommunityView {
   pub community: CommunitySafe,
   pub subscribed: SubscribedType,
@@ -44,13 +53,13 @@ pub struct CommunityView {
   pub counts: CommunityAggregates,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, Document)]
 pub struct PersonBlockView {
   pub person: PersonSafe,
   pub target: PersonSafe,
 }
 
-#[derive(Debug, PartialEq, Eq, Serialize, Deserialize, Clone)]
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize, Clone, Document)]
 pub struct PersonMentionView {
   pub person_mention: PersonMention,
   pub comment: Comment,
@@ -66,7 +75,7 @@ pub struct PersonMentionView {
   pub my_vote: Option<i16>,                // Left join to CommentLike
 }
 
-#[derive(Debug, PartialEq, Eq, Serialize, Deserialize, Clone)]
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize, Clone, Document)]
 pub struct CommentReplyView {
   pub comment_reply: CommentReply,
   pub comment: Comment,
@@ -82,7 +91,7 @@ pub struct CommentReplyView {
   pub my_vote: Option<i16>,                // Left join to CommentLike
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, Document)]
 pub struct PersonViewSafe {
   pub person: PersonSafe,
   pub counts: PersonAggregates,
@@ -0,0 +1,77 @@
+use crate::structs::{AdminBlockInstanceView, ModlogListParams};
+use diesel::{
+  result::Error,
+  BoolExpressionMethods,
+  ExpressionMethods,
+  IntoSql,
+  JoinOnDsl,
+  NullableExpressionMethods,
+  QueryDsl,
+};
+use diesel_async::RunQueryDsl;
+use lemmy_db_schema::{
+  newtypes::PersonId,
+  schema::{admin_block_instance, instance, person},
+  source::{
+    instance::Instance,
+    moderator::AdminBlockInstance,
+    person::{Person, PersonSafe},
+  },
+  traits::{ToSafe, ViewToVec},
+  utils::{get_read_conn, limit_and_offset, DbPool},
+};
+
+type AdminBlockInstanceViewTuple = (AdminBlockInstance, Option<PersonSafe>, Instance);
+
+impl AdminBlockInstanceView {
+  pub async fn list(pool: &DbPool, params: ModlogListParams) -> Result<Vec<Self>, Error> {
+    let conn = &mut get_read_conn(pool, params.my_person_id).await?;
+    let admin_person_id_join = params.mod_person_id.unwrap_or(PersonId(-1));
+    let show_mod_names = !params.hide_modlog_names;
+    let show_mod_names_expr = show_mod_names.as_sql::<diesel::sql_types::Bool>();
+
+    let admin_names_join = admin_block_instance::admin_person_id
+      .eq(person::id)
+      .and(show_mod_names_expr.or(person::id.eq(admin_person_id_join)));
+
+    let mut query = admin_block_instance::table
+      .left_join(person::table.on(admin_names_join))
+      .inner_join(instance::table)
+      .select((
+        admin_block_instance::all_columns,
+        Person::safe_columns_tuple().nullable(),
+        instance::all_columns,
+      ))
+      .into_boxed();
+
+    if let Some(admin_person_id) = params.mod_person_id {
+      query = query.filter(admin_block_instance::admin_person_id.eq(admin_person_id));
+    };
+
+    let (limit, offset) = limit_and_offset(params.page, params.limit)?;
+
+    let res = query
+      .limit(limit)
+      .offset(offset)
+      .order_by(admin_block_instance::when_.desc())
+      .load::<AdminBlockInstanceViewTuple>(conn)
+      .await?;
+
+    let results = Self::from_tuple_to_vec(res);
+    Ok(results)
+  }
+}
+
+impl ViewToVec for AdminBlockInstanceView {
+  type DbTuple = AdminBlockInstanceViewTuple;
+  fn from_tuple_to_vec(items: Vec<Self::DbTuple>) -> Vec<Self> {
+    items
+      .into_iter()
+      .map(|a| Self {
+        admin_block_instance: a.0,
+        admin: a.1,
+        instance: a.2,
+      })
+      .collect::<Vec<Self>>()
+  }
+}
@@ -1,4 +1,6 @@
 #[cfg(feature = "full")]
+pub mod admin_block_instance_view;
+#[cfg(feature = "full")]
 pub mod admin_purge_comment_view;
 #[cfg(feature = "full")]
 pub mod admin_purge_community_view;
@@ -18,7 +18,7 @@ use lemmy_db_schema::{
     person::{Person, PersonSafe},
   },
   traits::{ToSafe, ViewToVec},
-  utils::{get_conn, limit_and_offset, DbPool},
+  utils::{get_read_conn, limit_and_offset, DbPool},
 };
 
 type ModAddCommunityViewTuple = (
@@ -30,7 +30,7 @@ type ModAddCommunityViewTuple = (
 
 impl ModAddCommunityView {
   pub async fn list(pool: &DbPool, params: ModlogListParams) -> Result<Vec<Self>, Error> {
-    let conn = &mut get_conn(pool).await?;
+    let conn = &mut get_read_conn(pool, params.my_person_id).await?;
     let person_alias_1 = diesel::alias!(person as person1);
     let admin_person_id_join = params.mod_person_id.unwrap_or(PersonId(-1));
     let show_mod_names = !params.hide_modlog_names;
@@ -11,16 +11,17 @@ use diesel::{
 use diesel_async::RunQueryDsl;
 use lemmy_db_schema::{
   newtypes::PersonId,
-  schema::{comment, community, mod_remove_comment, person, post},
+  schema::{comment, community, community_rule, mod_remove_comment, person, post},
   source::{
     comment::Comment,
     community::{Community, CommunitySafe},
+    community_rule::CommunityRule,
     moderator::ModRemoveComment,
     person::{Person, PersonSafe},
     post::Post,
   },
   traits::{ToSafe, ViewToVec},
-  utils::{get_conn, limit_and_offset, DbPool},
+  utils::{get_read_conn, limit_and_offset, DbPool},
 };
 
 type ModRemoveCommentViewTuple = (
@@ -30,11 +31,12 @@ type ModRemoveCommentViewTuple = (
   PersonSafe,
   Post,
   CommunitySafe,
+  Option<CommunityRule>,
 );
 
 impl ModRemoveCommentView {
   pub async fn list(pool: &DbPool, params: ModlogListParams) -> Result<Vec<Self>, Error> {
-    let conn = &mut get_conn(pool).await?;
+    let conn = &mut get_read_conn(pool, params.my_person_id).await?;
     let person_alias_1 = diesel::alias!(lemmy_db_schema::schema::person as person1);
     let admin_person_id_join = params.mod_person_id.unwrap_or(PersonId(-1));
     let show_mod_names = !params.hide_modlog_names;
@@ -49,6 +51,9 @@ impl ModRemoveCommentView {
       .inner_join(person_alias_1.on(comment::creator_id.eq(person_alias_1.field(person::id))))
       .inner_join(post::table.on(comment::post_id.eq(post::id)))
       .inner_join(community::table.on(post::community_id.eq(community::id)))
+      .left_join(
+        community_rule::table.on(mod_remove_comment::rule_id.eq(community_rule::id.nullable())),
+      )
       .select((
         mod_remove_comment::all_columns,
         Person::safe_columns_tuple().nullable(),
@@ -56,6 +61,7 @@ impl ModRemoveCommentView {
         person_alias_1.fields(Person::safe_columns_tuple()),
         post::all_columns,
         Community::safe_columns_tuple(),
+        community_rule::all_columns.nullable(),
       ))
       .into_boxed();
 
@@ -97,6 +103,7 @@ impl ViewToVec for ModRemoveCommentView {
         commenter: a.3,
         post: a.4,
         community: a.5,
+        rule: a.6,
       })
       .collect::<Vec<Self>>()
   }
@@ -11,22 +11,29 @@ use diesel::{
 use diesel_async::RunQueryDsl;
 use lemmy_db_schema::{
   newtypes::PersonId,
-  schema::{community, mod_remove_post, person, post},
+  schema::{community, community_rule, mod_remove_post, person, post},
   source::{
     community::{Community, CommunitySafe},
+    community_rule::CommunityRule,
     moderator::ModRemovePost,
     person::{Person, PersonSafe},
     post::Post,
   },
   traits::{ToSafe, ViewToVec},
-  utils::{get_conn, limit_and_offset, DbPool},
+  utils::{get_read_conn, limit_and_offset, DbPool},
 };
 
-type ModRemovePostViewTuple = (ModRemovePost, Option<PersonSafe>, Post, CommunitySafe);
+type ModRemovePostViewTuple = (
+  ModRemovePost,
+  Option<PersonSafe>,
+  Post,
+  CommunitySafe,
+  Option<CommunityRule>,
+);
 
 impl ModRemovePostView {
   pub async fn list(pool: &DbPool, params: ModlogListParams) -> Result<Vec<Self>, Error> {
-    let conn = &mut get_conn(pool).await?;
+    let conn = &mut get_read_conn(pool, params.my_person_id).await?;
 
     let person_alias_1 = diesel::alias!(person as person1);
     let admin_person_id_join = params.mod_person_id.unwrap_or(PersonId(-1));
@@ -41,11 +48,15 @@ impl ModRemovePostView {
       .inner_join(post::table)
       .inner_join(community::table.on(post::community_id.eq(community::id)))
       .inner_join(person_alias_1.on(post::creator_id.eq(person_alias_1.field(person::id))))
+      .left_join(
+        community_rule::table.on(mod_remove_post::rule_id.eq(community_rule::id.nullable())),
+      )
       .select((
         mod_remove_post::all_columns,
         Person::safe_columns_tuple().nullable(),
         post::all_columns,
         Community::safe_columns_tuple(),
+        community_rule::all_columns.nullable(),
       ))
       .into_boxed();
 
@@ -85,6 +96,7 @@ impl ViewToVec for ModRemovePostView {
         moderator: a.1,
         post: a.2,
         community: a.3,
+        rule: a.4,
       })
       .collect::<Vec<Self>>()
   }
@@ -1,9 +1,13 @@
+use doku::Document;
 use lemmy_db_schema::{
   newtypes::{CommunityId, PersonId},
   source::{
     comment::Comment,
     community::CommunitySafe,
+    community_rule::CommunityRule,
+    instance::Instance,
     moderator::{
+      AdminBlockInstance,
       AdminPurgeComment,
       AdminPurgeCommunity,
       AdminPurgePerson,
@@ -26,7 +30,7 @@ use lemmy_db_schema::{
 };
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, Document)]
 pub struct ModAddCommunityView {
   pub mod_add_community: ModAddCommunity,
   pub moderator: Option<PersonSafe>,
@@ -34,14 +38,14 @@ pub struct ModAddCommunityView {
   pub modded_person: PersonSafe,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, Document)]
 pub struct ModAddView {
   pub mod_add: ModAdd,
   pub moderator: Option<PersonSafe>,
   pub modded_person: PersonSafe,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, Document)]
 pub struct ModBanFromCommunityView {
   pub mod_ban_from_community: ModBanFromCommunity,
   pub moderator: Option<PersonSafe>,
@@ -49,21 +53,21 @@ pub struct ModBanFromCommunityView {
   pub banned_person: PersonSafe,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, Document)]
 pub struct ModBanView {
   pub mod_ban: ModBan,
   pub moderator: Option<PersonSafe>,
   pub banned_person: PersonSafe,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, Document)]
 pub struct ModHideCommunityView {
   pub mod_hide_community: ModHideCommunity,
   pub admin: Option<PersonSafe>,
   pub community: CommunitySafe,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, Document)]
 pub struct ModLockPostView {
   pub mod_lock_post: ModLockPost,
   pub moderator: Option<PersonSafe>,
@@ -71,7 +75,7 @@ pub struct ModLockPostView {
   pub community: CommunitySafe,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, Document)]
 pub struct ModRemoveCommentView {
   pub mod_remove_comment: ModRemoveComment,
   pub moderator: Option<PersonSafe>,
@@ -79,24 +83,26 @@ pub struct ModRemoveCommentView {
   pub commenter: PersonSafe,
   pub post: Post,
   pub community: CommunitySafe,
+  pub rule: Option<CommunityRule>,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, Document)]
 pub struct ModRemoveCommunityView {
   pub mod_remove_community: ModRemoveCommunity,
   pub moderator: Option<PersonSafe>,
   pub community: CommunitySafe,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, Document)]
 pub struct ModRemovePostView {
   pub mod_remove_post: ModRemovePost,
   pub moderator: Option<PersonSafe>,
   pub post: Post,
   pub community: CommunitySafe,
+  pub rule: Option<CommunityRule>,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, Document)]
 pub struct ModFeaturePostView {
   pub mod_feature_post: ModFeaturePost,
   pub moderator: Option<PersonSafe>,
@@ -104,7 +110,7 @@ pub struct ModFeaturePostView {
   pub community: CommunitySafe,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, Document)]
 pub struct ModTransferCommunityView {
   pub mod_transfer_community: ModTransferCommunity,
   pub moderator: Option<PersonSafe>,
@@ -112,34 +118,43 @@ pub struct ModTransferCommunityView {
   pub modded_person: PersonSafe,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, Document)]
 pub struct AdminPurgeCommentView {
   pub admin_purge_comment: AdminPurgeComment,
   pub admin: Option<PersonSafe>,
   pub post: Post,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, Document)]
+pub struct AdminBlockInstanceView {
+  pub admin_block_instance: AdminBlockInstance,
+  pub admin: Option<PersonSafe>,
+  pub instance: Instance,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Document)]
 pub struct AdminPurgeCommunityView {
   pub admin_purge_community: AdminPurgeCommunity,
   pub admin: Option<PersonSafe>,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, Document)]
 pub struct AdminPurgePersonView {
   pub admin_purge_person: AdminPurgePerson,
   pub admin: Option<PersonSafe>,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, Document)]
 pub struct AdminPurgePostView {
   pub admin_purge_post: AdminPurgePost,
   pub admin: Option<PersonSafe>,
   pub community: CommunitySafe,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone, Copy)]
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Document)]
 pub struct ModlogListParams {
+  /// The person reading the modlog
+  pub my_person_id: Option<PersonId>,
   pub community_id: Option<CommunityId>,
   pub mod_person_id: Option<PersonId>,
   pub other_person_id: Option<PersonId>,
@@ -1,4 +1,7 @@
-use crate::{error::LemmyError, settings::structs::Settings};
+use crate::{
+  error::{LemmyError, LemmyErrorExt, LemmyErrorType},
+  settings::structs::Settings,
+};
 use html2text;
 use lettre::{
   message::{Mailbox, MultiPart},
@@ -22,18 +25,13 @@ pub fn send_email(
   html: &str,
   settings: &Settings,
 ) -> Result<(), LemmyError> {
-  let email_config = settings
-    .email
-    .clone()
-    .ok_or_else(|| LemmyError::from_message("no_email_setup"))?;
+  let email_config = settings.email.get().ok_or(LemmyErrorType::NoEmailSetup)?;
   let domain = settings.hostname.clone();
 
   let (smtp_server, smtp_port) = {
     let email_and_port = email_config.smtp_server.split(':').collect::<Vec<&str>>();
     if email_and_port.len() == 1 {
-      return Err(LemmyError::from_message(
-        "email.smtp_server needs a port, IE smtp.xxx.com:465",
-      ));
+      return Err(LemmyErrorType::EmailSmtpServerNeedsAPort.into());
     }
 
     (
@@ -89,6 +87,6 @@ pub fn send_email(
 
   match result {
     Ok(_) => Ok(()),
-    Err(e) => Err(LemmyError::from_error_message(e, "email_send_failed")),
+    Err(e) => Err(e).with_lemmy_type(LemmyErrorType::EmailSendFailed),
   }
 }
@@ -1,82 +1,267 @@
+use doku::Document;
+use serde::{Deserialize, Serialize};
 use std::{
   fmt,
   fmt::{Debug, Display},
 };
+use strum_macros::{Display, EnumIter, IntoStaticStr};
 use tracing_error::SpanTrace;
 
-#[derive(serde::Serialize)]
-struct ApiError {
-  error: String,
-}
-
 pub struct LemmyError {
-  pub message: Option<String>,
+  pub error_type: LemmyErrorType,
   pub inner: anyhow::Error,
   pub context: SpanTrace,
 }
 
+/// All errors which can be returned to API clients. Serialized as `{"error": "<code>"}`, where
+/// the code is the variant name in snake_case. Variants with details additionally have a
+/// `message` field, for example `{"error": "slurs", "message": ["word"]}`.
+///
+/// The codes are stable, so new variants may be added but existing ones must not be renamed.
+#[derive(
+  Display, Debug, Serialize, Deserialize, Clone, PartialEq, Eq, EnumIter, IntoStaticStr, Document,
+)]
+#[serde(tag = "error", content = "message", rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum LemmyErrorType {
+  ActorMissingPrivateKey,
+  ApplicationQuestionRequired,
+  Banned,
+  BioLengthOverflow,
+  CannotCreatePostOrCommentInDeletedOrRemovedCommunity,
+  CannotLeaveAdmin,
+  CannotReceivePage,
+  CannotReceiveRelayFollow,
+  CannotReceiveStoredActivity,
+  CantBlockAdmin,
+  CantBlockLocalInstance,
+  CantBlockYourself,
+  CantEnablePrivateInstanceAndFederationTogether,
+  CantPurgeLocalInstance,
+  CantReplayLocalActivity,
+  CantReplayPrivateMessage,
+  CantReplayUnverifiedActivity,
+  CantResendRemoteActivity,
+  CantResendPrivateMessage,
+  CaptchaIncorrect,
+  CommunityAlreadyExists,
+  CommunityBan,
+  CommunityBlockAlreadyExists,
+  CommunityDirectoryDisabled,
+  CommunityFollowerAlreadyExists,
+  CommunityModeratorAlreadyExists,
+  CommunityRuleTitleRequired,
+  CommunityUserAlreadyBanned,
+  ConnectionClosed,
+  CouldntAssignReport,
+  CouldntCreateComment,
+  CouldntCreatePost,
+  CouldntCreatePrivateMessage,
+  CouldntCreateReport,
+  CouldntCreateReportComment,
+  CouldntEscalateReport,
+  CouldntFindComment,
+  CouldntFindCommunity,
+  CouldntFindCommunityRule,
+  CouldntFindObject,
+  CouldntFindPost,
+  CouldntFindRelay,
+  CouldntFindThatUsernameOrEmail,
+  CouldntGetComments,
+  CouldntGetPosts,
+  CouldntLikeComment,
+  CouldntLikePost,
+  CouldntMarkPostAsRead,
+  CouldntParseActivity,
+  CouldntResolveReport,
+  CouldntRevertMigration,
+  CouldntSaveComment,
+  CouldntSavePost,
+  CouldntSetAllEmailVerified,
+  CouldntSetAllRegistrationsAccepted,
+  CouldntUpdateComment,
+  CouldntUpdateCommunity,
+  CouldntUpdateCommunityHiddenStatus,
+  CouldntUpdatePersonMentions,
+  CouldntUpdatePost,
+  CouldntUpdatePrivateMessage,
+  CouldntUpdateReplies,
+  CouldntUpdateUser,
+  Deleted,
+  /// The domain which is blocked
+  DomainBlocked(String),
+  /// The domain which is missing from the allowlist
+  DomainNotInAllowList(String),
+  DownvotesDisabled,
+  EmailAlreadyExists,
+  EmailNotVerified,
+  EmailRequired,
+  EmailSendFailed,
+  EmailSmtpServerNeedsAPort,
+  FailedToLockMutex,
+  FailedToResolve,
+  FederationDisabled,
+  FederationDisabledByStrictAllowList,
+  HoneypotFail,
+  ImageUrlMissingLastPathSegment,
+  ImageUrlMissingPathSegments,
+  InstanceIsPrivate,
+  InvalidCommunity,
+  InvalidCommunityName,
+  InvalidConfig,
+  InvalidDefaultPostListingType,
+  InvalidInbox,
+  InvalidMatrixId,
+  InvalidOutboxPage,
+  InvalidPassword,
+  InvalidPostTitle,
+  InvalidProtocolScheme,
+  InvalidQuery,
+  InvalidRelay,
+  InvalidSignature,
+  InvalidSlurFilterRegex,
+  InvalidTime,
+  InvalidUrl,
+  InvalidUsername,
+  InvalidVoteValue,
+  InvalidWebfingerQuery,
+  LanguageNotAllowed,
+  Locked,
+  MissingOperation,
+  MissingSignature,
+  NewPostCannotBeStickiedOrLocked,
+  NoAdmins,
+  NoCommentEditAllowed,
+  NoCommunityEditAllowed,
+  NoCommunityFoundInCc,
+  NoContentTypeHeader,
+  NoEmailSetup,
+  NoIdGiven,
+  NoLinesInHtml,
+  NoPostEditAllowed,
+  NoPrivateMessageEditAllowed,
+  NotAModOrAdmin,
+  NotAModerator,
+  NotAnAdmin,
+  NotAnImageType,
+  NotLoggedIn,
+  NotTopAdmin,
+  NoteConversionDisabled,
+  ObjectIsNotPublic,
+  ObjectNotLocal,
+  OnlyAdminsCanCreateCommunities,
+  OnlyLocalAdminCanRemoveCommunity,
+  OnlyLocalAdminCanRestoreCommunity,
+  OnlyModsCanPostInCommunity,
+  PageDoesNotSpecifyCreator,
+  PageDoesNotSpecifyGroup,
+  PasswordIncorrect,
+  PasswordsDontMatch,
+  PersonBlock,
+  PersonBlockAlreadyExists,
+  PictrsApiKeyNotProvided,
+  /// The message returned by pict-rs
+  PictrsResponseError(String),
+  PostTitleTooLong,
+  /// The limit which was exceeded, as number of requests per interval
+  RateLimitError {
+    limit: i32,
+    interval_secs: i32,
+  },
+  RegistrationApplicationAnswerRequired,
+  RegistrationApplicationPending,
+  RegistrationClosed,
+  /// The reason given by the admin who denied the application
+  RegistrationDenied(String),
+  RelayFollowMismatch,
+  RelayFollowNotPending,
+  RelayNotAccepted,
+  ReportAssigneeNotAModOrAdmin,
+  ReportAssigneeNotAnAdmin,
+  ReportCommentNeedsOneReport,
+  ReportReasonRequired,
+  ReportTooLong,
+  ReporterNotLocal,
+  RequestRetryLimitReached,
+  RuleNotInCommunity,
+  SiteAlreadyExists,
+  SiteBan,
+  SiteDescriptionLengthOverflow,
+  SiteMetadataPageIsNotDoctypeHtml,
+  SiteMissingPrivateKey,
+  /// The words which matched the slur filter
+  Slurs(Vec<String>),
+  SystemErrLogin,
+  TokenNotFound,
+  /// Any error without a more specific type, with its description
+  Unknown(String),
+  UserAlreadyExists,
+}
+
 impl LemmyError {
-  /// Create LemmyError from a message, including stack trace
-  pub fn from_message(message: &str) -> Self {
-    let inner = anyhow::anyhow!("{}", message);
+  pub fn to_json(&self) -> Result<String, Self> {
+    Ok(serde_json::to_string(&self.error_type)?)
+  }
+}
+
+impl<T> From<T> for LemmyError
+where
+  T: Into<anyhow::Error>,
+{
+  fn from(t: T) -> Self {
+    let inner = t.into();
     LemmyError {
-      message: Some(message.into()),
+      error_type: LemmyErrorType::Unknown(inner.to_string()),
       inner,
       context: SpanTrace::capture(),
     }
   }
+}
 
-  /// Create a LemmyError from error and message, including stack trace
-  pub fn from_error_message<E>(error: E, message: &str) -> Self
-  where
-    E: Into<anyhow::Error>,
-  {
+impl From<LemmyErrorType> for LemmyError {
+  fn from(error_type: LemmyErrorType) -> Self {
+    let inner = anyhow::anyhow!("{}", error_type);
     LemmyError {
-      message: Some(message.into()),
-      inner: error.into(),
+      error_type,
+      inner,
       context: SpanTrace::capture(),
     }
   }
+}
 
-  /// Add message to existing LemmyError (or overwrite existing error)
-  pub fn with_message(self, message: &str) -> Self {
-    LemmyError {
-      message: Some(message.into()),
-      ..self
-    }
-  }
+/// Attaches an error type to any other error, keeping the original error for logging.
+pub trait LemmyErrorExt<T, E: Into<anyhow::Error>> {
+  fn with_lemmy_type(self, error_type: LemmyErrorType) -> Result<T, LemmyError>;
+}
 
-  pub fn to_json(&self) -> Result<String, Self> {
-    let api_error = match &self.message {
-      Some(error) => ApiError {
-        error: error.into(),
-      },
-      None => ApiError {
-        error: "Unknown".into(),
-      },
-    };
-
-    Ok(serde_json::to_string(&api_error)?)
+impl<T, E: Into<anyhow::Error>> LemmyErrorExt<T, E> for Result<T, E> {
+  fn with_lemmy_type(self, error_type: LemmyErrorType) -> Result<T, LemmyError> {
+    self.map_err(|error| LemmyError {
+      error_type,
+      inner: error.into(),
+      context: SpanTrace::capture(),
+    })
   }
 }
 
-impl<T> From<T> for LemmyError
-where
-  T: Into<anyhow::Error>,
-{
-  fn from(t: T) -> Self {
-    LemmyError {
-      message: None,
-      inner: t.into(),
-      context: SpanTrace::capture(),
-    }
+/// Replaces the error type of an existing [LemmyError].
+pub trait LemmyErrorExt2<T> {
+  fn with_lemmy_type(self, error_type: LemmyErrorType) -> Result<T, LemmyError>;
+}
+
+impl<T> LemmyErrorExt2<T> for Result<T, LemmyError> {
+  fn with_lemmy_type(self, error_type: LemmyErrorType) -> Result<T, LemmyError> {
+    self.map_err(|error| LemmyError {
+      error_type,
+      ..error
+    })
   }
 }
 
 impl Debug for LemmyError {
   fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
     f.debug_struct("LemmyError")
-      .field("message", &self.message)
+      .field("error_type", &self.error_type)
       .field("inner", &self.inner)
       .field("context", &"SpanTrace")
       .finish()
@@ -85,9 +270,7 @@ impl Debug for LemmyError {
 
 impl Display for LemmyError {
   fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-    if let Some(message) = &self.message {
-      write!(f, "{message}: ")?;
-    }
+    write!(f, "{}: ", &self.error_type)?;
     writeln!(f, "{}", self.inner)?;
     fmt::Display::fmt(&self.context, f)
   }
@@ -102,14 +285,49 @@ impl actix_web::error::ResponseError for LemmyError {
   }
 
   fn error_response(&self) -> actix_web::HttpResponse {
-    if let Some(message) = &self.message {
-      actix_web::HttpResponse::build(self.status_code()).json(ApiError {
-        error: message.into(),
-      })
-    } else {
-      actix_web::HttpResponse::build(self.status_code())
-        .content_type("text/plain")
-        .body(self.inner.to_string())
+    actix_web::HttpResponse::build(self.status_code()).json(&self.error_type)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use actix_web::{body::MessageBody, ResponseError};
+  use strum::IntoEnumIterator;
+
+  #[test]
+  fn serializes_error_code() {
+    let err = LemmyError::from(LemmyErrorType::NotAnAdmin);
+    assert_eq!(err.to_json().unwrap(), r#"{"error":"not_an_admin"}"#);
+
+    let err = LemmyError::from(LemmyErrorType::Slurs(vec!["word".into()]));
+    let body = err.error_response().into_body().try_into_bytes().unwrap();
+    assert_eq!(body, r#"{"error":"slurs","message":["word"]}"#);
+  }
+
+  #[test]
+  fn keeps_inner_error() {
+    let res: Result<(), LemmyError> =
+      Err(anyhow::anyhow!("database down")).with_lemmy_type(LemmyErrorType::CouldntFindPost);
+    let err = res.unwrap_err();
+    assert_eq!(err.error_type, LemmyErrorType::CouldntFindPost);
+    assert_eq!(err.inner.to_string(), "database down");
+
+    let err = Err::<(), _>(err)
+      .with_lemmy_type(LemmyErrorType::Deleted)
+      .unwrap_err();
+    assert_eq!(err.error_type, LemmyErrorType::Deleted);
+    assert_eq!(err.inner.to_string(), "database down");
+  }
+
+  /// The strum name is used for logs and federation errors, so it needs to match the API code
+  #[test]
+  fn display_matches_error_code() {
+    for error_type in LemmyErrorType::iter() {
+      let json = serde_json::to_value(&error_type).unwrap();
+      assert_eq!(json["error"], error_type.to_string());
+      let deserialized: LemmyErrorType = serde_json::from_value(json).unwrap();
+      assert_eq!(deserialized, error_type);
     }
   }
 }
@@ -0,0 +1,137 @@
+//! Prometheus metrics which are collected by the different crates, and served on `/metrics` if
+//! enabled in the config.
+use crate::error::LemmyError;
+use once_cell::sync::Lazy;
+use prometheus::{
+  exponential_buckets,
+  register_histogram_vec,
+  register_int_counter_vec,
+  register_int_gauge,
+  register_int_gauge_vec,
+  Encoder,
+  HistogramTimer,
+  HistogramVec,
+  IntCounterVec,
+  IntGauge,
+  IntGaugeVec,
+  TextEncoder,
+};
+
+pub static HTTP_REQUESTS: Lazy<IntCounterVec> = Lazy::new(|| {
+  register_int_counter_vec!(
+    "lemmy_http_requests_total",
+    "Number of handled HTTP requests",
+    &["method", "route", "status"]
+  )
+  .expect("register metric")
+});
+
+pub static HTTP_REQUEST_DURATION: Lazy<HistogramVec> = Lazy::new(|| {
+  register_histogram_vec!(
+    "lemmy_http_request_duration_seconds",
+    "Time taken to handle HTTP requests",
+    &["method", "route"]
+  )
+  .expect("register metric")
+});
+
+/// Connections of the database pool, by state (`idle` or `active`)
+pub static DB_POOL_CONNECTIONS: Lazy<IntGaugeVec> = Lazy::new(|| {
+  register_int_gauge_vec!(
+    "lemmy_db_pool_connections",
+    "Connections of the database pool",
+    &["state"]
+  )
+  .expect("register metric")
+});
+
+pub static DB_POOL_MAX_SIZE: Lazy<IntGauge> = Lazy::new(|| {
+  register_int_gauge!(
+    "lemmy_db_pool_max_size",
+    "Maximum number of connections in the database pool"
+  )
+  .expect("register metric")
+});
+
+/// Activities which were received in the inbox (`inbound`) or delivered to remote inboxes
+/// (`outbound`), by activity type and result
+pub static FEDERATION_ACTIVITIES: Lazy<IntCounterVec> = Lazy::new(|| {
+  register_int_counter_vec!(
+    "lemmy_federation_activities_total",
+    "Number of received and delivered activities",
+    &["direction", "type", "result"]
+  )
+  .expect("register metric")
+});
+
+pub static RATE_LIMIT_REJECTIONS: Lazy<IntCounterVec> = Lazy::new(|| {
+  register_int_counter_vec!(
+    "lemmy_rate_limit_rejections_total",
+    "Number of requests which were rejected by the rate limit",
+    &["type"]
+  )
+  .expect("register metric")
+});
+
+pub static WEBSOCKET_SESSIONS: Lazy<IntGauge> = Lazy::new(|| {
+  register_int_gauge!(
+    "lemmy_websocket_sessions",
+    "Number of open websocket sessions"
+  )
+  .expect("register metric")
+});
+
+pub static SCHEDULED_TASK_DURATION: Lazy<HistogramVec> = Lazy::new(|| {
+  register_histogram_vec!(
+    "lemmy_scheduled_task_duration_seconds",
+    "Time taken to run scheduled tasks",
+    &["task"],
+    // From 100ms up to about 30 minutes
+    exponential_buckets(0.1, 3.0, 10).expect("valid buckets")
+  )
+  .expect("register metric")
+});
+
+/// Records a received or delivered activity.
+pub fn record_federation_activity(outbound: bool, kind: Option<&str>, success: bool) {
+  let direction = if outbound { "outbound" } else { "inbound" };
+  let result = if success { "success" } else { "failure" };
+  FEDERATION_ACTIVITIES
+    .with_label_values(&[direction, kind.unwrap_or("Unknown"), result])
+    .inc();
+}
+
+/// Measures the duration of a scheduled task until the returned timer is dropped.
+pub fn scheduled_task_timer(task: &str) -> HistogramTimer {
+  SCHEDULED_TASK_DURATION
+    .with_label_values(&[task])
+    .start_timer()
+}
+
+/// Returns all metrics in the Prometheus text format.
+pub fn encode_metrics() -> Result<String, LemmyError> {
+  let mut buffer = vec![];
+  TextEncoder::new().encode(&prometheus::gather(), &mut buffer)?;
+  Ok(String::from_utf8(buffer)?)
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::metrics::{encode_metrics, record_federation_activity, RATE_LIMIT_REJECTIONS};
+
+  #[test]
+  fn test_encode_metrics() {
+    RATE_LIMIT_REJECTIONS.with_label_values(&["Post"]).inc();
+    record_federation_activity(false, Some("Create"), true);
+    record_federation_activity(true, None, false);
+
+    let metrics = encode_metrics().unwrap();
+    assert!(metrics.contains("lemmy_rate_limit_rejections_total{type=\"Post\"} 1"));
+    assert!(metrics.contains(
+      "lemmy_federation_activities_total{direction=\"inbound\",result=\"success\",type=\"Create\"} 1"
+    ));
+    assert!(metrics.contains(
+      "lemmy_federation_activities_total{direction=\"outbound\",result=\"failure\",type=\"Unknown\"} 1"
+    ));
+  }
+}
@@ -1,7 +1,11 @@
-use crate::{error::LemmyError, IpAddr};
+use crate::{
+  error::{LemmyError, LemmyErrorType},
+  metrics::RATE_LIMIT_REJECTIONS,
+  IpAddr,
+};
 use actix_web::dev::{ConnectionInfo, Service, ServiceRequest, ServiceResponse, Transform};
 use futures::future::{ok, Ready};
-use rate_limiter::{RateLimitStorage, RateLimitType};
+use rate_limiter::{RateLimitBackend, RateLimitStorage, RateLimitType};
 use serde::{Deserialize, Serialize};
 use std::{
   future::Future,
@@ -11,6 +15,7 @@ use std::{
   task::{Context, Poll},
 };
 use tokio::sync::{mpsc, mpsc::Sender, OnceCell};
+use tracing::warn;
 use typed_builder::TypedBuilder;
 
 pub mod rate_limiter;
@@ -55,15 +60,10 @@ pub struct RateLimitConfig {
   pub search_per_second: i32,
 }
 
-#[derive(Debug, Clone)]
-struct RateLimit {
-  pub rate_limiter: RateLimitStorage,
-  pub rate_limit_config: RateLimitConfig,
-}
-
-#[derive(Debug, Clone)]
+#[derive(Clone)]
 pub struct RateLimitedGuard {
-  rate_limit: Arc<Mutex<RateLimit>>,
+  rate_limit_config: Arc<Mutex<RateLimitConfig>>,
+  storage: Arc<dyn RateLimitBackend>,
   type_: RateLimitType,
 }
 
@@ -71,30 +71,41 @@ pub struct RateLimitedGuard {
 #[derive(Clone)]
 pub struct RateLimitCell {
   tx: Sender<RateLimitConfig>,
-  rate_limit: Arc<Mutex<RateLimit>>,
+  rate_limit_config: Arc<Mutex<RateLimitConfig>>,
+  storage: Arc<dyn RateLimitBackend>,
 }
 
+static LOCAL_INSTANCE: OnceCell<RateLimitCell> = OnceCell::const_new();
+
 impl RateLimitCell {
   /// Initialize cell if it wasnt initialized yet. Otherwise returns the existing cell.
   pub async fn new(rate_limit_config: RateLimitConfig) -> &'static Self {
-    static LOCAL_INSTANCE: OnceCell<RateLimitCell> = OnceCell::const_new();
+    let storage = Arc::new(Mutex::new(RateLimitStorage::default()));
+    Self::with_backend(rate_limit_config, storage).await
+  }
+
+  /// Like `new`, but keeps the rate limit buckets in the given backend instead of process memory.
+  pub async fn with_backend(
+    rate_limit_config: RateLimitConfig,
+    storage: Arc<dyn RateLimitBackend>,
+  ) -> &'static Self {
     LOCAL_INSTANCE
       .get_or_init(|| async {
         let (tx, mut rx) = mpsc::channel::<RateLimitConfig>(4);
-        let rate_limit = Arc::new(Mutex::new(RateLimit {
-          rate_limiter: Default::default(),
-          rate_limit_config,
-        }));
-        let rate_limit2 = rate_limit.clone();
+        let rate_limit_config = Arc::new(Mutex::new(rate_limit_config));
+        let rate_limit_config2 = rate_limit_config.clone();
         tokio::spawn(async move {
           while let Some(r) = rx.recv().await {
-            rate_limit2
+            *rate_limit_config2
               .lock()
-              .expect("Failed to lock rate limit mutex for updating")
-              .rate_limit_config = r;
+              .expect("Failed to lock rate limit mutex for updating") = r;
           }
         });
-        RateLimitCell { tx, rate_limit }
+        RateLimitCell {
+          tx,
+          rate_limit_config,
+          storage,
+        }
       })
       .await
   }
@@ -131,7 +142,8 @@ impl RateLimitCell {
 
   fn kind(&self, type_: RateLimitType) -> RateLimitedGuard {
     RateLimitedGuard {
-      rate_limit: self.rate_limit.clone(),
+      rate_limit_config: self.rate_limit_config.clone(),
+      storage: self.storage.clone(),
       type_,
     }
   }
@@ -143,27 +155,50 @@ pub struct RateLimitedMiddleware<S> {
 }
 
 impl RateLimitedGuard {
-  /// Returns true if the request passed the rate limit, false if it failed and should be rejected.
-  pub fn check(self, ip_addr: IpAddr) -> bool {
-    // Does not need to be blocking because the RwLock in settings never held across await points,
-    // and the operation here locks only long enough to clone
-    let mut guard = self
-      .rate_limit
-      .lock()
-      .expect("Failed to lock rate limit mutex for reading");
-    let rate_limit = &guard.rate_limit_config;
-
-    let (kind, interval) = match self.type_ {
-      RateLimitType::Message => (rate_limit.message, rate_limit.message_per_second),
-      RateLimitType::Post => (rate_limit.post, rate_limit.post_per_second),
-      RateLimitType::Register => (rate_limit.register, rate_limit.register_per_second),
-      RateLimitType::Image => (rate_limit.image, rate_limit.image_per_second),
-      RateLimitType::Comment => (rate_limit.comment, rate_limit.comment_per_second),
-      RateLimitType::Search => (rate_limit.search, rate_limit.search_per_second),
+  /// Returns an error with the exceeded limit if the request failed and should be rejected.
+  pub async fn check(self, ip_addr: IpAddr) -> Result<(), LemmyError> {
+    // The config lock is only held long enough to read the values
+    let (kind, interval) = {
+      let rate_limit = self
+        .rate_limit_config
+        .lock()
+        .expect("Failed to lock rate limit mutex for reading");
+      match self.type_ {
+        RateLimitType::Message => (rate_limit.message, rate_limit.message_per_second),
+        RateLimitType::Post => (rate_limit.post, rate_limit.post_per_second),
+        RateLimitType::Register => (rate_limit.register, rate_limit.register_per_second),
+        RateLimitType::Image => (rate_limit.image, rate_limit.image_per_second),
+        RateLimitType::Comment => (rate_limit.comment, rate_limit.comment_per_second),
+        RateLimitType::Search => (rate_limit.search, rate_limit.search_per_second),
+      }
     };
-    let limiter = &mut guard.rate_limiter;
 
-    limiter.check_rate_limit_full(self.type_, &ip_addr, kind, interval)
+    let passed = match self
+      .storage
+      .check_rate_limit(self.type_, &ip_addr, kind, interval)
+      .await
+    {
+      Ok(passed) => passed,
+      Err(e) => {
+        // Dont lock everyone out if the backend is unavailable
+        warn!("Failed to check rate limit: {}", e);
+        true
+      }
+    };
+    if passed {
+      Ok(())
+    } else {
+      RATE_LIMIT_REJECTIONS
+        .with_label_values(&[self.type_.as_ref()])
+        .inc();
+      Err(
+        LemmyErrorType::RateLimitError {
+          limit: kind,
+          interval_secs: interval,
+        }
+        .into(),
+      )
+    }
   }
 }
 
@@ -208,14 +243,12 @@ where
     let service = self.service.clone();
 
     Box::pin(async move {
-      if rate_limited.check(ip_addr) {
-        service.call(req).await
-      } else {
-        let (http_req, _) = req.into_parts();
-        Ok(ServiceResponse::from_err(
-          LemmyError::from_message("rate_limit_error"),
-          http_req,
-        ))
+      match rate_limited.check(ip_addr).await {
+        Ok(()) => service.call(req).await,
+        Err(e) => {
+          let (http_req, _) = req.into_parts();
+          Ok(ServiceResponse::from_err(e, http_req))
+        }
       }
     })
   }
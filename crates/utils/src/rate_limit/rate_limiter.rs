@@ -1,5 +1,9 @@
-use crate::IpAddr;
-use std::{collections::HashMap, time::Instant};
+use crate::{
+  error::{LemmyError, LemmyErrorType},
+  IpAddr,
+};
+use async_trait::async_trait;
+use std::{collections::HashMap, sync::Mutex, time::Instant};
 use strum::IntoEnumIterator;
 use tracing::debug;
 
@@ -10,7 +14,7 @@ struct RateLimitBucket {
 }
 
 #[derive(Eq, PartialEq, Hash, Debug, EnumIter, Copy, Clone, AsRefStr)]
-pub(crate) enum RateLimitType {
+pub enum RateLimitType {
   Message,
   Register,
   Post,
@@ -19,6 +23,20 @@ pub(crate) enum RateLimitType {
   Search,
 }
 
+/// Storage for the rate limit buckets. By default they are kept in process memory, a shared
+/// backend lets multiple lemmy_server processes enforce the same limits.
+#[async_trait]
+pub trait RateLimitBackend: Send + Sync {
+  /// Returns true if the request passed the rate limit, false if it failed and should be rejected.
+  async fn check_rate_limit(
+    &self,
+    type_: RateLimitType,
+    ip: &IpAddr,
+    rate: i32,
+    per: i32,
+  ) -> Result<bool, LemmyError>;
+}
+
 /// Rate limiting based on rate type and IP addr
 #[derive(Debug, Clone, Default)]
 pub struct RateLimitStorage {
@@ -46,8 +64,6 @@ impl RateLimitStorage {
     }
   }
 
-  /// Rate limiting Algorithm described here: https://stackoverflow.com/a/668327/1655478
-  ///
   /// Returns true if the request passed the rate limit, false if it failed and should be rejected.
   #[allow(clippy::float_cmp)]
   pub(super) fn check_rate_limit_full(
@@ -64,17 +80,17 @@ impl RateLimitStorage {
         let time_passed = current.duration_since(rate_limit.last_checked).as_secs() as f64;
 
         // The initial value
-        if rate_limit.allowance == -2f64 {
-          rate_limit.allowance = f64::from(rate);
+        let allowance = if rate_limit.allowance == -2f64 {
+          None
+        } else {
+          Some(rate_limit.allowance)
         };
 
+        let (allowance, passed) = next_allowance(allowance, time_passed, rate, per);
         rate_limit.last_checked = current;
-        rate_limit.allowance += time_passed * (f64::from(rate) / f64::from(per));
-        if rate_limit.allowance > f64::from(rate) {
-          rate_limit.allowance = f64::from(rate);
-        }
+        rate_limit.allowance = allowance;
 
-        if rate_limit.allowance < 1.0 {
+        if !passed {
           debug!(
             "Rate limited type: {}, IP: {}, time_passed: {}, allowance: {}",
             type_.as_ref(),
@@ -82,11 +98,8 @@ impl RateLimitStorage {
             time_passed,
             rate_limit.allowance
           );
-          false
-        } else {
-          rate_limit.allowance -= 1.0;
-          true
         }
+        passed
       } else {
         true
       }
@@ -95,3 +108,41 @@ impl RateLimitStorage {
     }
   }
 }
+
+#[async_trait]
+impl RateLimitBackend for Mutex<RateLimitStorage> {
+  async fn check_rate_limit(
+    &self,
+    type_: RateLimitType,
+    ip: &IpAddr,
+    rate: i32,
+    per: i32,
+  ) -> Result<bool, LemmyError> {
+    let mut storage = self.lock().map_err(|_| LemmyErrorType::FailedToLockMutex)?;
+    Ok(storage.check_rate_limit_full(type_, ip, rate, per))
+  }
+}
+
+/// Rate limiting Algorithm described here: https://stackoverflow.com/a/668327/1655478
+///
+/// Takes the allowance which was left after the last check, or `None` for the first request, and
+/// the seconds which passed since then. Returns the new allowance, and true if the request passed
+/// the rate limit.
+pub fn next_allowance(
+  allowance: Option<f64>,
+  time_passed: f64,
+  rate: i32,
+  per: i32,
+) -> (f64, bool) {
+  let rate = f64::from(rate);
+  let mut allowance = allowance.unwrap_or(rate) + time_passed * (rate / f64::from(per));
+  if allowance > rate {
+    allowance = rate;
+  }
+
+  if allowance < 1.0 {
+    (allowance, false)
+  } else {
+    (allowance - 1.0, true)
+  }
+}
@@ -5,15 +5,35 @@ use crate::{
 };
 use anyhow::{anyhow, Context};
 use deser_hjson::from_str;
+use doku::{
+  json::{AutoComments, CommentsStyle, Formatting, ObjectsStyle, Printer},
+  Document,
+  Fields,
+  Type,
+  TypeKind,
+};
+use lettre::message::Mailbox;
 use once_cell::sync::Lazy;
 use percent_encoding::{utf8_percent_encode, NON_ALPHANUMERIC};
 use regex::Regex;
+use serde_json::{Map, Value};
 use std::{env, fs, io::Error};
 
+pub mod reloadable;
 pub mod structs;
 
 static DEFAULT_CONFIG_FILE: &str = "config/config.hjson";
 
+/// Environment variables with this prefix override the config file. Nested settings are separated
+/// by two underscores, eg `LEMMY_EMAIL__SMTP_SERVER` for `email.smtp_server`.
+const ENV_PREFIX: &str = "LEMMY_";
+/// Environment variables with the prefix which are not settings
+const ENV_NOT_SETTINGS: &[&str] = &["LEMMY_CONFIG_LOCATION", "LEMMY_DATABASE_URL"];
+/// Variables of lemmy-ui, which often end up in the same environment
+const ENV_LEMMY_UI_PREFIX: &str = "LEMMY_UI_";
+/// Settings which can be set, but are left out of the generated documentation
+const UNDOCUMENTED_SETTINGS: &[&str] = &["opentelemetry_url"];
+
 pub static SETTINGS: Lazy<Settings> =
   Lazy::new(|| Settings::init().expect("Failed to load settings file"));
 static WEBFINGER_REGEX: Lazy<Regex> = Lazy::new(|| {
@@ -25,20 +45,97 @@ static WEBFINGER_REGEX: Lazy<Regex> = Lazy::new(|| {
 });
 
 impl Settings {
-  /// Reads config from configuration file.
+  /// Reads config from configuration file, and overrides it with environment variables.
   ///
   /// Note: The env var `LEMMY_DATABASE_URL` is parsed in
   /// `lemmy_db_schema/src/lib.rs::get_database_url_from_env()`
-  /// Warning: Only call this once.
   pub(crate) fn init() -> Result<Self, LemmyError> {
-    // Read the config file
-    let config = from_str::<Settings>(&Self::read_config_file()?)?;
+    Self::parse(&Self::read_config_file()?, env::vars())
+  }
+
+  fn parse(
+    config_file: &str,
+    vars: impl Iterator<Item = (String, String)>,
+  ) -> Result<Self, LemmyError> {
+    let mut config = from_str::<Value>(config_file)?;
+    for (name, value) in vars {
+      apply_env_var(&mut config, &name, &value)?;
+    }
+    let config =
+      serde_json::from_value::<Settings>(config).map_err(|e| anyhow!("Invalid settings: {e}"))?;
+    config.validate()?;
+    Ok(config)
+  }
 
-    if config.hostname == "unset" {
+  /// Documentation of the settings with their default values, in the format of the config file
+  pub fn docs() -> String {
+    let fmt = Formatting {
+      auto_comments: AutoComments::none(),
+      comments_style: CommentsStyle {
+        separator: "#".to_owned(),
+      },
+      objects_style: ObjectsStyle {
+        surround_keys_with_quotes: false,
+        use_comma_as_separator: false,
+      },
+      ..Default::default()
+    };
+    let mut ty = Settings::ty();
+    if let TypeKind::Struct {
+      fields: Fields::Named { fields },
+      ..
+    } = &mut ty.kind
+    {
+      fields.retain(|(name, _)| !UNDOCUMENTED_SETTINGS.contains(name));
+    }
+    Printer::default()
+      .with_formatting(&fmt)
+      .with_value(&doku::Value::from(&Settings::default()))
+      .print(&ty)
+  }
+
+  /// Catches mistakes which would otherwise only show up once the setting is used
+  fn validate(&self) -> Result<(), LemmyError> {
+    if self.hostname == "unset" {
       return Err(anyhow!("Hostname variable is not set!").into());
     }
+    if let Some(email) = self.email.get() {
+      let port = email
+        .smtp_server
+        .split_once(':')
+        .map(|(_, port)| port.parse::<u16>());
+      if !matches!(port, Some(Ok(_))) {
+        return Err(anyhow!("email.smtp_server needs a port, eg \"localhost:25\"").into());
+      }
+      if let Err(e) = email.smtp_from_address.parse::<Mailbox>() {
+        return Err(anyhow!("Invalid email.smtp_from_address: {e}").into());
+      }
+      if !["none", "tls", "starttls"].contains(&email.tls_type.as_str()) {
+        return Err(anyhow!("email.tls_type must be none, tls or starttls").into());
+      }
+    }
+    Ok(())
+  }
 
-    Ok(config)
+  /// Reads the config file and environment variables again, and replaces the email, pictrs and
+  /// prometheus settings. Other settings can only be changed with a restart, the names of those
+  /// which changed are returned. This includes `opentelemetry_url`, as the tracing subscriber can't
+  /// be replaced once it is installed.
+  pub fn reload(&self) -> Result<Vec<String>, LemmyError> {
+    let new = Self::init()?;
+    self.email.set(new.email.get());
+    self.pictrs.set(new.pictrs.get());
+    self.prometheus.set(new.prometheus.get());
+
+    let needs_restart = match (serde_json::to_value(self)?, serde_json::to_value(new)?) {
+      (Value::Object(old), Value::Object(new)) => new
+        .into_iter()
+        .filter(|(name, value)| old.get(name) != Some(value))
+        .map(|(name, _)| name)
+        .collect(),
+      _ => vec![],
+    };
+    Ok(needs_restart)
   }
 
   pub fn get_database_url(&self) -> String {
@@ -98,7 +195,211 @@ impl Settings {
   pub fn pictrs_config(&self) -> Result<PictrsConfig, LemmyError> {
     self
       .pictrs
-      .clone()
+      .get()
       .ok_or_else(|| anyhow!("images_disabled").into())
   }
 }
+
+/// Overrides the setting named by the environment variable, if it is one. Variables which don't
+/// name a setting are ignored, `unknown_env_vars` lists them once logging is set up.
+fn apply_env_var(config: &mut Value, name: &str, value: &str) -> Result<(), LemmyError> {
+  let keys = match setting_keys(name) {
+    Some(keys) => keys,
+    None => return Ok(()),
+  };
+  let ty = match setting_type(&keys) {
+    Some(ty) => ty,
+    None => return Ok(()),
+  };
+  let value = env_value(&ty, value).map_err(|e| anyhow!("Invalid value for {name}: {e}"))?;
+
+  let mut target = config;
+  for key in keys {
+    // Sections which are missing from the config file get created
+    if !target.is_object() {
+      *target = Value::Object(Map::new());
+    }
+    if let Value::Object(section) = target {
+      target = section.entry(key).or_insert(Value::Null);
+    }
+  }
+  *target = value;
+  Ok(())
+}
+
+/// Keys of the setting which the environment variable overrides, if the variable is meant for
+/// lemmy_server
+fn setting_keys(name: &str) -> Option<Vec<String>> {
+  match name.strip_prefix(ENV_PREFIX) {
+    Some(path) if !ENV_NOT_SETTINGS.contains(&name) && !name.starts_with(ENV_LEMMY_UI_PREFIX) => {
+      Some(path.split("__").map(str::to_lowercase).collect())
+    }
+    _ => None,
+  }
+}
+
+/// Type of the setting with these keys, if there is one
+fn setting_type(keys: &[String]) -> Option<Type> {
+  let mut ty = Settings::ty();
+  for key in keys {
+    ty = field_type(&ty, key)?;
+  }
+  Some(ty)
+}
+
+/// Environment variables with the prefix which don't name a setting, eg because of a typo
+pub fn unknown_env_vars() -> Vec<String> {
+  env::vars()
+    .map(|(name, _)| name)
+    .filter(|name| setting_keys(name).is_some_and(|keys| setting_type(&keys).is_none()))
+    .collect()
+}
+
+/// Type of the named field of a section
+fn field_type(ty: &Type, name: &str) -> Option<Type> {
+  match &ty.kind {
+    TypeKind::Optional { ty } => field_type(ty, name),
+    TypeKind::Struct {
+      fields: Fields::Named { fields },
+      ..
+    } => fields
+      .iter()
+      .find(|(field, _)| *field == name)
+      .map(|(_, field)| field.ty.clone()),
+    _ => None,
+  }
+}
+
+/// Parses the value of an environment variable according to the type of the setting
+fn env_value(ty: &Type, value: &str) -> Result<Value, String> {
+  match &ty.kind {
+    // An empty variable unsets optional settings
+    TypeKind::Optional { .. } if value.is_empty() => Ok(Value::Null),
+    TypeKind::Optional { ty } => env_value(ty, value),
+    TypeKind::String | TypeKind::Enum { .. } => Ok(Value::String(value.to_string())),
+    TypeKind::Bool => value
+      .parse::<bool>()
+      .map(Value::from)
+      .map_err(|e| e.to_string()),
+    TypeKind::Integer => value
+      .parse::<i64>()
+      .map(Value::from)
+      .map_err(|e| e.to_string()),
+    TypeKind::Float => value
+      .parse::<f64>()
+      .map(Value::from)
+      .map_err(|e| e.to_string()),
+    // Lists and whole sections are written in hjson, eg `["a", "b"]`
+    _ => from_str::<Value>(value).map_err(|e| e.to_string()),
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::settings::structs::Settings;
+  use url::Url;
+
+  fn parse(config_file: &str, vars: &[(&str, &str)]) -> Result<Settings, String> {
+    let vars = vars.iter().map(|(k, v)| (k.to_string(), v.to_string()));
+    Settings::parse(config_file, vars).map_err(|e| e.to_string())
+  }
+
+  #[test]
+  fn test_env_overrides() {
+    let config_file = r#"{ hostname: "example.com", port: 8000, database: { pool_size: 3 } }"#;
+    let settings = parse(
+      config_file,
+      &[
+        ("LEMMY_PORT", "9000"),
+        ("LEMMY_TLS_ENABLED", "false"),
+        ("LEMMY_DATABASE__HOST", "db"),
+        (
+          "LEMMY_DATABASE__READ_REPLICAS__URLS",
+          r#"["postgres://replica/lemmy"]"#,
+        ),
+        ("LEMMY_EMAIL__SMTP_SERVER", "smtp:25"),
+        ("LEMMY_EMAIL__SMTP_FROM_ADDRESS", "noreply@example.com"),
+        ("LEMMY_EMAIL__TLS_TYPE", "starttls"),
+        ("LEMMY_PICTRS__API_KEY", "1234"),
+        ("LEMMY_CONFIG_LOCATION", "lemmy.hjson"),
+        ("LEMMY_UI_HTTPS", "true"),
+        ("HOME", "/root"),
+      ],
+    )
+    .unwrap();
+
+    assert_eq!(9000, settings.port);
+    assert!(!settings.tls_enabled);
+    assert_eq!("db", settings.database.host);
+    assert_eq!(3, settings.database.pool_size);
+    let replicas = settings.database.read_replicas.clone().unwrap();
+    assert_eq!(vec!["postgres://replica/lemmy".to_string()], replicas.urls);
+    // Sections which are missing from the file get their defaults
+    assert_eq!(5, replicas.pool_size);
+    let email = settings.email.get().unwrap();
+    assert_eq!("smtp:25", email.smtp_server);
+    assert_eq!("starttls", email.tls_type);
+    // Strings stay strings even if they look like numbers
+    assert_eq!(
+      Some("1234".to_string()),
+      settings.pictrs_config().unwrap().api_key
+    );
+  }
+
+  #[test]
+  fn test_env_errors() {
+    let config_file = r#"{ hostname: "example.com" }"#;
+    // Unknown variables are only logged
+    assert!(parse(config_file, &[("LEMMY_EMAIL__SMTP_SERVR", "smtp:25")]).is_ok());
+    assert!(parse(config_file, &[("LEMMY_HTTPS", "false")]).is_ok());
+    let error = parse(config_file, &[("LEMMY_PORT", "eighty")]).unwrap_err();
+    assert!(error.contains("Invalid value for LEMMY_PORT"));
+    let error = parse(config_file, &[("LEMMY_BIND", "localhost")]).unwrap_err();
+    assert!(error.contains("Invalid settings"));
+
+    let error = parse(
+      config_file,
+      &[
+        ("LEMMY_EMAIL__SMTP_SERVER", "smtp"),
+        ("LEMMY_EMAIL__SMTP_FROM_ADDRESS", "noreply@example.com"),
+        ("LEMMY_EMAIL__TLS_TYPE", "none"),
+      ],
+    )
+    .unwrap_err();
+    assert!(error.contains("needs a port"));
+    let error = parse("{}", &[]).unwrap_err();
+    assert!(error.contains("Hostname"));
+    assert!(parse("{}", &[("LEMMY_HOSTNAME", "example.com")]).is_ok());
+  }
+
+  #[test]
+  fn test_unknown_env_vars() {
+    let unknown = |name: &str| {
+      super::setting_keys(name).is_some_and(|keys| super::setting_type(&keys).is_none())
+    };
+    assert!(unknown("LEMMY_HTTPS"));
+    assert!(unknown("LEMMY_EMAIL__SMTP_SERVR"));
+    assert!(!unknown("LEMMY_EMAIL__SMTP_SERVER"));
+    assert!(!unknown("LEMMY_DATABASE_URL"));
+    assert!(!unknown("LEMMY_UI_HTTPS"));
+    assert!(!unknown("HOME"));
+  }
+
+  #[test]
+  fn test_undocumented_settings() {
+    let config_file = r#"{ hostname: "example.com" }"#;
+    let settings = parse(
+      config_file,
+      &[("LEMMY_OPENTELEMETRY_URL", "http://localhost:4317")],
+    )
+    .unwrap();
+    assert_eq!(
+      Some("http://localhost:4317/"),
+      settings.opentelemetry_url.as_ref().map(Url::as_str)
+    );
+
+    let docs = Settings::docs();
+    assert!(docs.contains("tls_enabled"));
+    assert!(!docs.contains("opentelemetry_url"));
+  }
+}
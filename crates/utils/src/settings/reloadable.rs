@@ -0,0 +1,50 @@
+use doku::{Document, Type};
+use parking_lot::RwLock;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::{fmt, sync::Arc};
+
+/// A setting which can be changed while lemmy is running, see [`Settings::reload`]. Clones share
+/// the value, so that a reload reaches every copy of the settings.
+///
+/// [`Settings::reload`]: crate::settings::structs::Settings::reload
+#[derive(Clone, Default)]
+pub struct Reloadable<T>(Arc<RwLock<T>>);
+
+impl<T: Clone> Reloadable<T> {
+  pub fn new(value: T) -> Self {
+    Reloadable(Arc::new(RwLock::new(value)))
+  }
+
+  /// Returns a copy of the current value
+  pub fn get(&self) -> T {
+    self.0.read().clone()
+  }
+
+  pub(crate) fn set(&self, value: T) {
+    *self.0.write() = value;
+  }
+}
+
+impl<T: fmt::Debug> fmt::Debug for Reloadable<T> {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    self.0.read().fmt(f)
+  }
+}
+
+impl<T: Serialize> Serialize for Reloadable<T> {
+  fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+    self.0.read().serialize(serializer)
+  }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for Reloadable<T> {
+  fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+    T::deserialize(deserializer).map(|value| Reloadable(Arc::new(RwLock::new(value))))
+  }
+}
+
+impl<T: Document> Document for Reloadable<T> {
+  fn ty() -> Type {
+    T::ty()
+  }
+}
@@ -5,6 +5,9 @@ use regex::Regex;
 static MENTIONS_REGEX: Lazy<Regex> = Lazy::new(|| {
   Regex::new(r"@(?P<name>[\w.]+)@(?P<domain>[a-zA-Z0-9._:-]+)").expect("compile regex")
 });
+/// Mentions at the start of a text, either as markdown link or as plain text
+static LEADING_MENTIONS_REGEX: Lazy<Regex> =
+  Lazy::new(|| Regex::new(r"^(\s*(\[[@!][^\]]*\]\([^)]*\)|[@!]\S+))+\s*").expect("compile regex"));
 // TODO nothing is done with community / group webfingers yet, so just ignore those for now
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct MentionData {
@@ -32,9 +35,18 @@ pub fn scrape_text_for_mentions(text: &str) -> Vec<MentionData> {
   out.into_iter().unique().collect()
 }
 
+/// Removes the mentions at the start of a text, like microblogging software adds them when
+/// addressing a post to a community.
+pub fn strip_leading_mentions(text: &str) -> &str {
+  match LEADING_MENTIONS_REGEX.find(text) {
+    Some(m) => &text[m.end()..],
+    None => text,
+  }
+}
+
 #[cfg(test)]
 mod test {
-  use crate::utils::mention::scrape_text_for_mentions;
+  use crate::utils::mention::{scrape_text_for_mentions, strip_leading_mentions};
 
   #[test]
   fn test_mentions_regex() {
@@ -45,4 +57,15 @@ mod test {
     assert_eq!(mentions[0].domain, "honk.teduangst.com".to_string());
     assert_eq!(mentions[1].domain, "lemmy-alpha:8540".to_string());
   }
+
+  #[test]
+  fn test_strip_leading_mentions() {
+    let text =
+      "[@tenforward](https://enterprise.lemmy.ml/c/tenforward) @picard@enterprise.lemmy.ml Engage!";
+    assert_eq!(strip_leading_mentions(text), "Engage!");
+    assert_eq!(
+      strip_leading_mentions("Make it so, [@riker](/u/riker)"),
+      "Make it so, [@riker](/u/riker)"
+    );
+  }
 }
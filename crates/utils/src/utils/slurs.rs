@@ -1,4 +1,4 @@
-use crate::error::LemmyError;
+use crate::error::{LemmyError, LemmyErrorExt, LemmyErrorType};
 use regex::{Regex, RegexBuilder};
 
 pub fn remove_slurs(test: &str, slur_regex: &Option<Regex>) -> String {
@@ -39,12 +39,20 @@ pub fn build_slur_regex(regex_str: Option<&str>) -> Option<Regex> {
   })
 }
 
+/// Makes sure that a slur filter entered by a user, for example by community moderators, can be
+/// compiled. Otherwise [build_slur_regex] would panic on it.
+pub fn check_slur_regex(regex_str: &str) -> Result<(), LemmyError> {
+  RegexBuilder::new(regex_str)
+    .case_insensitive(true)
+    .build()
+    .map(|_| ())
+    .with_lemmy_type(LemmyErrorType::InvalidSlurFilterRegex)
+}
+
 pub fn check_slurs(text: &str, slur_regex: &Option<Regex>) -> Result<(), LemmyError> {
   if let Err(slurs) = slur_check(text, slur_regex) {
-    Err(LemmyError::from_error_message(
-      anyhow::anyhow!("{}", slurs_vec_to_str(&slurs)),
-      "slurs",
-    ))
+    let slurs = slurs.into_iter().map(ToString::to_string).collect();
+    Err(LemmyErrorType::Slurs(slurs).into())
   } else {
     Ok(())
   }
@@ -60,15 +68,12 @@ pub fn check_slurs_opt(
   }
 }
 
-pub(crate) fn slurs_vec_to_str(slurs: &[&str]) -> String {
-  let start = "No slurs - ";
-  let combined = &slurs.join(", ");
-  [start, combined].concat()
-}
-
 #[cfg(test)]
 mod test {
-  use crate::utils::slurs::{remove_slurs, slur_check, slurs_vec_to_str};
+  use crate::{
+    error::LemmyErrorType,
+    utils::slurs::{check_slur_regex, check_slurs, remove_slurs, slur_check},
+  };
   use regex::RegexBuilder;
 
   #[test]
@@ -91,13 +96,18 @@ mod test {
       "retardeds",
       "tranny",
     ];
-    let has_slurs_err_str = "No slurs - Niggerz, cocksucker, faggot, kike, retardeds, tranny";
 
-    assert_eq!(slur_check(test, &slur_regex), Err(has_slurs_vec));
+    assert_eq!(slur_check(test, &slur_regex), Err(has_slurs_vec.clone()));
     assert_eq!(slur_check(slur_free, &slur_regex), Ok(()));
-    if let Err(slur_vec) = slur_check(test, &slur_regex) {
-      assert_eq!(&slurs_vec_to_str(&slur_vec), has_slurs_err_str);
-    }
+    let error_type = check_slurs(test, &slur_regex).unwrap_err().error_type;
+    let has_slurs = has_slurs_vec.into_iter().map(ToString::to_string).collect();
+    assert_eq!(error_type, LemmyErrorType::Slurs(has_slurs));
+  }
+
+  #[test]
+  fn test_check_slur_regex() {
+    assert!(check_slur_regex(r"(word|other\s?word)s?").is_ok());
+    assert!(check_slur_regex(r"(unclosed|group").is_err());
   }
 
   // These helped with testing
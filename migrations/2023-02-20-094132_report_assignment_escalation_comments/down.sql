@@ -0,0 +1,16 @@
+drop index idx_post_report_post;
+drop index idx_comment_report_comment;
+
+drop table report_comment;
+
+alter table post_report drop column assignee_id;
+alter table post_report drop column escalated;
+alter table post_report drop column resolution_reason;
+
+alter table comment_report drop column assignee_id;
+alter table comment_report drop column escalated;
+alter table comment_report drop column resolution_reason;
+
+alter table private_message_report drop column assignee_id;
+alter table private_message_report drop column escalated;
+alter table private_message_report drop column resolution_reason;
@@ -0,0 +1,32 @@
+-- Allow reports to be assigned to a moderator, escalated to the admins,
+-- and resolved with a reason
+alter table post_report add column assignee_id int references person on update cascade on delete set null;
+alter table post_report add column escalated boolean default false not null;
+alter table post_report add column resolution_reason text;
+
+alter table comment_report add column assignee_id int references person on update cascade on delete set null;
+alter table comment_report add column escalated boolean default false not null;
+alter table comment_report add column resolution_reason text;
+
+alter table private_message_report add column assignee_id int references person on update cascade on delete set null;
+alter table private_message_report add column escalated boolean default false not null;
+alter table private_message_report add column resolution_reason text;
+
+-- Internal discussion between moderators / admins about a single report
+create table report_comment (
+  id serial primary key,
+  creator_id int references person on update cascade on delete cascade not null,
+  post_report_id int references post_report on update cascade on delete cascade,
+  comment_report_id int references comment_report on update cascade on delete cascade,
+  private_message_report_id int references private_message_report on update cascade on delete cascade,
+  content text not null,
+  published timestamp not null default now(),
+  check (num_nonnulls(post_report_id, comment_report_id, private_message_report_id) = 1)
+);
+
+create index idx_report_comment_post_report on report_comment (post_report_id);
+create index idx_report_comment_comment_report on report_comment (comment_report_id);
+create index idx_report_comment_private_message_report on report_comment (private_message_report_id);
+
+create index idx_post_report_post on post_report (post_id);
+create index idx_comment_report_comment on comment_report (comment_id);
@@ -0,0 +1 @@
+alter table community drop column slur_filter_regex;
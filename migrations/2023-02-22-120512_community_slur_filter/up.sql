@@ -0,0 +1,2 @@
+-- Lets community moderators filter words in addition to the site wide slur filter
+alter table community add column slur_filter_regex text;
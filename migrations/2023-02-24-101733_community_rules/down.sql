@@ -0,0 +1,6 @@
+alter table post_report drop column rule_id;
+alter table comment_report drop column rule_id;
+alter table mod_remove_post drop column rule_id;
+alter table mod_remove_comment drop column rule_id;
+
+drop table community_rule;
@@ -0,0 +1,17 @@
+-- Ordered list of rules for each community
+create table community_rule (
+  id serial primary key,
+  community_id int references community on update cascade on delete cascade not null,
+  position int not null,
+  title text not null,
+  body text,
+  published timestamp not null default now(),
+  updated timestamp,
+  unique (community_id, position)
+);
+
+-- Reports and removals can cite the rule which was broken
+alter table post_report add column rule_id int references community_rule on update cascade on delete set null;
+alter table comment_report add column rule_id int references community_rule on update cascade on delete set null;
+alter table mod_remove_post add column rule_id int references community_rule on update cascade on delete set null;
+alter table mod_remove_comment add column rule_id int references community_rule on update cascade on delete set null;
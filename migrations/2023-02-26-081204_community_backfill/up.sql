@@ -0,0 +1,12 @@
+-- Progress of fetching the history of remote communities, one row per community
+create table community_backfill (
+  id serial primary key,
+  community_id int references community on update cascade on delete cascade not null unique,
+  pages_fetched int not null default 0,
+  posts_fetched int not null default 0,
+  comments_fetched int not null default 0,
+  error text,
+  published timestamp not null default now(),
+  updated timestamp,
+  finished timestamp
+);
@@ -0,0 +1,3 @@
+drop table converted_note;
+
+alter table local_site drop column convert_notes_to_posts;
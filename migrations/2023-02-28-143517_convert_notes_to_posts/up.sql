@@ -0,0 +1,11 @@
+-- Whether top level notes from microblogging software, which are addressed to a community, are
+-- turned into posts
+alter table local_site add column convert_notes_to_posts boolean default true not null;
+
+-- Posts which were converted from notes
+create table converted_note (
+  id serial primary key,
+  post_id int references post on update cascade on delete cascade not null unique,
+  published timestamp not null default now(),
+  updated timestamp
+);
@@ -0,0 +1,3 @@
+drop table instance_received_activity;
+
+drop table instance_federation_stats;
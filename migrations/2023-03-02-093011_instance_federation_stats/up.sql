@@ -0,0 +1,25 @@
+-- Health of federation with each remote instance, updated whenever an activity is received from or
+-- delivered to it
+create table instance_federation_stats (
+  id serial primary key,
+  instance_id int references instance on update cascade on delete cascade not null unique,
+  last_inbound_success timestamp,
+  inbound_failure_count bigint not null default 0,
+  last_outbound_success timestamp,
+  last_outbound_failure timestamp,
+  outbound_success_count bigint not null default 0,
+  outbound_failure_count bigint not null default 0,
+  -- Sum of the time taken by all successful deliveries, to calculate the average
+  delivery_time_total_ms bigint not null default 0,
+  published timestamp not null default now(),
+  updated timestamp
+);
+
+-- Number of activities which were received from each instance, by activity type
+create table instance_received_activity (
+  id serial primary key,
+  instance_id int references instance on update cascade on delete cascade not null,
+  kind text not null,
+  count bigint not null default 0,
+  unique (instance_id, kind)
+);
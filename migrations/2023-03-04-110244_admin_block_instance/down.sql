@@ -0,0 +1,3 @@
+drop table instance_block_hidden;
+
+drop table admin_block_instance;
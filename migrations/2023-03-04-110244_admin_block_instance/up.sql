@@ -0,0 +1,24 @@
+-- Modlog entry for blocking or unblocking an instance
+create table admin_block_instance (
+  id serial primary key,
+  admin_person_id int references person on update cascade on delete cascade not null,
+  instance_id int references instance on update cascade on delete cascade not null,
+  blocked boolean not null default true,
+  hide_content boolean not null default false,
+  purge_content boolean not null default false,
+  reason text,
+  when_ timestamp not null default now()
+);
+
+-- Content which was removed because its instance was blocked, so that it can be restored when the
+-- instance is unblocked. Exactly one of the content ids is set. Persons are hidden by banning them.
+-- Hidden content is only restored if no moderator removed it after it was hidden.
+create table instance_block_hidden (
+  id serial primary key,
+  instance_id int references instance on update cascade on delete cascade not null,
+  community_id int references community on update cascade on delete cascade,
+  post_id int references post on update cascade on delete cascade,
+  comment_id int references comment on update cascade on delete cascade,
+  person_id int references person on update cascade on delete cascade,
+  published timestamp not null default now()
+);
@@ -0,0 +1 @@
+alter table local_site drop column authorized_fetch;
@@ -0,0 +1,2 @@
+-- Whether ActivityPub fetches need to be signed by an instance which is allowed to federate
+alter table local_site add column authorized_fetch boolean default false not null;
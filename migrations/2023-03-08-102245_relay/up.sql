@@ -0,0 +1,13 @@
+-- ActivityPub relays which the site actor is subscribed to
+create table relay (
+  id serial primary key,
+  actor_id varchar(255) not null unique,
+  inbox_url varchar(255) not null,
+  public_key text not null,
+  -- True until the relay accepts our follow
+  pending boolean not null default true,
+  -- Also send activities of local communities to the relay
+  publish boolean not null default false,
+  published timestamp not null default now(),
+  last_refreshed_at timestamp not null default now()
+);
@@ -0,0 +1,9 @@
+alter table activity
+  drop column private_message,
+  drop column signature_verified;
+
+drop index idx_activity_actor;
+
+drop index idx_activity_type;
+
+drop index idx_activity_published;
@@ -0,0 +1,20 @@
+-- Allow admins to search the stored activities when debugging federation
+create index idx_activity_actor on activity ((data ->> 'actor'));
+
+create index idx_activity_type on activity ((data ->> 'type'));
+
+create index idx_activity_published on activity (published desc);
+
+-- Received activities can only be replayed once their HTTP signature was verified, and private
+-- messages are never shown to admins
+alter table activity
+  add column private_message boolean not null default false,
+  add column signature_verified boolean not null default false;
+
+update activity
+set private_message = true
+where (data ->> 'type' in ('Create', 'Update') and data -> 'object' ->> 'type' = 'ChatMessage')
+  or (data ->> 'type' = 'Delete'
+    and not coalesce(data -> 'to' ? 'https://www.w3.org/ns/activitystreams#Public', false))
+  or (data ->> 'type' = 'Undo' and data -> 'object' ->> 'type' = 'Delete'
+    and not coalesce(data -> 'object' -> 'to' ? 'https://www.w3.org/ns/activitystreams#Public', false));
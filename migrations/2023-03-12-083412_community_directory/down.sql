@@ -0,0 +1,3 @@
+drop table directory_community;
+
+alter table local_site drop column community_directory;
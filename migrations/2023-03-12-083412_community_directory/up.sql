@@ -0,0 +1,22 @@
+alter table local_site add column community_directory boolean default false not null;
+
+-- Communities of other instances which were found by crawling their community listings, so that
+-- users can discover them without knowing their exact names
+create table directory_community (
+  id serial primary key,
+  instance_id int references instance on update cascade on delete cascade not null,
+  actor_id varchar(255) not null unique,
+  name varchar(255) not null,
+  title varchar(255) not null,
+  description text,
+  icon text,
+  nsfw boolean not null default false,
+  subscribers bigint not null default 0,
+  posts bigint not null default 0,
+  -- Languages which the instance allows, empty if it allows all of them
+  language_ids int[] not null default '{}',
+  published timestamp not null default now(),
+  updated timestamp not null default now()
+);
+
+create index idx_directory_community_subscribers on directory_community (subscribers desc);
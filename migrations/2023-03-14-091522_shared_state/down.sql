@@ -0,0 +1,8 @@
+drop trigger notify_local_site_rate_limit on local_site_rate_limit;
+drop function notify_local_site_rate_limit;
+
+drop table websocket_message;
+drop function notify_websocket_message;
+
+drop table rate_limit_bucket;
+drop table captcha_answer;
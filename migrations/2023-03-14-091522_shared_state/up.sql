@@ -0,0 +1,53 @@
+-- Runtime state which is shared between lemmy_server processes, when the shared_state setting is
+-- "postgres"
+create table captcha_answer (
+  id serial primary key,
+  uuid varchar(36) not null unique,
+  answer text not null,
+  expires timestamp not null
+);
+
+create table rate_limit_bucket (
+  rate_limit_type varchar(20) not null,
+  ip varchar(255) not null,
+  allowance double precision not null,
+  last_checked timestamp not null default now(),
+  primary key (rate_limit_type, ip)
+);
+
+-- Websocket messages which need to be delivered by all processes. The payload of notifications is
+-- limited to 8000 bytes, so only the id is sent.
+create table websocket_message (
+  id serial primary key,
+  node_id varchar(36) not null,
+  room jsonb not null,
+  message text not null,
+  published timestamp not null default now()
+);
+
+create function notify_websocket_message()
+    returns trigger language plpgsql
+as $$
+begin
+  perform pg_notify('websocket_message', new.id::text);
+  return null;
+end $$;
+
+create trigger notify_websocket_message
+after insert on websocket_message
+for each row
+execute procedure notify_websocket_message();
+
+-- Lets the other processes reload the rate limits when an admin changes them
+create function notify_local_site_rate_limit()
+    returns trigger language plpgsql
+as $$
+begin
+  perform pg_notify('local_site_rate_limit', '');
+  return null;
+end $$;
+
+create trigger notify_local_site_rate_limit
+after update on local_site_rate_limit
+for each row
+execute procedure notify_local_site_rate_limit();
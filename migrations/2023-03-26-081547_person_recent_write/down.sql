@@ -0,0 +1 @@
+drop table person_recent_write;
@@ -0,0 +1,6 @@
+-- When each person last wrote something, so that every lemmy_server process sends their reads to
+-- the primary database for a while. Only used when the shared_state setting is "postgres".
+create table person_recent_write (
+  person_id int primary key,
+  written timestamp not null default now()
+);
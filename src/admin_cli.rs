@@ -0,0 +1,488 @@
+//! Subcommands for server maintenance, which run against the configured database instead of
+//! starting the server.
+use crate::{
+  code_migrations::run_advanced_migrations,
+  scheduled_tasks::{active_counts, reindex_aggregates_tables},
+  REQWEST_TIMEOUT,
+};
+use activitypub_federation::core::signatures::generate_actor_keypair;
+use clap::{Args, Subcommand};
+use diesel::{Connection, PgConnection};
+use diesel_migrations::MigrationHarness;
+use lemmy_api_common::{
+  lemmy_db_views::structs::{LocalUserView, SiteView},
+  lemmy_db_views_actor::structs::PersonViewSafe,
+  request::{build_user_agent, purge_image_from_pictrs},
+  utils::{
+    check_private_instance_and_federation_enabled,
+    generate_inbox_url,
+    generate_local_apub_endpoint,
+    generate_shared_inbox_url,
+    password_length_check,
+    purge_image_posts_for_community,
+    purge_image_posts_for_person,
+    purge_instance_images,
+    EndpointType,
+  },
+};
+use lemmy_db_schema::{
+  aggregates::structs::AggregateDifferences,
+  newtypes::PersonId,
+  source::{
+    community::{Community, CommunityUpdateForm},
+    instance::Instance,
+    local_site::LocalSite,
+    local_user::{LocalUser, LocalUserInsertForm},
+    moderator::{
+      AdminPurgeCommunity,
+      AdminPurgeCommunityForm,
+      AdminPurgePerson,
+      AdminPurgePersonForm,
+    },
+    person::{Person, PersonInsertForm, PersonUpdateForm},
+  },
+  traits::{ApubActor, Crud},
+  utils::{build_db_pool, get_database_url, revert_migrations, run_migrations, DbPool, MIGRATIONS},
+};
+use lemmy_utils::{
+  error::{LemmyError, LemmyErrorExt, LemmyErrorType},
+  settings::structs::Settings,
+  utils::validation::is_valid_actor_name,
+};
+use reqwest::Client;
+use reqwest_middleware::{ClientBuilder, ClientWithMiddleware};
+use std::io::{stdin, stdout, Write};
+
+/// Number of communities or persons which are deleted at once when purging an instance
+const PURGE_INSTANCE_BATCH_SIZE: i64 = 1000;
+
+#[derive(Subcommand, Debug)]
+pub enum AdminCommand {
+  /// Create a local admin user
+  CreateAdmin {
+    username: String,
+    #[arg(long)]
+    email: Option<String>,
+    #[command(flatten)]
+    password: PasswordArg,
+  },
+  /// Set a new password for a local user
+  ResetPassword {
+    username: String,
+    #[command(flatten)]
+    password: PasswordArg,
+  },
+  /// Run pending database migrations, or revert applied ones
+  #[command(subcommand)]
+  Migration(MigrationCommand),
+  /// Run the data migrations which are written in code, like on startup
+  CodeMigrations,
+  /// Permanently delete an item together with all of its content
+  #[command(subcommand)]
+  Purge(PurgeCommand),
+  /// Recalculate the aggregates from the source tables, and reindex them
+  RebuildAggregates {
+    /// Only list the wrong aggregates, without changing them
+    #[arg(long)]
+    dry_run: bool,
+  },
+  /// Generate a new keypair for a local user or community
+  #[command(subcommand)]
+  RegenerateKeys(ActorCommand),
+  /// Check that the configuration is valid and the database is reachable
+  CheckConfig,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum MigrationCommand {
+  /// Run all pending migrations
+  Run,
+  /// Revert the last applied migrations
+  Revert {
+    #[arg(long, default_value_t = 1)]
+    count: usize,
+  },
+}
+
+#[derive(Subcommand, Debug)]
+pub enum PurgeCommand {
+  /// Purge a local or remote person, by name for local persons or by actor id for remote ones
+  Person {
+    name: String,
+    #[arg(long)]
+    reason: Option<String>,
+  },
+  /// Purge a local or remote community, by name for local communities or by actor id for remote
+  /// ones
+  Community {
+    name: String,
+    #[arg(long)]
+    reason: Option<String>,
+  },
+  /// Purge all communities, persons and content of a remote instance
+  Instance { domain: String },
+}
+
+#[derive(Subcommand, Debug)]
+pub enum ActorCommand {
+  Person { name: String },
+  Community { name: String },
+}
+
+#[derive(Args, Debug)]
+pub struct PasswordArg {
+  /// The new password. If it is not given, it is read from stdin, so that it doesn't end up in the
+  /// shell history.
+  #[arg(long)]
+  password: Option<String>,
+}
+
+impl PasswordArg {
+  fn read(self) -> Result<String, LemmyError> {
+    let password = match self.password {
+      Some(p) => p,
+      None => {
+        print!("Password: ");
+        stdout().flush()?;
+        let mut line = String::new();
+        stdin().read_line(&mut line)?;
+        line.trim_end_matches(&['\r', '\n'][..]).to_string()
+      }
+    };
+    password_length_check(&password)?;
+    Ok(password)
+  }
+}
+
+pub async fn run_admin_command(
+  command: AdminCommand,
+  settings: &Settings,
+) -> Result<(), LemmyError> {
+  let db_url = get_database_url(Some(settings));
+  // These have to work without an up to date database schema
+  match command {
+    AdminCommand::Migration(MigrationCommand::Run) => {
+      run_migrations(&db_url);
+      println!("Migrations complete");
+      return Ok(());
+    }
+    AdminCommand::Migration(MigrationCommand::Revert { count }) => {
+      for version in revert_migrations(&db_url, count)? {
+        println!("Reverted {version}");
+      }
+      return Ok(());
+    }
+    AdminCommand::CheckConfig => return check_config(settings, &db_url).await,
+    _ => {}
+  }
+
+  let pool = build_db_pool(settings).await?;
+  match command {
+    AdminCommand::CreateAdmin {
+      username,
+      email,
+      password,
+    } => {
+      let person = create_admin(&pool, settings, &username, email, password.read()?).await?;
+      println!("Created admin {} with id {}", username, person.0);
+    }
+    AdminCommand::ResetPassword { username, password } => {
+      let local_user_view = LocalUserView::read_from_name(&pool, &username)
+        .await
+        .with_lemmy_type(LemmyErrorType::CouldntFindThatUsernameOrEmail)?;
+      let password = password.read()?;
+      LocalUser::update_password(&pool, local_user_view.local_user.id, &password).await?;
+      println!("Changed password of {username}");
+    }
+    AdminCommand::CodeMigrations => {
+      run_advanced_migrations(&pool, settings).await?;
+      println!("Code migrations complete");
+    }
+    AdminCommand::Purge(purge) => {
+      let client = build_client(settings)?;
+      match purge {
+        PurgeCommand::Person { name, reason } => {
+          purge_person(&pool, settings, &client, &name, reason).await?
+        }
+        PurgeCommand::Community { name, reason } => {
+          purge_community(&pool, settings, &client, &name, reason).await?
+        }
+        PurgeCommand::Instance { domain } => {
+          purge_instance(&pool, settings, &client, &domain).await?
+        }
+      }
+    }
+    AdminCommand::RebuildAggregates { dry_run } => {
+      for d in AggregateDifferences::check(&pool, !dry_run).await? {
+        println!("{}.{}: {} wrong rows", d.table, d.column, d.count);
+        for e in d.examples {
+          println!("  id {}: stored {}, actual {}", e.id, e.stored, e.actual);
+        }
+      }
+      if !dry_run {
+        let conn = &mut PgConnection::establish(&db_url)?;
+        active_counts(conn);
+        reindex_aggregates_tables(conn, true);
+        println!("Aggregates rebuilt");
+      }
+    }
+    AdminCommand::RegenerateKeys(actor) => regenerate_keys(&pool, actor).await?,
+    AdminCommand::Migration(_) | AdminCommand::CheckConfig => unreachable!(),
+  }
+  Ok(())
+}
+
+async fn create_admin(
+  pool: &DbPool,
+  settings: &Settings,
+  username: &str,
+  email: Option<String>,
+  password: String,
+) -> Result<PersonId, LemmyError> {
+  let site_view = SiteView::read_local(pool).await?;
+  if !is_valid_actor_name(
+    username,
+    site_view.local_site.actor_name_max_length as usize,
+  ) {
+    return Err(LemmyErrorType::InvalidUsername.into());
+  }
+
+  let keypair = generate_actor_keypair()?;
+  let actor_id = generate_local_apub_endpoint(
+    EndpointType::Person,
+    username,
+    &settings.get_protocol_and_hostname(),
+  )?;
+  let person_form = PersonInsertForm::builder()
+    .name(username.to_string())
+    .admin(Some(true))
+    .instance_id(site_view.site.instance_id)
+    .actor_id(Some(actor_id.clone()))
+    .private_key(Some(keypair.private_key))
+    .public_key(keypair.public_key)
+    .inbox_url(Some(generate_inbox_url(&actor_id)?))
+    .shared_inbox_url(Some(generate_shared_inbox_url(&actor_id)?))
+    .build();
+  let person = Person::create(pool, &person_form)
+    .await
+    .with_lemmy_type(LemmyErrorType::UserAlreadyExists)?;
+
+  let local_user_form = LocalUserInsertForm::builder()
+    .person_id(person.id)
+    .password_encrypted(password)
+    .email(email.map(|e| e.to_lowercase()))
+    // The admin is created by the operator, so there is nothing to verify
+    .email_verified(Some(true))
+    .accepted_application(Some(true))
+    .build();
+  if let Err(e) = LocalUser::create(pool, &local_user_form).await {
+    Person::delete(pool, person.id).await?;
+    return Err(e).with_lemmy_type(LemmyErrorType::EmailAlreadyExists);
+  }
+  Ok(person.id)
+}
+
+/// Reads a local actor by name, or a remote one by actor id
+async fn read_actor<T: ApubActor>(pool: &DbPool, name: &str) -> Result<T, diesel::result::Error> {
+  match url::Url::parse(name) {
+    Ok(actor_id) => T::read_from_apub_id(pool, &actor_id.into())
+      .await?
+      .ok_or(diesel::result::Error::NotFound),
+    Err(_) => T::read_from_name(pool, name, true).await,
+  }
+}
+
+/// The modlog needs an admin, the purge is listed under the top admin.
+async fn top_admin(pool: &DbPool) -> Result<Option<PersonId>, LemmyError> {
+  Ok(
+    PersonViewSafe::admins(pool)
+      .await?
+      .first()
+      .map(|a| a.person.id),
+  )
+}
+
+async fn purge_person(
+  pool: &DbPool,
+  settings: &Settings,
+  client: &ClientWithMiddleware,
+  name: &str,
+  reason: Option<String>,
+) -> Result<(), LemmyError> {
+  let person: Person = read_actor(pool, name)
+    .await
+    .with_lemmy_type(LemmyErrorType::CouldntFindThatUsernameOrEmail)?;
+  for image in [person.avatar, person.banner].into_iter().flatten() {
+    purge_image_from_pictrs(client, settings, &image).await.ok();
+  }
+  purge_image_posts_for_person(person.id, pool, settings, client).await?;
+  Person::delete(pool, person.id).await?;
+
+  if let Some(admin_person_id) = top_admin(pool).await? {
+    let form = AdminPurgePersonForm {
+      admin_person_id,
+      reason,
+    };
+    AdminPurgePerson::create(pool, &form).await?;
+  }
+  println!("Purged person {}", person.actor_id);
+  Ok(())
+}
+
+async fn purge_community(
+  pool: &DbPool,
+  settings: &Settings,
+  client: &ClientWithMiddleware,
+  name: &str,
+  reason: Option<String>,
+) -> Result<(), LemmyError> {
+  let community: Community = read_actor(pool, name)
+    .await
+    .with_lemmy_type(LemmyErrorType::CouldntFindCommunity)?;
+  for image in [community.icon, community.banner].into_iter().flatten() {
+    purge_image_from_pictrs(client, settings, &image).await.ok();
+  }
+  purge_image_posts_for_community(community.id, pool, settings, client).await?;
+  Community::delete(pool, community.id).await?;
+
+  if let Some(admin_person_id) = top_admin(pool).await? {
+    let form = AdminPurgeCommunityForm {
+      admin_person_id,
+      reason,
+    };
+    AdminPurgeCommunity::create(pool, &form).await?;
+  }
+  println!("Purged community {}", community.actor_id);
+  Ok(())
+}
+
+async fn purge_instance(
+  pool: &DbPool,
+  settings: &Settings,
+  client: &ClientWithMiddleware,
+  domain: &str,
+) -> Result<(), LemmyError> {
+  if settings.get_hostname_without_port()? == domain {
+    return Err(LemmyErrorType::CantPurgeLocalInstance.into());
+  }
+  let instance = Instance::read_from_domain(pool, domain)
+    .await
+    .with_lemmy_type(LemmyErrorType::CouldntFindObject)?;
+  purge_instance_images(instance.id, pool, settings, client).await?;
+  let mut total = 0;
+  loop {
+    let batch = Instance::purge_content_batch(pool, instance.id, PURGE_INSTANCE_BATCH_SIZE).await?;
+    if batch == 0 {
+      break;
+    }
+    total += batch;
+    println!("Purged {total} comments, posts, communities and persons");
+  }
+  Instance::delete(pool, instance.id).await?;
+  println!("Purged instance {domain}");
+  Ok(())
+}
+
+/// Remote instances keep using the cached public key until they refetch the actor, so activities
+/// signed with the new key may be rejected for a while.
+async fn regenerate_keys(pool: &DbPool, actor: ActorCommand) -> Result<(), LemmyError> {
+  let keypair = generate_actor_keypair()?;
+  match actor {
+    ActorCommand::Person { name } => {
+      let person = Person::read_from_name(pool, &name, true)
+        .await
+        .with_lemmy_type(LemmyErrorType::CouldntFindThatUsernameOrEmail)?;
+      let form = PersonUpdateForm::builder()
+        .private_key(Some(Some(keypair.private_key)))
+        .public_key(Some(keypair.public_key))
+        .build();
+      Person::update(pool, person.id, &form).await?;
+    }
+    ActorCommand::Community { name } => {
+      let community = Community::read_from_name(pool, &name, true)
+        .await
+        .with_lemmy_type(LemmyErrorType::CouldntFindCommunity)?;
+      let form = CommunityUpdateForm::builder()
+        .private_key(Some(Some(keypair.private_key)))
+        .public_key(Some(keypair.public_key))
+        .build();
+      Community::update(pool, community.id, &form).await?;
+    }
+  }
+  println!("Generated new keypair");
+  Ok(())
+}
+
+async fn check_config(settings: &Settings, db_url: &str) -> Result<(), LemmyError> {
+  let mut problems = vec![];
+  if let Err(e) = settings.get_hostname_without_port() {
+    problems.push(format!("Invalid hostname: {e}"));
+  }
+
+  match PgConnection::establish(db_url) {
+    Ok(mut conn) => {
+      if conn.has_pending_migration(MIGRATIONS).unwrap_or(true) {
+        problems.push("Database has pending migrations".to_string());
+      } else {
+        let pool = build_db_pool(settings).await?;
+        match LocalSite::read(&pool).await {
+          Ok(local_site) => {
+            if let Err(e) = check_private_instance_and_federation_enabled(&local_site) {
+              problems.push(e.to_string());
+            }
+            if settings.setup.is_some() {
+              println!("The setup section is ignored, because the site is already set up");
+            }
+          }
+          Err(_) if settings.setup.is_none() => problems
+            .push("The site is not set up yet, and there is no setup section in the config".into()),
+          Err(_) => {}
+        }
+      }
+    }
+    Err(e) => problems.push(format!("Couldn't connect to the database: {e}")),
+  }
+
+  if problems.is_empty() {
+    println!("Configuration is valid");
+    Ok(())
+  } else {
+    for problem in &problems {
+      println!("{problem}");
+    }
+    Err(LemmyErrorType::InvalidConfig.into())
+  }
+}
+
+fn build_client(settings: &Settings) -> Result<ClientWithMiddleware, LemmyError> {
+  let client = Client::builder()
+    .user_agent(build_user_agent(settings))
+    .timeout(REQWEST_TIMEOUT)
+    .build()?;
+  Ok(ClientBuilder::new(client).build())
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::{
+    admin_cli::{AdminCommand, MigrationCommand},
+    CmdArgs,
+  };
+  use clap::{CommandFactory, Parser};
+
+  #[test]
+  fn test_parse_admin_command() {
+    CmdArgs::command().debug_assert();
+
+    let args = CmdArgs::parse_from(["lemmy_server", "migration", "revert", "--count", "3"]);
+    assert!(matches!(
+      args.command,
+      Some(AdminCommand::Migration(MigrationCommand::Revert {
+        count: 3
+      }))
+    ));
+    let args = CmdArgs::parse_from(["lemmy_server", "--print-config-docs"]);
+    assert!(args.print_config_docs);
+    assert!(args.command.is_none());
+  }
+}
@@ -1,14 +1,18 @@
-use crate::api_routes_websocket::websocket;
-use actix_web::{guard, web, Error, HttpResponse, Result};
+use crate::{api_routes_websocket::websocket, openapi::openapi_document};
+use actix_web::{guard, http::Method, web, Error, HttpResponse, Result, Route};
+use doku::Document;
 use lemmy_api::Perform;
 use lemmy_api_common::{
+  auth::RequestAuth,
   comment::{
+    AssignCommentReport,
     CreateComment,
     CreateCommentLike,
     CreateCommentReport,
     DeleteComment,
     DistinguishComment,
     EditComment,
+    EscalateCommentReport,
     GetComment,
     GetComments,
     ListCommentReports,
@@ -27,6 +31,8 @@ use lemmy_api_common::{
     GetCommunity,
     HideCommunity,
     ListCommunities,
+    ListCommunityBackfills,
+    ListDirectoryCommunities,
     RemoveCommunity,
     TransferCommunity,
   },
@@ -36,6 +42,7 @@ use lemmy_api_common::{
     BanPerson,
     BlockPerson,
     ChangePassword,
+    CreateReportComment,
     DeleteAccount,
     GetBannedPersons,
     GetCaptcha,
@@ -44,6 +51,8 @@ use lemmy_api_common::{
     GetReplies,
     GetReportCount,
     GetUnreadCount,
+    ListMyCommunityBans,
+    ListReportComments,
     Login,
     MarkAllAsRead,
     MarkCommentReplyAsRead,
@@ -55,11 +64,13 @@ use lemmy_api_common::{
     VerifyEmail,
   },
   post::{
+    AssignPostReport,
     CreatePost,
     CreatePostLike,
     CreatePostReport,
     DeletePost,
     EditPost,
+    EscalatePostReport,
     FeaturePost,
     GetPost,
     GetPosts,
@@ -72,6 +83,7 @@ use lemmy_api_common::{
     SavePost,
   },
   private_message::{
+    AssignPrivateMessageReport,
     CreatePrivateMessage,
     CreatePrivateMessageReport,
     DeletePrivateMessage,
@@ -82,280 +94,398 @@ use lemmy_api_common::{
     ResolvePrivateMessageReport,
   },
   site::{
+    AddRelay,
     ApproveRegistrationApplication,
+    BlockInstance,
     CreateSite,
     EditSite,
+    GetFederationStats,
     GetModlog,
     GetSite,
     GetUnreadRegistrationApplicationCount,
     LeaveAdmin,
+    ListActivities,
     ListRegistrationApplications,
+    ListRelays,
     PurgeComment,
     PurgeCommunity,
     PurgePerson,
     PurgePost,
+    RemoveRelay,
+    ReplayActivity,
+    ResendActivity,
     ResolveObject,
     Search,
+    VerifyAggregates,
   },
+  utils::record_write,
   websocket::structs::{CommunityJoin, ModJoin, PostJoin, UserJoin},
 };
 use lemmy_api_crud::PerformCrud;
 use lemmy_apub::{api::PerformApub, SendActivity};
-use lemmy_utils::rate_limit::RateLimitCell;
-use serde::Deserialize;
+use lemmy_utils::rate_limit::{RateLimitCell, RateLimitedGuard};
+use once_cell::sync::Lazy;
+use serde::{de::DeserializeOwned, Deserialize};
+use std::collections::HashSet;
+
+/// A single endpoint of the HTTP API. The same table is used to register the routes with actix,
+/// and to generate the OpenAPI document.
+pub struct Endpoint {
+  pub method: Method,
+  /// Path relative to `/api/v3`
+  pub path: &'static str,
+  pub request_name: &'static str,
+  pub request: fn() -> doku::Type,
+  pub response_name: &'static str,
+  pub response: fn() -> doku::Type,
+  rate_limit: fn(&RateLimitCell) -> RateLimitedGuard,
+  route: fn() -> Route,
+}
 
 pub fn config(cfg: &mut web::ServiceConfig, rate_limit: &RateLimitCell) {
-  cfg.service(
-    web::scope("/api/v3")
-      // Websocket
-      .service(web::resource("/ws").to(websocket))
-      // Site
-      .service(
-        web::scope("/site")
-          .wrap(rate_limit.message())
-          .route("", web::get().to(route_get_crud::<GetSite>))
-          // Admin Actions
-          .route("", web::post().to(route_post_crud::<CreateSite>))
-          .route("", web::put().to(route_post_crud::<EditSite>)),
-      )
-      .service(
-        web::resource("/modlog")
-          .wrap(rate_limit.message())
-          .route(web::get().to(route_get::<GetModlog>)),
-      )
-      .service(
-        web::resource("/search")
-          .wrap(rate_limit.search())
-          .route(web::get().to(route_get_apub::<Search>)),
-      )
-      .service(
-        web::resource("/resolve_object")
-          .wrap(rate_limit.message())
-          .route(web::get().to(route_get_apub::<ResolveObject>)),
-      )
-      // Community
-      .service(
-        web::resource("/community")
-          .guard(guard::Post())
-          .wrap(rate_limit.register())
-          .route(web::post().to(route_post_crud::<CreateCommunity>)),
-      )
-      .service(
-        web::scope("/community")
-          .wrap(rate_limit.message())
-          .route("", web::get().to(route_get_apub::<GetCommunity>))
-          .route("", web::put().to(route_post_crud::<EditCommunity>))
-          .route("/hide", web::put().to(route_post::<HideCommunity>))
-          .route("/list", web::get().to(route_get_crud::<ListCommunities>))
-          .route("/follow", web::post().to(route_post::<FollowCommunity>))
-          .route("/block", web::post().to(route_post::<BlockCommunity>))
-          .route(
-            "/delete",
-            web::post().to(route_post_crud::<DeleteCommunity>),
-          )
-          // Mod Actions
-          .route(
-            "/remove",
-            web::post().to(route_post_crud::<RemoveCommunity>),
-          )
-          .route("/transfer", web::post().to(route_post::<TransferCommunity>))
-          .route("/ban_user", web::post().to(route_post::<BanFromCommunity>))
-          .route("/mod", web::post().to(route_post::<AddModToCommunity>))
-          .route("/join", web::post().to(route_post::<CommunityJoin>))
-          .route("/mod/join", web::post().to(route_post::<ModJoin>)),
-      )
-      // Post
-      .service(
-        // Handle POST to /post separately to add the post() rate limitter
-        web::resource("/post")
-          .guard(guard::Post())
-          .wrap(rate_limit.post())
-          .route(web::post().to(route_post_crud::<CreatePost>)),
-      )
-      .service(
-        web::scope("/post")
-          .wrap(rate_limit.message())
-          .route("", web::get().to(route_get_crud::<GetPost>))
-          .route("", web::put().to(route_post_crud::<EditPost>))
-          .route("/delete", web::post().to(route_post_crud::<DeletePost>))
-          .route("/remove", web::post().to(route_post_crud::<RemovePost>))
-          .route(
-            "/mark_as_read",
-            web::post().to(route_post::<MarkPostAsRead>),
-          )
-          .route("/lock", web::post().to(route_post::<LockPost>))
-          .route("/feature", web::post().to(route_post::<FeaturePost>))
-          .route("/list", web::get().to(route_get_apub::<GetPosts>))
-          .route("/like", web::post().to(route_post::<CreatePostLike>))
-          .route("/save", web::put().to(route_post::<SavePost>))
-          .route("/join", web::post().to(route_post::<PostJoin>))
-          .route("/report", web::post().to(route_post::<CreatePostReport>))
-          .route(
-            "/report/resolve",
-            web::put().to(route_post::<ResolvePostReport>),
-          )
-          .route("/report/list", web::get().to(route_get::<ListPostReports>))
-          .route(
-            "/site_metadata",
-            web::get().to(route_get::<GetSiteMetadata>),
-          ),
-      )
-      // Comment
-      .service(
-        // Handle POST to /comment separately to add the comment() rate limitter
-        web::resource("/comment")
-          .guard(guard::Post())
-          .wrap(rate_limit.comment())
-          .route(web::post().to(route_post_crud::<CreateComment>)),
-      )
-      .service(
-        web::scope("/comment")
-          .wrap(rate_limit.message())
-          .route("", web::get().to(route_get_crud::<GetComment>))
-          .route("", web::put().to(route_post_crud::<EditComment>))
-          .route("/delete", web::post().to(route_post_crud::<DeleteComment>))
-          .route("/remove", web::post().to(route_post_crud::<RemoveComment>))
-          .route(
-            "/mark_as_read",
-            web::post().to(route_post::<MarkCommentReplyAsRead>),
-          )
-          .route(
-            "/distinguish",
-            web::post().to(route_post::<DistinguishComment>),
-          )
-          .route("/like", web::post().to(route_post::<CreateCommentLike>))
-          .route("/save", web::put().to(route_post::<SaveComment>))
-          .route("/list", web::get().to(route_get_apub::<GetComments>))
-          .route("/report", web::post().to(route_post::<CreateCommentReport>))
-          .route(
-            "/report/resolve",
-            web::put().to(route_post::<ResolveCommentReport>),
-          )
-          .route(
-            "/report/list",
-            web::get().to(route_get::<ListCommentReports>),
-          ),
-      )
-      // Private Message
-      .service(
-        web::scope("/private_message")
-          .wrap(rate_limit.message())
-          .route("/list", web::get().to(route_get_crud::<GetPrivateMessages>))
-          .route("", web::post().to(route_post_crud::<CreatePrivateMessage>))
-          .route("", web::put().to(route_post_crud::<EditPrivateMessage>))
-          .route(
-            "/delete",
-            web::post().to(route_post_crud::<DeletePrivateMessage>),
-          )
-          .route(
-            "/mark_as_read",
-            web::post().to(route_post::<MarkPrivateMessageAsRead>),
-          )
-          .route(
-            "/report",
-            web::post().to(route_post::<CreatePrivateMessageReport>),
-          )
-          .route(
-            "/report/resolve",
-            web::put().to(route_post::<ResolvePrivateMessageReport>),
-          )
-          .route(
-            "/report/list",
-            web::get().to(route_get::<ListPrivateMessageReports>),
-          ),
-      )
-      // User
-      .service(
-        // Account action, I don't like that it's in /user maybe /accounts
-        // Handle /user/register separately to add the register() rate limitter
-        web::resource("/user/register")
-          .guard(guard::Post())
-          .wrap(rate_limit.register())
-          .route(web::post().to(route_post_crud::<Register>)),
-      )
-      .service(
-        // Handle captcha separately
-        web::resource("/user/get_captcha")
-          .wrap(rate_limit.post())
-          .route(web::get().to(route_get::<GetCaptcha>)),
-      )
-      // User actions
-      .service(
-        web::scope("/user")
-          .wrap(rate_limit.message())
-          .route("", web::get().to(route_get_apub::<GetPersonDetails>))
-          .route("/mention", web::get().to(route_get::<GetPersonMentions>))
-          .route(
-            "/mention/mark_as_read",
-            web::post().to(route_post::<MarkPersonMentionAsRead>),
-          )
-          .route("/replies", web::get().to(route_get::<GetReplies>))
-          .route("/join", web::post().to(route_post::<UserJoin>))
-          // Admin action. I don't like that it's in /user
-          .route("/ban", web::post().to(route_post::<BanPerson>))
-          .route("/banned", web::get().to(route_get::<GetBannedPersons>))
-          .route("/block", web::post().to(route_post::<BlockPerson>))
-          // Account actions. I don't like that they're in /user maybe /accounts
-          .route("/login", web::post().to(route_post::<Login>))
-          .route(
-            "/delete_account",
-            web::post().to(route_post_crud::<DeleteAccount>),
-          )
-          .route(
-            "/password_reset",
-            web::post().to(route_post::<PasswordReset>),
-          )
-          .route(
-            "/password_change",
-            web::post().to(route_post::<PasswordChangeAfterReset>),
-          )
-          // mark_all_as_read feels off being in this section as well
-          .route(
-            "/mark_all_as_read",
-            web::post().to(route_post::<MarkAllAsRead>),
-          )
-          .route(
-            "/save_user_settings",
-            web::put().to(route_post::<SaveUserSettings>),
-          )
-          .route(
-            "/change_password",
-            web::put().to(route_post::<ChangePassword>),
-          )
-          .route("/report_count", web::get().to(route_get::<GetReportCount>))
-          .route("/unread_count", web::get().to(route_get::<GetUnreadCount>))
-          .route("/verify_email", web::post().to(route_post::<VerifyEmail>))
-          .route("/leave_admin", web::post().to(route_post::<LeaveAdmin>)),
-      )
-      // Admin Actions
-      .service(
-        web::scope("/admin")
-          .wrap(rate_limit.message())
-          .route("/add", web::post().to(route_post::<AddAdmin>))
-          .route(
-            "/registration_application/count",
-            web::get().to(route_get::<GetUnreadRegistrationApplicationCount>),
-          )
-          .route(
-            "/registration_application/list",
-            web::get().to(route_get::<ListRegistrationApplications>),
-          )
-          .route(
-            "/registration_application/approve",
-            web::put().to(route_post::<ApproveRegistrationApplication>),
-          ),
-      )
-      .service(
-        web::scope("/admin/purge")
-          .wrap(rate_limit.message())
-          .route("/person", web::post().to(route_post::<PurgePerson>))
-          .route("/community", web::post().to(route_post::<PurgeCommunity>))
-          .route("/post", web::post().to(route_post::<PurgePost>))
-          .route("/comment", web::post().to(route_post::<PurgeComment>)),
-      ),
-  );
+  let mut scope = web::scope("/api/v3")
+    // Websocket
+    .service(web::resource("/ws").to(websocket))
+    .service(web::resource("/openapi.json").route(web::get().to(openapi)));
+  // Each endpoint gets its own resource, so that different methods on the same path can have
+  // different rate limits
+  for endpoint in endpoints() {
+    scope = scope.service(
+      web::resource(endpoint.path)
+        .guard(guard::Method(endpoint.method.clone()))
+        .wrap((endpoint.rate_limit)(rate_limit))
+        .route((endpoint.route)()),
+    );
+  }
+  cfg.service(scope);
+}
+
+pub fn endpoints() -> Vec<Endpoint> {
+  vec![
+    // Site
+    get_crud::<GetSite>("/site", RateLimitCell::message),
+    // Admin Actions
+    post_crud::<CreateSite>("/site", RateLimitCell::message),
+    put_crud::<EditSite>("/site", RateLimitCell::message),
+    get::<GetModlog>("/modlog", RateLimitCell::message),
+    get_apub::<Search>("/search", RateLimitCell::search),
+    get_apub::<ResolveObject>("/resolve_object", RateLimitCell::message),
+    // Community
+    post_crud::<CreateCommunity>("/community", RateLimitCell::register),
+    get_apub::<GetCommunity>("/community", RateLimitCell::message),
+    put_crud::<EditCommunity>("/community", RateLimitCell::message),
+    put::<HideCommunity>("/community/hide", RateLimitCell::message),
+    get_crud::<ListCommunities>("/community/list", RateLimitCell::message),
+    get::<ListDirectoryCommunities>("/community/directory", RateLimitCell::message),
+    post::<FollowCommunity>("/community/follow", RateLimitCell::message),
+    post::<BlockCommunity>("/community/block", RateLimitCell::message),
+    post_crud::<DeleteCommunity>("/community/delete", RateLimitCell::message),
+    // Mod Actions
+    post_crud::<RemoveCommunity>("/community/remove", RateLimitCell::message),
+    post::<TransferCommunity>("/community/transfer", RateLimitCell::message),
+    post::<BanFromCommunity>("/community/ban_user", RateLimitCell::message),
+    post::<AddModToCommunity>("/community/mod", RateLimitCell::message),
+    post::<CommunityJoin>("/community/join", RateLimitCell::message),
+    post::<ModJoin>("/community/mod/join", RateLimitCell::message),
+    // Post
+    post_crud::<CreatePost>("/post", RateLimitCell::post),
+    get_crud::<GetPost>("/post", RateLimitCell::message),
+    put_crud::<EditPost>("/post", RateLimitCell::message),
+    post_crud::<DeletePost>("/post/delete", RateLimitCell::message),
+    post_crud::<RemovePost>("/post/remove", RateLimitCell::message),
+    post::<MarkPostAsRead>("/post/mark_as_read", RateLimitCell::message),
+    post::<LockPost>("/post/lock", RateLimitCell::message),
+    post::<FeaturePost>("/post/feature", RateLimitCell::message),
+    get_apub::<GetPosts>("/post/list", RateLimitCell::message),
+    post::<CreatePostLike>("/post/like", RateLimitCell::message),
+    put::<SavePost>("/post/save", RateLimitCell::message),
+    post::<PostJoin>("/post/join", RateLimitCell::message),
+    post::<CreatePostReport>("/post/report", RateLimitCell::message),
+    put::<ResolvePostReport>("/post/report/resolve", RateLimitCell::message),
+    get::<ListPostReports>("/post/report/list", RateLimitCell::message),
+    put::<AssignPostReport>("/post/report/assign", RateLimitCell::message),
+    put::<EscalatePostReport>("/post/report/escalate", RateLimitCell::message),
+    get::<GetSiteMetadata>("/post/site_metadata", RateLimitCell::message),
+    // Comment
+    post_crud::<CreateComment>("/comment", RateLimitCell::comment),
+    get_crud::<GetComment>("/comment", RateLimitCell::message),
+    put_crud::<EditComment>("/comment", RateLimitCell::message),
+    post_crud::<DeleteComment>("/comment/delete", RateLimitCell::message),
+    post_crud::<RemoveComment>("/comment/remove", RateLimitCell::message),
+    post::<MarkCommentReplyAsRead>("/comment/mark_as_read", RateLimitCell::message),
+    post::<DistinguishComment>("/comment/distinguish", RateLimitCell::message),
+    post::<CreateCommentLike>("/comment/like", RateLimitCell::message),
+    put::<SaveComment>("/comment/save", RateLimitCell::message),
+    get_apub::<GetComments>("/comment/list", RateLimitCell::message),
+    post::<CreateCommentReport>("/comment/report", RateLimitCell::message),
+    put::<ResolveCommentReport>("/comment/report/resolve", RateLimitCell::message),
+    get::<ListCommentReports>("/comment/report/list", RateLimitCell::message),
+    put::<AssignCommentReport>("/comment/report/assign", RateLimitCell::message),
+    put::<EscalateCommentReport>("/comment/report/escalate", RateLimitCell::message),
+    // Private Message
+    get_crud::<GetPrivateMessages>("/private_message/list", RateLimitCell::message),
+    post_crud::<CreatePrivateMessage>("/private_message", RateLimitCell::message),
+    put_crud::<EditPrivateMessage>("/private_message", RateLimitCell::message),
+    post_crud::<DeletePrivateMessage>("/private_message/delete", RateLimitCell::message),
+    post::<MarkPrivateMessageAsRead>("/private_message/mark_as_read", RateLimitCell::message),
+    post::<CreatePrivateMessageReport>("/private_message/report", RateLimitCell::message),
+    put::<ResolvePrivateMessageReport>("/private_message/report/resolve", RateLimitCell::message),
+    get::<ListPrivateMessageReports>("/private_message/report/list", RateLimitCell::message),
+    put::<AssignPrivateMessageReport>("/private_message/report/assign", RateLimitCell::message),
+    // User
+    post_crud::<Register>("/user/register", RateLimitCell::register),
+    get::<GetCaptcha>("/user/get_captcha", RateLimitCell::post),
+    // User actions
+    get_apub::<GetPersonDetails>("/user", RateLimitCell::message),
+    get::<GetPersonMentions>("/user/mention", RateLimitCell::message),
+    post::<MarkPersonMentionAsRead>("/user/mention/mark_as_read", RateLimitCell::message),
+    get::<GetReplies>("/user/replies", RateLimitCell::message),
+    post::<UserJoin>("/user/join", RateLimitCell::message),
+    // Admin action. I don't like that it's in /user
+    post::<BanPerson>("/user/ban", RateLimitCell::message),
+    get::<GetBannedPersons>("/user/banned", RateLimitCell::message),
+    get::<ListMyCommunityBans>("/user/community_bans", RateLimitCell::message),
+    post::<BlockPerson>("/user/block", RateLimitCell::message),
+    // Account actions. I don't like that they're in /user maybe /accounts
+    post::<Login>("/user/login", RateLimitCell::message),
+    post_crud::<DeleteAccount>("/user/delete_account", RateLimitCell::message),
+    post::<PasswordReset>("/user/password_reset", RateLimitCell::message),
+    post::<PasswordChangeAfterReset>("/user/password_change", RateLimitCell::message),
+    // mark_all_as_read feels off being in this section as well
+    post::<MarkAllAsRead>("/user/mark_all_as_read", RateLimitCell::message),
+    put::<SaveUserSettings>("/user/save_user_settings", RateLimitCell::message),
+    put::<ChangePassword>("/user/change_password", RateLimitCell::message),
+    get::<GetReportCount>("/user/report_count", RateLimitCell::message),
+    post::<CreateReportComment>("/user/report_comment", RateLimitCell::message),
+    get::<ListReportComments>("/user/report_comment/list", RateLimitCell::message),
+    get::<GetUnreadCount>("/user/unread_count", RateLimitCell::message),
+    post::<VerifyEmail>("/user/verify_email", RateLimitCell::message),
+    post::<LeaveAdmin>("/user/leave_admin", RateLimitCell::message),
+    // Admin Actions
+    post::<AddAdmin>("/admin/add", RateLimitCell::message),
+    get::<GetUnreadRegistrationApplicationCount>(
+      "/admin/registration_application/count",
+      RateLimitCell::message,
+    ),
+    get::<ListRegistrationApplications>(
+      "/admin/registration_application/list",
+      RateLimitCell::message,
+    ),
+    put::<ApproveRegistrationApplication>(
+      "/admin/registration_application/approve",
+      RateLimitCell::message,
+    ),
+    get::<ListCommunityBackfills>("/admin/community_backfill/list", RateLimitCell::message),
+    post::<BlockInstance>("/admin/block_instance", RateLimitCell::message),
+    get::<GetFederationStats>("/admin/federation_stats", RateLimitCell::message),
+    get::<ListRelays>("/admin/relay/list", RateLimitCell::message),
+    post_apub::<AddRelay>("/admin/relay/add", RateLimitCell::message),
+    post_apub::<RemoveRelay>("/admin/relay/remove", RateLimitCell::message),
+    get::<ListActivities>("/admin/activity/list", RateLimitCell::message),
+    post_apub::<ReplayActivity>("/admin/activity/replay", RateLimitCell::message),
+    post_apub::<ResendActivity>("/admin/activity/resend", RateLimitCell::message),
+    post::<VerifyAggregates>("/admin/aggregates/verify", RateLimitCell::message),
+    post::<PurgePerson>("/admin/purge/person", RateLimitCell::message),
+    post::<PurgeCommunity>("/admin/purge/community", RateLimitCell::message),
+    post::<PurgePost>("/admin/purge/post", RateLimitCell::message),
+    post::<PurgeComment>("/admin/purge/comment", RateLimitCell::message),
+  ]
+}
+
+static OPENAPI_DOCUMENT: Lazy<String> = Lazy::new(|| openapi_document(&endpoints()).to_string());
+
+async fn openapi() -> HttpResponse {
+  HttpResponse::Ok()
+    .content_type("application/json")
+    .body(OPENAPI_DOCUMENT.as_str())
+}
+
+fn endpoint<Data: Document, Response: Document>(
+  method: Method,
+  path: &'static str,
+  rate_limit: fn(&RateLimitCell) -> RateLimitedGuard,
+  route: fn() -> Route,
+) -> Endpoint {
+  Endpoint {
+    method,
+    path,
+    request_name: short_type_name::<Data>(),
+    request: Data::ty,
+    response_name: short_type_name::<Response>(),
+    response: Response::ty,
+    rate_limit,
+    route,
+  }
+}
+
+fn short_type_name<T>() -> &'static str {
+  let name = std::any::type_name::<T>();
+  name.rsplit("::").next().unwrap_or(name)
+}
+
+fn get<Data>(path: &'static str, rate_limit: fn(&RateLimitCell) -> RateLimitedGuard) -> Endpoint
+where
+  Data: Perform
+    + SendActivity<Response = <Data as Perform>::Response>
+    + Clone
+    + DeserializeOwned
+    + Document
+    + Send
+    + 'static,
+  <Data as Perform>::Response: Document,
+{
+  endpoint::<Data, <Data as Perform>::Response>(Method::GET, path, rate_limit, || {
+    web::get().to(route_get::<Data>)
+  })
+}
+
+fn post<Data>(path: &'static str, rate_limit: fn(&RateLimitCell) -> RateLimitedGuard) -> Endpoint
+where
+  Data: Perform
+    + SendActivity<Response = <Data as Perform>::Response>
+    + Clone
+    + DeserializeOwned
+    + RequestAuth
+    + Document
+    + Send
+    + 'static,
+  <Data as Perform>::Response: Document,
+{
+  endpoint::<Data, <Data as Perform>::Response>(Method::POST, path, rate_limit, || {
+    web::post().to(route_post::<Data>)
+  })
+}
+
+fn put<Data>(path: &'static str, rate_limit: fn(&RateLimitCell) -> RateLimitedGuard) -> Endpoint
+where
+  Data: Perform
+    + SendActivity<Response = <Data as Perform>::Response>
+    + Clone
+    + DeserializeOwned
+    + RequestAuth
+    + Document
+    + Send
+    + 'static,
+  <Data as Perform>::Response: Document,
+{
+  endpoint::<Data, <Data as Perform>::Response>(Method::PUT, path, rate_limit, || {
+    web::put().to(route_post::<Data>)
+  })
+}
+
+fn get_crud<Data>(
+  path: &'static str,
+  rate_limit: fn(&RateLimitCell) -> RateLimitedGuard,
+) -> Endpoint
+where
+  Data: PerformCrud
+    + SendActivity<Response = <Data as PerformCrud>::Response>
+    + Clone
+    + DeserializeOwned
+    + Document
+    + Send
+    + 'static,
+  <Data as PerformCrud>::Response: Document,
+{
+  endpoint::<Data, <Data as PerformCrud>::Response>(Method::GET, path, rate_limit, || {
+    web::get().to(route_get_crud::<Data>)
+  })
+}
+
+fn post_crud<Data>(
+  path: &'static str,
+  rate_limit: fn(&RateLimitCell) -> RateLimitedGuard,
+) -> Endpoint
+where
+  Data: PerformCrud
+    + SendActivity<Response = <Data as PerformCrud>::Response>
+    + Clone
+    + DeserializeOwned
+    + RequestAuth
+    + Document
+    + Send
+    + 'static,
+  <Data as PerformCrud>::Response: Document,
+{
+  endpoint::<Data, <Data as PerformCrud>::Response>(Method::POST, path, rate_limit, || {
+    web::post().to(route_post_crud::<Data>)
+  })
+}
+
+fn put_crud<Data>(
+  path: &'static str,
+  rate_limit: fn(&RateLimitCell) -> RateLimitedGuard,
+) -> Endpoint
+where
+  Data: PerformCrud
+    + SendActivity<Response = <Data as PerformCrud>::Response>
+    + Clone
+    + DeserializeOwned
+    + RequestAuth
+    + Document
+    + Send
+    + 'static,
+  <Data as PerformCrud>::Response: Document,
+{
+  endpoint::<Data, <Data as PerformCrud>::Response>(Method::PUT, path, rate_limit, || {
+    web::put().to(route_post_crud::<Data>)
+  })
 }
 
+fn get_apub<Data>(
+  path: &'static str,
+  rate_limit: fn(&RateLimitCell) -> RateLimitedGuard,
+) -> Endpoint
+where
+  Data: PerformApub
+    + SendActivity<Response = <Data as PerformApub>::Response>
+    + Clone
+    + DeserializeOwned
+    + Document
+    + Send
+    + 'static,
+  <Data as PerformApub>::Response: Document,
+{
+  endpoint::<Data, <Data as PerformApub>::Response>(Method::GET, path, rate_limit, || {
+    web::get().to(route_get_apub::<Data>)
+  })
+}
+
+fn post_apub<Data>(
+  path: &'static str,
+  rate_limit: fn(&RateLimitCell) -> RateLimitedGuard,
+) -> Endpoint
+where
+  Data: PerformApub
+    + SendActivity<Response = <Data as PerformApub>::Response>
+    + Clone
+    + DeserializeOwned
+    + RequestAuth
+    + Document
+    + Send
+    + 'static,
+  <Data as PerformApub>::Response: Document,
+{
+  endpoint::<Data, <Data as PerformApub>::Response>(Method::POST, path, rate_limit, || {
+    web::post().to(route_post_apub::<Data>)
+  })
+}
+
+/// Requests which change something. The writer is recorded before the request is performed,
+/// as it usually reads the changed rows back right away.
+async fn record_request_write<Data: RequestAuth>(data: &Data, context: &LemmyContext) {
+  record_write(data.auth(), context.pool(), context.secret()).await;
+}
+
+/// Names of the requests which change something, the same names are used for websocket operations
+pub static WRITE_REQUESTS: Lazy<HashSet<&'static str>> = Lazy::new(|| {
+  endpoints()
+    .into_iter()
+    .filter(|e| e.method != Method::GET)
+    .map(|e| e.request_name)
+    .collect()
+});
+
 async fn perform<'a, Data>(
   data: Data,
   context: web::Data<LemmyContext>,
@@ -405,6 +535,25 @@ where
   Ok(HttpResponse::Ok().json(res))
 }
 
+async fn route_post_apub<'a, Data>(
+  data: web::Json<Data>,
+  context: web::Data<LemmyContext>,
+) -> Result<HttpResponse, Error>
+where
+  Data: PerformApub
+    + SendActivity<Response = <Data as PerformApub>::Response>
+    + Clone
+    + RequestAuth
+    + Deserialize<'a>
+    + Send
+    + 'static,
+{
+  record_request_write(&data.0, &context).await;
+  let res = data.perform(&context, None).await?;
+  SendActivity::send_activity(&data.0, &res, &context).await?;
+  Ok(HttpResponse::Ok().json(res))
+}
+
 async fn route_post<'a, Data>(
   data: web::Json<Data>,
   context: web::Data<LemmyContext>,
@@ -413,10 +562,12 @@ where
   Data: Perform
     + SendActivity<Response = <Data as Perform>::Response>
     + Clone
+    + RequestAuth
     + Deserialize<'a>
     + Send
     + 'static,
 {
+  record_request_write(&data.0, &context).await;
   perform::<Data>(data.0, context).await
 }
 
@@ -460,9 +611,11 @@ where
   Data: PerformCrud
     + SendActivity<Response = <Data as PerformCrud>::Response>
     + Clone
+    + RequestAuth
     + Deserialize<'a>
     + Send
     + 'static,
 {
+  record_request_write(&data.0, &context).await;
   perform_crud::<Data>(data.0, context).await
 }
@@ -1,3 +1,4 @@
+use crate::api_routes_http::WRITE_REQUESTS;
 use actix_web::{web, Error, HttpRequest, HttpResponse};
 use actix_web_actors::ws;
 use actix_ws::{MessageStream, Session};
@@ -5,12 +6,14 @@ use futures::stream::StreamExt;
 use lemmy_api::Perform;
 use lemmy_api_common::{
   comment::{
+    AssignCommentReport,
     CreateComment,
     CreateCommentLike,
     CreateCommentReport,
     DeleteComment,
     DistinguishComment,
     EditComment,
+    EscalateCommentReport,
     GetComment,
     GetComments,
     ListCommentReports,
@@ -28,6 +31,8 @@ use lemmy_api_common::{
     FollowCommunity,
     GetCommunity,
     ListCommunities,
+    ListCommunityBackfills,
+    ListDirectoryCommunities,
     RemoveCommunity,
     TransferCommunity,
   },
@@ -37,6 +42,7 @@ use lemmy_api_common::{
     BanPerson,
     BlockPerson,
     ChangePassword,
+    CreateReportComment,
     DeleteAccount,
     GetBannedPersons,
     GetCaptcha,
@@ -45,6 +51,8 @@ use lemmy_api_common::{
     GetReplies,
     GetReportCount,
     GetUnreadCount,
+    ListMyCommunityBans,
+    ListReportComments,
     Login,
     MarkAllAsRead,
     MarkCommentReplyAsRead,
@@ -56,11 +64,13 @@ use lemmy_api_common::{
     VerifyEmail,
   },
   post::{
+    AssignPostReport,
     CreatePost,
     CreatePostLike,
     CreatePostReport,
     DeletePost,
     EditPost,
+    EscalatePostReport,
     FeaturePost,
     GetPost,
     GetPosts,
@@ -73,6 +83,7 @@ use lemmy_api_common::{
     SavePost,
   },
   private_message::{
+    AssignPrivateMessageReport,
     CreatePrivateMessage,
     CreatePrivateMessageReport,
     DeletePrivateMessage,
@@ -83,21 +94,31 @@ use lemmy_api_common::{
     ResolvePrivateMessageReport,
   },
   site::{
+    AddRelay,
     ApproveRegistrationApplication,
+    BlockInstance,
     CreateSite,
     EditSite,
+    GetFederationStats,
     GetModlog,
     GetSite,
     GetUnreadRegistrationApplicationCount,
     LeaveAdmin,
+    ListActivities,
     ListRegistrationApplications,
+    ListRelays,
     PurgeComment,
     PurgeCommunity,
     PurgePerson,
     PurgePost,
+    RemoveRelay,
+    ReplayActivity,
+    ResendActivity,
     ResolveObject,
     Search,
+    VerifyAggregates,
   },
+  utils::record_write,
   websocket::{
     serialize_websocket_message,
     structs::{CommunityJoin, ModJoin, PostJoin, UserJoin},
@@ -108,7 +129,12 @@ use lemmy_api_common::{
 };
 use lemmy_api_crud::PerformCrud;
 use lemmy_apub::{api::PerformApub, SendActivity};
-use lemmy_utils::{error::LemmyError, rate_limit::RateLimitCell, ConnectionId, IpAddr};
+use lemmy_utils::{
+  error::{LemmyError, LemmyErrorType},
+  rate_limit::RateLimitCell,
+  ConnectionId,
+  IpAddr,
+};
 use serde::Deserialize;
 use serde_json::Value;
 use std::{
@@ -136,8 +162,8 @@ pub async fn websocket(
       .to_string(),
   );
 
-  let check = rate_limiter.message().check(client_ip.clone());
-  if !check {
+  let check = rate_limiter.message().check(client_ip.clone()).await;
+  if check.is_err() {
     debug!(
       "Websocket join with IP: {} has been rate limited.",
       &client_ip
@@ -251,46 +277,43 @@ async fn parse_json_message(
   let data = &json["data"].to_string();
   let op = &json["op"]
     .as_str()
-    .ok_or_else(|| LemmyError::from_message("missing op"))?;
+    .ok_or(LemmyErrorType::MissingOperation)?;
+
+  if WRITE_REQUESTS.contains(op) {
+    record_write(
+      json["data"]["auth"].as_str(),
+      context.pool(),
+      context.secret(),
+    )
+    .await;
+  }
 
   // check if api call passes the rate limit, and generate future for later execution
   if let Ok(user_operation_crud) = UserOperationCrud::from_str(op) {
-    let passed = match user_operation_crud {
-      UserOperationCrud::Register => rate_limiter.register().check(ip),
-      UserOperationCrud::CreatePost => rate_limiter.post().check(ip),
-      UserOperationCrud::CreateCommunity => rate_limiter.register().check(ip),
-      UserOperationCrud::CreateComment => rate_limiter.comment().check(ip),
-      _ => rate_limiter.message().check(ip),
-    };
-    check_rate_limit_passed(passed)?;
+    match user_operation_crud {
+      UserOperationCrud::Register => rate_limiter.register().check(ip).await,
+      UserOperationCrud::CreatePost => rate_limiter.post().check(ip).await,
+      UserOperationCrud::CreateCommunity => rate_limiter.register().check(ip).await,
+      UserOperationCrud::CreateComment => rate_limiter.comment().check(ip).await,
+      _ => rate_limiter.message().check(ip).await,
+    }?;
     match_websocket_operation_crud(context, connection_id, user_operation_crud, data).await
   } else if let Ok(user_operation) = UserOperation::from_str(op) {
-    let passed = match user_operation {
-      UserOperation::GetCaptcha => rate_limiter.post().check(ip),
-      _ => rate_limiter.message().check(ip),
-    };
-    check_rate_limit_passed(passed)?;
+    match user_operation {
+      UserOperation::GetCaptcha => rate_limiter.post().check(ip).await,
+      _ => rate_limiter.message().check(ip).await,
+    }?;
     match_websocket_operation(context, connection_id, user_operation, data).await
   } else {
     let user_operation = UserOperationApub::from_str(op)?;
-    let passed = match user_operation {
-      UserOperationApub::Search => rate_limiter.search().check(ip),
-      _ => rate_limiter.message().check(ip),
-    };
-    check_rate_limit_passed(passed)?;
+    match user_operation {
+      UserOperationApub::Search => rate_limiter.search().check(ip).await,
+      _ => rate_limiter.message().check(ip).await,
+    }?;
     match_websocket_operation_apub(context, connection_id, user_operation, data).await
   }
 }
 
-fn check_rate_limit_passed(passed: bool) -> Result<(), LemmyError> {
-  if passed {
-    Ok(())
-  } else {
-    // if rate limit was hit, respond with message
-    Err(LemmyError::from_message("rate_limit_error"))
-  }
-}
-
 pub async fn match_websocket_operation_crud(
   context: LemmyContext,
   id: ConnectionId,
@@ -425,6 +448,18 @@ pub async fn match_websocket_operation_apub(
       do_websocket_operation_apub::<ResolveObject>(context, id, op, data).await
     }
     UserOperationApub::Search => do_websocket_operation_apub::<Search>(context, id, op, data).await,
+    UserOperationApub::AddRelay => {
+      do_websocket_operation_apub::<AddRelay>(context, id, op, data).await
+    }
+    UserOperationApub::RemoveRelay => {
+      do_websocket_operation_apub::<RemoveRelay>(context, id, op, data).await
+    }
+    UserOperationApub::ReplayActivity => {
+      do_websocket_operation_apub::<ReplayActivity>(context, id, op, data).await
+    }
+    UserOperationApub::ResendActivity => {
+      do_websocket_operation_apub::<ResendActivity>(context, id, op, data).await
+    }
   }
 }
 
@@ -471,6 +506,28 @@ pub async fn match_websocket_operation(
     UserOperation::GetBannedPersons => {
       do_websocket_operation::<GetBannedPersons>(context, id, op, data).await
     }
+    UserOperation::ListMyCommunityBans => {
+      do_websocket_operation::<ListMyCommunityBans>(context, id, op, data).await
+    }
+    UserOperation::ListCommunityBackfills => {
+      do_websocket_operation::<ListCommunityBackfills>(context, id, op, data).await
+    }
+    UserOperation::ListDirectoryCommunities => {
+      do_websocket_operation::<ListDirectoryCommunities>(context, id, op, data).await
+    }
+    UserOperation::BlockInstance => {
+      do_websocket_operation::<BlockInstance>(context, id, op, data).await
+    }
+    UserOperation::GetFederationStats => {
+      do_websocket_operation::<GetFederationStats>(context, id, op, data).await
+    }
+    UserOperation::ListRelays => do_websocket_operation::<ListRelays>(context, id, op, data).await,
+    UserOperation::ListActivities => {
+      do_websocket_operation::<ListActivities>(context, id, op, data).await
+    }
+    UserOperation::VerifyAggregates => {
+      do_websocket_operation::<VerifyAggregates>(context, id, op, data).await
+    }
     UserOperation::BlockPerson => {
       do_websocket_operation::<BlockPerson>(context, id, op, data).await
     }
@@ -507,6 +564,12 @@ pub async fn match_websocket_operation(
     UserOperation::GetReportCount => {
       do_websocket_operation::<GetReportCount>(context, id, op, data).await
     }
+    UserOperation::CreateReportComment => {
+      do_websocket_operation::<CreateReportComment>(context, id, op, data).await
+    }
+    UserOperation::ListReportComments => {
+      do_websocket_operation::<ListReportComments>(context, id, op, data).await
+    }
     UserOperation::GetUnreadCount => {
       do_websocket_operation::<GetUnreadCount>(context, id, op, data).await
     }
@@ -527,6 +590,9 @@ pub async fn match_websocket_operation(
     UserOperation::ListPrivateMessageReports => {
       do_websocket_operation::<ListPrivateMessageReports>(context, id, op, data).await
     }
+    UserOperation::AssignPrivateMessageReport => {
+      do_websocket_operation::<AssignPrivateMessageReport>(context, id, op, data).await
+    }
 
     // Site ops
     UserOperation::GetModlog => do_websocket_operation::<GetModlog>(context, id, op, data).await,
@@ -580,6 +646,12 @@ pub async fn match_websocket_operation(
     UserOperation::ResolvePostReport => {
       do_websocket_operation::<ResolvePostReport>(context, id, op, data).await
     }
+    UserOperation::AssignPostReport => {
+      do_websocket_operation::<AssignPostReport>(context, id, op, data).await
+    }
+    UserOperation::EscalatePostReport => {
+      do_websocket_operation::<EscalatePostReport>(context, id, op, data).await
+    }
     UserOperation::GetSiteMetadata => {
       do_websocket_operation::<GetSiteMetadata>(context, id, op, data).await
     }
@@ -603,6 +675,12 @@ pub async fn match_websocket_operation(
     UserOperation::ResolveCommentReport => {
       do_websocket_operation::<ResolveCommentReport>(context, id, op, data).await
     }
+    UserOperation::AssignCommentReport => {
+      do_websocket_operation::<AssignCommentReport>(context, id, op, data).await
+    }
+    UserOperation::EscalateCommentReport => {
+      do_websocket_operation::<EscalateCommentReport>(context, id, op, data).await
+    }
   }
 }
 
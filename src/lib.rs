@@ -1,14 +1,25 @@
+pub mod admin_cli;
 pub mod api_routes_http;
 pub mod api_routes_websocket;
 pub mod code_migrations;
+pub mod openapi;
+pub mod prometheus_metrics;
 pub mod root_span_builder;
 pub mod scheduled_tasks;
+pub mod shared_state;
 #[cfg(feature = "console")]
 pub mod telemetry;
 
-use crate::{code_migrations::run_advanced_migrations, root_span_builder::QuieterRootSpanBuilder};
-use actix_web::{middleware, web::Data, App, HttpServer, Result};
-use doku::json::{AutoComments, CommentsStyle, Formatting, ObjectsStyle};
+use crate::{
+  admin_cli::{run_admin_command, AdminCommand},
+  code_migrations::run_advanced_migrations,
+  prometheus_metrics::{record_request, serve_prometheus},
+  root_span_builder::QuieterRootSpanBuilder,
+  shared_state::{acquire_scheduled_tasks_lock, listen_for_notifications, PostgresRateLimit},
+};
+use actix_rt::signal::unix::{signal, SignalKind};
+use actix_web::{dev::ServerHandle, middleware, web::Data, App, HttpServer, Result};
+use clap::Parser;
 use lemmy_api_common::{
   context::LemmyContext,
   lemmy_db_views::structs::SiteView,
@@ -19,22 +30,33 @@ use lemmy_api_common::{
   },
   websocket::chat_server::ChatServer,
 };
+use lemmy_apub::{
+  federation_stats::FederationStatsMiddleware,
+  fetcher::backfill::resume_backfills,
+  signed_fetch::SignedFetchMiddleware,
+};
 use lemmy_db_schema::{
   source::secret::Secret,
   utils::{build_db_pool, get_database_url, run_migrations},
 };
 use lemmy_routes::{feeds, images, nodeinfo, webfinger};
 use lemmy_utils::{
-  error::LemmyError,
+  error::{LemmyError, LemmyErrorType},
   rate_limit::RateLimitCell,
-  settings::{structs::Settings, SETTINGS},
+  settings::{
+    structs::{PrometheusConfig, Settings, SharedStateBackend},
+    unknown_env_vars,
+    SETTINGS,
+  },
+  version::VERSION,
 };
 use reqwest::Client;
 use reqwest_middleware::ClientBuilder;
 use reqwest_retry::{policies::ExponentialBackoff, RetryTransientMiddleware};
 use reqwest_tracing::TracingMiddleware;
-use std::{env, sync::Arc, thread, time::Duration};
-use tracing::subscriber::set_global_default;
+use std::{sync::Arc, thread, time::Duration};
+use tokio::sync::oneshot;
+use tracing::{error, info, subscriber::set_global_default, warn};
 use tracing_actix_web::TracingLogger;
 use tracing_error::ErrorLayer;
 use tracing_log::LogTracer;
@@ -44,26 +66,42 @@ use url::Url;
 /// Max timeout for http requests
 pub(crate) const REQWEST_TIMEOUT: Duration = Duration::from_secs(10);
 
+#[derive(Parser, Debug)]
+#[command(version = VERSION, about = "A link aggregator for the fediverse")]
+pub struct CmdArgs {
+  /// Print the config with all options and their defaults, and exit
+  #[arg(long)]
+  print_config_docs: bool,
+  /// Print all error types which the API can return, and exit
+  #[arg(long)]
+  print_error_types: bool,
+  /// Run a maintenance command instead of starting the server
+  #[command(subcommand)]
+  command: Option<AdminCommand>,
+}
+
 /// Placing the main function in lib.rs allows other crates to import it and embed Lemmy
 pub async fn start_lemmy_server() -> Result<(), LemmyError> {
-  let args: Vec<String> = env::args().collect();
-  if args.len() == 2 && args[1] == "--print-config-docs" {
-    let fmt = Formatting {
-      auto_comments: AutoComments::none(),
-      comments_style: CommentsStyle {
-        separator: "#".to_owned(),
-      },
-      objects_style: ObjectsStyle {
-        surround_keys_with_quotes: false,
-        use_comma_as_separator: false,
-      },
-      ..Default::default()
-    };
-    println!("{}", doku::to_json_fmt_val(&fmt, &Settings::default()));
+  let args = CmdArgs::parse();
+  if args.print_config_docs {
+    println!("{}", Settings::docs());
+    return Ok(());
+  }
+  if args.print_error_types {
+    println!("{}", doku::to_json::<LemmyErrorType>());
     return Ok(());
   }
 
   let settings = SETTINGS.to_owned();
+  for name in unknown_env_vars() {
+    warn!(
+      "Ignoring environment variable {}, which is not a setting",
+      name
+    );
+  }
+  if let Some(command) = args.command {
+    return run_admin_command(command, &settings).await;
+  }
 
   // Set up the bb8 connection pool
   let db_url = get_database_url(Some(&settings));
@@ -94,7 +132,13 @@ pub async fn start_lemmy_server() -> Result<(), LemmyError> {
   // Set up the rate limiter
   let rate_limit_config =
     local_site_rate_limit_to_rate_limit_config(&site_view.local_site_rate_limit);
-  let rate_limit_cell = RateLimitCell::new(rate_limit_config).await;
+  let shared_state = settings.shared_state == SharedStateBackend::Postgres;
+  let rate_limit_cell = if shared_state {
+    let backend = Arc::new(PostgresRateLimit::new(pool.clone()));
+    RateLimitCell::with_backend(rate_limit_config, backend).await
+  } else {
+    RateLimitCell::new(rate_limit_config).await
+  };
 
   println!(
     "Starting http server at {}:{}",
@@ -116,6 +160,8 @@ pub async fn start_lemmy_server() -> Result<(), LemmyError> {
 
   let client = ClientBuilder::new(reqwest_client.clone())
     .with(TracingMiddleware::default())
+    .with(FederationStatsMiddleware::new(pool.clone()))
+    .with(SignedFetchMiddleware::new(pool.clone()))
     .with(RetryTransientMiddleware::new_with_policy(retry_policy))
     .build();
 
@@ -124,12 +170,58 @@ pub async fn start_lemmy_server() -> Result<(), LemmyError> {
     .with(TracingMiddleware::default())
     .build();
 
-  // Schedules various cleanup tasks for the DB
+  // With shared state, only the process which holds the lock runs the scheduled tasks
+  let (scheduled_tx, scheduled_rx) = oneshot::channel();
+  let scheduled_db_url = db_url.clone();
+  let retention = settings.retention.clone();
   thread::spawn(move || {
-    scheduled_tasks::setup(db_url, user_agent).expect("Couldn't set up scheduled_tasks");
+    let _lock = shared_state.then(|| acquire_scheduled_tasks_lock(&scheduled_db_url));
+    scheduled_tx.send(()).ok();
+    // Schedules various cleanup tasks for the DB
+    scheduled_tasks::setup(scheduled_db_url, user_agent, retention)
+      .expect("Couldn't set up scheduled_tasks");
   });
 
-  let chat_server = Arc::new(ChatServer::startup());
+  let chat_server = if shared_state {
+    Arc::new(ChatServer::startup_shared(pool.clone()))
+  } else {
+    Arc::new(ChatServer::startup())
+  };
+
+  let background_context = LemmyContext::create(
+    pool.clone(),
+    chat_server.clone(),
+    client.clone(),
+    settings.clone(),
+    secret.clone(),
+    rate_limit_cell.clone(),
+  );
+  if shared_state {
+    actix_rt::spawn(listen_for_notifications(db_url, background_context.clone()));
+  }
+  let prometheus = match settings.prometheus.get() {
+    Some(config) => {
+      let server = serve_prometheus(&config, background_context.clone())?;
+      Some((config, server))
+    }
+    None => None,
+  };
+  actix_rt::spawn(reload_settings_on_hangup(
+    background_context.clone(),
+    prometheus,
+  ));
+  let backfill_context = background_context.clone();
+  actix_rt::spawn(async move {
+    if let Err(e) = resume_backfills(&backfill_context).await {
+      error!("Failed to resume community backfills: {}", e);
+    }
+  });
+  // Schedules the tasks which federate, like lifting expired community bans
+  actix_rt::spawn(async move {
+    if scheduled_rx.await.is_ok() {
+      scheduled_tasks::setup_async(background_context).await;
+    }
+  });
 
   // Create Http server with websocket support
   let settings_bind = settings.clone();
@@ -145,6 +237,7 @@ pub async fn start_lemmy_server() -> Result<(), LemmyError> {
     App::new()
       .wrap(middleware::Logger::default())
       .wrap(TracingLogger::<QuieterRootSpanBuilder>::new())
+      .wrap_fn(record_request)
       .app_data(Data::new(context))
       .app_data(Data::new(rate_limit_cell.clone()))
       // The routes
@@ -166,6 +259,45 @@ pub async fn start_lemmy_server() -> Result<(), LemmyError> {
   Ok(())
 }
 
+/// Reloads the settings which can be changed at runtime whenever the process receives SIGHUP. The
+/// prometheus endpoint is restarted if its address changed.
+async fn reload_settings_on_hangup(
+  context: LemmyContext,
+  mut prometheus: Option<(PrometheusConfig, ServerHandle)>,
+) -> Result<(), LemmyError> {
+  let mut hangup = signal(SignalKind::hangup())?;
+  while hangup.recv().await.is_some() {
+    match context.settings().reload() {
+      Ok(needs_restart) if needs_restart.is_empty() => info!("Reloaded settings"),
+      Ok(needs_restart) => warn!(
+        "Reloaded settings, changes to {} only take effect after a restart",
+        needs_restart.join(", ")
+      ),
+      Err(e) => {
+        error!(
+          "Failed to reload settings, keeping the previous ones: {}",
+          e
+        );
+        continue;
+      }
+    }
+
+    let config = context.settings().prometheus.get();
+    if prometheus.as_ref().map(|(running, _)| running) != config.as_ref() {
+      if let Some((_, server)) = prometheus.take() {
+        server.stop(true).await;
+      }
+      if let Some(config) = config {
+        match serve_prometheus(&config, context.clone()) {
+          Ok(server) => prometheus = Some((config, server)),
+          Err(e) => error!("Failed to serve prometheus metrics: {}", e),
+        }
+      }
+    }
+  }
+  Ok(())
+}
+
 pub fn init_logging(opentelemetry_url: &Option<Url>) -> Result<(), LemmyError> {
   LogTracer::init()?;
 
@@ -0,0 +1,292 @@
+use crate::api_routes_http::Endpoint;
+use actix_web::http::Method;
+use doku::{Document, Fields, Tag, Type, TypeKind, Variant};
+use lemmy_utils::{error::LemmyErrorType, version::VERSION};
+use serde_json::{json, Map, Value};
+
+/// Generates an OpenAPI 3 document for the HTTP API. Request and response types are listed under
+/// `components/schemas` with the name of the Rust struct, nested types are inlined.
+pub fn openapi_document(endpoints: &[Endpoint]) -> Value {
+  let mut paths = Map::new();
+  let mut schemas = Map::new();
+  schemas.insert(
+    "LemmyErrorType".to_string(),
+    type_schema(&LemmyErrorType::ty()),
+  );
+
+  for endpoint in endpoints {
+    let request = (endpoint.request)();
+    let mut operation = json!({
+      "operationId": endpoint.request_name,
+      "responses": {
+        "200": json_content("Success", endpoint.response_name),
+        "400": json_content("Error", "LemmyErrorType"),
+        "404": json_content("Not found", "LemmyErrorType"),
+      }
+    });
+    if endpoint.method == Method::GET {
+      operation["parameters"] = Value::Array(query_parameters(&request));
+    } else {
+      operation["requestBody"] = json_content("", endpoint.request_name);
+      operation["requestBody"]["required"] = Value::Bool(true);
+    }
+    schemas.insert(endpoint.request_name.to_string(), type_schema(&request));
+    schemas.insert(
+      endpoint.response_name.to_string(),
+      type_schema(&(endpoint.response)()),
+    );
+
+    let path = paths
+      .entry(endpoint.path.to_string())
+      .or_insert_with(|| Value::Object(Map::new()));
+    path[endpoint.method.as_str().to_lowercase()] = operation;
+  }
+
+  json!({
+    "openapi": "3.0.3",
+    "info": {
+      "title": "Lemmy",
+      "version": VERSION,
+    },
+    "servers": [{ "url": "/api/v3" }],
+    "paths": paths,
+    "components": { "schemas": schemas },
+  })
+}
+
+fn json_content(description: &str, schema_name: &str) -> Value {
+  json!({
+    "description": description,
+    "content": {
+      "application/json": {
+        "schema": { "$ref": format!("#/components/schemas/{schema_name}") }
+      }
+    }
+  })
+}
+
+/// GET requests pass the fields of the request struct as query parameters
+fn query_parameters(request: &Type) -> Vec<Value> {
+  named_fields(request)
+    .into_iter()
+    .map(|(name, ty)| {
+      let mut parameter = json!({
+        "name": name,
+        "in": "query",
+        "required": !is_optional(ty),
+        "schema": type_schema(ty),
+      });
+      if let Some(comment) = ty.comment {
+        parameter["description"] = Value::String(comment.to_string());
+      }
+      parameter
+    })
+    .collect()
+}
+
+fn named_fields(ty: &Type) -> Vec<(&'static str, &Type)> {
+  match &ty.kind {
+    TypeKind::Struct {
+      fields: Fields::Named { fields },
+      ..
+    } => fields
+      .iter()
+      .flat_map(|(name, field)| {
+        if field.flattened {
+          named_fields(&field.ty)
+        } else {
+          vec![(*name, &field.ty)]
+        }
+      })
+      .collect(),
+    _ => vec![],
+  }
+}
+
+fn is_optional(ty: &Type) -> bool {
+  matches!(ty.kind, TypeKind::Optional { .. })
+}
+
+fn type_schema(ty: &Type) -> Value {
+  let mut schema = match &ty.kind {
+    _ if ty.metas.get("json") == Some("any") => json!({}),
+    TypeKind::Bool => json!({ "type": "boolean" }),
+    TypeKind::Integer => json!({ "type": "integer" }),
+    TypeKind::Float => json!({ "type": "number" }),
+    TypeKind::String => json!({ "type": "string" }),
+    TypeKind::Optional { ty } => {
+      let mut schema = type_schema(ty);
+      schema["nullable"] = Value::Bool(true);
+      schema
+    }
+    TypeKind::Array { ty, .. } => json!({ "type": "array", "items": type_schema(ty) }),
+    TypeKind::Map { value, .. } => {
+      json!({ "type": "object", "additionalProperties": type_schema(value) })
+    }
+    TypeKind::Tuple { fields } => json!({
+      "type": "array",
+      "minItems": fields.len(),
+      "maxItems": fields.len(),
+    }),
+    TypeKind::Struct {
+      fields,
+      transparent,
+    } => match fields {
+      Fields::Named { fields } if *transparent && fields.len() == 1 => type_schema(&fields[0].1.ty),
+      _ => fields_schema(fields),
+    },
+    TypeKind::Enum { tag, variants } => enum_schema(*tag, variants),
+  };
+  if let Some(comment) = ty.comment {
+    schema["description"] = Value::String(comment.to_string());
+  }
+  if ty.metas.get("sensitive") == Some("true") {
+    schema["format"] = Value::String("password".to_string());
+  }
+  schema
+}
+
+fn fields_schema(fields: &Fields) -> Value {
+  match fields {
+    Fields::Named { .. } => {
+      let mut properties = Map::new();
+      let mut required = vec![];
+      let ty = Type::from(fields.clone());
+      for (name, ty) in named_fields(&ty) {
+        if !is_optional(ty) {
+          required.push(name);
+        }
+        properties.insert(name.to_string(), type_schema(ty));
+      }
+      json!({ "type": "object", "properties": properties, "required": required })
+    }
+    // Serde serializes newtypes as their only field
+    Fields::Unnamed { fields } if fields.len() == 1 => type_schema(&fields[0].ty),
+    Fields::Unnamed { fields } => json!({
+      "type": "array",
+      "minItems": fields.len(),
+      "maxItems": fields.len(),
+    }),
+    Fields::Unit => json!({ "nullable": true }),
+  }
+}
+
+fn enum_schema(tag: Tag, variants: &[Variant]) -> Value {
+  let is_unit = |v: &Variant| matches!(v.fields, Fields::Unit);
+  if tag == Tag::External && variants.iter().all(is_unit) {
+    let values: Vec<_> = variants.iter().map(|v| v.id).collect();
+    return json!({ "type": "string", "enum": values });
+  }
+
+  let one_of: Vec<_> = variants
+    .iter()
+    .map(|variant| {
+      let mut schema = match tag {
+        Tag::Adjacent { tag, content } => {
+          let mut schema = tagged_object(tag, variant.id);
+          if !is_unit(variant) {
+            schema["properties"][content] = fields_schema(&variant.fields);
+            append_required(&mut schema, content);
+          }
+          schema
+        }
+        Tag::Internal { tag } => {
+          let mut schema = fields_schema(&variant.fields);
+          schema["type"] = json!("object");
+          schema["properties"][tag] = json!({ "type": "string", "enum": [variant.id] });
+          append_required(&mut schema, tag);
+          schema
+        }
+        Tag::External if is_unit(variant) => json!({ "type": "string", "enum": [variant.id] }),
+        Tag::External => json!({
+          "type": "object",
+          "properties": { variant.id: fields_schema(&variant.fields) },
+          "required": [variant.id],
+        }),
+        Tag::None => fields_schema(&variant.fields),
+      };
+      if let Some(comment) = variant.comment {
+        schema["description"] = Value::String(comment.to_string());
+      }
+      schema
+    })
+    .collect();
+  json!({ "oneOf": one_of })
+}
+
+fn tagged_object(tag: &str, id: &str) -> Value {
+  json!({
+    "type": "object",
+    "properties": { tag: { "type": "string", "enum": [id] } },
+    "required": [tag],
+  })
+}
+
+fn append_required(schema: &mut Value, name: &str) {
+  match schema["required"].as_array_mut() {
+    Some(required) => required.push(json!(name)),
+    None => schema["required"] = json!([name]),
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::{api_routes_http::endpoints, openapi::openapi_document};
+  use serde_json::Value;
+  use std::collections::HashSet;
+
+  #[test]
+  fn test_openapi_document() {
+    let endpoints = endpoints();
+    let doc = openapi_document(&endpoints);
+
+    // Every endpoint is documented, with a unique operation id
+    let mut operation_ids = HashSet::new();
+    for endpoint in &endpoints {
+      let method = endpoint.method.as_str().to_lowercase();
+      let operation = &doc["paths"][endpoint.path][&method];
+      let operation_id = operation["operationId"].as_str().unwrap();
+      assert!(operation_ids.insert(operation_id), "{operation_id}");
+    }
+
+    // All references point to a schema
+    let doc_str = doc.to_string();
+    for reference in doc_str.split(r##""$ref":"#/components/schemas/"##).skip(1) {
+      let name = reference.split('"').next().unwrap();
+      assert!(doc["components"]["schemas"].get(name).is_some(), "{name}");
+    }
+
+    let get_posts = &doc["paths"]["/post/list"]["get"]["parameters"];
+    let param = |name: &str| -> &Value {
+      get_posts
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|p| p["name"] == name)
+        .unwrap()
+    };
+    assert_eq!(param("sort")["required"], false);
+    let sort_values = param("sort")["schema"]["enum"].as_array().unwrap();
+    assert!(sort_values.contains(&Value::from("Hot")));
+    assert!(param("type_")["schema"]["enum"]
+      .as_array()
+      .unwrap()
+      .contains(&Value::from("Subscribed")));
+    assert_eq!(param("auth")["schema"]["format"], "password");
+    assert_eq!(param("community_id")["schema"]["type"], "integer");
+
+    let login = &doc["components"]["schemas"]["Login"];
+    assert_eq!(login["properties"]["password"]["format"], "password");
+    assert!(login["required"]
+      .as_array()
+      .unwrap()
+      .contains(&Value::from("password")));
+
+    let error = &doc["components"]["schemas"]["LemmyErrorType"]["oneOf"];
+    assert!(error
+      .as_array()
+      .unwrap()
+      .iter()
+      .any(|e| e["properties"]["error"]["enum"][0] == "not_logged_in"));
+  }
+}
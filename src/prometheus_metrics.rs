@@ -0,0 +1,93 @@
+use actix_web::{
+  dev::{ServerHandle, Service, ServiceRequest, ServiceResponse},
+  web,
+  App,
+  Error,
+  HttpResponse,
+  HttpServer,
+};
+use futures::Future;
+use lemmy_api_common::context::LemmyContext;
+use lemmy_utils::{
+  error::LemmyError,
+  metrics::{
+    encode_metrics,
+    DB_POOL_CONNECTIONS,
+    DB_POOL_MAX_SIZE,
+    HTTP_REQUESTS,
+    HTTP_REQUEST_DURATION,
+    WEBSOCKET_SESSIONS,
+  },
+  settings::structs::PrometheusConfig,
+};
+use std::time::Instant;
+
+/// Serves the metrics on their own address, so that they are not reachable through the public
+/// API.
+pub fn serve_prometheus(
+  config: &PrometheusConfig,
+  context: LemmyContext,
+) -> Result<ServerHandle, LemmyError> {
+  println!(
+    "Serving prometheus metrics at {}:{}",
+    config.bind, config.port
+  );
+  let server = HttpServer::new(move || {
+    App::new()
+      .app_data(web::Data::new(context.clone()))
+      .route("/metrics", web::get().to(metrics))
+  })
+  .workers(1)
+  .bind((config.bind, config.port))?
+  .run();
+  let handle = server.handle();
+  actix_rt::spawn(server);
+  Ok(handle)
+}
+
+async fn metrics(context: web::Data<LemmyContext>) -> Result<HttpResponse, LemmyError> {
+  // Gauges are read at scrape time, the other metrics are recorded where they happen
+  let state = context.pool().state();
+  DB_POOL_CONNECTIONS
+    .with_label_values(&["idle"])
+    .set(state.idle_connections.into());
+  DB_POOL_CONNECTIONS
+    .with_label_values(&["active"])
+    .set((state.connections - state.idle_connections).into());
+  DB_POOL_MAX_SIZE.set(context.settings().database.pool_size.into());
+  WEBSOCKET_SESSIONS.set(context.chat_server().get_users_online()? as i64);
+
+  Ok(
+    HttpResponse::Ok()
+      .content_type("text/plain; version=0.0.4")
+      .body(encode_metrics()?),
+  )
+}
+
+/// Counts the requests and their duration for each route. Requests which don't match any route
+/// are grouped together, so that random paths can't create new metrics.
+pub fn record_request<S, B>(
+  req: ServiceRequest,
+  service: &S,
+) -> impl Future<Output = Result<ServiceResponse<B>, Error>>
+where
+  S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error>,
+{
+  let method = req.method().to_string();
+  let start = Instant::now();
+  let res = service.call(req);
+  async move {
+    let res = res.await?;
+    let route = res
+      .request()
+      .match_pattern()
+      .unwrap_or_else(|| "unmatched".to_string());
+    HTTP_REQUESTS
+      .with_label_values(&[&method, &route, res.status().as_str()])
+      .inc();
+    HTTP_REQUEST_DURATION
+      .with_label_values(&[&method, &route])
+      .observe(start.elapsed().as_secs_f64());
+    Ok(res)
+  }
+}
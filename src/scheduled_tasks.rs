@@ -2,18 +2,48 @@ use clokwerk::{Scheduler, TimeUnits};
 // Import week days and WeekDay
 use diesel::{sql_query, PgConnection, RunQueryDsl};
 use diesel::{Connection, ExpressionMethods, QueryDsl};
+use lemmy_api_common::{
+  community::BanFromCommunityResponse,
+  context::LemmyContext,
+  lemmy_db_views::structs::LocalUserView,
+  lemmy_db_views_actor::structs::PersonViewSafe,
+  request::purge_image_from_pictrs,
+  websocket::UserOperation,
+};
+use lemmy_apub::{
+  activities::block::SiteOrCommunity,
+  fetcher::community_directory::crawl_community_directory,
+  protocol::activities::block::undo_block_user::UndoBlockUser,
+};
 use lemmy_db_schema::{
-  source::instance::{Instance, InstanceForm},
+  aggregates::structs::AggregateDifferences,
+  source::{
+    community::{Community, CommunityPersonBan, CommunityPersonBanForm},
+    instance::{Instance, InstanceForm},
+    moderator::{ModBanFromCommunity, ModBanFromCommunityForm},
+    person::Person,
+    post::Post,
+  },
+  traits::{Bannable, Crud},
   utils::naive_now,
 };
 use lemmy_routes::nodeinfo::NodeInfo;
-use lemmy_utils::{error::LemmyError, REQWEST_TIMEOUT};
+use lemmy_utils::{
+  error::{LemmyError, LemmyErrorType},
+  metrics::scheduled_task_timer,
+  settings::structs::RetentionConfig,
+  REQWEST_TIMEOUT,
+};
 use reqwest::blocking::Client;
 use std::{thread, time::Duration};
-use tracing::info;
+use tracing::{info, warn};
 
 /// Schedules various cleanup tasks for lemmy in a background thread
-pub fn setup(db_url: String, user_agent: String) -> Result<(), LemmyError> {
+pub fn setup(
+  db_url: String,
+  user_agent: String,
+  retention: RetentionConfig,
+) -> Result<(), LemmyError> {
   // Setup the connections
   let mut scheduler = Scheduler::new();
 
@@ -34,11 +64,12 @@ pub fn setup(db_url: String, user_agent: String) -> Result<(), LemmyError> {
     update_banned_when_expired(conn);
     reindex_aggregates_tables(conn, true);
     drop_ccnew_indexes(conn);
+    clear_shared_state(conn);
   });
 
-  clear_old_activities(&mut conn);
-  scheduler.every(1.weeks()).run(move || {
-    clear_old_activities(&mut conn);
+  enforce_retention(&mut conn, &retention);
+  scheduler.every(1.days()).run(move || {
+    enforce_retention(&mut conn, &retention);
   });
 
   update_instance_software(&mut conn_2, &user_agent);
@@ -53,10 +84,215 @@ pub fn setup(db_url: String, user_agent: String) -> Result<(), LemmyError> {
   }
 }
 
+/// Schedules the tasks which need a full context, for example to federate activities. Unlike the
+/// ones above these run on the async runtime.
+pub async fn setup_async(context: LemmyContext) {
+  let hourly = async {
+    let mut interval = tokio::time::interval(Duration::from_secs(60 * 60));
+    loop {
+      // The first tick completes immediately, so this also runs on startup
+      interval.tick().await;
+      lift_expired_community_bans(&context).await;
+    }
+  };
+  let daily = async {
+    let mut interval = tokio::time::interval(Duration::from_secs(24 * 60 * 60));
+    loop {
+      interval.tick().await;
+      repair_aggregates(&context).await;
+      prune_remote_content(&context).await;
+      let _timer = scheduled_task_timer("crawl_community_directory");
+      crawl_community_directory(&context).await;
+    }
+  };
+  tokio::join!(hourly, daily);
+}
+
+/// Overwrites aggregates which drifted from the source tables, and logs what was wrong
+async fn repair_aggregates(context: &LemmyContext) {
+  let _timer = scheduled_task_timer("repair_aggregates");
+  info!("Repairing aggregates ...");
+  match AggregateDifferences::check(context.pool(), true).await {
+    Ok(differences) => {
+      for d in differences {
+        warn!(
+          "Repaired {} rows of {}.{}, eg {:?}",
+          d.count, d.table, d.column, d.examples
+        );
+      }
+      info!("Done.");
+    }
+    Err(e) => warn!("Failed to repair aggregates: {}", e),
+  }
+}
+
+/// Deletes old remote posts and comments in communities which nobody here follows, if enabled
+/// in the retention settings. Their thumbnails are purged from pictrs as well, unless another post
+/// uses them.
+async fn prune_remote_content(context: &LemmyContext) {
+  let retention = &context.settings().retention;
+  let days = match retention.remote_content_days {
+    Some(d) => d,
+    None => return,
+  };
+  let _timer = scheduled_task_timer("prune_remote_content");
+  info!("Pruning remote content ...");
+  let older_than = naive_now() - chrono::Duration::days(days.into());
+  // Remote images are only cached here if they are served by the local pictrs
+  let local_images = format!(
+    "{}/pictrs/image/",
+    context.settings().get_protocol_and_hostname()
+  );
+  let mut pruned = 0;
+  loop {
+    let posts =
+      match Post::prune_remote_batch(context.pool(), older_than, retention.batch_size.into()).await
+      {
+        Ok(posts) if posts.is_empty() => break,
+        Ok(posts) => posts,
+        Err(e) => {
+          warn!("Failed to prune remote content: {}", e);
+          break;
+        }
+      };
+    pruned += posts.len();
+    // Only thumbnails are generated for the post, a linked image may belong to a local user
+    for thumbnail in posts.into_iter().filter_map(|p| p.thumbnail_url) {
+      if !thumbnail.as_str().starts_with(&local_images) {
+        continue;
+      }
+      match Post::uses_image(context.pool(), &thumbnail).await {
+        Ok(false) => {
+          purge_image_from_pictrs(context.client(), context.settings(), &thumbnail)
+            .await
+            .ok();
+        }
+        Ok(true) => {}
+        Err(e) => warn!("Failed to check use of thumbnail {}: {}", thumbnail, e),
+      }
+    }
+  }
+  info!("Pruned {} remote posts with their comments", pruned);
+}
+
+/// Lifts temporary community bans once they expire. For local communities this also writes an
+/// unban modlog entry, federates the unban and notifies the user.
+async fn lift_expired_community_bans(context: &LemmyContext) {
+  let _timer = scheduled_task_timer("lift_expired_community_bans");
+  info!("Lifting expired community bans ...");
+  let expired = match CommunityPersonBan::list_expired(context.pool()).await {
+    Ok(e) => e,
+    Err(e) => {
+      warn!("Failed to list expired community bans: {}", e);
+      return;
+    }
+  };
+  for ban in expired {
+    if let Err(e) = lift_community_ban(&ban, context).await {
+      warn!(
+        "Failed to lift ban of person {} from community {}: {}",
+        ban.person_id.0, ban.community_id.0, e
+      );
+    }
+  }
+  info!("Done.");
+}
+
+async fn lift_community_ban(
+  ban: &CommunityPersonBan,
+  context: &LemmyContext,
+) -> Result<(), LemmyError> {
+  let form = CommunityPersonBanForm {
+    community_id: ban.community_id,
+    person_id: ban.person_id,
+    expires: None,
+  };
+  CommunityPersonBan::unban(context.pool(), &form).await?;
+
+  // Remote communities take care of the unban themselves, and federate it to us
+  let community = Community::read(context.pool(), ban.community_id).await?;
+  if !community.local {
+    return Ok(());
+  }
+
+  // Attribute the unban to the mod who issued the ban, as long as they can still sign
+  // activities. Otherwise fall back to the first admin.
+  let banning_mod =
+    match ModBanFromCommunity::read_latest_ban(context.pool(), ban.community_id, ban.person_id)
+      .await
+    {
+      Ok(mod_ban) => Person::read(context.pool(), mod_ban.mod_person_id)
+        .await
+        .ok()
+        .filter(|p| p.local && !p.deleted),
+      Err(_) => None,
+    };
+  let mod_ = match banning_mod {
+    Some(m) => m,
+    None => {
+      let admin = PersonViewSafe::admins(context.pool())
+        .await?
+        .into_iter()
+        .next()
+        .ok_or(LemmyErrorType::NoAdmins)?;
+      Person::read(context.pool(), admin.person.id).await?
+    }
+  };
+
+  let reason = Some("Ban expired".to_string());
+  let form = ModBanFromCommunityForm {
+    mod_person_id: mod_.id,
+    other_person_id: ban.person_id,
+    community_id: ban.community_id,
+    reason: reason.clone(),
+    banned: Some(false),
+    expires: None,
+  };
+  ModBanFromCommunity::create(context.pool(), &form).await?;
+
+  let banned_person = Person::read(context.pool(), ban.person_id).await?;
+  UndoBlockUser::send(
+    &SiteOrCommunity::Community(community.into()),
+    &banned_person.clone().into(),
+    &mod_.into(),
+    reason,
+    context,
+  )
+  .await?;
+
+  let res = BanFromCommunityResponse {
+    person_view: PersonViewSafe::read(context.pool(), ban.person_id).await?,
+    banned: false,
+  };
+  context
+    .chat_server()
+    .send_community_room_message(
+      &UserOperation::BanFromCommunity,
+      &res,
+      ban.community_id,
+      None,
+    )
+    .await?;
+  if banned_person.local {
+    let local_user_view = LocalUserView::read_person(context.pool(), ban.person_id).await?;
+    context
+      .chat_server()
+      .send_user_room_message(
+        &UserOperation::BanFromCommunity,
+        &res,
+        local_user_view.local_user.id,
+        None,
+      )
+      .await?;
+  }
+  Ok(())
+}
+
 /// Reindex the aggregates tables every one hour
 /// This is necessary because hot_rank is actually a mutable function:
 /// https://dba.stackexchange.com/questions/284052/how-to-create-an-index-based-on-a-time-based-function-in-postgres?noredirect=1#comment555727_284052
-fn reindex_aggregates_tables(conn: &mut PgConnection, concurrently: bool) {
+pub(crate) fn reindex_aggregates_tables(conn: &mut PgConnection, concurrently: bool) {
+  let _timer = scheduled_task_timer("reindex_aggregates_tables");
   for table_name in &[
     "post_aggregates",
     "comment_aggregates",
@@ -74,19 +310,94 @@ fn reindex_table(conn: &mut PgConnection, table_name: &str, concurrently: bool)
   info!("Done.");
 }
 
-/// Clear old activities (this table gets very large)
-fn clear_old_activities(conn: &mut PgConnection) {
+/// Deletes the rows which are older than configured in the retention settings. This runs in
+/// batches, so that large tables like activity aren't locked for long.
+fn enforce_retention(conn: &mut PgConnection, retention: &RetentionConfig) {
+  let _timer = scheduled_task_timer("enforce_retention");
+  info!("Deleting old rows...");
+  let policies = [
+    ("activity", retention.activity_days, ""),
+    (
+      "password_reset_request",
+      retention.password_reset_request_days,
+      "",
+    ),
+    ("email_verification", retention.email_verification_days, ""),
+    ("post_read", retention.post_read_days, ""),
+    (
+      "registration_application",
+      retention.registration_application_days,
+      "and admin_id is not null and deny_reason is null",
+    ),
+  ];
+  for (table, days, condition) in policies {
+    let days = match days {
+      Some(d) => d,
+      None => continue,
+    };
+    let query = format!(
+      "delete from {table} where id in (
+         select id from {table} where published < now() - interval '{days} days' {condition}
+         limit {}
+       )",
+      retention.batch_size
+    );
+    let mut deleted = 0;
+    loop {
+      match sql_query(&query).execute(conn) {
+        Ok(0) => break,
+        Ok(count) => deleted += count,
+        Err(e) => {
+          warn!("Failed to delete old rows from {}: {}", table, e);
+          break;
+        }
+      }
+    }
+    info!("Deleted {} rows from {}", deleted, table);
+  }
+  info!("Done.");
+}
+
+/// Clear the runtime state which processes share through the database, once it is no longer
+/// needed
+fn clear_shared_state(conn: &mut PgConnection) {
+  let _timer = scheduled_task_timer("clear_shared_state");
   use diesel::dsl::{now, IntervalDsl};
-  use lemmy_db_schema::schema::activity::dsl::{activity, published};
-  info!("Clearing old activities...");
-  diesel::delete(activity.filter(published.lt(now - 6.months())))
+  use lemmy_db_schema::schema::{
+    captcha_answer,
+    person_recent_write,
+    rate_limit_bucket,
+    websocket_message,
+  };
+  info!("Clearing expired shared state...");
+  diesel::delete(captcha_answer::table.filter(captcha_answer::expires.lt(now)))
     .execute(conn)
-    .expect("clear old activities");
+    .expect("clear expired captchas");
+  // Rate limit intervals are at most a few hours long
+  diesel::delete(
+    rate_limit_bucket::table.filter(rate_limit_bucket::last_checked.lt(now - IntervalDsl::days(1))),
+  )
+  .execute(conn)
+  .expect("clear old rate limit buckets");
+  // The other processes read these right after they are created
+  diesel::delete(
+    websocket_message::table
+      .filter(websocket_message::published.lt(now - IntervalDsl::minutes(10))),
+  )
+  .execute(conn)
+  .expect("clear old websocket messages");
+  // Read-your-writes windows are only seconds long
+  diesel::delete(
+    person_recent_write::table.filter(person_recent_write::written.lt(now - IntervalDsl::days(1))),
+  )
+  .execute(conn)
+  .expect("clear old recent writes");
   info!("Done.");
 }
 
 /// Re-calculate the site and community active counts every 12 hours
-fn active_counts(conn: &mut PgConnection) {
+pub(crate) fn active_counts(conn: &mut PgConnection) {
+  let _timer = scheduled_task_timer("active_counts");
   info!("Updating active site and community aggregates ...");
 
   let intervals = vec![
@@ -116,6 +427,7 @@ fn active_counts(conn: &mut PgConnection) {
 
 /// Set banned to false after ban expires
 fn update_banned_when_expired(conn: &mut PgConnection) {
+  let _timer = scheduled_task_timer("update_banned_when_expired");
   info!("Updating banned column if it expires ...");
   let update_ban_expires_stmt =
     "update person set banned = false where banned = true and ban_expires < now()";
@@ -127,6 +439,7 @@ fn update_banned_when_expired(conn: &mut PgConnection) {
 /// Drops the phantom CCNEW indexes created by postgres
 /// https://github.com/LemmyNet/lemmy/issues/2431
 fn drop_ccnew_indexes(conn: &mut PgConnection) {
+  let _timer = scheduled_task_timer("drop_ccnew_indexes");
   info!("Dropping phantom ccnew indexes...");
   let drop_stmt = "select drop_ccnew_indexes()";
   sql_query(drop_stmt)
@@ -136,6 +449,7 @@ fn drop_ccnew_indexes(conn: &mut PgConnection) {
 
 /// Updates the instance software and version
 fn update_instance_software(conn: &mut PgConnection, user_agent: &str) {
+  let _timer = scheduled_task_timer("update_instance_software");
   use lemmy_db_schema::schema::instance;
   info!("Updating instances software and versions...");
 
@@ -179,9 +493,43 @@ fn update_instance_software(conn: &mut PgConnection, user_agent: &str) {
 
 #[cfg(test)]
 mod tests {
+  use crate::scheduled_tasks::enforce_retention;
+  use diesel::{sql_query, Connection, PgConnection, RunQueryDsl};
+  use lemmy_db_schema::utils::get_database_url;
   use lemmy_routes::nodeinfo::NodeInfo;
+  use lemmy_utils::settings::structs::RetentionConfig;
   use reqwest::Client;
 
+  #[test]
+  fn test_enforce_retention() {
+    let conn = &mut PgConnection::establish(&get_database_url(None)).unwrap();
+    sql_query(
+      "insert into activity (data, local, published, ap_id) values
+         ('{}', true, now() - interval '40 days', 'http://example.com/retention/1'),
+         ('{}', true, now() - interval '35 days', 'http://example.com/retention/2'),
+         ('{}', true, now() - interval '20 days', 'http://example.com/retention/3')",
+    )
+    .execute(conn)
+    .unwrap();
+
+    let retention = RetentionConfig {
+      activity_days: Some(30),
+      password_reset_request_days: None,
+      email_verification_days: None,
+      post_read_days: None,
+      registration_application_days: None,
+      remote_content_days: None,
+      batch_size: 1,
+    };
+    enforce_retention(conn, &retention);
+
+    let deleted =
+      sql_query("delete from activity where ap_id like 'http://example.com/retention/%'")
+        .execute(conn)
+        .unwrap();
+    assert_eq!(1, deleted);
+  }
+
   #[tokio::test]
   async fn test_nodeinfo() {
     let client = Client::builder().build().unwrap();
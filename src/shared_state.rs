@@ -0,0 +1,135 @@
+use async_trait::async_trait;
+use diesel::{sql_function, sql_types::BigInt, Connection, PgConnection, RunQueryDsl};
+use futures::{stream, StreamExt};
+use lemmy_api_common::{context::LemmyContext, utils::local_site_rate_limit_to_rate_limit_config};
+use lemmy_db_schema::{
+  source::{local_site_rate_limit::LocalSiteRateLimit, rate_limit_bucket::RateLimitBucket},
+  utils::DbPool,
+};
+use lemmy_utils::{
+  error::{LemmyError, LemmyErrorType},
+  rate_limit::rate_limiter::{RateLimitBackend, RateLimitType},
+  IpAddr,
+};
+use std::{thread, time::Duration};
+use tokio::sync::mpsc;
+use tokio_postgres::{AsyncMessage, NoTls, Notification};
+use tracing::{info, warn};
+
+/// Postgres advisory lock which is held by the process that runs the scheduled tasks
+const SCHEDULED_TASKS_LOCK_ID: i64 = 0x6c656d6d79;
+
+sql_function!(fn pg_try_advisory_lock(key: BigInt) -> Bool);
+
+/// Keeps the rate limit buckets in the database, so that all processes enforce the same limits.
+pub struct PostgresRateLimit {
+  pool: DbPool,
+}
+
+impl PostgresRateLimit {
+  pub fn new(pool: DbPool) -> Self {
+    PostgresRateLimit { pool }
+  }
+}
+
+#[async_trait]
+impl RateLimitBackend for PostgresRateLimit {
+  async fn check_rate_limit(
+    &self,
+    type_: RateLimitType,
+    ip: &IpAddr,
+    rate: i32,
+    per: i32,
+  ) -> Result<bool, LemmyError> {
+    Ok(RateLimitBucket::check(&self.pool, type_.as_ref(), &ip.0, rate, per).await?)
+  }
+}
+
+/// Blocks until this process is elected to run the scheduled tasks. The returned connection holds
+/// a session level advisory lock, which Postgres releases when the process goes away, so that
+/// another process takes over.
+pub fn acquire_scheduled_tasks_lock(db_url: &str) -> PgConnection {
+  let mut conn = PgConnection::establish(db_url).expect("could not establish connection");
+  loop {
+    let acquired = diesel::select(pg_try_advisory_lock(SCHEDULED_TASKS_LOCK_ID))
+      .get_result::<bool>(&mut conn)
+      .unwrap_or(false);
+    if acquired {
+      info!("This process now runs the scheduled tasks");
+      return conn;
+    }
+    thread::sleep(Duration::from_secs(60));
+  }
+}
+
+/// Receives the notifications of the other processes, namely websocket messages to deliver to
+/// the local connections, and changed rate limits. Reconnects if the connection is lost.
+pub async fn listen_for_notifications(db_url: String, context: LemmyContext) {
+  loop {
+    if let Err(e) = listen(&db_url, &context).await {
+      warn!("Lost connection for shared state notifications: {}", e);
+    }
+    tokio::time::sleep(Duration::from_secs(5)).await;
+  }
+}
+
+async fn listen(db_url: &str, context: &LemmyContext) -> Result<(), LemmyError> {
+  let (client, mut connection) = tokio_postgres::connect(db_url, NoTls).await?;
+
+  // The connection needs to be polled for the client to make progress, and it is also the one
+  // which yields the notifications
+  let (tx, mut rx) = mpsc::unbounded_channel::<Notification>();
+  let mut messages = stream::poll_fn(move |cx| connection.poll_message(cx));
+  tokio::spawn(async move {
+    while let Some(message) = messages.next().await {
+      match message {
+        Ok(AsyncMessage::Notification(n)) => {
+          if tx.send(n).is_err() {
+            break;
+          }
+        }
+        Ok(_) => {}
+        Err(e) => {
+          warn!("Shared state connection failed: {}", e);
+          break;
+        }
+      }
+    }
+  });
+
+  client
+    .batch_execute("listen websocket_message; listen local_site_rate_limit")
+    .await?;
+
+  while let Some(notification) = rx.recv().await {
+    if let Err(e) = handle_notification(&notification, context).await {
+      warn!(
+        "Failed to handle {} notification: {}",
+        notification.channel(),
+        e
+      );
+    }
+  }
+  Err(LemmyErrorType::ConnectionClosed.into())
+}
+
+async fn handle_notification(
+  notification: &Notification,
+  context: &LemmyContext,
+) -> Result<(), LemmyError> {
+  match notification.channel() {
+    "websocket_message" => {
+      let message_id = notification.payload().parse()?;
+      context
+        .chat_server()
+        .handle_relayed_message(message_id)
+        .await
+    }
+    "local_site_rate_limit" => {
+      let local_site_rate_limit = LocalSiteRateLimit::read(context.pool()).await?;
+      let config = local_site_rate_limit_to_rate_limit_config(&local_site_rate_limit);
+      context.settings_updated_channel().send(config).await
+    }
+    _ => Ok(()),
+  }
+}